@@ -16,72 +16,140 @@
 //! - Safety monitoring and fault handling
 //! - Workflow orchestration (NEW!)
 
-mod components;
+use score_showcase::components::{
+    check_workflow, install_interrupt_handler, list_workflows, run_benchmarks, run_differential, run_fuzz,
+    run_replay, run_scenario, run_soak, run_state_diagrams, run_tick_budget, run_trace_matrix, run_transition_coverage,
+    run_workflow, CalibrationBook, CalibrationCommand, CountingAllocator, SimRng, ToolCommand,
+};
+use score_showcase::prelude::*;
 
-use components::{CarSystem, EngineComponent, BrakesComponent};
+/// Counts heap usage for the soak test
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 /// Main entry point
 fn main() -> Result<(), String> {
-    let mut car = CarSystem::new();
+    // Resolve defaults → config file → environment → command line
+    let config = match SystemConfig::load() {
+        Ok(cfg) => cfg,
+        Err(e) if e == "HELP" => {
+            SystemConfig::print_help();
+            return Ok(());
+        }
+        Err(e) => {
+            println!("❌ Error: {}", e);
+            SystemConfig::print_help();
+            return Err(e);
+        }
+    };
+
+    // One-shot calibration commands
+    if let Some(command) = &config.calibration_command {
+        let book = if config.calibration.file.is_empty() {
+            CalibrationBook::builtin()
+        } else {
+            CalibrationBook::load(&config.calibration.file)?
+        };
+        return match command {
+            CalibrationCommand::Diff(a, b) => book.print_diff(a, b),
+            CalibrationCommand::Export(path) => {
+                book.save(path)?;
+                println!("🎚️  Calibration sets exported to {}", path);
+                Ok(())
+            }
+        };
+    }
+
+    // One-shot tool modes
+    if let Some(command) = &config.tool_command {
+        return match command {
+            ToolCommand::TransitionCoverage => run_transition_coverage(),
+            ToolCommand::StateDiagrams(path) => run_state_diagrams(path),
+            ToolCommand::Bench(output) => run_benchmarks(&config, output.as_deref()),
+            ToolCommand::DiffRun(a, b) => run_differential(&config, a, b).map(|_| ()),
+            ToolCommand::TraceMatrix(path) => run_trace_matrix(path),
+            ToolCommand::TickBudget(budget_us) => run_tick_budget(&config, *budget_us),
+            ToolCommand::Soak => run_soak(&config),
+            ToolCommand::Fuzz(target) => {
+                run_fuzz(target, config.ticks, config.seed.unwrap_or_else(SimRng::seed_from_time))
+            }
+            ToolCommand::Scenario(path) => run_scenario(&config, path),
+            ToolCommand::Replay(path) => run_replay(&config, path),
+            ToolCommand::WorkflowList => list_workflows(),
+            ToolCommand::WorkflowRun(name) => run_workflow(&config, name),
+            ToolCommand::WorkflowCheck(name) => check_workflow(&config, name),
+        };
+    }
+
+    let ticks = config.ticks;
+    let mut car = CarSystemBuilder::new().with_config(config).build()?;
 
     // Phase 7: Use workflows instead of manual steps
-    println!("\n{}\n", "━".repeat(60));
-    println!("🎭 PHASE 7: Workflow Orchestration Demonstration");
-    println!("{}\n", "━".repeat(60));
+    log_info!("CarSystem", "\n{}\n", "━".repeat(60));
+    log_info!("CarSystem", "🎭 PHASE 7: Workflow Orchestration Demonstration");
+    log_info!("CarSystem", "{}\n", "━".repeat(60));
 
     // 1. Initialize components
     car.initialize()?;
 
     // 2. Execute Start Car workflow
     let start_workflow = CarSystem::create_start_workflow();
-    start_workflow.execute(&mut car)?;
+    start_workflow.execute(&mut car).result()?;
 
-    // 3. Run event loop
-    car.run_event_loop(30)?;
+    // 3. Run event loop; Ctrl+C stops it after the current tick
+    if let Err(e) = install_interrupt_handler(car.shutdown_handle()) {
+        log_warn!("CarSystem", "⚠️  {} - Ctrl+C will stop the process immediately", e);
+    }
+    car.run_event_loop(ticks)?;
+
+    // Interrupted: shut down cleanly and skip the rest of the demo
+    if car.shutdown_requested() {
+        CarSystem::create_shutdown_workflow().execute(&mut car).result()?;
+        return car.shutdown();
+    }
 
     // 4. Execute Shutdown workflow
-    println!("\n{}", "━".repeat(60));
-    println!("🎭 Executing Shutdown Workflow...");
-    println!("{}\n", "━".repeat(60));
+    log_info!("CarSystem", "\n{}", "━".repeat(60));
+    log_info!("CarSystem", "🎭 Executing Shutdown Workflow...");
+    log_info!("CarSystem", "{}\n", "━".repeat(60));
 
     let shutdown_workflow = CarSystem::create_shutdown_workflow();
-    shutdown_workflow.execute(&mut car)?;
+    shutdown_workflow.execute(&mut car).result()?;
 
     // 5. Demo: Emergency Stop workflow
-    println!("\n{}", "━".repeat(60));
-    println!("🚨 EMERGENCY STOP WORKFLOW (Demo)");
-    println!("{}\n", "━".repeat(60));
+    log_info!("CarSystem", "\n{}", "━".repeat(60));
+    log_info!("CarSystem", "🚨 EMERGENCY STOP WORKFLOW (Demo)");
+    log_info!("CarSystem", "{}\n", "━".repeat(60));
 
     let emergency_workflow = CarSystem::create_emergency_stop_workflow();
 
-    // Re-initialize for demo
-    car.engine = EngineComponent::new();
-    car.brakes = BrakesComponent::new();
+    // Re-initialize for demo (keeping persisted service counters)
+    car.replace_engine_and_brakes()?;
 
-    emergency_workflow.execute(&mut car)?;
+    emergency_workflow.execute(&mut car).result()?;
 
     car.shutdown()?;
 
-    println!("\n╔══════════════════════════════════════════════════════════════╗");
-    println!("║           ✅ Phase 7 Complete!                               ║");
-    println!("║                                                                ║");
-    println!("║  You've learned:                                              ║");
-    println!("║  ✓ Component-based architecture                               ║");
-    println!("║  ✓ Component lifecycle management                             ║");
-    println!("║  ✓ State management                                           ║");
-    println!("║  ✓ Multi-component orchestration                              ║");
-    println!("║  ✓ Message-based communication                                ║");
-    println!("║  ✓ Publish-subscribe pattern                                  ║");
-    println!("║  ✓ State machine pattern                                      ║");
-    println!("║  ✓ Valid state transitions                                    ║");
-    println!("║  ✓ Event loop for continuous processing                        ║");
-    println!("║  ✓ Real-time tick-based processing                             ║");
-    println!("║  ✓ Safety monitoring                                         ║");
-    println!("║  ✓ Fault handling with severity levels                         ║");
-    println!("║  ✓ ISO 26262 style safety checks                             ║");
-    println!("║  ✓ Workflow orchestration (NEW!)                              ║");
-    println!("║  ✓ Sequential action execution (NEW!)                         ║");
-    println!("╚══════════════════════════════════════════════════════════════╝\n");
+    log_info!("CarSystem", "\n╔══════════════════════════════════════════════════════════════╗");
+    log_info!("CarSystem", "║           ✅ Phase 7 Complete!                               ║");
+    log_info!("CarSystem", "║                                                                ║");
+    log_info!("CarSystem", "║  You've learned:                                              ║");
+    log_info!("CarSystem", "║  ✓ Component-based architecture                               ║");
+    log_info!("CarSystem", "║  ✓ Component lifecycle management                             ║");
+    log_info!("CarSystem", "║  ✓ State management                                           ║");
+    log_info!("CarSystem", "║  ✓ Multi-component orchestration                              ║");
+    log_info!("CarSystem", "║  ✓ Message-based communication                                ║");
+    log_info!("CarSystem", "║  ✓ Publish-subscribe pattern                                  ║");
+    log_info!("CarSystem", "║  ✓ State machine pattern                                      ║");
+    log_info!("CarSystem", "║  ✓ Valid state transitions                                    ║");
+    log_info!("CarSystem", "║  ✓ Event loop for continuous processing                        ║");
+    log_info!("CarSystem", "║  ✓ Real-time tick-based processing                             ║");
+    log_info!("CarSystem", "║  ✓ Safety monitoring                                         ║");
+    log_info!("CarSystem", "║  ✓ Fault handling with severity levels                         ║");
+    log_info!("CarSystem", "║  ✓ ISO 26262 style safety checks                             ║");
+    log_info!("CarSystem", "║  ✓ Workflow orchestration (NEW!)                              ║");
+    log_info!("CarSystem", "║  ✓ Sequential action execution (NEW!)                         ║");
+    log_info!("CarSystem", "╚══════════════════════════════════════════════════════════════╝\n");
 
     Ok(())
 }
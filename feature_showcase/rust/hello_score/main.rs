@@ -6,81 +6,7 @@
 
 use std::env;
 
-/// S-CORE style log level
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum LogLevel {
-    Error,
-    Warn,
-    Info,
-    Debug,
-    Trace,
-}
-
-impl LogLevel {
-    fn as_str(&self) -> &'static str {
-        match self {
-            LogLevel::Error => "ERROR",
-            LogLevel::Warn => "WARN ",
-            LogLevel::Info => "INFO ",
-            LogLevel::Debug => "DEBUG",
-            LogLevel::Trace => "TRACE",
-        }
-    }
-}
-
-/// S-CORE style structured log entry
-struct LogEntry {
-    level: LogLevel,
-    component: String,
-    message: String,
-    timestamp: u64,
-}
-
-impl LogEntry {
-    fn new(level: LogLevel, component: &str, message: &str) -> Self {
-        Self {
-            level,
-            component: component.to_string(),
-            message: message.to_string(),
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_millis() as u64,
-        }
-    }
-    
-    fn format(&self) -> String {
-        format!("[{:?}] {}: {}", self.level, self.component, self.message)
-    }
-}
-
-/// S-CORE style logger
-struct ScoreLogger {
-    min_level: LogLevel,
-    component: String,
-}
-
-impl ScoreLogger {
-    fn new(component: &str, min_level: LogLevel) -> Self {
-        Self {
-            min_level,
-            component: component.to_string(),
-        }
-    }
-    
-    fn log(&self, level: LogLevel, message: &str) {
-        if level <= self.min_level {
-            let entry = LogEntry::new(level, &self.component, message);
-            println!("{}", entry.format());
-        }
-    }
-    
-    fn error(&self, message: &str) { self.log(LogLevel::Error, message); }
-    fn warn(&self, message: &str) { self.log(LogLevel::Warn, message); }
-    fn info(&self, message: &str) { self.log(LogLevel::Info, message); }
-    fn debug(&self, message: &str) { self.log(LogLevel::Debug, message); }
-    fn trace(&self, message: &str) { self.log(LogLevel::Trace, message); }
-}
+use score_showcase::prelude::*;
 
 /// S-CORE style configuration
 struct ScoreConfig {
@@ -88,17 +14,6 @@ struct ScoreConfig {
     log_level: LogLevel,
 }
 
-fn parse_log_level(s: &str) -> Result<LogLevel, String> {
-    match s.to_uppercase().as_str() {
-        "ERROR" => Ok(LogLevel::Error),
-        "WARN" => Ok(LogLevel::Warn),
-        "INFO" => Ok(LogLevel::Info),
-        "DEBUG" => Ok(LogLevel::Debug),
-        "TRACE" => Ok(LogLevel::Trace),
-        _ => Err(format!("Invalid log level: {}", s)),
-    }
-}
-
 impl ScoreConfig {
     fn from_args() -> Result<Self, String> {
         let args: Vec<String> = env::args().collect();
@@ -119,7 +34,7 @@ impl ScoreConfig {
                 }
                 "--log-level" | "-l" => {
                     if i + 1 < args.len() {
-                        log_level = parse_log_level(&args[i + 1])?;
+                        log_level = LogLevel::parse(&args[i + 1])?;
                         i += 2;
                     } else {
                         return Err("--log-level requires a value".to_string());
@@ -177,11 +92,11 @@ impl ScoreComponent {
         self.counter += 1;
         self.logger.debug(&format!("Counter incremented to {}", self.counter));
         
-        if self.counter % 10 == 0 {
+        if self.counter.is_multiple_of(10) {
             self.logger.warn(&format!("Counter reached milestone: {}", self.counter));
         }
         
-        if self.counter % 100 == 0 {
+        if self.counter.is_multiple_of(100) {
             self.logger.info(&format!("Counter reached century: {}", self.counter));
         }
         
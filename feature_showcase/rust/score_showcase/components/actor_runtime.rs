@@ -0,0 +1,743 @@
+//! Actor runtime - components on their own threads
+//! Demonstrates S-CORE deployment patterns:
+//! - Actors: each component runs on its own thread and owns its state; it
+//!   only talks to the rest of the car through its links (inbox and
+//!   outbox), never through another component's fields
+//! - Lock-free messaging: every sender-receiver pair has its own SPSC ring,
+//!   so publishing is wait-free and an inbox is drained with one index
+//!   update per sender; no actor ever waits on a bus lock
+//! - Supervision: the supervisor paces the actors tick by tick, reports the
+//!   ones that miss the tick deadline and restarts the ones whose thread died
+//! - Ownership hand-back: stopping the runtime returns every component and
+//!   the message bus to the supervisor, so single-threaded code can carry
+//!   on where the actors stopped
+//!
+//! The rings are laid out once, when the runtime starts, following the bus
+//! subscriptions: a published message goes into the ring of every
+//! subscribed participant, a directed one into the addressee's ring. A
+//! full ring drops the message and counts it. Messages still in the rings
+//! when the runtime stops are moved to the bus queues.
+//!
+//! One tick: the supervisor sends `Tick` to every actor; each actor drains
+//! its inbox into `ComponentActor::handle`, runs `process`, publishes its
+//! outbox and reports back with its component's status (signals, raw
+//! sensor readings and health). Messages published during a tick are
+//! handled in the next one.
+
+use std::any::Any;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::components::{
+    spsc_ring, BrakesComponent, CarComponent, CarMessage, ComponentId, Consumer, DashboardComponent,
+    EngineComponent, HealthStatus, MessageBus, Producer, SteeringComponent, Subscription,
+};
+use crate::log_info;
+
+/// Messages one ring holds; a sender publishes a handful per tick
+const RING_CAPACITY: usize = 64;
+
+type RingProducer = Producer<CarMessage, RING_CAPACITY>;
+type RingConsumer = Consumer<CarMessage, RING_CAPACITY>;
+
+/// A component that can run as an actor
+pub trait ComponentActor: CarComponent + Send + 'static {
+    /// React to a message from the inbox (command or subscribed event)
+    fn handle(&mut self, message: &CarMessage) -> Result<(), String>;
+
+    /// Append the messages to publish after processing
+    fn collect_messages(&self, messages: &mut Vec<CarMessage>);
+
+    /// Fill in the status fields of this component after processing
+    fn report_status(&self, status: &mut ActorStatus);
+}
+
+/// What the actors report about their components after a tick
+/// Every actor fills in the fields of its own component; the supervisor
+/// builds its snapshot and the safety inputs from the merged status.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ActorStatus {
+    pub rpm: Option<u32>,
+    pub temperature: Option<f32>,
+    /// Raw readings of the redundant temperature sensors
+    pub temperature_readings: Vec<f32>,
+    /// Interned state name (see `EngineStateMachine::as_str`)
+    pub engine_state: Option<&'static str>,
+    pub brake_pressure: Option<u8>,
+    /// Raw readings of the redundant brake pressure sensors
+    pub pressure_readings: Vec<f32>,
+    pub steering_angle: Option<i16>,
+    pub odometer: Option<f32>,
+    pub fuel_level: Option<u8>,
+    pub warnings: Option<Vec<String>>,
+}
+
+impl ActorStatus {
+    /// Take over every field `other` reports
+    fn merge(&mut self, other: ActorStatus) {
+        self.rpm = other.rpm.or(self.rpm);
+        self.temperature = other.temperature.or(self.temperature);
+        if !other.temperature_readings.is_empty() {
+            self.temperature_readings = other.temperature_readings;
+        }
+        self.engine_state = other.engine_state.or(self.engine_state);
+        self.brake_pressure = other.brake_pressure.or(self.brake_pressure);
+        if !other.pressure_readings.is_empty() {
+            self.pressure_readings = other.pressure_readings;
+        }
+        self.steering_angle = other.steering_angle.or(self.steering_angle);
+        self.odometer = other.odometer.or(self.odometer);
+        self.fuel_level = other.fuel_level.or(self.fuel_level);
+        if other.warnings.is_some() {
+            self.warnings = other.warnings;
+        }
+    }
+}
+
+impl ComponentActor for EngineComponent {
+    fn handle(&mut self, message: &CarMessage) -> Result<(), String> {
+        match message {
+            CarMessage::EngineStart => self.start(),
+            CarMessage::EngineStop => self.stop(),
+            other => {
+                self.process_messages(slice::from_ref(other));
+                Ok(())
+            }
+        }
+    }
+
+    fn collect_messages(&self, messages: &mut Vec<CarMessage>) {
+        self.get_messages_into(messages);
+    }
+
+    fn report_status(&self, status: &mut ActorStatus) {
+        status.rpm = Some(self.get_rpm());
+        status.temperature = Some(self.get_temperature());
+        status.temperature_readings.extend_from_slice(self.temperature_readings());
+        status.engine_state = Some(self.get_engine_state().as_str());
+    }
+}
+
+impl ComponentActor for BrakesComponent {
+    fn handle(&mut self, message: &CarMessage) -> Result<(), String> {
+        match *message {
+            CarMessage::BrakeApply { pressure } => self.apply(pressure),
+            CarMessage::BrakeRelease => {
+                self.release();
+                Ok(())
+            }
+            CarMessage::SpeedUpdate { km_h } => {
+                self.set_vehicle_speed(km_h);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn collect_messages(&self, messages: &mut Vec<CarMessage>) {
+        self.get_messages_into(messages);
+    }
+
+    fn report_status(&self, status: &mut ActorStatus) {
+        status.brake_pressure = Some(self.get_pressure());
+        status.pressure_readings.extend_from_slice(self.pressure_readings());
+    }
+}
+
+impl ComponentActor for SteeringComponent {
+    fn handle(&mut self, message: &CarMessage) -> Result<(), String> {
+        match *message {
+            CarMessage::SteeringTurn { angle } => self.turn(angle),
+            CarMessage::SteeringCenter => {
+                self.center();
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn collect_messages(&self, messages: &mut Vec<CarMessage>) {
+        self.get_messages_into(messages);
+    }
+
+    fn report_status(&self, status: &mut ActorStatus) {
+        status.steering_angle = Some(self.get_angle());
+    }
+}
+
+impl ComponentActor for DashboardComponent {
+    fn handle(&mut self, message: &CarMessage) -> Result<(), String> {
+        if let CarMessage::SpeedUpdate { km_h } = *message {
+            self.set_speed(km_h);
+            self.update_odometer(km_h as f32 / 10.0);
+        }
+        self.process_messages(slice::from_ref(message));
+        Ok(())
+    }
+
+    /// The dashboard only displays; it publishes nothing
+    fn collect_messages(&self, _messages: &mut Vec<CarMessage>) {}
+
+    fn report_status(&self, status: &mut ActorStatus) {
+        status.odometer = Some(self.get_odometer());
+        status.fuel_level = Some(self.get_fuel_level());
+        status.warnings = Some(self.get_warnings().to_vec());
+    }
+}
+
+/// Sending end towards one receiver
+struct Outbound {
+    to: ComponentId,
+    /// What the receiver gets from `publish`; directed messages always go
+    subscription: Option<Subscription>,
+    ring: RingProducer,
+}
+
+/// One participant's ends of the rings: an inbound ring from every other
+/// participant and an outbound ring to every other participant
+/// Only the owning thread uses them, so nothing here takes a lock.
+pub struct ActorLinks {
+    id: ComponentId,
+    inbound: Vec<(ComponentId, RingConsumer)>,
+    outbound: Vec<Outbound>,
+    trace: bool,
+    /// Messages dropped because a ring was full, since the last tick
+    dropped: u64,
+}
+
+impl ActorLinks {
+    fn new(id: ComponentId, trace: bool) -> Self {
+        Self {
+            id,
+            inbound: Vec::new(),
+            outbound: Vec::new(),
+            trace,
+            dropped: 0,
+        }
+    }
+
+    pub fn id(&self) -> ComponentId {
+        self.id
+    }
+
+    /// Publish a message to every subscribed participant without blocking
+    pub fn publish(&mut self, message: CarMessage) {
+        if self.trace {
+            log_info!("MessageBus", "  📨 [{}] → {}", self.id.as_str(), message.format());
+        }
+        for link in &mut self.outbound {
+            if link.subscription.as_ref().is_some_and(|s| s.wants(&message))
+                && link.ring.push(message.clone()).is_err()
+            {
+                self.dropped += 1;
+            }
+        }
+    }
+
+    /// Publish a batch of messages, draining `messages`
+    pub fn publish_batch(&mut self, messages: &mut Vec<CarMessage>) {
+        for message in messages.drain(..) {
+            self.publish(message);
+        }
+    }
+
+    /// Send a message to one participant only, without blocking
+    pub fn send_to(&mut self, to: ComponentId, message: CarMessage) -> Result<(), String> {
+        if self.trace {
+            log_info!("MessageBus", "  📨 [{}] → {}: {}", self.id, to, message.format());
+        }
+        let Some(link) = self.outbound.iter_mut().find(|link| link.to == to) else {
+            return Err(format!("Cannot send to {}: not linked to {}", to, self.id));
+        };
+        link.ring.push(message).map_err(|message| {
+            self.dropped += 1;
+            format!("Cannot send {} to {}: ring full", message.type_name(), to)
+        })
+    }
+
+    /// Append all pending messages to a reused buffer, sender by sender
+    pub fn receive_all_into(&mut self, out: &mut Vec<CarMessage>) {
+        for (_, ring) in &mut self.inbound {
+            ring.drain_into(out);
+        }
+    }
+
+    /// Messages dropped since the last call
+    fn take_dropped(&mut self) -> u64 {
+        mem::take(&mut self.dropped)
+    }
+
+    /// Move the messages still in the inbound rings to the bus queue
+    fn hand_back(&mut self, bus: &mut MessageBus) {
+        for (from, ring) in &mut self.inbound {
+            while let Some(message) = ring.pop() {
+                // Every participant is registered, so this cannot fail
+                let _ = bus.send_to(*from, self.id, message);
+            }
+        }
+    }
+}
+
+/// Links for every participant, one ring per ordered pair
+/// Routing follows the bus subscriptions at the time of the call.
+fn link_all(bus: &MessageBus, ids: &[ComponentId]) -> Vec<ActorLinks> {
+    let mut links: Vec<ActorLinks> = ids.iter().map(|&id| ActorLinks::new(id, bus.is_tracing())).collect();
+    for from in 0..ids.len() {
+        for to in 0..ids.len() {
+            if from == to {
+                continue;
+            }
+            let (producer, consumer) = spsc_ring();
+            links[from].outbound.push(Outbound {
+                to: ids[to],
+                subscription: bus.subscription(ids[to]).cloned(),
+                ring: producer,
+            });
+            links[to].inbound.push((ids[from], consumer));
+        }
+    }
+    links
+}
+
+/// What the supervisor tells an actor
+enum Command {
+    Tick(u64),
+    Stop,
+}
+
+/// An actor's answer to one tick
+struct Report {
+    id: ComponentId,
+    tick: u64,
+    result: Result<(), String>,
+    status: ActorStatus,
+    health: HealthStatus,
+    dropped: u64,
+}
+
+/// Outcome of one supervised tick
+#[derive(Debug, Default)]
+pub struct TickOutcome {
+    /// Actors that didn't report before the deadline
+    pub missed: Vec<&'static str>,
+    /// Actors whose message handling or processing failed, with the error
+    pub errors: Vec<(&'static str, String)>,
+    /// Actors restarted because their thread died, with the reason
+    pub restarted: Vec<(&'static str, String)>,
+    /// Messages dropped because a ring was full
+    pub dropped: u64,
+    /// Status reported by the actors that answered in time
+    pub status: ActorStatus,
+    /// Health of the actors that answered in time
+    pub health: Vec<(&'static str, HealthStatus)>,
+}
+
+/// What an actor thread leaves behind: its links, and its component unless
+/// it panicked (then the panic message)
+type ActorExit = (ActorLinks, Result<Box<dyn Any + Send>, String>);
+
+type ActorThread = (Sender<Command>, JoinHandle<ActorExit>);
+
+/// Components handed back by `stop`, with their ids
+type Components = Vec<(ComponentId, Box<dyn Any + Send>)>;
+
+/// Starts a fresh actor in place of one whose thread died
+type Respawn = Box<dyn Fn(ActorLinks, Sender<Report>) -> Result<ActorThread, String>>;
+
+struct Actor {
+    id: ComponentId,
+    name: &'static str,
+    commands: Sender<Command>,
+    thread: JoinHandle<ActorExit>,
+    respawn: Respawn,
+}
+
+/// Runs components as actors and supervises them
+pub struct ActorRuntime {
+    bus: MessageBus,
+    /// Links the supervisor sends commands and reads events with
+    supervisor: ActorLinks,
+    /// Links of participants not spawned yet
+    unspawned: Vec<ActorLinks>,
+    actors: Vec<Actor>,
+    reports: Sender<Report>,
+    results: Receiver<Report>,
+    restarts: u64,
+    missed_deadlines: u64,
+    dropped_messages: u64,
+}
+
+impl ActorRuntime {
+    /// Runtime for the components `actors`, supervised by the component
+    /// `supervisor`
+    /// All of them must be registered on the bus, which the runtime holds
+    /// until it stops. Messages already queued for an actor are moved to
+    /// its inbox.
+    pub fn new(mut bus: MessageBus, supervisor: ComponentId, actors: &[ComponentId]) -> Self {
+        let mut ids = vec![supervisor];
+        ids.extend_from_slice(actors);
+        let mut unspawned = link_all(&bus, &ids);
+        let mut supervisor = unspawned.remove(0);
+
+        let mut pending = Vec::new();
+        for &id in actors {
+            bus.receive_all_into(id, &mut pending);
+            for message in pending.drain(..) {
+                // A fresh ring only overflows with a backlog; the drop is counted
+                let _ = supervisor.send_to(id, message);
+            }
+        }
+
+        let (reports, results) = mpsc::channel();
+        Self {
+            bus,
+            supervisor,
+            unspawned,
+            actors: Vec::new(),
+            reports,
+            results,
+            restarts: 0,
+            missed_deadlines: 0,
+            dropped_messages: 0,
+        }
+    }
+
+    /// Move an initialized component to its own thread
+    /// `id` must be one of the runtime's actors. If the thread dies, the
+    /// actor is replaced by an initialized `restart()`.
+    pub fn spawn<A: ComponentActor>(&mut self, id: ComponentId, actor: A, restart: fn() -> A) -> Result<(), String> {
+        let name = actor.name();
+        let Some(index) = self.unspawned.iter().position(|links| links.id == id) else {
+            return Err(format!("Cannot spawn {}: not an actor of this runtime, or already running", name));
+        };
+        let links = self.unspawned.swap_remove(index);
+        let (commands, thread) = start_thread(actor, links, self.reports.clone())?;
+        self.actors.push(Actor {
+            id,
+            name,
+            commands,
+            thread,
+            respawn: Box::new(move |links, reports| {
+                let mut actor = restart();
+                actor.initialize()?;
+                start_thread(actor, links, reports)
+            }),
+        });
+        Ok(())
+    }
+
+    pub fn bus(&self) -> &MessageBus {
+        &self.bus
+    }
+
+    /// The message bus, for what doesn't go through the rings (cycle
+    /// bookkeeping, diagnostics)
+    pub fn bus_mut(&mut self) -> &mut MessageBus {
+        &mut self.bus
+    }
+
+    /// The supervisor's own links
+    pub fn supervisor(&mut self) -> &mut ActorLinks {
+        &mut self.supervisor
+    }
+
+    /// Number of running actors
+    pub fn len(&self) -> usize {
+        self.actors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actors.is_empty()
+    }
+
+    /// Actors restarted so far
+    pub fn restarts(&self) -> u64 {
+        self.restarts
+    }
+
+    /// Ticks missed by any actor so far
+    pub fn missed_deadlines(&self) -> u64 {
+        self.missed_deadlines
+    }
+
+    /// Messages dropped on full rings so far
+    pub fn dropped_messages(&self) -> u64 {
+        self.dropped_messages
+    }
+
+    /// Run one tick on every actor and wait up to `deadline` for them
+    /// Reports arriving after the deadline are dropped with the next tick.
+    pub fn tick(&mut self, tick: u64, deadline: Duration) -> TickOutcome {
+        let mut outcome = TickOutcome {
+            dropped: self.supervisor.take_dropped(),
+            ..TickOutcome::default()
+        };
+        self.restart_dead(&mut outcome);
+
+        let mut pending = Vec::with_capacity(self.actors.len());
+        for actor in &self.actors {
+            if actor.commands.send(Command::Tick(tick)).is_ok() {
+                pending.push(actor.id);
+            }
+        }
+        let deadline = Instant::now() + deadline;
+        while !pending.is_empty() {
+            let Some(wait) = deadline.checked_duration_since(Instant::now()) else {
+                break;
+            };
+            let Ok(report) = self.results.recv_timeout(wait) else {
+                break;
+            };
+            // Late reports still count their drops
+            outcome.dropped += report.dropped;
+            if report.tick != tick {
+                continue;
+            }
+            pending.retain(|id| *id != report.id);
+            let name = self.name_of(report.id);
+            if let Err(error) = report.result {
+                outcome.errors.push((name, error));
+            }
+            outcome.status.merge(report.status);
+            outcome.health.push((name, report.health));
+        }
+
+        self.missed_deadlines += pending.len() as u64;
+        self.dropped_messages += outcome.dropped;
+        outcome.missed = pending.into_iter().map(|id| self.name_of(id)).collect();
+        outcome
+    }
+
+    /// Stop all actors and hand the bus and their components back
+    /// Components whose thread died are missing from the result.
+    pub fn stop(mut self) -> (MessageBus, Components) {
+        for actor in &self.actors {
+            // An actor that already died has dropped its receiver
+            let _ = actor.commands.send(Command::Stop);
+        }
+        let mut components = Vec::with_capacity(self.actors.len());
+        for actor in self.actors {
+            let Ok((mut links, component)) = actor.thread.join() else {
+                continue;
+            };
+            links.hand_back(&mut self.bus);
+            if let Ok(component) = component {
+                components.push((actor.id, component));
+            }
+        }
+        self.supervisor.hand_back(&mut self.bus);
+        for links in &mut self.unspawned {
+            links.hand_back(&mut self.bus);
+        }
+        (self.bus, components)
+    }
+
+    /// Replace every actor whose thread ended
+    /// An actor that cannot be restarted is removed.
+    fn restart_dead(&mut self, outcome: &mut TickOutcome) {
+        for actor in mem::take(&mut self.actors) {
+            if !actor.thread.is_finished() {
+                self.actors.push(actor);
+                continue;
+            }
+            let Ok((links, component)) = actor.thread.join() else {
+                outcome.errors.push((actor.name, "restart failed: links lost".to_string()));
+                continue;
+            };
+            match (actor.respawn)(links, self.reports.clone()) {
+                Ok((commands, thread)) => {
+                    let reason = component.map_or_else(|panic| panic, |_| "stopped".to_string());
+                    outcome.restarted.push((actor.name, reason));
+                    self.restarts += 1;
+                    self.actors.push(Actor {
+                        commands,
+                        thread,
+                        ..actor
+                    });
+                }
+                Err(e) => outcome.errors.push((actor.name, format!("restart failed: {}", e))),
+            }
+        }
+    }
+
+    fn name_of(&self, id: ComponentId) -> &'static str {
+        self.actors
+            .iter()
+            .find(|actor| actor.id == id)
+            .map_or("unknown", |actor| actor.name)
+    }
+}
+
+/// Start an actor's thread
+/// The thread catches the actor's panic so its links survive for a restart.
+fn start_thread<A: ComponentActor>(actor: A, links: ActorLinks, reports: Sender<Report>) -> Result<ActorThread, String> {
+    let name = actor.name();
+    let (commands, received) = mpsc::channel();
+    let thread = thread::Builder::new()
+        .name(format!("actor-{}", name.to_lowercase()))
+        .spawn(move || {
+            let mut links = links;
+            let component = panic::catch_unwind(AssertUnwindSafe(|| run_actor(actor, &mut links, received, reports)))
+                .map(|actor| Box::new(actor) as Box<dyn Any + Send>)
+                .map_err(|panic| panic_message(panic.as_ref()));
+            (links, component)
+        })
+        .map_err(|e| format!("Cannot start {} actor: {}", name, e))?;
+    Ok((commands, thread))
+}
+
+/// Actor thread body: one cycle per `Tick` until told to stop
+fn run_actor<A: ComponentActor>(
+    mut actor: A,
+    links: &mut ActorLinks,
+    commands: Receiver<Command>,
+    reports: Sender<Report>,
+) -> A {
+    let mut inbox = Vec::with_capacity(RING_CAPACITY);
+    let mut outbox = Vec::new();
+    while let Ok(Command::Tick(tick)) = commands.recv() {
+        links.receive_all_into(&mut inbox);
+        let mut result = Ok(());
+        for message in inbox.drain(..) {
+            let handled = actor.handle(&message);
+            result = result.and(handled);
+        }
+        let result = result.and(actor.process());
+        actor.collect_messages(&mut outbox);
+        links.publish_batch(&mut outbox);
+        let mut status = ActorStatus::default();
+        actor.report_status(&mut status);
+
+        let report = Report {
+            id: links.id,
+            tick,
+            result,
+            status,
+            health: actor.health_check(),
+            dropped: links.take_dropped(),
+        };
+        if reports.send(report).is_err() {
+            break;
+        }
+    }
+    actor
+}
+
+/// Text of a panic payload
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::ComponentState;
+
+    /// Publishes every message it handles; panics on `BrakeRelease`
+    #[derive(Default)]
+    struct Echo {
+        handled: Vec<CarMessage>,
+    }
+
+    impl CarComponent for Echo {
+        fn name(&self) -> &'static str {
+            "Echo"
+        }
+
+        fn initialize(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn process(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> ComponentState {
+            ComponentState::Online
+        }
+    }
+
+    impl ComponentActor for Echo {
+        fn handle(&mut self, message: &CarMessage) -> Result<(), String> {
+            if *message == CarMessage::BrakeRelease {
+                panic!("echo told to crash");
+            }
+            self.handled.push(message.clone());
+            Ok(())
+        }
+
+        fn collect_messages(&self, messages: &mut Vec<CarMessage>) {
+            messages.extend(self.handled.iter().cloned());
+        }
+
+        fn report_status(&self, status: &mut ActorStatus) {
+            status.rpm = Some(self.handled.len() as u32);
+        }
+    }
+
+    fn runtime_with_echo() -> ActorRuntime {
+        let mut bus = MessageBus::new();
+        bus.set_trace(false);
+        bus.register_component(ComponentId::CAR_SYSTEM);
+        bus.register_component(ComponentId::ENGINE);
+        bus.subscribe_all(ComponentId::CAR_SYSTEM);
+        let mut runtime = ActorRuntime::new(bus, ComponentId::CAR_SYSTEM, &[ComponentId::ENGINE]);
+        runtime.spawn(ComponentId::ENGINE, Echo::default(), Echo::default).unwrap();
+        runtime
+    }
+
+    fn received(runtime: &mut ActorRuntime) -> Vec<CarMessage> {
+        let mut inbox = Vec::new();
+        runtime.supervisor().receive_all_into(&mut inbox);
+        inbox
+    }
+
+    #[test]
+    fn messages_go_through_the_rings() {
+        let mut runtime = runtime_with_echo();
+        let speed = CarMessage::SpeedUpdate { km_h: 50 };
+        runtime.supervisor().send_to(ComponentId::ENGINE, speed.clone()).unwrap();
+
+        let outcome = runtime.tick(0, Duration::from_secs(5));
+        assert!(outcome.missed.is_empty() && outcome.errors.is_empty());
+        assert_eq!(received(&mut runtime), vec![speed]);
+        // Nothing went through the bus queues
+        assert_eq!(runtime.bus().total_pending(), 0);
+        runtime.stop();
+    }
+
+    #[test]
+    fn a_restarted_actor_keeps_its_links() {
+        let mut runtime = runtime_with_echo();
+        runtime.supervisor().send_to(ComponentId::ENGINE, CarMessage::BrakeRelease).unwrap();
+        let outcome = runtime.tick(0, Duration::from_millis(200));
+        assert_eq!(outcome.missed, vec!["Echo"]);
+
+        let speed = CarMessage::SpeedUpdate { km_h: 30 };
+        runtime.supervisor().send_to(ComponentId::ENGINE, speed.clone()).unwrap();
+        let outcome = runtime.tick(1, Duration::from_secs(5));
+        assert_eq!(outcome.restarted, vec![("Echo", "echo told to crash".to_string())]);
+        assert_eq!(received(&mut runtime), vec![speed]);
+        runtime.stop();
+    }
+
+    #[test]
+    fn stopping_hands_undelivered_messages_to_the_bus() {
+        let mut runtime = runtime_with_echo();
+        runtime.supervisor().send_to(ComponentId::ENGINE, CarMessage::SteeringCenter).unwrap();
+
+        let (mut bus, components) = runtime.stop();
+        assert_eq!(components.len(), 1);
+        assert_eq!(bus.receive_all(ComponentId::ENGINE), vec![CarMessage::SteeringCenter]);
+    }
+}
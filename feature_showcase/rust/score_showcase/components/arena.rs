@@ -0,0 +1,98 @@
+//! Bump arena for values that live as long as their owner
+//! Demonstrates S-CORE deterministic memory patterns:
+//! - Many small objects placed in a few large chunks instead of one heap
+//!   allocation each
+//! - Allocation is a pointer bump; everything is freed at once
+//! - Destructors still run, in reverse allocation order
+//!
+//! Used by workflows to store step closures. Returned pointers stay valid
+//! until the arena is dropped (chunks are never moved or freed earlier),
+//! so the owner must keep the arena alive as long as it uses them.
+
+use std::mem::{self, MaybeUninit};
+use std::ptr::{self, NonNull};
+
+/// Bytes per chunk
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// Alignment every chunk provides
+const CHUNK_ALIGN: usize = mem::align_of::<u128>();
+
+type Chunk = Box<[MaybeUninit<u128>]>;
+
+/// Type-erased destructor of one arena value
+struct DropEntry {
+    ptr: *mut u8,
+    drop: unsafe fn(*mut u8),
+}
+
+unsafe fn drop_value<T>(ptr: *mut u8) {
+    ptr::drop_in_place(ptr as *mut T);
+}
+
+unsafe fn drop_boxed<T>(ptr: *mut u8) {
+    drop(Box::from_raw(ptr as *mut T));
+}
+
+/// Chunked bump allocator
+#[derive(Default)]
+pub struct BumpArena {
+    chunks: Vec<Chunk>,
+    /// Bytes used in the last chunk
+    offset: usize,
+    drops: Vec<DropEntry>,
+    bytes: usize,
+}
+
+impl BumpArena {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move a value into the arena
+    /// Values too large or too aligned for a chunk are boxed individually.
+    pub fn alloc<T>(&mut self, value: T) -> NonNull<T> {
+        let (size, align) = (mem::size_of::<T>(), mem::align_of::<T>());
+        if size > CHUNK_SIZE || align > CHUNK_ALIGN {
+            let ptr = Box::into_raw(Box::new(value));
+            self.drops.push(DropEntry { ptr: ptr as *mut u8, drop: drop_boxed::<T> });
+            return unsafe { NonNull::new_unchecked(ptr) };
+        }
+
+        let mut start = self.offset.next_multiple_of(align);
+        if self.chunks.is_empty() || start + size > CHUNK_SIZE {
+            self.chunks.push(Box::new_uninit_slice(CHUNK_SIZE / CHUNK_ALIGN));
+            start = 0;
+        }
+        let chunk = self.chunks.last_mut().expect("arena has a chunk");
+        // Safety: start + size is within the chunk and start is aligned for T
+        let ptr = unsafe { (chunk.as_mut_ptr() as *mut u8).add(start) as *mut T };
+        unsafe { ptr.write(value) };
+        self.offset = start + size;
+        self.bytes += size;
+
+        if mem::needs_drop::<T>() {
+            self.drops.push(DropEntry { ptr: ptr as *mut u8, drop: drop_value::<T> });
+        }
+        unsafe { NonNull::new_unchecked(ptr) }
+    }
+
+    /// Bytes of values stored in chunks
+    pub fn allocated_bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Number of chunks allocated
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+}
+
+impl Drop for BumpArena {
+    fn drop(&mut self) {
+        for entry in self.drops.drain(..).rev() {
+            // Safety: every entry points at a live value written by alloc()
+            unsafe { (entry.drop)(entry.ptr) };
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Async event loop on Tokio
+//! Demonstrates S-CORE cooperative scheduling patterns:
+//! - Ticks run on a `tokio::time::interval` instead of a sleeping thread,
+//!   so the car system shares a runtime with async I/O (sockets, MQTT)
+//! - Tick callbacks are async and may await I/O between processing steps
+//! - Schedule, error policy, overrun check, live tick rate and
+//!   pause/step control behave as in the sync `EventLoop`, which stays
+//!   the default
+//!
+//! ```ignore
+//! let mut event_loop = AsyncEventLoop::new(config);
+//! event_loop.start();
+//! while let Some(tick) = event_loop.next_tick(100).await {
+//!     let result = car.tick(tick).map(drop);
+//!     event_loop.finish_tick(result);
+//! }
+//! event_loop.stop();
+//! ```
+//!
+//! Needs the `async_loop` feature, which depends on the `tokio` crate
+//! (`time` and `rt` features).
+
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::time::{self, Interval, MissedTickBehavior};
+
+use crate::components::event_loop::{
+    EventLoop, EventLoopConfig, JitterStats, LoopControl, OverrunRecovery, TimingReport,
+};
+
+/// Event loop whose ticks are awaited on a Tokio timer
+pub struct AsyncEventLoop {
+    inner: EventLoop,
+    /// Tick timer, rebuilt when the live tick rate changes
+    interval: Option<Interval>,
+    /// Period of `interval`
+    period: Duration,
+    /// Start of the last tick handed out by `next_tick` (loop clock time)
+    tick_start: Option<Duration>,
+}
+
+impl AsyncEventLoop {
+    pub fn new(config: EventLoopConfig) -> Self {
+        Self {
+            inner: EventLoop::new(config),
+            interval: None,
+            period: Duration::ZERO,
+            tick_start: None,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    pub fn tick_count(&self) -> u64 {
+        self.inner.tick_count()
+    }
+
+    /// Get a handle to the live tick rate (see `EventLoop::tick_rate_handle`)
+    pub fn tick_rate_handle(&self) -> Arc<AtomicU64> {
+        self.inner.tick_rate_handle()
+    }
+
+    /// Tick start jitter measured so far (wall-clock runs only)
+    pub fn jitter(&self) -> JitterStats {
+        self.inner.jitter()
+    }
+
+    /// Execution times, jitter and deadline misses since start
+    pub fn timing_report(&self) -> TimingReport {
+        self.inner.timing_report()
+    }
+
+    /// Count deadline misses in an existing counter
+    /// (see `EventLoop::deadline_miss_handle`)
+    pub fn set_deadline_miss_handle(&mut self, deadline_misses: Arc<AtomicU64>) {
+        self.inner.set_deadline_miss_handle(deadline_misses);
+    }
+
+    /// Why the error policy stopped the loop (None = not stopped by errors)
+    pub fn stop_reason(&self) -> Option<&str> {
+        self.inner.stop_reason()
+    }
+
+    pub fn start(&mut self) {
+        self.inner.start();
+        self.interval = None;
+        self.tick_start = None;
+    }
+
+    pub fn stop(&mut self) {
+        self.inner.stop();
+    }
+
+    /// Get a handle to the shutdown flag (see `EventLoop::shutdown_handle`)
+    pub fn shutdown_handle(&self) -> Arc<AtomicBool> {
+        self.inner.shutdown_handle()
+    }
+
+    pub fn set_shutdown_handle(&mut self, shutdown: Arc<AtomicBool>) {
+        self.inner.set_shutdown_handle(shutdown);
+    }
+
+    /// Get a handle to pause, resume and step the loop
+    /// (see `EventLoop::control_handle`)
+    pub fn control_handle(&self) -> LoopControl {
+        self.inner.control_handle()
+    }
+
+    pub fn set_control_handle(&mut self, control: LoopControl) {
+        self.inner.set_control_handle(control);
+    }
+
+    /// Wait while the loop is paused without blocking the runtime
+    async fn hold_while_paused(&mut self) {
+        let control = self.inner.control_handle();
+        if control.try_pass() {
+            return;
+        }
+        println!("\n⏸️  Event loop paused before tick {}", self.inner.tick_count());
+        while !control.try_pass() && !self.inner.shutdown_requested() {
+            time::sleep(Duration::from_millis(50)).await;
+        }
+        if control.is_paused() {
+            println!("⏭️  Step: tick {}", self.inner.tick_count());
+        } else {
+            println!("▶️  Event loop resumed");
+        }
+        // Restart the schedule from now
+        self.interval = None;
+        self.tick_start = None;
+    }
+
+    /// Wait for the start of the next tick (held while paused)
+    /// Returns its tick number, or None once the loop stopped (error
+    /// policy or shutdown request) or `limit` ticks have run. Simulated
+    /// clocks don't wait but still yield, so other tasks on the runtime
+    /// keep making progress.
+    pub async fn next_tick(&mut self, limit: u64) -> Option<u64> {
+        if !self.inner.keep_running() || self.inner.tick_count() >= limit {
+            return None;
+        }
+        self.hold_while_paused().await;
+        if !self.inner.keep_running() {
+            return None;
+        }
+        if let Some(clock) = self.inner.time_source().sim_clock() {
+            // Jump to the next tick like the sync loop, but let other tasks run
+            if let Some(tick_start) = self.tick_start {
+                clock.advance_to(tick_start + self.inner.tick_period());
+            }
+            tokio::task::yield_now().await;
+        } else {
+            let period = self.inner.tick_period();
+            if self.interval.is_none() || period != self.period {
+                let mut interval = time::interval(period);
+                // Like the sync loop: after an overrun the schedule restarts
+                // from now, or bursts to catch up with `CatchUp`
+                interval.set_missed_tick_behavior(match self.inner.config().overrun_recovery {
+                    OverrunRecovery::Restart => MissedTickBehavior::Delay,
+                    OverrunRecovery::CatchUp(_) => MissedTickBehavior::Burst,
+                });
+                self.interval = Some(interval);
+                self.period = period;
+            }
+            let scheduled = self.interval.as_mut()?.tick().await.into_std();
+            let lateness = Instant::now().saturating_duration_since(scheduled);
+            self.inner.record_jitter(lateness);
+            if let OverrunRecovery::CatchUp(max_ticks) = self.inner.config().overrun_recovery {
+                // Ticks a period or more late are catching up; bound the burst
+                if lateness >= period && !self.inner.catch_up(lateness, period, max_ticks) {
+                    self.interval = None;
+                }
+            }
+            if !self.inner.keep_running() {
+                return None;
+            }
+        }
+        self.tick_start = Some(self.inner.now());
+        Some(self.inner.tick_count())
+    }
+
+    /// Complete the tick handed out by `next_tick` with its result
+    /// Returns the duration the tick took
+    pub fn finish_tick(&mut self, result: Result<(), String>) -> Duration {
+        let tick_start = self.tick_start.unwrap_or_else(|| self.inner.now());
+        let tick_duration = self.inner.complete_tick(result, tick_start);
+        if !self.inner.config().is_simulated() && tick_duration > self.period {
+            eprintln!("⚠️  Warning: Tick {} took {:.2}ms (target: {:.2}ms) - can't keep up!",
+                self.inner.tick_count() - 1,
+                tick_duration.as_secs_f64() * 1000.0,
+                self.period.as_secs_f64() * 1000.0
+            );
+        }
+        tick_duration
+    }
+
+    /// Run for a fixed number of ticks with an async callback
+    pub async fn run_for<F, Fut>(&mut self, num_ticks: u64, mut callback: F)
+    where
+        F: FnMut(u64) -> Fut,
+        Fut: Future<Output = Result<(), String>>,
+    {
+        self.start();
+        while let Some(tick_num) = self.next_tick(num_ticks).await {
+            let result = callback(tick_num).await;
+            self.finish_tick(result);
+        }
+        self.stop();
+    }
+
+    /// Run until the error policy or a shutdown request stops the loop
+    pub async fn run<F, Fut>(&mut self, callback: F)
+    where
+        F: FnMut(u64) -> Fut,
+        Fut: Future<Output = Result<(), String>>,
+    {
+        self.run_for(u64::MAX, callback).await;
+    }
+}
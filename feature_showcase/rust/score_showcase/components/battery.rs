@@ -0,0 +1,341 @@
+//! Battery component - traction battery of EV and hybrid powertrains
+//! Demonstrates S-CORE patterns:
+//! - State machine (idle / discharging / charging / regenerating)
+//! - Physical model: state of charge, voltage sag under load, heating
+//!   with current and cooling towards ambient
+//! - Built-in protection: no discharge when empty, no charge when full
+//! - Message publishing (low charge, over-temperature)
+//!
+//! The car system decides each cycle how the traction power is split
+//! between engine and battery (`PowerSplit`); the battery only follows
+//! the power it is asked for.
+
+use std::fmt;
+
+use crate::components::{CarComponent, ComponentState, CarMessage, HealthStatus};
+use crate::components::state_machine::{ChargingState, StateMachine, TransitionMachine};
+use crate::components::event_loop::DEFAULT_TIME_STEP_S;
+use crate::log_info;
+
+/// Open-circuit voltage of an empty pack, in V...
+const EMPTY_VOLTAGE_V: f32 = 320.0;
+
+/// ...and of a full one
+const FULL_VOLTAGE_V: f32 = 400.0;
+
+/// Internal resistance of the pack, in Ω
+const INTERNAL_RESISTANCE_OHM: f32 = 0.08;
+
+/// Heat needed to warm the pack by 1 °C, in J
+const THERMAL_MASS_J_PER_C: f32 = 20_000.0;
+
+/// Heat the cooling loop removes per °C above ambient, in W
+const COOLING_W_PER_C: f32 = 100.0;
+
+const AMBIENT_TEMPERATURE_C: f32 = 20.0;
+
+/// Published as `BatteryLow` below this state of charge, in %
+const LOW_SOC_PERCENT: f32 = 20.0;
+
+/// Published as `BatteryOverheating` above this temperature, in °C
+const HOT_TEMPERATURE_C: f32 = 50.0;
+
+/// Hybrid mode drives electrically up to this speed, in km/h...
+const HYBRID_EV_MAX_KMH: u8 = 50;
+
+/// ...as long as the battery holds more than this, in %
+const HYBRID_EV_MIN_SOC_PERCENT: f32 = 30.0;
+
+/// Below this the engine charges the battery in hybrid mode, in %
+const HYBRID_CHARGE_BELOW_SOC_PERCENT: f32 = 60.0;
+
+/// Generator power while the engine charges the battery, in kW
+const HYBRID_CHARGE_KW: f32 = 10.0;
+
+/// Regenerated power per % brake pressure and km/h, in kW
+const REGEN_KW_PER_PERCENT_KMH: f32 = 0.01;
+
+/// Most power the motor can regenerate, in kW
+const MAX_REGEN_KW: f32 = 60.0;
+
+/// Which machines propel the car
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowertrainMode {
+    /// Engine only, no traction battery
+    Combustion,
+    /// Battery only; the engine is not used for propulsion
+    Ev,
+    /// Electric at low speed, engine above, engine charges a low battery
+    Hybrid,
+}
+
+impl PowertrainMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PowertrainMode::Combustion => "combustion",
+            PowertrainMode::Ev => "ev",
+            PowertrainMode::Hybrid => "hybrid",
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<PowertrainMode, String> {
+        match s.trim().to_lowercase().as_str() {
+            "combustion" | "ice" => Ok(PowertrainMode::Combustion),
+            "ev" | "electric" => Ok(PowertrainMode::Ev),
+            "hybrid" => Ok(PowertrainMode::Hybrid),
+            _ => Err(format!("Invalid powertrain mode: {} (combustion|ev|hybrid)", s)),
+        }
+    }
+
+    /// Check whether the car has a traction battery
+    pub fn has_battery(&self) -> bool {
+        *self != PowertrainMode::Combustion
+    }
+}
+
+impl fmt::Display for PowertrainMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// How the traction power is split for one cycle
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerSplit {
+    /// What the battery does
+    pub battery: ChargingState,
+    /// Battery power in kW (drawn or absorbed, depending on `battery`)
+    pub battery_kw: f32,
+    /// The engine propels the car (burns fuel for driving)
+    pub combustion: bool,
+}
+
+impl PowerSplit {
+    /// Split for a cycle at `speed` with the brakes at `brake_pressure`
+    pub fn decide(mode: PowertrainMode, speed: u8, brake_pressure: u8, soc: f32, engine_running: bool) -> Self {
+        let idle = |combustion| Self { battery: ChargingState::Idle, battery_kw: 0.0, combustion };
+        if mode == PowertrainMode::Combustion {
+            return idle(true);
+        }
+        if speed > 0 && brake_pressure > 0 {
+            let kw = (brake_pressure as f32 * speed as f32 * REGEN_KW_PER_PERCENT_KMH).min(MAX_REGEN_KW);
+            return Self { battery: ChargingState::Regenerating, battery_kw: kw, combustion: false };
+        }
+        let electric = match mode {
+            PowertrainMode::Ev => true,
+            _ => speed <= HYBRID_EV_MAX_KMH && soc > HYBRID_EV_MIN_SOC_PERCENT,
+        };
+        if electric {
+            if speed == 0 {
+                return idle(false);
+            }
+            return Self { battery: ChargingState::Discharging, battery_kw: traction_power_kw(speed), combustion: false };
+        }
+        if engine_running && soc < HYBRID_CHARGE_BELOW_SOC_PERCENT {
+            return Self { battery: ChargingState::Charging, battery_kw: HYBRID_CHARGE_KW, combustion: true };
+        }
+        idle(speed > 0)
+    }
+}
+
+/// Power needed to hold a speed: rolling resistance plus drag, in kW
+pub fn traction_power_kw(speed: u8) -> f32 {
+    let v = speed as f32;
+    0.06 * v + 10.0 * (v / 100.0).powi(3)
+}
+
+/// Battery component - manages the traction battery
+pub struct BatteryComponent {
+    state: ComponentState,
+    charging_state: TransitionMachine<ChargingState>,
+    capacity_kwh: f32,
+    soc: f32,         // 0-100%
+    temperature: f32, // °C
+    /// Terminal voltage under the present load
+    voltage: f32,
+    /// Pack current in A (positive = discharging)
+    current: f32,
+    /// Requested power in kW, signed like `current`
+    power_kw: f32,
+    /// Simulated seconds per process() call
+    dt_s: f32,
+}
+
+impl BatteryComponent {
+    /// Create a battery of `capacity_kwh` charged to `soc` percent
+    pub fn new(capacity_kwh: f32, soc: f32) -> Self {
+        let soc = soc.clamp(0.0, 100.0);
+        Self {
+            state: ComponentState::Offline,
+            charging_state: ChargingState::machine(),
+            capacity_kwh,
+            soc,
+            temperature: AMBIENT_TEMPERATURE_C,
+            voltage: open_circuit_voltage(soc),
+            current: 0.0,
+            power_kw: 0.0,
+            dt_s: DEFAULT_TIME_STEP_S,
+        }
+    }
+
+    /// Set the simulated time covered by each process() call
+    pub fn set_time_step(&mut self, dt_s: f32) {
+        self.dt_s = dt_s;
+    }
+
+    /// Stamp the following state transitions with this tick
+    pub fn set_tick(&mut self, tick: u64) {
+        self.charging_state.set_tick(tick);
+    }
+
+    /// Follow the battery part of a power split until the next call
+    /// Drive and generator current don't swap directly; the battery goes
+    /// through `Idle` in between. An empty pack stays idle instead of
+    /// discharging, a full one instead of charging.
+    pub fn apply(&mut self, split: &PowerSplit) -> Result<(), String> {
+        let blocked = match split.battery {
+            ChargingState::Discharging => self.soc <= 0.0,
+            ChargingState::Charging | ChargingState::Regenerating => self.soc >= 100.0,
+            ChargingState::Idle => false,
+        };
+        if blocked {
+            self.idle();
+            return Ok(());
+        }
+        let state = split.battery.clone();
+        if *self.charging_state.current_state() != state {
+            if !self.charging_state.can_transition_to(&state) {
+                self.charging_state.transition_because(ChargingState::Idle, "current reversal")?;
+            }
+            self.charging_state.transition_because(state, "power split")?;
+        }
+        self.power_kw = match split.battery {
+            ChargingState::Discharging => split.battery_kw,
+            ChargingState::Charging | ChargingState::Regenerating => -split.battery_kw,
+            ChargingState::Idle => 0.0,
+        };
+        Ok(())
+    }
+
+    /// Open the contactors
+    pub fn idle(&mut self) {
+        if self.charging_state.can_transition_to(&ChargingState::Idle) {
+            self.charging_state.set_state_because(ChargingState::Idle, "idle");
+        }
+        self.power_kw = 0.0;
+    }
+
+    /// Battery state machine with its transition history
+    pub fn state_machine(&self) -> &TransitionMachine<ChargingState> {
+        &self.charging_state
+    }
+
+    /// Get current charging state
+    pub fn get_charging_state(&self) -> &ChargingState {
+        self.charging_state.current_state()
+    }
+
+    /// Get state of charge in %
+    pub fn get_soc(&self) -> f32 {
+        self.soc
+    }
+
+    /// Get pack temperature in °C
+    pub fn get_temperature(&self) -> f32 {
+        self.temperature
+    }
+
+    /// Get terminal voltage in V
+    pub fn get_voltage(&self) -> f32 {
+        self.voltage
+    }
+
+    /// Get pack current in A (positive = discharging)
+    pub fn get_current(&self) -> f32 {
+        self.current
+    }
+
+    /// Raise the pack temperature (fault injection)
+    pub fn inject_temperature_spike(&mut self, degrees: f32) {
+        self.temperature += degrees;
+    }
+
+    /// Get messages to publish (Phase 3: Communication)
+    pub fn get_messages(&self) -> Vec<CarMessage> {
+        let mut messages = Vec::new();
+        self.get_messages_into(&mut messages);
+        messages
+    }
+
+    /// Append messages to publish to a reused buffer
+    pub fn get_messages_into(&self, messages: &mut Vec<CarMessage>) {
+        if self.temperature > HOT_TEMPERATURE_C {
+            messages.push(CarMessage::BatteryOverheating { temperature: self.temperature });
+        }
+        if self.soc < LOW_SOC_PERCENT {
+            messages.push(CarMessage::BatteryLow { soc: self.soc as u8 });
+        }
+    }
+}
+
+/// Open-circuit voltage at a state of charge (linear between empty and full)
+fn open_circuit_voltage(soc: f32) -> f32 {
+    EMPTY_VOLTAGE_V + (FULL_VOLTAGE_V - EMPTY_VOLTAGE_V) * soc / 100.0
+}
+
+impl CarComponent for BatteryComponent {
+    fn name(&self) -> &'static str {
+        "Battery"
+    }
+
+    fn initialize(&mut self) -> Result<(), String> {
+        log_info!("Battery", "🔧 Battery: Initializing component...");
+        self.state = ComponentState::Initializing;
+
+        log_info!("Battery", "  🔍 Battery: Checking cell voltages... OK");
+        log_info!("Battery", "  🔍 Battery: Testing contactors... OK");
+        log_info!("Battery", "  🔋 Battery: {:.0} kWh, charged to {:.1}%", self.capacity_kwh, self.soc);
+
+        self.state = ComponentState::Online;
+        log_info!("Battery", "✅ Battery: Initialized (state: {})", self.state);
+        Ok(())
+    }
+
+    fn process(&mut self) -> Result<(), String> {
+        let open_circuit = open_circuit_voltage(self.soc);
+        self.current = self.power_kw * 1000.0 / open_circuit;
+        self.voltage = open_circuit - self.current * INTERNAL_RESISTANCE_OHM;
+
+        let energy_kwh = self.power_kw * self.dt_s / 3600.0;
+        self.soc = (self.soc - energy_kwh / self.capacity_kwh * 100.0).clamp(0.0, 100.0);
+
+        // Joule heating against the cooling loop
+        let heating_w = self.current * self.current * INTERNAL_RESISTANCE_OHM;
+        let cooling_w = (self.temperature - AMBIENT_TEMPERATURE_C) * COOLING_W_PER_C;
+        self.temperature += (heating_w - cooling_w) * self.dt_s / THERMAL_MASS_J_PER_C;
+        Ok(())
+    }
+
+    fn get_state(&self) -> ComponentState {
+        self.state.clone()
+    }
+
+    fn shutdown(&mut self) -> Result<(), String> {
+        log_info!("Battery", "🔧 Battery: Shutting down...");
+        self.idle();
+        self.current = 0.0;
+        self.state = ComponentState::Offline;
+        log_info!("Battery", "✅ Battery: Shut down at {:.1}% (state: {})", self.soc, self.get_charging_state());
+        Ok(())
+    }
+
+    fn health_check(&self) -> HealthStatus {
+        if !self.soc.is_finite() || !self.temperature.is_finite() {
+            return HealthStatus::Unhealthy("charge or temperature reading is not a number");
+        }
+        if self.soc <= 0.0 {
+            return HealthStatus::Degraded("pack empty, traction power unavailable");
+        }
+        HealthStatus::from_state(&self.state)
+    }
+}
@@ -0,0 +1,517 @@
+//! Latency and throughput benchmarks
+//! Demonstrates S-CORE performance verification patterns:
+//! - Message bus publish/receive throughput
+//! - Per-tick processing time of the full system (rendering disabled)
+//! - Workflow execution overhead
+//! - Config-generated workflows: boxed vs. arena-stored step closures
+//! - Cross-thread messaging: lock-free SPSC ring vs. mutex-protected queue
+//! - Safety checking: all rules vs. only rules with changed inputs
+//! - Results exported as JSON for tracking over time
+//! - Tick-budget guard: fail when p99 tick time exceeds a budget
+
+use std::collections::VecDeque;
+use std::fs;
+use std::hint::black_box;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::components::config::{LogLevel, SystemConfig};
+use crate::components::event_loop::ClockSource;
+use crate::components::harness::TestHarness;
+use crate::components::json::{object, JsonValue};
+use crate::components::message_bus::{MessageBus, SharedMessageBus};
+use crate::components::messages::{CarMessage, ComponentId};
+use crate::components::safety::{SafetyInputs, SafetyMonitor, SAFETY_RULES};
+use crate::components::spsc::spsc_ring;
+use crate::components::system::CarSystem;
+use crate::components::workflow::{Workflow, WorkflowBuilder};
+
+/// Timing of one benchmark
+pub struct BenchResult {
+    pub name: &'static str,
+    pub iterations: u64,
+    pub total: Duration,
+    /// Slowest single iteration
+    pub max: Duration,
+}
+
+impl BenchResult {
+    /// Mean time per iteration in nanoseconds
+    pub fn mean_ns(&self) -> f64 {
+        if self.iterations == 0 {
+            return 0.0;
+        }
+        self.total.as_nanos() as f64 / self.iterations as f64
+    }
+
+    /// Iterations per second
+    pub fn per_second(&self) -> f64 {
+        let secs = self.total.as_secs_f64();
+        if secs == 0.0 {
+            return 0.0;
+        }
+        self.iterations as f64 / secs
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        object([
+            ("name", JsonValue::from(self.name)),
+            ("iterations", JsonValue::from(self.iterations)),
+            ("total_ms", JsonValue::from(self.total.as_secs_f64() * 1000.0)),
+            ("mean_ns", JsonValue::from(self.mean_ns())),
+            ("max_ns", JsonValue::from(self.max.as_nanos() as f64)),
+            ("per_second", JsonValue::from(self.per_second())),
+        ])
+    }
+}
+
+/// Time `iterations` runs of `op`
+fn measure<F>(name: &'static str, iterations: u64, mut op: F) -> Result<BenchResult, String>
+where
+    F: FnMut() -> Result<(), String>,
+{
+    let mut total = Duration::ZERO;
+    let mut max = Duration::ZERO;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        op()?;
+        let elapsed = start.elapsed();
+        total += elapsed;
+        max = max.max(elapsed);
+    }
+    Ok(BenchResult {
+        name,
+        iterations,
+        total,
+        max,
+    })
+}
+
+/// One message published to two subscribers and received by both
+fn bench_message_bus(iterations: u64) -> Result<BenchResult, String> {
+    let mut bus = MessageBus::new();
+    bus.set_trace(false);
+    bus.register_component(ComponentId::ENGINE);
+    bus.register_component(ComponentId::DASHBOARD);
+    bus.register_component(ComponentId::CAR_SYSTEM);
+    bus.subscribe_all(ComponentId::DASHBOARD);
+    bus.subscribe_all(ComponentId::CAR_SYSTEM);
+
+    let mut received = Vec::new();
+    measure("message_bus_publish_receive", iterations, || {
+        bus.publish(ComponentId::ENGINE, CarMessage::EngineRpmChange { rpm: 800 });
+        bus.receive_all_into(ComponentId::DASHBOARD, &mut received);
+        bus.receive_all_into(ComponentId::CAR_SYSTEM, &mut received);
+        received.clear();
+        Ok(())
+    })
+}
+
+/// Batches of eight messages published at once and received in one call
+/// Reported per message for comparison with the single-message benchmark.
+fn bench_message_bus_batch(iterations: u64) -> Result<BenchResult, String> {
+    const BATCH: u64 = 8;
+    let mut bus = MessageBus::new();
+    bus.set_trace(false);
+    bus.register_component(ComponentId::ENGINE);
+    bus.register_component(ComponentId::DASHBOARD);
+    bus.register_component(ComponentId::CAR_SYSTEM);
+    bus.subscribe_all(ComponentId::DASHBOARD);
+    bus.subscribe_all(ComponentId::CAR_SYSTEM);
+
+    let mut batch = Vec::with_capacity(BATCH as usize);
+    let mut received = Vec::with_capacity(BATCH as usize);
+    let mut result = measure("message_bus_batch_of_8", iterations.div_ceil(BATCH), || {
+        batch.extend((0..BATCH as u32).map(|rpm| CarMessage::EngineRpmChange { rpm }));
+        bus.publish_batch(ComponentId::ENGINE, &mut batch);
+        bus.receive_batch(ComponentId::DASHBOARD, &mut received, BATCH as usize);
+        bus.receive_batch(ComponentId::CAR_SYSTEM, &mut received, BATCH as usize);
+        received.clear();
+        Ok(())
+    })?;
+    result.iterations *= BATCH;
+    Ok(result)
+}
+
+/// One message published and received through `SharedMessageBus` handles
+/// The difference to `message_bus_publish_receive` is the locking cost.
+fn bench_shared_message_bus(iterations: u64) -> Result<BenchResult, String> {
+    let mut bus = MessageBus::new();
+    bus.set_trace(false);
+    let shared = SharedMessageBus::from_bus(bus);
+    let engine = shared.register(ComponentId::ENGINE);
+    let dashboard = shared.register(ComponentId::DASHBOARD);
+    dashboard.subscribe_all();
+
+    let mut received = Vec::new();
+    measure("message_bus_shared_handles", iterations, || {
+        engine.publish(CarMessage::EngineRpmChange { rpm: 800 });
+        dashboard.receive_all_into(&mut received);
+        received.clear();
+        Ok(())
+    })
+}
+
+/// `CarSystem::process_cycle` with a running engine and no rendering
+fn bench_process_cycle(config: &SystemConfig, iterations: u64) -> Result<BenchResult, String> {
+    let mut car = CarSystem::with_config(config.clone());
+    car.message_bus.set_trace(false);
+    car.engine.start()?;
+
+    measure("process_cycle", iterations, || car.process_cycle(60))
+}
+
+/// `process_cycle` with independent components on parallel threads
+fn bench_process_cycle_parallel(config: &SystemConfig, iterations: u64) -> Result<BenchResult, String> {
+    let mut config = config.clone();
+    config.event_loop.parallel_components = true;
+    let mut car = CarSystem::with_config(config);
+    car.message_bus.set_trace(false);
+    car.engine.start()?;
+
+    measure("process_cycle_parallel", iterations, || car.process_cycle(60))
+}
+
+/// A three-step no-op workflow, measuring orchestration overhead only
+fn bench_workflow(config: &SystemConfig, iterations: u64) -> Result<BenchResult, String> {
+    let mut car = CarSystem::with_config(config.clone());
+    let mut builder = WorkflowBuilder::new("Bench", "No-op steps");
+    for name in ["First", "Second", "Third"] {
+        builder.step(name, "No-op", Box::new(|_system| Ok(())));
+    }
+    let workflow = builder.build();
+
+    measure("workflow_execute_3_steps", iterations, || workflow.execute_quiet(&mut car))
+}
+
+/// Steps of the generated workflows
+const GENERATED_WORKFLOW_STEPS: usize = 500;
+
+/// A workflow as a config loader would generate it: many steps, each
+/// closure capturing its own parameters
+fn generated_workflow(arena: bool) -> Workflow {
+    let mut builder = if arena {
+        WorkflowBuilder::with_arena("Generated", "Config-generated steps")
+    } else {
+        WorkflowBuilder::new("Generated", "Config-generated steps")
+    };
+    for index in 0..GENERATED_WORKFLOW_STEPS {
+        let limit = index as f32 * 0.5;
+        let label = index as u32;
+        let check = move |system: &mut CarSystem| {
+            black_box((label, system.engine.get_temperature() < limit + 1000.0));
+            Ok(())
+        };
+        if arena {
+            builder.step_fn("Check", "Generated check", check);
+        } else {
+            builder.step("Check", "Generated check", Box::new(check));
+        }
+    }
+    builder.build()
+}
+
+/// Construction and execution of generated workflows, boxed vs. arena
+fn bench_generated_workflows(config: &SystemConfig, iterations: u64) -> Result<Vec<BenchResult>, String> {
+    let build_iterations = iterations.min(200);
+    let mut results = Vec::new();
+    for (arena, build_name, execute_name) in [
+        (false, "workflow_build_500_boxed", "workflow_execute_500_boxed"),
+        (true, "workflow_build_500_arena", "workflow_execute_500_arena"),
+    ] {
+        results.push(measure(build_name, build_iterations, || {
+            black_box(generated_workflow(arena));
+            Ok(())
+        })?);
+
+        let mut car = CarSystem::with_config(config.clone());
+        let workflow = generated_workflow(arena);
+        results.push(measure(execute_name, iterations, || workflow.execute_quiet(&mut car))?);
+    }
+    Ok(results)
+}
+
+/// Rule set sizes for the safety benchmarks (multiples of the built-in
+/// rules) with the full and incremental benchmark names, over a drive
+const SAFETY_RULE_SCALES: &[(usize, &str, &str)] = &[
+    (1, "safety_full_6_rules", "safety_incremental_6_rules"),
+    (10, "safety_full_60_rules", "safety_incremental_60_rules"),
+    (100, "safety_full_600_rules", "safety_incremental_600_rules"),
+];
+
+/// Same rule set sizes over a parked car
+const PARKED_RULE_SCALES: &[(usize, &str, &str)] = &[
+    (1, "safety_full_6_rules_parked", "safety_incremental_6_rules_parked"),
+    (10, "safety_full_60_rules_parked", "safety_incremental_60_rules_parked"),
+    (100, "safety_full_600_rules_parked", "safety_incremental_600_rules_parked"),
+];
+
+/// Safety inputs of consecutive ticks of a simulated run
+/// Driving changes speed, temperature and rpm every tick; parked (engine
+/// off) almost nothing changes, as on a car waiting with ignition on.
+fn record_safety_inputs(config: &SystemConfig, ticks: u64, driving: bool) -> Result<Vec<SafetyInputs>, String> {
+    let mut config = config.clone();
+    config.log_level = LogLevel::Error;
+    let mut car = CarSystem::with_config(config);
+    car.message_bus.set_trace(false);
+    if driving {
+        car.engine.start()?;
+    }
+    (0..ticks)
+        .map(|tick| Ok(SafetyInputs::from_snapshot(&*car.tick(tick)?)))
+        .collect()
+}
+
+/// Full and incremental safety checks over a recorded drive and a parked
+/// car, for growing rule sets
+/// Prints the speedup of incremental checking per rule set: below it
+/// costs more than it saves, and its benefit grows with the rule count
+/// and with how stable the inputs are.
+fn bench_safety_checks(config: &SystemConfig, iterations: u64) -> Result<Vec<BenchResult>, String> {
+    let mut results = Vec::new();
+    for (scales, driving) in [(SAFETY_RULE_SCALES, true), (PARKED_RULE_SCALES, false)] {
+        let trace = record_safety_inputs(config, iterations.min(1000), driving)?;
+        for &(scale, full_name, incremental_name) in scales {
+            let rules = SAFETY_RULES.repeat(scale);
+
+            let monitor = SafetyMonitor::with_config(&config.safety).with_rules(rules.clone());
+            let mut inputs = trace.iter().cycle();
+            let full = measure(full_name, iterations, || {
+                if let Some(inputs) = inputs.next() {
+                    black_box(monitor.check_inputs(inputs));
+                }
+                Ok(())
+            })?;
+
+            let mut monitor = SafetyMonitor::with_config(&config.safety).with_rules(rules);
+            let mut inputs = trace.iter().cycle();
+            let incremental = measure(incremental_name, iterations, || {
+                if let Some(inputs) = inputs.next() {
+                    black_box(monitor.check_incremental(inputs));
+                }
+                Ok(())
+            })?;
+            println!("   {:<36} {:.1} of {} rules evaluated per check, {:.2}x the speed of a full check",
+                     incremental_name,
+                     monitor.rule_evaluations() as f64 / iterations as f64,
+                     monitor.rule_count(),
+                     full.mean_ns() / incremental.mean_ns().max(1.0));
+            results.push(full);
+            results.push(incremental);
+        }
+    }
+    Ok(results)
+}
+
+/// Capacity of the cross-thread message rings
+const RING_CAPACITY: usize = 256;
+
+/// Messages sent from a producer thread through an SPSC ring
+/// `max` is the longest wait between two received batches.
+fn bench_spsc_ring(iterations: u64) -> Result<BenchResult, String> {
+    let (mut producer, mut consumer) = spsc_ring::<CarMessage, RING_CAPACITY>();
+
+    let start = Instant::now();
+    let sender = thread::spawn(move || {
+        for rpm in 0..iterations {
+            let mut message = CarMessage::EngineRpmChange { rpm: rpm as u32 };
+            while let Err(rejected) = producer.push(message) {
+                message = rejected;
+                thread::yield_now();
+            }
+        }
+    });
+
+    let mut batch = Vec::with_capacity(RING_CAPACITY);
+    let mut received = 0;
+    let mut max = Duration::ZERO;
+    let mut last = Instant::now();
+    while received < iterations {
+        if consumer.drain_into(&mut batch) == 0 {
+            thread::yield_now();
+            continue;
+        }
+        received += batch.len() as u64;
+        batch.clear();
+        max = max.max(last.elapsed());
+        last = Instant::now();
+    }
+    sender.join().map_err(|_| "SPSC producer thread panicked".to_string())?;
+
+    Ok(BenchResult {
+        name: "spsc_ring_cross_thread",
+        iterations,
+        total: start.elapsed(),
+        max,
+    })
+}
+
+/// The same traffic through a mutex-protected queue, for comparison
+fn bench_mutex_queue(iterations: u64) -> Result<BenchResult, String> {
+    let queue = Arc::new(Mutex::new(VecDeque::with_capacity(RING_CAPACITY)));
+    let sender_queue = queue.clone();
+
+    let start = Instant::now();
+    let sender = thread::spawn(move || {
+        for rpm in 0..iterations {
+            loop {
+                let mut queue = sender_queue.lock().unwrap_or_else(|e| e.into_inner());
+                if queue.len() < RING_CAPACITY {
+                    queue.push_back(CarMessage::EngineRpmChange { rpm: rpm as u32 });
+                    break;
+                }
+                drop(queue);
+                thread::yield_now();
+            }
+        }
+    });
+
+    let mut batch = Vec::with_capacity(RING_CAPACITY);
+    let mut received = 0;
+    let mut max = Duration::ZERO;
+    let mut last = Instant::now();
+    while received < iterations {
+        batch.extend(queue.lock().unwrap_or_else(|e| e.into_inner()).drain(..));
+        if batch.is_empty() {
+            thread::yield_now();
+            continue;
+        }
+        received += batch.len() as u64;
+        batch.clear();
+        max = max.max(last.elapsed());
+        last = Instant::now();
+    }
+    sender.join().map_err(|_| "Mutex queue producer thread panicked".to_string())?;
+
+    Ok(BenchResult {
+        name: "mutex_queue_cross_thread",
+        iterations,
+        total: start.elapsed(),
+        max,
+    })
+}
+
+/// Run all benchmarks, print a table and optionally write JSON results
+pub fn run_benchmarks(config: &SystemConfig, output: Option<&str>) -> Result<(), String> {
+    let mut config = config.clone();
+    config.render = false;
+    let iterations = config.ticks.max(1);
+
+    println!("⏱️  Benchmarks ({} iterations each)\n", iterations);
+    if cfg!(debug_assertions) {
+        println!("⚠️  Unoptimized build: timings are not representative, build with -O\n");
+    }
+    let mut results = vec![
+        bench_message_bus(iterations)?,
+        bench_message_bus_batch(iterations)?,
+        bench_shared_message_bus(iterations)?,
+        bench_process_cycle(&config, iterations)?,
+        bench_process_cycle_parallel(&config, iterations)?,
+        bench_workflow(&config, iterations)?,
+        bench_spsc_ring(iterations)?,
+        bench_mutex_queue(iterations)?,
+    ];
+    results.extend(bench_generated_workflows(&config, iterations)?);
+    results.extend(bench_safety_checks(&config, iterations)?);
+
+    println!();
+    println!("  {:<36} {:>12} {:>12} {:>14}", "benchmark", "mean", "max", "per second");
+    for result in &results {
+        println!(
+            "  {:<36} {:>9.0} ns {:>9.0} ns {:>14.0}",
+            result.name,
+            result.mean_ns(),
+            result.max.as_nanos() as f64,
+            result.per_second()
+        );
+    }
+
+    if let Some(path) = output {
+        let doc = object([
+            ("iterations", JsonValue::from(iterations)),
+            (
+                "results",
+                JsonValue::Array(results.iter().map(BenchResult::to_json).collect()),
+            ),
+        ]);
+        fs::write(path, doc.to_pretty_string() + "\n")
+            .map_err(|e| format!("Cannot write '{}': {}", path, e))?;
+        println!("\n💾 Results written to {}", path);
+    }
+    Ok(())
+}
+
+/// Tick duration percentiles of a guarded run
+#[derive(Debug, Clone)]
+pub struct TickBudgetReport {
+    pub ticks: u64,
+    pub p50: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+    pub budget: Duration,
+}
+
+impl TickBudgetReport {
+    /// Check whether p99 stayed within the budget
+    pub fn passed(&self) -> bool {
+        self.p99 <= self.budget
+    }
+}
+
+/// Run `config.ticks` simulated ticks and measure their durations
+/// Projects reusing the components can call this from their own test
+/// pipelines and gate on `passed()`.
+pub fn measure_tick_budget(config: &SystemConfig, budget: Duration) -> Result<TickBudgetReport, String> {
+    let mut config = config.clone();
+    config.event_loop.clock = ClockSource::Simulated;
+    config.render = false;
+    config.persistency.path.clear();
+    config.hardware = Default::default();
+
+    let mut harness = TestHarness::with_config(config.clone())?;
+    harness.system.message_bus.set_trace(false);
+    harness.start_car()?;
+
+    let mut durations = Vec::with_capacity(config.ticks as usize);
+    for _ in 0..config.ticks {
+        let start = Instant::now();
+        harness.tick()?;
+        durations.push(start.elapsed());
+    }
+    if durations.is_empty() {
+        return Err("tick budget check needs at least one tick".to_string());
+    }
+    durations.sort();
+
+    let percentile = |p: usize| durations[((durations.len() - 1) * p) / 100];
+    Ok(TickBudgetReport {
+        ticks: durations.len() as u64,
+        p50: percentile(50),
+        p99: percentile(99),
+        max: durations[durations.len() - 1],
+        budget,
+    })
+}
+
+/// Check the tick budget and fail if p99 exceeds it
+pub fn run_tick_budget(config: &SystemConfig, budget_us: u64) -> Result<(), String> {
+    let report = measure_tick_budget(config, Duration::from_micros(budget_us))?;
+
+    println!("\n⏱️  Tick budget ({} ticks)", report.ticks);
+    println!("   p50:    {:>8.1} µs", report.p50.as_secs_f64() * 1e6);
+    println!("   p99:    {:>8.1} µs", report.p99.as_secs_f64() * 1e6);
+    println!("   max:    {:>8.1} µs", report.max.as_secs_f64() * 1e6);
+    println!("   budget: {:>8} µs", budget_us);
+
+    if report.passed() {
+        println!("✅ p99 tick duration within budget");
+        Ok(())
+    } else {
+        Err(format!(
+            "p99 tick duration {:.1} µs exceeds budget of {} µs",
+            report.p99.as_secs_f64() * 1e6,
+            budget_us
+        ))
+    }
+}
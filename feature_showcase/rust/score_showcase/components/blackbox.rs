@@ -0,0 +1,409 @@
+//! Crash-safe black box recorder
+//! Demonstrates automotive event data recorder patterns:
+//! - A pre-allocated, memory-mapped file used as a fixed-size ring of records
+//! - Every record is written in place as it happens, so the data is in the
+//!   OS page cache even if the process dies abnormally
+//! - A panic hook writes a final PANIC record and syncs the file to disk;
+//!   it is installed once per process and records to the most recently
+//!   installed black box
+//!
+//! File layout: one header slot followed by `capacity` record slots of
+//! `SLOT_SIZE` bytes each. Every slot holds one JSON line padded with spaces.
+//!
+//! The file is mapped with `mmap` on 64-bit Linux. Other platforms write
+//! each slot at its offset instead, which keeps the same crash guarantees
+//! at the cost of one system call per record.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::panic;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Once};
+
+use crate::components::json::{object, JsonValue};
+use crate::components::messages::{CarMessage, ComponentId};
+use crate::components::snapshot::SystemSnapshot;
+use crate::log_error;
+
+/// Size of one record slot in bytes
+pub const SLOT_SIZE: usize = 1024;
+
+/// Records reserved per tick (one snapshot plus routed messages)
+pub const RECORDS_PER_TICK: usize = 8;
+
+const MAGIC: &str = "SCORE-BLACKBOX-1";
+
+/// Black box the panic hook records to
+static PANIC_TARGET: Mutex<Option<Arc<Shared>>> = Mutex::new(None);
+
+/// Guards the one-time panic hook installation
+static PANIC_HOOK: Once = Once::new();
+
+/// Shared writable mapping of a whole file
+/// Declares the few libc functions it needs itself: std links libc anyway,
+/// and the workspace has no external crates.
+#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+mod mapping {
+    use std::fs::File;
+    use std::io;
+    use std::os::unix::io::AsRawFd;
+
+    const PROT_READ: i32 = 1;
+    const PROT_WRITE: i32 = 2;
+    const MAP_SHARED: i32 = 1;
+    const MS_SYNC: i32 = 4;
+
+    extern "C" {
+        fn mmap(addr: *mut u8, len: usize, prot: i32, flags: i32, fd: i32, offset: i64) -> *mut u8;
+        fn munmap(addr: *mut u8, len: usize) -> i32;
+        fn msync(addr: *mut u8, len: usize, flags: i32) -> i32;
+    }
+
+    pub struct MappedFile {
+        ptr: *mut u8,
+        len: usize,
+        /// Kept open for as long as the mapping exists
+        _file: File,
+    }
+
+    // The mapping is owned memory; access is serialized by the recorder's lock
+    unsafe impl Send for MappedFile {}
+
+    impl MappedFile {
+        /// Map the first `len` bytes of `file`, which must be at least that long
+        pub fn map(file: File, len: usize) -> io::Result<Self> {
+            // SAFETY: a fresh shared mapping of a file we keep open; the
+            // kernel picks the address
+            let ptr = unsafe {
+                mmap(std::ptr::null_mut(), len, PROT_READ | PROT_WRITE, MAP_SHARED, file.as_raw_fd(), 0)
+            };
+            if ptr as isize == -1 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(Self { ptr, len, _file: file })
+        }
+
+        pub fn bytes_mut(&mut self) -> &mut [u8] {
+            // SAFETY: `ptr` maps `len` bytes until drop, borrowed mutably here
+            unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+        }
+
+        /// Write the mapped pages back to the file
+        pub fn sync(&self) -> io::Result<()> {
+            // SAFETY: `ptr` and `len` describe our own live mapping
+            match unsafe { msync(self.ptr, self.len, MS_SYNC) } {
+                0 => Ok(()),
+                _ => Err(io::Error::last_os_error()),
+            }
+        }
+    }
+
+    impl Drop for MappedFile {
+        fn drop(&mut self) {
+            // SAFETY: unmapped once; no slice into it outlives `&mut self`
+            unsafe {
+                munmap(self.ptr, self.len);
+            }
+        }
+    }
+}
+
+/// Where the ring of slots lives
+#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+struct Slots(mapping::MappedFile);
+
+#[cfg(not(all(target_os = "linux", target_pointer_width = "64")))]
+struct Slots(File);
+
+impl Slots {
+    #[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+    fn open(file: File, len: usize) -> std::io::Result<Self> {
+        mapping::MappedFile::map(file, len).map(Slots)
+    }
+
+    #[cfg(not(all(target_os = "linux", target_pointer_width = "64")))]
+    fn open(file: File, _len: usize) -> std::io::Result<Self> {
+        Ok(Slots(file))
+    }
+
+    /// Overwrite one slot with a line, padded with spaces
+    #[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+    fn write_slot(&mut self, slot: u64, line: &[u8]) -> std::io::Result<()> {
+        let start = slot as usize * SLOT_SIZE;
+        let buf = &mut self.0.bytes_mut()[start..start + SLOT_SIZE];
+        buf[..line.len()].copy_from_slice(line);
+        buf[line.len()..].fill(b' ');
+        buf[SLOT_SIZE - 1] = b'\n';
+        Ok(())
+    }
+
+    /// Overwrite one slot with a line, padded with spaces
+    #[cfg(not(all(target_os = "linux", target_pointer_width = "64")))]
+    fn write_slot(&mut self, slot: u64, line: &[u8]) -> std::io::Result<()> {
+        use std::io::{Seek, SeekFrom};
+
+        let mut buf = vec![b' '; SLOT_SIZE];
+        buf[..line.len()].copy_from_slice(line);
+        buf[SLOT_SIZE - 1] = b'\n';
+        self.0.seek(SeekFrom::Start(slot * SLOT_SIZE as u64))?;
+        self.0.write_all(&buf)
+    }
+
+    /// Force written slots to disk
+    #[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+    fn sync(&self) -> std::io::Result<()> {
+        self.0.sync()
+    }
+
+    /// Force written slots to disk
+    #[cfg(not(all(target_os = "linux", target_pointer_width = "64")))]
+    fn sync(&self) -> std::io::Result<()> {
+        self.0.sync_data()
+    }
+}
+
+/// State shared between the recorder and its panic hook
+struct Shared {
+    slots: Mutex<Slots>,
+    capacity: u64,
+    /// Sequence number of the next record
+    next_seq: AtomicU64,
+    /// Tick of the most recent record
+    last_tick: AtomicU64,
+}
+
+impl Shared {
+    fn write_record(&self, slots: &mut Slots, record: JsonValue) -> std::io::Result<()> {
+        let seq = self.next_seq.fetch_add(1, Ordering::SeqCst);
+        let slot = 1 + seq % self.capacity;
+
+        let mut line = record_line(seq, record);
+        if line.len() >= SLOT_SIZE - 1 {
+            // Never spill into the neighbouring slot or the slot's newline
+            line = record_line(
+                seq,
+                object([
+                    ("kind", JsonValue::from("truncated")),
+                    ("tick", JsonValue::from(self.last_tick.load(Ordering::SeqCst))),
+                ]),
+            );
+        }
+
+        slots.write_slot(slot, line.as_bytes())
+    }
+}
+
+/// Write a PANIC record to the panic hook's black box
+fn record_panic(info: &panic::PanicHookInfo<'_>) {
+    // try_lock: the panic may have happened while a lock was held
+    let Ok(target) = PANIC_TARGET.try_lock() else {
+        return;
+    };
+    let Some(shared) = target.as_ref() else {
+        return;
+    };
+    let Ok(mut slots) = shared.slots.try_lock() else {
+        return;
+    };
+    let record = object([
+        ("kind", JsonValue::from("panic")),
+        ("tick", JsonValue::from(shared.last_tick.load(Ordering::SeqCst))),
+        ("message", JsonValue::from(info.to_string())),
+    ]);
+    let _ = shared.write_record(&mut slots, record);
+    let _ = slots.sync();
+}
+
+fn record_line(seq: u64, record: JsonValue) -> String {
+    let mut record = record;
+    if let JsonValue::Object(map) = &mut record {
+        map.insert("seq".to_string(), JsonValue::from(seq));
+    }
+    record.to_string()
+}
+
+/// Black box recorder keeping the last records in a ring file
+pub struct BlackBox {
+    shared: Arc<Shared>,
+    path: String,
+}
+
+impl BlackBox {
+    /// Create (or overwrite) a pre-allocated black box file
+    /// `capacity` is the number of records kept before the oldest is overwritten
+    pub fn create(path: &str, capacity: usize) -> Result<Self, String> {
+        let capacity = capacity.max(1) as u64;
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(|e| format!("Cannot create black box '{}': {}", path, e))?;
+
+        // Pre-allocate every slot up front so recording never grows the file
+        let mut blank = vec![b' '; SLOT_SIZE];
+        blank[SLOT_SIZE - 1] = b'\n';
+        let mut header = blank.clone();
+        let text = format!("{} capacity={} slot_size={}", MAGIC, capacity, SLOT_SIZE);
+        header[..text.len()].copy_from_slice(text.as_bytes());
+
+        let io_err = |e: std::io::Error| format!("Cannot pre-allocate black box '{}': {}", path, e);
+        file.write_all(&header).map_err(io_err)?;
+        for _ in 0..capacity {
+            file.write_all(&blank).map_err(io_err)?;
+        }
+        file.sync_all().map_err(io_err)?;
+
+        let len = (capacity as usize + 1) * SLOT_SIZE;
+        let slots = Slots::open(file, len).map_err(|e| format!("Cannot map black box '{}': {}", path, e))?;
+
+        Ok(Self {
+            shared: Arc::new(Shared {
+                slots: Mutex::new(slots),
+                capacity,
+                next_seq: AtomicU64::new(0),
+                last_tick: AtomicU64::new(0),
+            }),
+            path: path.to_string(),
+        })
+    }
+
+    /// Capacity in records needed to cover `seconds` at the given tick rate
+    pub fn capacity_for(seconds: u64, tick_rate_ms: u64) -> usize {
+        let ticks = (seconds * 1000 / tick_rate_ms.max(1)).max(1) as usize;
+        ticks * RECORDS_PER_TICK
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Make this black box record panics and sync the file
+    /// The hook is installed on the first call only; later calls redirect
+    /// it. The previously installed hook still runs afterwards.
+    pub fn install_panic_hook(&self) {
+        if let Ok(mut target) = PANIC_TARGET.lock() {
+            *target = Some(Arc::clone(&self.shared));
+        }
+        PANIC_HOOK.call_once(|| {
+            let previous = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                record_panic(info);
+                previous(info);
+            }));
+        });
+    }
+
+    /// Record the snapshot of one tick
+    pub fn record_snapshot(&self, snapshot: &SystemSnapshot) {
+        self.shared.last_tick.store(snapshot.tick, Ordering::SeqCst);
+        self.write(object([
+            ("kind", JsonValue::from("snapshot")),
+            ("tick", JsonValue::from(snapshot.tick)),
+            ("data", snapshot.to_json()),
+        ]));
+    }
+
+    /// Record a message routed on the bus
+    pub fn record_message(&self, from: ComponentId, message: &CarMessage) {
+        self.record_messages(from, std::slice::from_ref(message));
+    }
+
+    /// Record a batch of messages from one component under a single lock
+    pub fn record_messages(&self, from: ComponentId, messages: &[CarMessage]) {
+        if messages.is_empty() {
+            return;
+        }
+        let tick = self.shared.last_tick.load(Ordering::SeqCst);
+        if let Ok(mut slots) = self.shared.slots.lock() {
+            for message in messages {
+                let record = object([
+                    ("kind", JsonValue::from("message")),
+                    ("tick", JsonValue::from(tick)),
+                    ("from", JsonValue::from(from.as_str())),
+                    ("message", JsonValue::from(message.format())),
+                    ("data", message.to_json()),
+                ]);
+                if let Err(e) = self.shared.write_record(&mut slots, record) {
+                    log_error!("BlackBox", "⚠️  Black box write failed: {}", e);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Force recorded data to disk (e.g. at shutdown)
+    pub fn sync(&self) -> Result<(), String> {
+        let slots = self.shared.slots.lock().map_err(|_| "Black box lock poisoned".to_string())?;
+        slots.sync().map_err(|e| format!("Cannot sync black box: {}", e))
+    }
+
+    fn write(&self, record: JsonValue) {
+        if let Ok(mut slots) = self.shared.slots.lock() {
+            if let Err(e) = self.shared.write_record(&mut slots, record) {
+                log_error!("BlackBox", "⚠️  Black box write failed: {}", e);
+            }
+        }
+    }
+
+    /// Read all records of a black box file, oldest first
+    pub fn read_records(path: &str) -> Result<Vec<JsonValue>, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read black box '{}': {}", path, e))?;
+        let mut lines = text.lines();
+        match lines.next() {
+            Some(header) if header.starts_with(MAGIC) => {}
+            _ => return Err(format!("{}: not a black box file", path)),
+        }
+
+        let mut records: Vec<JsonValue> = lines
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .filter_map(|line| JsonValue::parse(line).ok())
+            .collect();
+        records.sort_by_key(|r| r.get("seq").and_then(JsonValue::as_u64).unwrap_or(0));
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("blackbox-{}-{}.bin", name, std::process::id()));
+        path.to_string_lossy().into_owned()
+    }
+
+    /// Record whose line (with `seq` 0) is exactly `len` bytes long
+    fn record_of_len(len: usize) -> JsonValue {
+        let record = |text: String| object([("kind", JsonValue::from("note")), ("text", JsonValue::from(text.as_str()))]);
+        let base = record_line(0, record(String::new())).len();
+        record("x".repeat(len - base))
+    }
+
+    fn round_trip(name: &str, record: JsonValue) -> Vec<JsonValue> {
+        let path = temp_path(name);
+        let blackbox = BlackBox::create(&path, 4).unwrap();
+        blackbox.write(record);
+        let records = BlackBox::read_records(&path).unwrap();
+        let _ = fs::remove_file(&path);
+        records
+    }
+
+    #[test]
+    fn longest_record_that_fits_is_kept() {
+        let record = record_of_len(SLOT_SIZE - 2);
+        let records = round_trip("fits", record.clone());
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].get("text"), record.get("text"));
+    }
+
+    #[test]
+    fn record_filling_the_newline_byte_is_truncated() {
+        let records = round_trip("boundary", record_of_len(SLOT_SIZE - 1));
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].get("kind").and_then(JsonValue::as_str), Some("truncated"));
+    }
+}
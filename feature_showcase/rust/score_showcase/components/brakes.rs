@@ -0,0 +1,313 @@
+//! Brakes component - manages brake pressure and application
+//! Demonstrates S-CORE patterns:
+//! - State management (released → applying → holding state machine)
+//! - Anti-lock braking: hard braking at speed activates ABS
+//! - Input validation
+//! - Gradual state changes (pressure decay)
+//! - Message publishing (Phase 3)
+//! - Optional redundant pressure sensors (see `Voter`)
+
+use crate::components::{CarComponent, ComponentState, CarMessage, HealthStatus};
+use crate::components::state_machine::{BrakeStateMachine, StateMachine, TransitionMachine};
+use crate::components::event_loop::DEFAULT_TIME_STEP_S;
+use crate::components::units::display_units;
+use crate::components::voter::RedundantSignal;
+use crate::{log_info, log_warn};
+
+/// Pressure decay when released, in % per second
+const DECAY_RATE_PERCENT_PER_S: f32 = 10.0;
+
+/// ABS activates above this pressure, in %...
+const ABS_PRESSURE_PERCENT: u8 = 90;
+
+/// ...when the car is faster than this, in km/h
+const ABS_MIN_SPEED_KMH: u8 = 60;
+
+/// Brakes component - manages the car's braking system
+pub struct BrakesComponent {
+    state: ComponentState,
+    brake_state: TransitionMachine<BrakeStateMachine>,
+    /// State when messages were last produced (to publish changes)
+    published_state: BrakeStateMachine,
+    /// ABS and fault changes of the last process() call
+    state_messages: Vec<CarMessage>,
+    pressure: u8, // 0-100%
+    /// Vehicle speed for the ABS decision, in km/h
+    vehicle_speed: u8,
+    /// Decay not yet applied to the integer pressure
+    decay_remainder: f32,
+    /// Simulated seconds per process() call
+    dt_s: f32,
+    application_count: u64,
+    /// Redundant pressure sensors (none unless configured)
+    pressure_channels: RedundantSignal,
+}
+
+impl BrakesComponent {
+    /// Create a new brakes component
+    pub fn new() -> Self {
+        Self {
+            state: ComponentState::Offline,
+            brake_state: BrakeStateMachine::machine(),
+            published_state: BrakeStateMachine::Released,
+            state_messages: Vec::with_capacity(2),
+            pressure: 0,
+            vehicle_speed: 0,
+            decay_remainder: 0.0,
+            dt_s: DEFAULT_TIME_STEP_S,
+            application_count: 0,
+            pressure_channels: RedundantSignal::default(),
+        }
+    }
+
+    /// Apply brakes with specified pressure (0-100%)
+    pub fn apply(&mut self, pressure: u8) -> Result<(), String> {
+        if pressure > 100 {
+            return Err("Pressure cannot exceed 100%".to_string());
+        }
+
+        // ABS keeps control of a new pressure; a faulted system just holds it
+        match self.brake_state.current_state() {
+            BrakeStateMachine::AbsActive | BrakeStateMachine::Fault => {}
+            _ => self.enter(BrakeStateMachine::Applying, "applied")?,
+        }
+        self.pressure = pressure;
+        self.decay_remainder = 0.0;
+        self.application_count += 1;
+        log_info!("Brakes", pressure = pressure; "  🛞 Brakes: Applied at {}% pressure", pressure);
+        Ok(())
+    }
+
+    /// Release brakes (a faulted system keeps holding its pressure)
+    pub fn release(&mut self) {
+        let faulted = *self.brake_state.current_state() == BrakeStateMachine::Fault;
+        if !faulted && self.brake_state.can_transition_to(&BrakeStateMachine::Released) {
+            log_info!("Brakes", "  🛞 Brakes: Releasing");
+            self.brake_state.set_state_because(BrakeStateMachine::Released, "released");
+        }
+    }
+
+    /// Enter the fault state (e.g. the pressure sensors disagree)
+    /// The current pressure is held and ABS is unavailable until
+    /// `clear_fault`.
+    pub fn report_fault(&mut self, reason: &'static str) {
+        if self.brake_state.can_transition_to(&BrakeStateMachine::Fault) {
+            log_warn!("Brakes", "  🛞 Brakes: FAULT - {}, holding {}% without ABS", reason, self.pressure);
+            self.brake_state.set_state_because(BrakeStateMachine::Fault, reason);
+        }
+    }
+
+    /// Leave the fault state; the held pressure is released
+    pub fn clear_fault(&mut self) {
+        if *self.brake_state.current_state() == BrakeStateMachine::Fault {
+            log_info!("Brakes", "  🛞 Brakes: Fault cleared, releasing");
+            self.brake_state.set_state_because(BrakeStateMachine::Released, "fault cleared");
+        }
+    }
+
+    /// Tell the brakes the vehicle speed (km/h), used to decide on ABS
+    pub fn set_vehicle_speed(&mut self, km_h: u8) {
+        self.vehicle_speed = km_h;
+    }
+
+    /// Move the state machine to `state` (staying in a state is always allowed)
+    fn enter(&mut self, state: BrakeStateMachine, reason: &'static str) -> Result<(), String> {
+        if *self.brake_state.current_state() == state {
+            return Ok(());
+        }
+        self.brake_state.transition_because(state, reason)
+    }
+
+    /// Brake state machine with its transition history
+    pub fn state_machine(&self) -> &TransitionMachine<BrakeStateMachine> {
+        &self.brake_state
+    }
+
+    /// Stamp the following state transitions with this tick
+    pub fn set_tick(&mut self, tick: u64) {
+        self.brake_state.set_tick(tick);
+    }
+
+    /// Get current brake state
+    pub fn get_brake_state(&self) -> &BrakeStateMachine {
+        self.brake_state.current_state()
+    }
+
+    /// Get current brake pressure
+    pub fn get_pressure(&self) -> u8 {
+        self.pressure
+    }
+
+    /// Total number of brake applications (service counter)
+    pub fn get_application_count(&self) -> u64 {
+        self.application_count
+    }
+
+    /// Restore the service counter from persisted data
+    pub fn set_application_count(&mut self, count: u64) {
+        self.application_count = count;
+    }
+
+    /// Set the simulated time covered by each process() call
+    pub fn set_time_step(&mut self, dt_s: f32) {
+        self.dt_s = dt_s;
+    }
+
+    /// Measure the pressure with several sensors (fewer than 2 = one sensor)
+    pub fn set_pressure_channels(&mut self, channels: usize) {
+        self.pressure_channels = RedundantSignal::new(if channels < 2 { 0 } else { channels });
+    }
+
+    /// Readings of the redundant pressure sensors in % (empty without redundancy)
+    pub fn pressure_readings(&self) -> &[f32] {
+        self.pressure_channels.readings()
+    }
+
+    /// Give one pressure sensor a fixed error in % (fault injection)
+    pub fn set_pressure_channel_offset(&mut self, channel: usize, offset: f32) -> Result<(), String> {
+        self.pressure_channels.set_offset(channel, offset)
+    }
+
+    /// Check if brakes are applied
+    pub fn is_applied(&self) -> bool {
+        matches!(
+            self.brake_state.current_state(),
+            BrakeStateMachine::Applying | BrakeStateMachine::Holding | BrakeStateMachine::AbsActive
+        )
+    }
+
+    /// Check if anti-lock braking is active
+    pub fn is_abs_active(&self) -> bool {
+        *self.brake_state.current_state() == BrakeStateMachine::AbsActive
+    }
+
+    /// ABS activation condition: hard braking at speed
+    fn abs_required(&self) -> bool {
+        self.pressure > ABS_PRESSURE_PERCENT && self.vehicle_speed > ABS_MIN_SPEED_KMH
+    }
+
+    /// Messages for ABS and fault changes since the last call
+    fn update_state_messages(&mut self) {
+        let state = self.brake_state.current_state().clone();
+        self.state_messages.clear();
+        let was_abs = self.published_state == BrakeStateMachine::AbsActive;
+        let was_fault = self.published_state == BrakeStateMachine::Fault;
+        if !was_abs && state == BrakeStateMachine::AbsActive {
+            self.state_messages.push(CarMessage::AbsActivated {
+                pressure: self.pressure,
+                km_h: self.vehicle_speed,
+            });
+        } else if was_abs && state != BrakeStateMachine::AbsActive {
+            self.state_messages.push(CarMessage::AbsDeactivated);
+        }
+        if !was_fault && state == BrakeStateMachine::Fault {
+            self.state_messages.push(CarMessage::BrakeFault);
+        } else if was_fault && state != BrakeStateMachine::Fault {
+            self.state_messages.push(CarMessage::BrakeFaultCleared);
+        }
+        self.published_state = state;
+    }
+
+    /// Get messages to publish (Phase 3: Communication)
+    pub fn get_messages(&self) -> Vec<CarMessage> {
+        let mut messages = Vec::new();
+        self.get_messages_into(&mut messages);
+        messages
+    }
+
+    /// Append messages to publish to a reused buffer
+    pub fn get_messages_into(&self, messages: &mut Vec<CarMessage>) {
+        messages.extend(self.state_messages.iter().cloned());
+
+        // Report brake pressure changes
+        if self.pressure > 0 {
+            messages.push(CarMessage::BrakePressureChange {
+                pressure: self.pressure,
+            });
+        }
+    }
+}
+
+impl Default for BrakesComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CarComponent for BrakesComponent {
+    fn name(&self) -> &'static str {
+        "Brakes"
+    }
+
+    fn initialize(&mut self) -> Result<(), String> {
+        log_info!("Brakes", "🔧 Brakes: Initializing component...");
+        self.state = ComponentState::Initializing;
+
+        // Simulate initialization checks
+        log_info!("Brakes", "  🔍 Brakes: Checking brake fluid... OK");
+        log_info!("Brakes", "  🔍 Brakes: Checking brake pads... OK");
+        log_info!("Brakes", "  🔍 Brakes: Checking ABS system... OK");
+
+        self.state = ComponentState::Online;
+        log_info!("Brakes", "✅ Brakes: Initialized (state: {})", self.state);
+        Ok(())
+    }
+
+    fn process(&mut self) -> Result<(), String> {
+        match self.brake_state.current_state() {
+            // Brake pressure slowly releases if not actively applied
+            // This simulates gradual pressure decay
+            BrakeStateMachine::Released if self.pressure > 0 => {
+                self.decay_remainder += DECAY_RATE_PERCENT_PER_S * self.dt_s;
+                let decay = self.decay_remainder.floor().min(100.0);
+                self.decay_remainder -= decay;
+                self.pressure = self.pressure.saturating_sub(decay as u8);
+                if self.pressure == 0 {
+                    log_info!("Brakes", "  🛞 Brakes: Fully released");
+                }
+            }
+            // The commanded pressure is reached within one cycle
+            BrakeStateMachine::Applying => self.enter(BrakeStateMachine::Holding, "pressure reached")?,
+            _ => {}
+        }
+
+        // ABS takes over hard braking at speed and hands back once it's over
+        let state = self.brake_state.current_state();
+        if *state == BrakeStateMachine::Holding && self.abs_required() {
+            let units = display_units();
+            log_info!("Brakes", "  🛞 Brakes: ABS active ({}% at {:.0} {})", self.pressure,
+                      units.speed(self.vehicle_speed as f32), units.speed_unit());
+            self.enter(BrakeStateMachine::AbsActive, "hard braking at speed")?;
+        } else if *state == BrakeStateMachine::AbsActive && !self.abs_required() {
+            log_info!("Brakes", "  🛞 Brakes: ABS inactive");
+            self.enter(BrakeStateMachine::Holding, "wheel lock risk over")?;
+        }
+
+        self.pressure_channels.measure(self.pressure as f32, || 0.0);
+        self.update_state_messages();
+
+        Ok(())
+    }
+
+    fn get_state(&self) -> ComponentState {
+        self.state.clone()
+    }
+
+    fn shutdown(&mut self) -> Result<(), String> {
+        log_info!("Brakes", "🔧 Brakes: Shutting down...");
+        self.release();
+        self.state = ComponentState::Offline;
+        log_info!("Brakes", "✅ Brakes: Shut down (state: {})", self.get_brake_state());
+        Ok(())
+    }
+
+    fn health_check(&self) -> HealthStatus {
+        if self.pressure > 100 {
+            return HealthStatus::Unhealthy("pressure above 100%");
+        }
+        if *self.brake_state.current_state() == BrakeStateMachine::Fault {
+            return HealthStatus::Degraded("pressure sensors disagree, ABS unavailable");
+        }
+        HealthStatus::from_state(&self.state)
+    }
+}
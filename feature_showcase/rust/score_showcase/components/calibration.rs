@@ -0,0 +1,197 @@
+//! Safety limit calibration sets
+//! Demonstrates automotive calibration patterns:
+//! - Several named parameter sets ("city", "track", "winter") for one ECU
+//! - Export/import of the sets to a calibration file
+//! - Switching the active set at runtime and diffing two sets
+//!
+//! Calibration file format (JSON); each set is a safety limits object
+//! (see `safety_file`) and fields missing from a set fall back to the
+//! default safety limits:
+//! ```json
+//! { "active": "city",
+//!   "sets": { "city": { "max_speed": 50 }, "track": { "max_speed": 200 } } }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::components::config::SafetyConfig;
+use crate::components::json::{object, JsonValue};
+
+/// A collection of named safety limit sets with one active set
+#[derive(Debug, Clone)]
+pub struct CalibrationBook {
+    sets: BTreeMap<String, SafetyConfig>,
+    active: String,
+}
+
+impl CalibrationBook {
+    /// Built-in calibration sets
+    pub fn builtin() -> Self {
+        let base = SafetyConfig::default();
+        let mut sets = BTreeMap::new();
+        sets.insert("standard".to_string(), base.clone());
+        sets.insert(
+            "city".to_string(),
+            SafetyConfig {
+                max_speed: 50,
+                max_rpm: 4500,
+                ..base.clone()
+            },
+        );
+        sets.insert(
+            "track".to_string(),
+            SafetyConfig {
+                max_speed: 200,
+                max_temperature: 105.0,
+                max_rpm: 7500,
+                max_brake_pressure: 95,
+                ..base.clone()
+            },
+        );
+        sets.insert(
+            "winter".to_string(),
+            SafetyConfig {
+                max_speed: 90,
+                max_brake_pressure: 60,
+                min_fuel: 25,
+                ..base
+            },
+        );
+
+        Self {
+            sets,
+            active: "standard".to_string(),
+        }
+    }
+
+    /// Import calibration sets from a JSON file
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read calibration file '{}': {}", path, e))?;
+        let json = JsonValue::parse(&text).map_err(|e| format!("{}: {}", path, e))?;
+        Self::from_json(&json).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    /// Build from parsed JSON
+    pub fn from_json(json: &JsonValue) -> Result<Self, String> {
+        let sets_json = json
+            .get("sets")
+            .and_then(JsonValue::as_object)
+            .ok_or("missing 'sets' object")?;
+
+        let mut sets = BTreeMap::new();
+        for (name, fields) in sets_json {
+            let limits = SafetyConfig::from_json(fields).map_err(|e| format!("set '{}': {}", name, e))?;
+            sets.insert(name.clone(), limits);
+        }
+        if sets.is_empty() {
+            return Err("calibration file defines no sets".to_string());
+        }
+
+        let active = match json.get("active").and_then(JsonValue::as_str) {
+            Some(name) => name.to_string(),
+            None => sets.keys().next().cloned().unwrap_or_default(),
+        };
+        if !sets.contains_key(&active) {
+            return Err(format!("active set '{}' is not defined", active));
+        }
+
+        Ok(Self { sets, active })
+    }
+
+    /// Export all sets as JSON
+    pub fn to_json(&self) -> JsonValue {
+        let sets = self
+            .sets
+            .iter()
+            .map(|(name, limits)| (name.clone(), limits.to_json()))
+            .collect();
+        object([
+            ("active", JsonValue::from(self.active.as_str())),
+            ("sets", JsonValue::Object(sets)),
+        ])
+    }
+
+    /// Export all sets to a JSON file
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_json().to_pretty_string() + "\n")
+            .map_err(|e| format!("Cannot write calibration file '{}': {}", path, e))
+    }
+
+    /// Names of all sets
+    pub fn names(&self) -> Vec<&str> {
+        self.sets.keys().map(String::as_str).collect()
+    }
+
+    /// Name of the active set
+    pub fn active_name(&self) -> &str {
+        &self.active
+    }
+
+    /// Limits of the active set
+    pub fn active(&self) -> &SafetyConfig {
+        &self.sets[&self.active]
+    }
+
+    /// Limits of a named set
+    pub fn get(&self, name: &str) -> Option<&SafetyConfig> {
+        self.sets.get(name)
+    }
+
+    /// Switch the active set
+    pub fn select(&mut self, name: &str) -> Result<&SafetyConfig, String> {
+        if !self.sets.contains_key(name) {
+            return Err(format!(
+                "Unknown calibration set '{}' (available: {})",
+                name,
+                self.names().join(", ")
+            ));
+        }
+        self.active = name.to_string();
+        Ok(self.active())
+    }
+
+    /// Fields that differ between two sets: (field, value in a, value in b)
+    pub fn diff(&self, a: &str, b: &str) -> Result<Vec<(&'static str, String, String)>, String> {
+        let left = self.get(a).ok_or_else(|| format!("Unknown calibration set '{}'", a))?;
+        let right = self.get(b).ok_or_else(|| format!("Unknown calibration set '{}'", b))?;
+
+        let left = limit_fields(left);
+        let right = limit_fields(right);
+        Ok(left
+            .into_iter()
+            .zip(right)
+            .filter(|((_, l), (_, r))| l != r)
+            .map(|((field, l), (_, r))| (field, l, r))
+            .collect())
+    }
+
+    /// Print a diff between two sets
+    pub fn print_diff(&self, a: &str, b: &str) -> Result<(), String> {
+        let changes = self.diff(a, b)?;
+        println!("🎚️  Calibration diff: {} → {}", a, b);
+        if changes.is_empty() {
+            println!("   (identical)");
+        }
+        for (field, left, right) in changes {
+            println!("   {:<20} {:>8} → {:<8}", field, left, right);
+        }
+        Ok(())
+    }
+}
+
+/// Limit fields in textual form, in a fixed order
+fn limit_fields(limits: &SafetyConfig) -> Vec<(&'static str, String)> {
+    vec![
+        ("max_speed", limits.max_speed.to_string()),
+        ("max_temperature", limits.max_temperature.to_string()),
+        ("max_rpm", limits.max_rpm.to_string()),
+        ("min_fuel", limits.min_fuel.to_string()),
+        ("max_brake_pressure", limits.max_brake_pressure.to_string()),
+        ("max_speed_step", limits.max_speed_step.to_string()),
+        ("max_temperature_step", limits.max_temperature_step.to_string()),
+        ("max_battery_temperature", limits.max_battery_temperature.to_string()),
+        ("min_battery_soc", limits.min_battery_soc.to_string()),
+    ]
+}
@@ -0,0 +1,175 @@
+//! Latched faults
+//! Demonstrates S-CORE fault management patterns (like ISO 26262):
+//! - Critical and Emergency warnings latch as faults that stay reported
+//!   after the condition clears, until they are acknowledged
+//! - Acknowledging a fault whose condition is still present keeps it
+//!   listed; it goes away once the condition has cleared too
+//! - Faults keep a stable ID so a driver or tester can refer to them
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::safety::{SafetySeverity, SafetyWarning};
+
+/// One latched fault
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fault {
+    pub id: u32,
+    /// Most recent warning of this fault
+    pub warning: SafetyWarning,
+    /// Condition present in the last check
+    pub active: bool,
+    pub acknowledged: bool,
+    /// Checks in which the condition was present
+    pub occurrences: u32,
+}
+
+impl Fault {
+    pub fn type_name(&self) -> &'static str {
+        self.warning.type_name()
+    }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = match (self.active, self.acknowledged) {
+            (true, false) => "active",
+            (true, true) => "active, acknowledged",
+            (false, _) => "cleared, not acknowledged",
+        };
+        write!(f, "#{} {} ({})", self.id, self.type_name(), state)
+    }
+}
+
+/// Faults latched from Critical and Emergency warnings, one per warning type
+#[derive(Debug, Clone, Default)]
+pub struct FaultStore {
+    faults: Vec<Fault>,
+    next_id: u32,
+}
+
+impl FaultStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the warnings of one check
+    pub fn record(&mut self, warnings: &[SafetyWarning]) {
+        for fault in &mut self.faults {
+            fault.active = false;
+        }
+        for warning in warnings.iter().filter(|w| w.severity() >= SafetySeverity::Critical) {
+            match self.faults.iter_mut().find(|f| f.type_name() == warning.type_name()) {
+                Some(fault) => {
+                    fault.warning = warning.clone();
+                    fault.active = true;
+                    fault.occurrences += 1;
+                }
+                None => {
+                    self.next_id += 1;
+                    self.faults.push(Fault {
+                        id: self.next_id,
+                        warning: warning.clone(),
+                        active: true,
+                        acknowledged: false,
+                        occurrences: 1,
+                    });
+                }
+            }
+        }
+        self.release();
+    }
+
+    /// Acknowledge a fault; it is released as soon as its condition is gone
+    pub fn acknowledge(&mut self, id: u32) -> Result<(), String> {
+        let fault = self
+            .faults
+            .iter_mut()
+            .find(|f| f.id == id)
+            .ok_or_else(|| format!("Unknown fault #{}", id))?;
+        fault.acknowledged = true;
+        self.release();
+        Ok(())
+    }
+
+    /// Acknowledge every fault, returning how many there were
+    pub fn acknowledge_all(&mut self) -> usize {
+        let count = self.faults.len();
+        for fault in &mut self.faults {
+            fault.acknowledged = true;
+        }
+        self.release();
+        count
+    }
+
+    /// Active and latched faults, oldest first
+    pub fn active_faults(&self) -> &[Fault] {
+        &self.faults
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.faults.is_empty()
+    }
+
+    /// Drop faults that are cleared and acknowledged
+    fn release(&mut self) {
+        self.faults.retain(|f| f.active || !f.acknowledged);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overheating(current: f32) -> SafetyWarning {
+        SafetyWarning::Overheating { current, max: 95.0 }
+    }
+
+    #[test]
+    fn critical_warning_latches_after_the_condition_clears() {
+        let mut store = FaultStore::new();
+        store.record(&[overheating(97.0), SafetyWarning::LowFuel { level: 10 }]);
+        assert_eq!(store.active_faults().len(), 1, "only Critical and Emergency warnings latch");
+        let id = store.active_faults()[0].id;
+
+        store.record(&[overheating(98.0)]);
+        store.record(&[]);
+        let fault = &store.active_faults()[0];
+        assert_eq!((fault.id, fault.active, fault.acknowledged), (id, false, false));
+        assert_eq!(fault.occurrences, 2);
+        assert_eq!(fault.warning, overheating(98.0));
+        assert_eq!(fault.to_string(), format!("#{} Overheating (cleared, not acknowledged)", id));
+
+        store.acknowledge(id).unwrap();
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn acknowledged_fault_stays_listed_while_active() {
+        let mut store = FaultStore::new();
+        store.record(&[overheating(97.0)]);
+        let id = store.active_faults()[0].id;
+
+        store.acknowledge(id).unwrap();
+        let fault = &store.active_faults()[0];
+        assert!(fault.active && fault.acknowledged);
+        assert_eq!(fault.to_string(), format!("#{} Overheating (active, acknowledged)", id));
+
+        store.record(&[overheating(96.0)]);
+        assert_eq!(store.active_faults().len(), 1);
+        store.record(&[]);
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn acknowledging_an_unknown_fault_fails() {
+        let mut store = FaultStore::new();
+        store.record(&[overheating(97.0)]);
+        let id = store.active_faults()[0].id;
+
+        assert_eq!(store.acknowledge(id + 1), Err(format!("Unknown fault #{}", id + 1)));
+        assert!(!store.active_faults()[0].acknowledged);
+    }
+}
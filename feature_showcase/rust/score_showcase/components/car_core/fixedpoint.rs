@@ -0,0 +1,159 @@
+//! Fixed-point signal representation
+//! Demonstrates S-CORE signal encoding patterns:
+//! - Physical values carried as scaled integers (physical = raw × factor + offset)
+//! - Scaling metadata per signal, as in CAN signal definitions (DBC files)
+//! - Integer comparisons once values and limits are encoded
+//!
+//! The module is always available. Building with the `fixed_point` crate
+//! feature (Bazel target `car_system_example_fixed_point`) makes sensor
+//! reads carry CAN resolution and lets the safety monitor compare encoded
+//! values instead of floats.
+
+use core::cmp::Ordering;
+use core::fmt;
+
+/// Scaling metadata of one signal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalScale {
+    pub name: &'static str,
+    pub unit: &'static str,
+    /// Physical value of one raw step
+    pub factor: f32,
+    /// Physical value of raw 0
+    pub offset: f32,
+    /// Width of the raw value on the bus
+    pub bits: u8,
+    pub signed: bool,
+}
+
+/// Engine temperature: 0.1 °C steps from -40 °C, 12 bits
+pub const TEMPERATURE: SignalScale = SignalScale {
+    name: "temperature",
+    unit: "°C",
+    factor: 0.1,
+    offset: -40.0,
+    bits: 12,
+    signed: false,
+};
+
+/// Brake pressure: 1 % steps, 7 bits
+pub const BRAKE_PRESSURE: SignalScale = SignalScale {
+    name: "brake_pressure",
+    unit: "%",
+    factor: 1.0,
+    offset: 0.0,
+    bits: 7,
+    signed: false,
+};
+
+/// Steering angle: 0.5° steps, signed 12 bits
+pub const STEERING_ANGLE: SignalScale = SignalScale {
+    name: "steering_angle",
+    unit: "°",
+    factor: 0.5,
+    offset: 0.0,
+    bits: 12,
+    signed: true,
+};
+
+/// All fixed-point signals (e.g. for generating a CAN database)
+pub const SIGNALS: &[SignalScale] = &[TEMPERATURE, BRAKE_PRESSURE, STEERING_ANGLE];
+
+impl SignalScale {
+    /// Smallest raw value
+    pub fn raw_min(&self) -> i32 {
+        if self.signed {
+            -(1 << (self.bits - 1))
+        } else {
+            0
+        }
+    }
+
+    /// Largest raw value
+    pub fn raw_max(&self) -> i32 {
+        if self.signed {
+            (1 << (self.bits - 1)) - 1
+        } else {
+            (1 << self.bits) - 1
+        }
+    }
+
+    /// Encode a physical value, rounding to the resolution and saturating
+    /// at the signal range (NaN encodes as the raw value of 0.0)
+    pub fn encode(&'static self, physical: f32) -> FixedSignal {
+        let physical = if physical.is_nan() { 0.0 } else { physical };
+        let steps = round_half_away(((physical - self.offset) / self.factor) as f64);
+        FixedSignal {
+            raw: steps.clamp(self.raw_min() as i64, self.raw_max() as i64) as i32,
+            scale: self,
+        }
+    }
+
+    /// Wrap a raw bus value
+    pub fn from_raw(&'static self, raw: i32) -> FixedSignal {
+        FixedSignal {
+            raw: raw.clamp(self.raw_min(), self.raw_max()),
+            scale: self,
+        }
+    }
+
+    /// Round a physical value to what the signal can carry
+    pub fn quantize(&'static self, physical: f32) -> f32 {
+        self.encode(physical).physical()
+    }
+}
+
+/// Round to the nearest integer, halves away from zero (`f64::round`
+/// needs std); out-of-range values saturate
+fn round_half_away(value: f64) -> i64 {
+    if value >= 0.0 {
+        (value + 0.5) as i64
+    } else {
+        (value - 0.5) as i64
+    }
+}
+
+/// A signal value as raw integer plus its scaling
+#[derive(Debug, Clone, Copy)]
+pub struct FixedSignal {
+    pub raw: i32,
+    pub scale: &'static SignalScale,
+}
+
+impl FixedSignal {
+    /// Decode to the physical value
+    pub fn physical(&self) -> f32 {
+        self.raw as f32 * self.scale.factor + self.scale.offset
+    }
+}
+
+/// Values of the same signal compare by raw value; different signals
+/// don't compare
+impl PartialEq for FixedSignal {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale.name == other.scale.name && self.raw == other.raw
+    }
+}
+
+impl PartialOrd for FixedSignal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.scale.name == other.scale.name {
+            Some(self.raw.cmp(&other.raw))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for FixedSignal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Show as many decimals as one raw step needs
+        let mut decimals = 0;
+        let mut step = self.scale.factor;
+        while decimals < 6 && step != (step as i64) as f32 {
+            step *= 10.0;
+            decimals += 1;
+        }
+        write!(f, "{:.*}{}", decimals, self.physical(), self.scale.unit)
+    }
+}
@@ -0,0 +1,130 @@
+//! Safety goals
+//! Demonstrates S-CORE functional safety patterns (ISO 26262):
+//! - Every safety check serves a safety goal from the hazard analysis
+//! - Each goal carries an ASIL (A lowest to D highest, QM = no safety
+//!   requirement) that sets how rigorously it must be developed
+//! - Check reports group violations by goal, most critical goal first
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::safety::SafetyWarning;
+
+/// Automotive Safety Integrity Level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Asil {
+    QM,
+    A,
+    B,
+    C,
+    D,
+}
+
+impl fmt::Display for Asil {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Asil::QM => write!(f, "QM"),
+            level => write!(f, "ASIL {:?}", level),
+        }
+    }
+}
+
+/// Top-level safety requirement from the hazard analysis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyGoal {
+    pub id: &'static str,
+    pub asil: Asil,
+    pub statement: &'static str,
+}
+
+impl fmt::Display for SafetyGoal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} [{}] {}", self.id, self.asil, self.statement)
+    }
+}
+
+pub const SG_SPEED: SafetyGoal = SafetyGoal {
+    id: "SG-01",
+    asil: Asil::B,
+    statement: "Vehicle speed shall stay within the permitted limit",
+};
+pub const SG_ENGINE: SafetyGoal = SafetyGoal {
+    id: "SG-02",
+    asil: Asil::B,
+    statement: "The engine shall be protected from overheating and overspeed",
+};
+pub const SG_BRAKING: SafetyGoal = SafetyGoal {
+    id: "SG-03",
+    asil: Asil::D,
+    statement: "Braking shall stay available and controllable",
+};
+pub const SG_MOTION: SafetyGoal = SafetyGoal {
+    id: "SG-04",
+    asil: Asil::C,
+    statement: "Unintended vehicle motion shall be prevented",
+};
+pub const SG_DIAGNOSTICS: SafetyGoal = SafetyGoal {
+    id: "SG-05",
+    asil: Asil::B,
+    statement: "Faulty sensors and components shall be detected before they cause a hazard",
+};
+pub const SG_BATTERY: SafetyGoal = SafetyGoal {
+    id: "SG-06",
+    asil: Asil::C,
+    statement: "The traction battery shall be kept within its temperature and charge limits",
+};
+pub const QM_FUEL: SafetyGoal = SafetyGoal {
+    id: "QM-01",
+    asil: Asil::QM,
+    statement: "The driver shall be warned before running out of fuel",
+};
+
+/// All goals of the car system
+pub const SAFETY_GOALS: &[SafetyGoal] =
+    &[SG_SPEED, SG_ENGINE, SG_BRAKING, SG_MOTION, SG_DIAGNOSTICS, SG_BATTERY, QM_FUEL];
+
+impl SafetyWarning {
+    /// Safety goal served by the check that raises this warning
+    pub fn goal(&self) -> &'static SafetyGoal {
+        match self {
+            SafetyWarning::SpeedExceeded { .. } => &SG_SPEED,
+            SafetyWarning::Overheating { .. } | SafetyWarning::HighRPM { .. } => &SG_ENGINE,
+            SafetyWarning::BrakePressureTooHigh { .. } => &SG_BRAKING,
+            SafetyWarning::EngineStateInvalid { .. } => &SG_MOTION,
+            SafetyWarning::SensorImplausible { .. }
+            | SafetyWarning::SensorDisagreement { .. }
+            | SafetyWarning::ComponentUnresponsive { .. }
+            | SafetyWarning::DeadlineMissed { .. }
+            | SafetyWarning::ComponentUnhealthy { .. } => &SG_DIAGNOSTICS,
+            SafetyWarning::BatteryOverheating { .. }
+            | SafetyWarning::BatteryDeepDischarge { .. } => &SG_BATTERY,
+            SafetyWarning::LowFuel { .. } => &QM_FUEL,
+        }
+    }
+}
+
+/// Violations of one check, grouped by safety goal
+pub struct GoalReport<'a> {
+    /// Highest ASIL first, then by goal ID; warnings in check order
+    pub groups: Vec<(&'static SafetyGoal, Vec<&'a SafetyWarning>)>,
+}
+
+impl<'a> GoalReport<'a> {
+    pub fn new(warnings: &'a [SafetyWarning]) -> Self {
+        let mut groups: Vec<(&'static SafetyGoal, Vec<&'a SafetyWarning>)> = Vec::new();
+        for warning in warnings {
+            let goal = warning.goal();
+            match groups.iter_mut().find(|(g, _)| g.id == goal.id) {
+                Some((_, violations)) => violations.push(warning),
+                None => groups.push((goal, alloc::vec![warning])),
+            }
+        }
+        groups.sort_by(|(a, _), (b, _)| b.asil.cmp(&a.asil).then(a.id.cmp(b.id)));
+        Self { groups }
+    }
+
+    /// ASIL of the most critical violated goal
+    pub fn highest_asil(&self) -> Option<Asil> {
+        self.groups.first().map(|(goal, _)| goal.asil)
+    }
+}
@@ -0,0 +1,11 @@
+//! `#![no_std]` build of the car core for embedded targets
+//! The binary includes the same modules through `components::car_core`.
+
+#![no_std]
+
+extern crate alloc;
+
+#[path = "mod.rs"]
+mod car_core;
+
+pub use car_core::*;
@@ -0,0 +1,347 @@
+//! Message types for component communication
+//! This defines all events that components can publish/subscribe to
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// Car messages - events that components can send
+#[derive(Debug, Clone, PartialEq)]
+pub enum CarMessage {
+    /// Engine events
+    EngineStart,
+    EngineStop,
+    EngineOverheating { temperature: f32 },
+    EngineRpmChange { rpm: u32 },
+
+    /// Brake events
+    BrakeApply { pressure: u8 },
+    BrakeRelease,
+    BrakePressureChange { pressure: u8 },
+    AbsActivated { pressure: u8, km_h: u8 },
+    AbsDeactivated,
+    BrakeFault,
+    BrakeFaultCleared,
+
+    /// Steering events
+    SteeringTurn { angle: i16 },
+    SteeringCenter,
+
+    /// Vehicle events
+    SpeedUpdate { km_h: u8 },
+    FuelWarning { level: u8 },
+
+    /// Traction battery events (EV and hybrid powertrains)
+    BatteryLow { soc: u8 },
+    BatteryOverheating { temperature: f32 },
+
+    /// Climate control events
+    HvacLoad { watts: u16 },
+
+    /// Driver assistance events
+    CollisionImminent { distance: f32, ttc: f32 },
+    /// Position in m east (x) and north (y) of the start, compass heading
+    /// in degrees and the distance left along the route (None = no route)
+    PositionUpdate { x: f32, y: f32, heading: f32, to_destination: Option<f32> },
+
+    /// System events
+    ComponentError { component: ComponentId, error: String },
+    /// A failed component was restarted by the supervisor (attempt 1 = first restart)
+    ComponentRestarted { component: ComponentId, attempt: u32 },
+    ConfigChanged { key: String, value: String },
+    SelectCalibration { name: String },
+
+    /// Diagnostics requests and replies (request/reply, not broadcast)
+    QuerySignal { signal: String },
+    SignalReport { signal: String, value: f64 },
+}
+
+/// Delivery priority of a message, highest last
+/// Receivers get pending messages in priority order; within one priority
+/// they keep their publish order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MessagePriority {
+    /// Periodic value updates
+    Low,
+    /// Commands and state changes
+    Normal,
+    /// Driver-relevant warnings and brake commands
+    High,
+    /// Safety-relevant events
+    Critical,
+}
+
+impl MessagePriority {
+    /// All priorities, highest first (delivery order)
+    pub const DELIVERY_ORDER: [MessagePriority; 4] = [
+        MessagePriority::Critical,
+        MessagePriority::High,
+        MessagePriority::Normal,
+        MessagePriority::Low,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessagePriority::Low => "low",
+            MessagePriority::Normal => "normal",
+            MessagePriority::High => "high",
+            MessagePriority::Critical => "critical",
+        }
+    }
+}
+
+impl fmt::Display for MessagePriority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl CarMessage {
+    /// Delivery priority of this message type
+    pub fn priority(&self) -> MessagePriority {
+        match self {
+            CarMessage::EngineOverheating { .. }
+            | CarMessage::BatteryOverheating { .. }
+            | CarMessage::CollisionImminent { .. }
+            | CarMessage::ComponentError { .. }
+            | CarMessage::BrakeFault => MessagePriority::Critical,
+            CarMessage::BrakeApply { .. }
+            | CarMessage::AbsActivated { .. }
+            | CarMessage::ComponentRestarted { .. }
+            | CarMessage::FuelWarning { .. }
+            | CarMessage::BatteryLow { .. } => MessagePriority::High,
+            CarMessage::EngineRpmChange { .. }
+            | CarMessage::BrakePressureChange { .. }
+            | CarMessage::SpeedUpdate { .. }
+            | CarMessage::HvacLoad { .. }
+            | CarMessage::PositionUpdate { .. } => MessagePriority::Low,
+            _ => MessagePriority::Normal,
+        }
+    }
+
+    /// Get message type name for logging
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            CarMessage::EngineStart => "EngineStart",
+            CarMessage::EngineStop => "EngineStop",
+            CarMessage::EngineOverheating { .. } => "EngineOverheating",
+            CarMessage::EngineRpmChange { .. } => "EngineRpmChange",
+            CarMessage::BrakeApply { .. } => "BrakeApply",
+            CarMessage::BrakeRelease => "BrakeRelease",
+            CarMessage::BrakePressureChange { .. } => "BrakePressureChange",
+            CarMessage::AbsActivated { .. } => "AbsActivated",
+            CarMessage::AbsDeactivated => "AbsDeactivated",
+            CarMessage::BrakeFault => "BrakeFault",
+            CarMessage::BrakeFaultCleared => "BrakeFaultCleared",
+            CarMessage::SteeringTurn { .. } => "SteeringTurn",
+            CarMessage::SteeringCenter => "SteeringCenter",
+            CarMessage::SpeedUpdate { .. } => "SpeedUpdate",
+            CarMessage::FuelWarning { .. } => "FuelWarning",
+            CarMessage::BatteryLow { .. } => "BatteryLow",
+            CarMessage::BatteryOverheating { .. } => "BatteryOverheating",
+            CarMessage::HvacLoad { .. } => "HvacLoad",
+            CarMessage::CollisionImminent { .. } => "CollisionImminent",
+            CarMessage::PositionUpdate { .. } => "PositionUpdate",
+            CarMessage::ComponentError { .. } => "ComponentError",
+            CarMessage::ComponentRestarted { .. } => "ComponentRestarted",
+            CarMessage::ConfigChanged { .. } => "ConfigChanged",
+            CarMessage::SelectCalibration { .. } => "SelectCalibration",
+            CarMessage::QuerySignal { .. } => "QuerySignal",
+            CarMessage::SignalReport { .. } => "SignalReport",
+        }
+    }
+
+    /// Format message for display
+    pub fn format(&self) -> String {
+        match self {
+            CarMessage::EngineStart => "Engine started".to_string(),
+            CarMessage::EngineStop => "Engine stopped".to_string(),
+            CarMessage::EngineOverheating { temperature } => {
+                format!("⚠️ ENGINE OVERHEATING: {}°C", temperature)
+            }
+            CarMessage::EngineRpmChange { rpm } => format!("Engine RPM: {}", rpm),
+            CarMessage::BrakeApply { pressure } => format!("Brakes applied: {}%", pressure),
+            CarMessage::BrakeRelease => "Brakes released".to_string(),
+            CarMessage::BrakePressureChange { pressure } => {
+                format!("Brake pressure: {}%", pressure)
+            }
+            CarMessage::AbsActivated { pressure, km_h } => {
+                format!("ABS active: {}% at {} km/h", pressure, km_h)
+            }
+            CarMessage::AbsDeactivated => "ABS inactive".to_string(),
+            CarMessage::BrakeFault => "⚠️ BRAKE FAULT: ABS unavailable".to_string(),
+            CarMessage::BrakeFaultCleared => "Brake fault cleared".to_string(),
+            CarMessage::SteeringTurn { angle } => format!("Steering turned: {}°", angle),
+            CarMessage::SteeringCenter => "Steering centered".to_string(),
+            CarMessage::SpeedUpdate { km_h } => format!("Speed: {} km/h", km_h),
+            CarMessage::FuelWarning { level } => {
+                format!("⚠️ LOW FUEL: {}%", level)
+            }
+            CarMessage::BatteryLow { soc } => format!("⚠️ LOW BATTERY: {}%", soc),
+            CarMessage::BatteryOverheating { temperature } => {
+                format!("⚠️ BATTERY OVERHEATING: {:.1}°C", temperature)
+            }
+            CarMessage::HvacLoad { watts } => format!("HVAC load: {} W", watts),
+            CarMessage::CollisionImminent { distance, ttc } => {
+                format!("🚨 COLLISION IMMINENT: {:.1} m ahead, {:.1}s to impact", distance, ttc)
+            }
+            CarMessage::PositionUpdate { x, y, heading, to_destination } => match to_destination {
+                Some(distance) => format!("Position: ({:.0}, {:.0}) m heading {:.0}°, {:.0} m to go",
+                                          x, y, heading, distance),
+                None => format!("Position: ({:.0}, {:.0}) m heading {:.0}°", x, y, heading),
+            },
+            CarMessage::ComponentError { component, error } => {
+                format!("❌ ERROR in {}: {}", component.as_str(), error)
+            }
+            CarMessage::ComponentRestarted { component, attempt } => {
+                format!("🔄 {} restarted (attempt {})", component.as_str(), attempt)
+            }
+            CarMessage::ConfigChanged { key, value } => {
+                format!("⚙️ Config changed: {} = {}", key, value)
+            }
+            CarMessage::SelectCalibration { name } => {
+                format!("🎚️ Select calibration: {}", name)
+            }
+            CarMessage::QuerySignal { signal } => format!("🔎 Report {}", signal),
+            CarMessage::SignalReport { signal, value } => format!("🔎 {} = {}", signal, value),
+        }
+    }
+}
+
+/// Component ID for message routing
+/// Compared and hashed as a small integer; also carries the name it was
+/// registered under, for logging. The well-known components are constants,
+/// components added at runtime get an ID from a `ComponentRegistry`.
+/// IDs of runtime components are only meaningful within the registry that
+/// handed them out.
+#[derive(Debug, Clone, Copy)]
+pub struct ComponentId {
+    raw: u32,
+    name: &'static str,
+}
+
+impl ComponentId {
+    pub const ENGINE: ComponentId = ComponentId::well_known(0, "Engine");
+    pub const BRAKES: ComponentId = ComponentId::well_known(1, "Brakes");
+    pub const STEERING: ComponentId = ComponentId::well_known(2, "Steering");
+    pub const DASHBOARD: ComponentId = ComponentId::well_known(3, "Dashboard");
+    pub const CAR_SYSTEM: ComponentId = ComponentId::well_known(4, "CarSystem");
+    /// Messages injected from outside the car (tests, tools)
+    pub const EXTERNAL: ComponentId = ComponentId::well_known(5, "External");
+    /// Traction battery (EV and hybrid powertrains only)
+    pub const BATTERY: ComponentId = ComponentId::well_known(6, "Battery");
+    /// Climate control (only when enabled)
+    pub const HVAC: ComponentId = ComponentId::well_known(7, "HVAC");
+    /// Forward obstacle sensor (only when automatic emergency braking is enabled)
+    pub const OBSTACLE_SENSOR: ComponentId = ComponentId::well_known(8, "ObstacleSensor");
+    /// Odometry and route tracking (only when enabled)
+    pub const LOCATION: ComponentId = ComponentId::well_known(9, "Location");
+
+    /// All well-known IDs
+    pub const WELL_KNOWN: [ComponentId; 10] = [
+        ComponentId::ENGINE,
+        ComponentId::BRAKES,
+        ComponentId::STEERING,
+        ComponentId::DASHBOARD,
+        ComponentId::CAR_SYSTEM,
+        ComponentId::EXTERNAL,
+        ComponentId::BATTERY,
+        ComponentId::HVAC,
+        ComponentId::OBSTACLE_SENSOR,
+        ComponentId::LOCATION,
+    ];
+
+    /// Raw IDs below this are reserved for well-known components
+    pub const FIRST_DYNAMIC: u32 = 64;
+
+    const fn well_known(raw: u32, name: &'static str) -> Self {
+        Self { raw, name }
+    }
+
+    pub fn raw(&self) -> u32 {
+        self.raw
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.name
+    }
+
+    /// Check whether this is one of the built-in components
+    pub fn is_well_known(&self) -> bool {
+        self.raw < Self::FIRST_DYNAMIC
+    }
+}
+
+impl PartialEq for ComponentId {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for ComponentId {}
+
+impl Hash for ComponentId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl fmt::Display for ComponentId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// Hands out component IDs at runtime
+/// Registering a name again returns the ID it already has, so the name
+/// works as an interned handle; well-known names map to their constants.
+#[derive(Debug, Clone)]
+pub struct ComponentRegistry {
+    ids: Vec<ComponentId>,
+}
+
+impl ComponentRegistry {
+    /// Create a registry holding the well-known IDs
+    pub fn new() -> Self {
+        Self {
+            ids: ComponentId::WELL_KNOWN.to_vec(),
+        }
+    }
+
+    /// ID of a component name, registering it if it is new
+    pub fn register(&mut self, name: &'static str) -> ComponentId {
+        if let Some(id) = self.lookup(name) {
+            return id;
+        }
+        let dynamic = self.ids.iter().filter(|id| !id.is_well_known()).count() as u32;
+        let id = ComponentId {
+            raw: ComponentId::FIRST_DYNAMIC + dynamic,
+            name,
+        };
+        self.ids.push(id);
+        id
+    }
+
+    /// ID registered under a name
+    pub fn lookup(&self, name: &str) -> Option<ComponentId> {
+        self.ids.iter().find(|id| id.name == name).copied()
+    }
+
+    /// ID with a raw value
+    pub fn get(&self, raw: u32) -> Option<ComponentId> {
+        self.ids.iter().find(|id| id.raw == raw).copied()
+    }
+
+    /// All registered IDs, well-known ones first
+    pub fn ids(&self) -> &[ComponentId] {
+        &self.ids
+    }
+}
+
+impl Default for ComponentRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
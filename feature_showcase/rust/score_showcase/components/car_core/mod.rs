@@ -0,0 +1,114 @@
+//! Platform-independent core of the car system
+//! Demonstrates S-CORE portability patterns:
+//! - Component trait, lifecycle states, messages, state machines and
+//!   safety rules depend only on `core` and `alloc`
+//! - Everything needing an OS (event loop sleep, printing, files,
+//!   threads) lives outside this module
+//!
+//! The same sources build as a `#![no_std]` library through `lib.rs`
+//! (Bazel target `car_core`) for embedded targets. Modules here refer to
+//! each other with `super::` paths only, so they compile in both crates.
+
+use alloc::string::String;
+use core::fmt;
+
+pub mod faults;
+pub mod fixedpoint;
+pub mod goals;
+pub mod messages;
+pub mod safety;
+pub mod state_machine;
+pub mod voter;
+pub mod watchdog;
+
+/// Common component trait - all car components must implement this
+/// This mirrors S-CORE's component-based architecture where each component
+/// has a well-defined lifecycle and behavior
+pub trait CarComponent {
+    /// Returns the component name for logging
+    fn name(&self) -> &'static str;
+
+    /// Initialize the component - called once at startup
+    /// Similar to S-CORE component initialization
+    fn initialize(&mut self) -> Result<(), String>;
+
+    /// Process the component - called repeatedly during operation
+    /// Similar to S-CORE's process loop
+    fn process(&mut self) -> Result<(), String>;
+
+    /// Get the current state of the component
+    /// Similar to S-CORE's state management
+    fn get_state(&self) -> ComponentState;
+
+    /// Stop the component in an orderly way - called once at shutdown
+    /// Similar to S-CORE component deinitialization
+    fn shutdown(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Diagnose the component without changing it
+    /// Used by the safety monitor; the default only looks at `get_state`.
+    fn health_check(&self) -> HealthStatus {
+        HealthStatus::from_state(&self.get_state())
+    }
+}
+
+/// Result of a component's self-diagnosis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    Healthy,
+    /// Working with reduced function (e.g. lost a redundant sensor)
+    Degraded(&'static str),
+    /// Not working; the car must not rely on it
+    Unhealthy(&'static str),
+}
+
+impl HealthStatus {
+    /// Health implied by a lifecycle state alone
+    pub fn from_state(state: &ComponentState) -> Self {
+        match state {
+            ComponentState::Error(_) => HealthStatus::Unhealthy("component in error state"),
+            ComponentState::Initializing => HealthStatus::Degraded("still initializing"),
+            ComponentState::Offline | ComponentState::Online => HealthStatus::Healthy,
+        }
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        *self == HealthStatus::Healthy
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthStatus::Healthy => write!(f, "HEALTHY"),
+            HealthStatus::Degraded(reason) => write!(f, "DEGRADED: {}", reason),
+            HealthStatus::Unhealthy(reason) => write!(f, "UNHEALTHY: {}", reason),
+        }
+    }
+}
+
+/// Component state enum - represents the lifecycle state
+/// Similar to S-CORE's component state management
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentState {
+    /// Component is offline/not initialized
+    Offline,
+    /// Component is initializing
+    Initializing,
+    /// Component is online and operational
+    Online,
+    /// Component encountered an error
+    Error(String),
+}
+
+impl fmt::Display for ComponentState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComponentState::Offline => write!(f, "OFFLINE"),
+            ComponentState::Initializing => write!(f, "INITIALIZING"),
+            ComponentState::Online => write!(f, "ONLINE"),
+            ComponentState::Error(msg) => write!(f, "ERROR: {}", msg),
+        }
+    }
+}
@@ -0,0 +1,684 @@
+//! State machine trait and implementations
+//! This demonstrates S-CORE's state management patterns
+//!
+//! States and allowed transitions are declared as data
+//! (`StateMachineBuilder`): the builder validates the declaration once and
+//! produces a `TransitionMachine` that enforces it. The engine, brakes,
+//! steering and battery charging machines are all declared this way.
+//!
+//! A `TransitionMachine` keeps a bounded audit trail of its transitions
+//! (from, to, tick, reason), so a component that ends up in an unexpected
+//! state during a long run can be traced back. The trail is preallocated;
+//! recording a transition never allocates.
+//!
+//! Diagrams (`to_dot`, `to_plantuml`) are generated from the same table
+//! the machine enforces, so they cannot drift from the implementation.
+//!
+//! Besides being moved by method calls (`transition`), a machine whose
+//! state type is `EventDriven` can follow bus messages: `handle_event`
+//! maps an event such as `CarMessage::EngineStart` to the transition it
+//! triggers in the current state, still guarded by the declared table.
+
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use super::messages::CarMessage;
+
+/// State machine trait - enforces valid state transitions
+pub trait StateMachine: Sized {
+    type State: fmt::Debug;
+
+    /// Get current state
+    fn current_state(&self) -> &Self::State;
+
+    /// Check if transition is valid
+    fn can_transition_to(&self, new_state: &Self::State) -> bool;
+
+    /// Transition to new state (returns error if invalid)
+    fn transition(&mut self, new_state: Self::State) -> Result<(), String> {
+        if !self.can_transition_to(&new_state) {
+            return Err(format!(
+                "Invalid transition: {:?} → {:?}",
+                self.current_state(),
+                new_state
+            ));
+        }
+        self.set_state(new_state);
+        Ok(())
+    }
+
+    /// Internal method to set state (after validation)
+    fn set_state(&mut self, new_state: Self::State);
+}
+
+/// States that move in response to bus messages (event-driven FSM)
+pub trait EventDriven: Sized {
+    /// One event of every kind that moves the machine (for coverage runs)
+    const EVENTS: &'static [CarMessage];
+
+    /// State `event` leads to from this one, or None if it is ignored here
+    fn on_event(&self, event: &CarMessage) -> Option<Self>;
+}
+
+/// Transitions a `TransitionMachine` remembers unless built otherwise
+pub const DEFAULT_HISTORY_LEN: usize = 32;
+
+/// One transition in a machine's audit trail
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitionRecord<S> {
+    pub from: S,
+    pub to: S,
+    /// Tick set with `TransitionMachine::set_tick` when it happened
+    pub tick: u64,
+    /// Why it happened ("" for plain `StateMachine::transition`)
+    pub reason: &'static str,
+}
+
+/// Declares a state machine as data and validates it on `build()`
+///
+/// ```ignore
+/// let machine = StateMachineBuilder::new("Brakes", BrakeStateMachine::Released)
+///     .states(&BrakeStateMachine::ALL)
+///     .transitions(BrakeStateMachine::TRANSITIONS)
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct StateMachineBuilder<S> {
+    name: &'static str,
+    initial: S,
+    states: Vec<S>,
+    transitions: Vec<(S, S)>,
+    history_len: usize,
+}
+
+impl<S: Clone + PartialEq + fmt::Debug> StateMachineBuilder<S> {
+    pub fn new(name: &'static str, initial: S) -> Self {
+        Self {
+            name,
+            initial,
+            states: Vec::new(),
+            transitions: Vec::new(),
+            history_len: DEFAULT_HISTORY_LEN,
+        }
+    }
+
+    /// Remember the last `len` transitions (0 = no audit trail)
+    pub fn history(mut self, len: usize) -> Self {
+        self.history_len = len;
+        self
+    }
+
+    pub fn state(mut self, state: S) -> Self {
+        self.states.push(state);
+        self
+    }
+
+    pub fn states(mut self, states: &[S]) -> Self {
+        self.states.extend_from_slice(states);
+        self
+    }
+
+    /// Allow moving from `from` to `to`
+    pub fn transition(mut self, from: S, to: S) -> Self {
+        self.transitions.push((from, to));
+        self
+    }
+
+    pub fn transitions(mut self, transitions: &[(S, S)]) -> Self {
+        self.transitions.extend_from_slice(transitions);
+        self
+    }
+
+    /// Check the declaration: states and transitions declared once, the
+    /// initial state and every transition end declared as states, and
+    /// every state reachable from the initial one
+    pub fn build(self) -> Result<TransitionMachine<S>, String> {
+        let name = self.name;
+        for (i, state) in self.states.iter().enumerate() {
+            if self.states[..i].contains(state) {
+                return Err(format!("{}: state {:?} declared twice", name, state));
+            }
+        }
+        if !self.states.contains(&self.initial) {
+            return Err(format!("{}: initial state {:?} is not declared", name, self.initial));
+        }
+        for (i, (from, to)) in self.transitions.iter().enumerate() {
+            if !self.states.contains(from) || !self.states.contains(to) {
+                return Err(format!("{}: transition {:?} → {:?} uses an undeclared state", name, from, to));
+            }
+            if self.transitions[..i].contains(&(from.clone(), to.clone())) {
+                return Err(format!("{}: transition {:?} → {:?} declared twice", name, from, to));
+            }
+        }
+
+        let mut reached = vec![self.initial.clone()];
+        let mut next = 0;
+        while let Some(state) = reached.get(next).cloned() {
+            for (_, to) in self.transitions.iter().filter(|(from, _)| *from == state) {
+                if !reached.contains(to) {
+                    reached.push(to.clone());
+                }
+            }
+            next += 1;
+        }
+        if let Some(state) = self.states.iter().find(|state| !reached.contains(state)) {
+            return Err(format!("{}: state {:?} is unreachable from {:?}", name, state, self.initial));
+        }
+
+        Ok(TransitionMachine {
+            name,
+            current: self.initial.clone(),
+            initial: self.initial,
+            states: self.states,
+            transitions: self.transitions,
+            tick: 0,
+            history: VecDeque::with_capacity(self.history_len),
+            history_len: self.history_len,
+        })
+    }
+}
+
+/// State machine enforcing a declared transition table
+#[derive(Debug, Clone)]
+pub struct TransitionMachine<S> {
+    name: &'static str,
+    initial: S,
+    current: S,
+    states: Vec<S>,
+    transitions: Vec<(S, S)>,
+    /// Tick stamped on recorded transitions
+    tick: u64,
+    /// Most recent transitions, oldest first
+    history: VecDeque<TransitionRecord<S>>,
+    history_len: usize,
+}
+
+impl<S: Clone + PartialEq + fmt::Debug> TransitionMachine<S> {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn initial_state(&self) -> &S {
+        &self.initial
+    }
+
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    pub fn transitions(&self) -> &[(S, S)] {
+        &self.transitions
+    }
+
+    /// Check the table, regardless of the current state
+    pub fn allows(&self, from: &S, to: &S) -> bool {
+        self.transitions.iter().any(|(f, t)| f == from && t == to)
+    }
+
+    /// States reachable from the current one in a single transition
+    pub fn valid_transitions(&self) -> Vec<S> {
+        self.transitions
+            .iter()
+            .filter(|(from, _)| *from == self.current)
+            .map(|(_, to)| to.clone())
+            .collect()
+    }
+
+    /// Return to the initial state (e.g. when the component is replaced)
+    pub fn reset(&mut self) {
+        self.current = self.initial.clone();
+    }
+
+    /// Set the tick recorded with the following transitions
+    pub fn set_tick(&mut self, tick: u64) {
+        self.tick = tick;
+    }
+
+    /// Validated transition, recorded with a reason
+    pub fn transition_because(&mut self, new_state: S, reason: &'static str) -> Result<(), String> {
+        if !self.can_transition_to(&new_state) {
+            return Err(format!(
+                "Invalid transition: {:?} → {:?} ({})",
+                self.current, new_state, reason
+            ));
+        }
+        self.set_state_because(new_state, reason);
+        Ok(())
+    }
+
+    /// Recorded transitions, oldest first
+    pub fn history(&self) -> impl Iterator<Item = &TransitionRecord<S>> {
+        self.history.iter()
+    }
+
+    pub fn last_transition(&self) -> Option<&TransitionRecord<S>> {
+        self.history.back()
+    }
+
+    /// Enter a state already checked with `can_transition_to` and append
+    /// the transition to the audit trail (dropping the oldest when full)
+    pub fn set_state_because(&mut self, new_state: S, reason: &'static str) {
+        if self.history_len > 0 {
+            if self.history.len() == self.history_len {
+                self.history.pop_front();
+            }
+            self.history.push_back(TransitionRecord {
+                from: self.current.clone(),
+                to: new_state.clone(),
+                tick: self.tick,
+                reason,
+            });
+        }
+        self.current = new_state;
+    }
+}
+
+impl<S: Clone + PartialEq + fmt::Debug + fmt::Display> TransitionMachine<S> {
+    /// State graph in Graphviz DOT (render with `dot -Tsvg`)
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = writeln!(out, "digraph \"{}\" {{", self.name);
+        let _ = writeln!(out, "    rankdir=LR;");
+        let _ = writeln!(out, "    node [shape=box, style=rounded];");
+        let _ = writeln!(out, "    start [shape=point];");
+        for state in &self.states {
+            let _ = writeln!(out, "    \"{}\";", state);
+        }
+        let _ = writeln!(out, "    start -> \"{}\";", self.initial);
+        for (from, to) in &self.transitions {
+            let _ = writeln!(out, "    \"{}\" -> \"{}\";", from, to);
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// State graph as a PlantUML state diagram
+    pub fn to_plantuml(&self) -> String {
+        // Aliases keep state names that aren't identifiers valid
+        let alias = |state: &S| self.states.iter().position(|s| s == state).unwrap_or(0);
+        let mut out = String::new();
+        let _ = writeln!(out, "@startuml {}", self.name);
+        let _ = writeln!(out, "title {}", self.name);
+        for (i, state) in self.states.iter().enumerate() {
+            let _ = writeln!(out, "state \"{}\" as s{}", state, i);
+        }
+        let _ = writeln!(out, "[*] --> s{}", alias(&self.initial));
+        for (from, to) in &self.transitions {
+            let _ = writeln!(out, "s{} --> s{}", alias(from), alias(to));
+        }
+        out.push_str("@enduml\n");
+        out
+    }
+}
+
+impl<S: Clone + PartialEq + fmt::Debug + EventDriven> TransitionMachine<S> {
+    /// Take the transition an event triggers in the current state,
+    /// recorded with the event type as reason
+    /// Returns whether the state changed; events that mean nothing in the
+    /// current state are ignored.
+    ///
+    /// ```ignore
+    /// let mut engine = EngineStateMachine::machine();
+    /// engine.handle_event(&CarMessage::EngineStart)?;  // OFF → STARTING
+    /// ```
+    pub fn handle_event(&mut self, event: &CarMessage) -> Result<bool, String> {
+        match self.current.on_event(event) {
+            Some(next) => self.transition_because(next, event.type_name()).map(|_| true),
+            None => Ok(false),
+        }
+    }
+}
+
+impl<S: Clone + PartialEq + fmt::Debug> StateMachine for TransitionMachine<S> {
+    type State = S;
+
+    fn current_state(&self) -> &S {
+        &self.current
+    }
+
+    fn can_transition_to(&self, new_state: &S) -> bool {
+        self.allows(&self.current, new_state)
+    }
+
+    fn set_state(&mut self, new_state: S) {
+        self.set_state_because(new_state, "");
+    }
+}
+
+/// Engine state machine with valid transitions
+#[derive(Debug, Clone, PartialEq)]
+pub enum EngineStateMachine {
+    Off,
+    Starting,
+    Running,
+    Stopping,
+}
+
+impl EngineStateMachine {
+    /// All engine states
+    pub const ALL: [EngineStateMachine; 4] = [
+        EngineStateMachine::Off,
+        EngineStateMachine::Starting,
+        EngineStateMachine::Running,
+        EngineStateMachine::Stopping,
+    ];
+
+    /// State name (stable identifier, e.g. in snapshots)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EngineStateMachine::Off => "OFF",
+            EngineStateMachine::Starting => "STARTING",
+            EngineStateMachine::Running => "RUNNING",
+            EngineStateMachine::Stopping => "STOPPING",
+        }
+    }
+
+    /// Look up a state by name, returning its interned name
+    pub fn intern(name: &str) -> Option<&'static str> {
+        Self::ALL.iter().map(Self::as_str).find(|state| *state == name)
+    }
+
+    /// Allowed transitions (from, to)
+    pub const TRANSITIONS: &'static [(EngineStateMachine, EngineStateMachine)] = &[
+        (EngineStateMachine::Off, EngineStateMachine::Starting),
+        (EngineStateMachine::Starting, EngineStateMachine::Running),
+        (EngineStateMachine::Starting, EngineStateMachine::Off),
+        (EngineStateMachine::Running, EngineStateMachine::Stopping),
+        (EngineStateMachine::Stopping, EngineStateMachine::Off),
+    ];
+
+    /// Machine enforcing `TRANSITIONS`, starting in `Off`
+    pub fn machine() -> TransitionMachine<EngineStateMachine> {
+        StateMachineBuilder::new("Engine", EngineStateMachine::Off)
+            .states(&Self::ALL)
+            .transitions(Self::TRANSITIONS)
+            .build()
+            .expect("engine transition table is valid")
+    }
+
+    /// Get all valid transitions from current state
+    pub fn valid_transitions(&self) -> Vec<EngineStateMachine> {
+        Self::TRANSITIONS
+            .iter()
+            .filter(|(from, _)| from == self)
+            .map(|(_, to)| to.clone())
+            .collect()
+    }
+
+    /// Check if transition is valid
+    pub fn can_transition_to(&self, new_state: &EngineStateMachine) -> bool {
+        Self::TRANSITIONS.iter().any(|(from, to)| from == self && to == new_state)
+    }
+
+    /// Transition with validation
+    pub fn transition(&self) -> Result<EngineStateMachine, String> {
+        match self {
+            EngineStateMachine::Off => Ok(EngineStateMachine::Starting),
+            EngineStateMachine::Starting => Ok(EngineStateMachine::Running),
+            EngineStateMachine::Running => Ok(EngineStateMachine::Stopping),
+            EngineStateMachine::Stopping => Ok(EngineStateMachine::Off),
+        }
+    }
+}
+
+impl EventDriven for EngineStateMachine {
+    const EVENTS: &'static [CarMessage] = &[
+        CarMessage::EngineStart,
+        CarMessage::EngineStop,
+        CarMessage::EngineRpmChange { rpm: 800 },
+        CarMessage::EngineRpmChange { rpm: 0 },
+    ];
+
+    /// Start/stop requests begin a phase; the RPM reports end it
+    fn on_event(&self, event: &CarMessage) -> Option<Self> {
+        match (self, event) {
+            (EngineStateMachine::Off, CarMessage::EngineStart) => Some(EngineStateMachine::Starting),
+            (EngineStateMachine::Starting, CarMessage::EngineRpmChange { rpm }) if *rpm > 0 => {
+                Some(EngineStateMachine::Running)
+            }
+            (EngineStateMachine::Starting, CarMessage::EngineStop) => Some(EngineStateMachine::Off),
+            (EngineStateMachine::Running, CarMessage::EngineStop) => Some(EngineStateMachine::Stopping),
+            (EngineStateMachine::Stopping, CarMessage::EngineRpmChange { rpm: 0 }) => Some(EngineStateMachine::Off),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for EngineStateMachine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Brake state machine (with anti-lock braking)
+#[derive(Debug, Clone, PartialEq)]
+pub enum BrakeStateMachine {
+    /// Pedal released; remaining pressure decays
+    Released,
+    /// Pressure commanded, not yet reached
+    Applying,
+    /// Pressure reached and held
+    Holding,
+    /// Anti-lock braking modulating the pressure (hard braking at speed)
+    AbsActive,
+    /// Pressure sensors disagree; the last pressure is held, no ABS
+    Fault,
+}
+
+impl BrakeStateMachine {
+    pub const ALL: [BrakeStateMachine; 5] = [
+        BrakeStateMachine::Released,
+        BrakeStateMachine::Applying,
+        BrakeStateMachine::Holding,
+        BrakeStateMachine::AbsActive,
+        BrakeStateMachine::Fault,
+    ];
+
+    /// Allowed transitions (from, to)
+    pub const TRANSITIONS: &'static [(BrakeStateMachine, BrakeStateMachine)] = &[
+        (BrakeStateMachine::Released, BrakeStateMachine::Applying),
+        (BrakeStateMachine::Applying, BrakeStateMachine::Holding),
+        (BrakeStateMachine::Applying, BrakeStateMachine::Released),
+        (BrakeStateMachine::Holding, BrakeStateMachine::Applying),
+        (BrakeStateMachine::Holding, BrakeStateMachine::AbsActive),
+        (BrakeStateMachine::Holding, BrakeStateMachine::Released),
+        (BrakeStateMachine::AbsActive, BrakeStateMachine::Holding),
+        (BrakeStateMachine::AbsActive, BrakeStateMachine::Released),
+        (BrakeStateMachine::Released, BrakeStateMachine::Fault),
+        (BrakeStateMachine::Applying, BrakeStateMachine::Fault),
+        (BrakeStateMachine::Holding, BrakeStateMachine::Fault),
+        (BrakeStateMachine::AbsActive, BrakeStateMachine::Fault),
+        (BrakeStateMachine::Fault, BrakeStateMachine::Released),
+    ];
+
+    /// Machine enforcing `TRANSITIONS`, starting in `Released`
+    pub fn machine() -> TransitionMachine<BrakeStateMachine> {
+        StateMachineBuilder::new("Brakes", BrakeStateMachine::Released)
+            .states(&Self::ALL)
+            .transitions(Self::TRANSITIONS)
+            .build()
+            .expect("brake transition table is valid")
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BrakeStateMachine::Released => "RELEASED",
+            BrakeStateMachine::Applying => "APPLYING",
+            BrakeStateMachine::Holding => "HOLDING",
+            BrakeStateMachine::AbsActive => "ABS_ACTIVE",
+            BrakeStateMachine::Fault => "FAULT",
+        }
+    }
+}
+
+impl EventDriven for BrakeStateMachine {
+    const EVENTS: &'static [CarMessage] = &[
+        CarMessage::BrakeApply { pressure: 50 },
+        CarMessage::BrakePressureChange { pressure: 50 },
+        CarMessage::BrakeRelease,
+        CarMessage::AbsActivated { pressure: 95, km_h: 80 },
+        CarMessage::AbsDeactivated,
+        CarMessage::BrakeFault,
+        CarMessage::BrakeFaultCleared,
+    ];
+
+    /// A pressure report while applying means the pressure was reached
+    fn on_event(&self, event: &CarMessage) -> Option<Self> {
+        match (self, event) {
+            (Self::Released | Self::Holding, CarMessage::BrakeApply { .. }) => Some(Self::Applying),
+            (Self::Applying, CarMessage::BrakePressureChange { pressure }) if *pressure > 0 => Some(Self::Holding),
+            (Self::Applying | Self::Holding | Self::AbsActive, CarMessage::BrakeRelease) => Some(Self::Released),
+            (Self::Holding, CarMessage::AbsActivated { .. }) => Some(Self::AbsActive),
+            (Self::AbsActive, CarMessage::AbsDeactivated) => Some(Self::Holding),
+            (Self::Fault, CarMessage::BrakeFaultCleared) => Some(Self::Released),
+            (_, CarMessage::BrakeFault) if *self != Self::Fault => Some(Self::Fault),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BrakeStateMachine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Steering states
+#[derive(Debug, Clone, PartialEq)]
+pub enum SteeringState {
+    Centered,
+    /// Held at an angle by the driver
+    Turned,
+    /// Power steering returning to center
+    Returning,
+}
+
+impl SteeringState {
+    pub const ALL: [SteeringState; 3] = [SteeringState::Centered, SteeringState::Turned, SteeringState::Returning];
+
+    /// Allowed transitions (from, to)
+    pub const TRANSITIONS: &'static [(SteeringState, SteeringState)] = &[
+        (SteeringState::Centered, SteeringState::Turned),
+        (SteeringState::Turned, SteeringState::Returning),
+        (SteeringState::Turned, SteeringState::Centered),
+        (SteeringState::Returning, SteeringState::Turned),
+        (SteeringState::Returning, SteeringState::Centered),
+    ];
+
+    /// Machine enforcing `TRANSITIONS`, starting in `Centered`
+    pub fn machine() -> TransitionMachine<SteeringState> {
+        StateMachineBuilder::new("Steering", SteeringState::Centered)
+            .states(&Self::ALL)
+            .transitions(Self::TRANSITIONS)
+            .build()
+            .expect("steering transition table is valid")
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SteeringState::Centered => "CENTERED",
+            SteeringState::Turned => "TURNED",
+            SteeringState::Returning => "RETURNING",
+        }
+    }
+}
+
+impl EventDriven for SteeringState {
+    const EVENTS: &'static [CarMessage] = &[
+        CarMessage::SteeringTurn { angle: 15 },
+        CarMessage::SteeringCenter,
+        CarMessage::SteeringTurn { angle: 0 },
+    ];
+
+    /// A center request starts the return; an angle of 0 means centered
+    fn on_event(&self, event: &CarMessage) -> Option<Self> {
+        match (self, event) {
+            (SteeringState::Centered | SteeringState::Returning, CarMessage::SteeringTurn { angle }) if *angle != 0 => {
+                Some(SteeringState::Turned)
+            }
+            (SteeringState::Turned, CarMessage::SteeringCenter) => Some(SteeringState::Returning),
+            (SteeringState::Turned | SteeringState::Returning, CarMessage::SteeringTurn { angle: 0 }) => {
+                Some(SteeringState::Centered)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SteeringState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Traction battery states (EV and hybrid powertrains)
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChargingState {
+    /// Contactors open, no current
+    Idle,
+    /// Powering the traction motor
+    Discharging,
+    /// Charged by the engine's generator
+    Charging,
+    /// Charged by the traction motor while braking
+    Regenerating,
+}
+
+impl ChargingState {
+    pub const ALL: [ChargingState; 4] = [
+        ChargingState::Idle,
+        ChargingState::Discharging,
+        ChargingState::Charging,
+        ChargingState::Regenerating,
+    ];
+
+    /// Allowed transitions (from, to)
+    /// Drive and generator current only swap through `Idle`; regeneration
+    /// can take over from either and hand back to either.
+    pub const TRANSITIONS: &'static [(ChargingState, ChargingState)] = &[
+        (ChargingState::Idle, ChargingState::Discharging),
+        (ChargingState::Idle, ChargingState::Charging),
+        (ChargingState::Idle, ChargingState::Regenerating),
+        (ChargingState::Discharging, ChargingState::Idle),
+        (ChargingState::Discharging, ChargingState::Regenerating),
+        (ChargingState::Charging, ChargingState::Idle),
+        (ChargingState::Charging, ChargingState::Regenerating),
+        (ChargingState::Regenerating, ChargingState::Idle),
+        (ChargingState::Regenerating, ChargingState::Discharging),
+        (ChargingState::Regenerating, ChargingState::Charging),
+    ];
+
+    /// Machine enforcing `TRANSITIONS`, starting in `Idle`
+    pub fn machine() -> TransitionMachine<ChargingState> {
+        StateMachineBuilder::new("Battery", ChargingState::Idle)
+            .states(&Self::ALL)
+            .transitions(Self::TRANSITIONS)
+            .build()
+            .expect("charging transition table is valid")
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChargingState::Idle => "IDLE",
+            ChargingState::Discharging => "DISCHARGING",
+            ChargingState::Charging => "CHARGING",
+            ChargingState::Regenerating => "REGENERATING",
+        }
+    }
+
+    /// Look up a state by name, returning its interned name
+    pub fn intern(name: &str) -> Option<&'static str> {
+        Self::ALL.iter().map(Self::as_str).find(|state| *state == name)
+    }
+}
+
+impl fmt::Display for ChargingState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
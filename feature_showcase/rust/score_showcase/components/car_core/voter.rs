@@ -0,0 +1,123 @@
+//! Redundant sensor voting
+//! Demonstrates S-CORE redundancy patterns (like ISO 26262):
+//! - A critical signal is measured by several independent channels
+//! - The voted value is the median: with three channels one faulty
+//!   channel cannot move it (2-out-of-3)
+//! - A channel further than the tolerance from the voted value is
+//!   reported, so a drifting sensor is noticed before a second one fails
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Most channels a signal can have (voting works on a stack buffer)
+pub const MAX_CHANNELS: usize = 8;
+
+/// Result of voting over the channels of one signal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vote {
+    pub value: f32,
+    /// Largest distance of a channel from `value`
+    pub spread: f32,
+    pub tolerance: f32,
+}
+
+impl Vote {
+    /// Check whether every channel is within the tolerance
+    pub fn agrees(&self) -> bool {
+        self.spread <= self.tolerance
+    }
+}
+
+/// Median voter with a disagreement tolerance
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Voter {
+    /// Largest allowed distance of a channel from the voted value
+    pub tolerance: f32,
+}
+
+impl Voter {
+    pub fn new(tolerance: f32) -> Self {
+        Self { tolerance }
+    }
+
+    /// Median of the readings (mean of the middle two for an even count)
+    /// Readings past `MAX_CHANNELS` are ignored; None without readings.
+    pub fn median(readings: &[f32]) -> Option<f32> {
+        let count = readings.len().min(MAX_CHANNELS);
+        if count == 0 {
+            return None;
+        }
+        let mut sorted = [0.0f32; MAX_CHANNELS];
+        sorted[..count].copy_from_slice(&readings[..count]);
+        let sorted = &mut sorted[..count];
+        sorted.sort_unstable_by(f32::total_cmp);
+        Some(if count % 2 == 1 {
+            sorted[count / 2]
+        } else {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2.0
+        })
+    }
+
+    /// Vote over redundant readings; None with fewer than two channels
+    pub fn vote(&self, readings: &[f32]) -> Option<Vote> {
+        if readings.len() < 2 {
+            return None;
+        }
+        let value = Self::median(readings)?;
+        let spread = readings
+            .iter()
+            .take(MAX_CHANNELS)
+            .map(|reading| (reading - value).abs())
+            .fold(0.0, f32::max);
+        Some(Vote {
+            value,
+            spread,
+            tolerance: self.tolerance,
+        })
+    }
+}
+
+/// Readings of the redundant channels of one signal
+#[derive(Debug, Clone, Default)]
+pub struct RedundantSignal {
+    readings: Vec<f32>,
+    /// Fixed error of each channel (fault injection)
+    offsets: Vec<f32>,
+}
+
+impl RedundantSignal {
+    /// A signal measured by `channels` sensors (at most `MAX_CHANNELS`)
+    pub fn new(channels: usize) -> Self {
+        let channels = channels.min(MAX_CHANNELS);
+        Self {
+            readings: alloc::vec![0.0; channels],
+            offsets: alloc::vec![0.0; channels],
+        }
+    }
+
+    pub fn channels(&self) -> usize {
+        self.readings.len()
+    }
+
+    /// Measure a value on every channel; `noise` is called once per channel
+    pub fn measure(&mut self, value: f32, mut noise: impl FnMut() -> f32) {
+        for (reading, offset) in self.readings.iter_mut().zip(&self.offsets) {
+            *reading = value + offset + noise();
+        }
+    }
+
+    pub fn readings(&self) -> &[f32] {
+        &self.readings
+    }
+
+    /// Give one channel a fixed error (fault injection)
+    pub fn set_offset(&mut self, channel: usize, offset: f32) -> Result<(), String> {
+        let slot = self
+            .offsets
+            .get_mut(channel)
+            .ok_or_else(|| format!("no sensor channel {} (have {})", channel, self.readings.len()))?;
+        *slot = offset;
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+//! Component watchdog
+//! Demonstrates S-CORE liveness supervision patterns:
+//! - Every supervised component pets the watchdog each time it is processed
+//! - A component that misses `max_missed` consecutive cycles is reported
+//!   unresponsive (a Critical safety warning) until it is processed again
+//! - Expiry is signalled once per outage so a degraded-mode reaction
+//!   runs once, not every cycle
+
+use alloc::vec::Vec;
+
+use super::safety::SafetyWarning;
+
+/// Liveness state of one supervised component
+#[derive(Debug, Clone)]
+struct Supervised {
+    name: &'static str,
+    petted: bool,
+    /// Consecutive cycles without a pet
+    missed: u32,
+    expired: bool,
+}
+
+/// Watchdog over the components processed each cycle
+#[derive(Debug, Clone, Default)]
+pub struct Watchdog {
+    /// Missed cycles before a component counts as unresponsive (0 = off)
+    max_missed: u32,
+    components: Vec<Supervised>,
+}
+
+impl Watchdog {
+    pub fn new(max_missed: u32) -> Self {
+        Self {
+            max_missed,
+            components: Vec::new(),
+        }
+    }
+
+    /// Change the deadline; supervision state is kept
+    pub fn set_max_missed(&mut self, max_missed: u32) {
+        self.max_missed = max_missed;
+    }
+
+    /// Start supervising a component (again, if it was already)
+    pub fn supervise(&mut self, name: &'static str) {
+        self.components.retain(|c| c.name != name);
+        self.components.push(Supervised {
+            name,
+            petted: false,
+            missed: 0,
+            expired: false,
+        });
+    }
+
+    /// Stop supervising one component
+    pub fn release(&mut self, name: &str) {
+        self.components.retain(|c| c.name != name);
+    }
+
+    /// Stop supervising everything
+    pub fn clear(&mut self) {
+        self.components.clear();
+    }
+
+    /// Report a component alive for the current cycle; unknown names are ignored
+    pub fn pet(&mut self, name: &str) {
+        if let Some(component) = self.components.iter_mut().find(|c| c.name == name) {
+            component.petted = true;
+        }
+    }
+
+    /// Close the current cycle
+    /// Returns whether a component missed its deadline in this cycle.
+    pub fn end_cycle(&mut self) -> bool {
+        let mut expired = false;
+        for component in &mut self.components {
+            if component.petted {
+                component.missed = 0;
+                component.expired = false;
+            } else {
+                component.missed = component.missed.saturating_add(1);
+                if self.max_missed > 0 && !component.expired && component.missed >= self.max_missed {
+                    component.expired = true;
+                    expired = true;
+                }
+            }
+            component.petted = false;
+        }
+        expired
+    }
+
+    /// Unresponsive components and the cycles they missed so far
+    pub fn expired(&self) -> impl Iterator<Item = (&'static str, u32)> + '_ {
+        self.components
+            .iter()
+            .filter(|c| c.expired)
+            .map(|c| (c.name, c.missed))
+    }
+
+    /// One warning per unresponsive component
+    pub fn warnings(&self) -> impl Iterator<Item = SafetyWarning> + '_ {
+        self.expired()
+            .map(|(component, missed)| SafetyWarning::ComponentUnresponsive { component, missed })
+    }
+}
@@ -0,0 +1,110 @@
+//! Chaos mode for robustness testing
+//! Demonstrates S-CORE resilience testing patterns:
+//! - Components randomly miss their processing slot (delays)
+//! - A percentage of bus messages is dropped
+//! - Faults are injected while the event loop runs
+//! - The system must still end in a safe state
+//!
+//! All decisions come from a stream forked off the simulation seed, so a
+//! failing chaos run is reproduced by passing the same `--seed` again.
+
+use crate::components::config::ChaosConfig;
+use crate::components::messages::ComponentId;
+use crate::components::rng::SimRng;
+
+/// Fault that chaos mode can inject into a running system
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChaosFault {
+    /// Sudden engine temperature rise
+    EngineOverheat,
+    /// Fuel level drops sharply
+    FuelLeak,
+    /// Brakes stuck at high pressure
+    BrakeSpike,
+}
+
+impl ChaosFault {
+    const ALL: [ChaosFault; 3] = [ChaosFault::EngineOverheat, ChaosFault::FuelLeak, ChaosFault::BrakeSpike];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChaosFault::EngineOverheat => "engine overheat",
+            ChaosFault::FuelLeak => "fuel leak",
+            ChaosFault::BrakeSpike => "brake spike",
+        }
+    }
+}
+
+/// Counters of everything chaos mode did during a run
+#[derive(Debug, Clone, Default)]
+pub struct ChaosStats {
+    pub delayed: u64,
+    pub dropped: u64,
+    pub faults: Vec<(u64, ChaosFault)>,
+}
+
+/// Seeded source of chaos decisions
+pub struct ChaosMonkey {
+    config: ChaosConfig,
+    rng: SimRng,
+    stats: ChaosStats,
+}
+
+impl ChaosMonkey {
+    pub fn new(config: &ChaosConfig, rng: SimRng) -> Self {
+        Self {
+            config: config.clone(),
+            rng,
+            stats: ChaosStats::default(),
+        }
+    }
+
+    fn roll(&mut self, percent: u8) -> bool {
+        percent > 0 && self.rng.below(100) < percent as u32
+    }
+
+    /// Decide whether a component misses its processing slot this tick
+    pub fn delay(&mut self, component: ComponentId) -> bool {
+        let delayed = self.roll(self.config.delay_percent);
+        if delayed {
+            self.stats.delayed += 1;
+            println!("  🐒 Chaos: {} delayed", component.as_str());
+        }
+        delayed
+    }
+
+    /// Decide whether a published message is lost
+    pub fn drop_message(&mut self) -> bool {
+        let dropped = self.roll(self.config.drop_percent);
+        if dropped {
+            self.stats.dropped += 1;
+        }
+        dropped
+    }
+
+    /// Pick a fault to inject this tick, if any
+    pub fn next_fault(&mut self, tick: u64) -> Option<ChaosFault> {
+        if !self.roll(self.config.fault_percent) {
+            return None;
+        }
+        let fault = ChaosFault::ALL[self.rng.below(ChaosFault::ALL.len() as u32) as usize];
+        self.stats.faults.push((tick, fault));
+        println!("  🐒 Chaos: injecting {} at tick {}", fault.as_str(), tick);
+        Some(fault)
+    }
+
+    pub fn stats(&self) -> &ChaosStats {
+        &self.stats
+    }
+
+    /// Print a summary of the run
+    pub fn print_report(&self) {
+        println!("🐒 Chaos report:");
+        println!("   Delayed component slots: {}", self.stats.delayed);
+        println!("   Dropped messages:        {}", self.stats.dropped);
+        println!("   Injected faults:         {}", self.stats.faults.len());
+        for (tick, fault) in &self.stats.faults {
+            println!("     tick {:>5}: {}", tick, fault.as_str());
+        }
+    }
+}
@@ -0,0 +1,1348 @@
+//! Layered system configuration
+//! Demonstrates S-CORE configuration patterns:
+//! - One validated config struct consumed by all subsystems
+//! - Layered sources with a fixed precedence order:
+//!   defaults → profile → config file → environment variables → CLI flags
+//!
+//! Every setting has a dotted key (e.g. `safety.max_speed`). The config file
+//! is JSON with nested objects, environment variables use the `CAR_` prefix
+//! (`CAR_SAFETY_MAX_SPEED`), and CLI flags use `--set key=value` or one of the
+//! shorthand flags listed in `print_help()`.
+//!
+//! The command line (`car_system [COMMAND] [OPTIONS]`) is parsed by hand
+//! rather than with clap: the library and its binaries build from the
+//! standard library alone, which keeps the minimal footprint build free of
+//! third-party crates. Every subcommand and flag must therefore also be
+//! added to `print_help()`, which clap would otherwise generate.
+
+use std::env;
+use std::fmt;
+use std::fs;
+
+use crate::components::battery::PowertrainMode;
+use crate::components::dashboard::{DashboardMode, DEFAULT_MAX_WARNINGS};
+use crate::components::units::UnitSystem;
+use crate::components::vehicle_dynamics::DynamicsMode;
+use crate::components::event_loop::{
+    ClockSource, ErrorPolicy, EventLoopConfig, ExecutionMode, OverrunRecovery, TaskSchedule, TimeScaleMode,
+};
+use crate::components::golden::GoldenMode;
+use crate::components::hardware::HardwareBackend;
+use crate::components::logging::LogConfig;
+use crate::components::profile::Profile;
+use crate::components::realtime::RealtimeConfig;
+use crate::components::supervisor::{RestartPolicy, SupervisorConfig};
+use crate::components::system::SCHEDULED_TASKS;
+use crate::components::voter::MAX_CHANNELS;
+pub use crate::components::safety::SafetyConfig;
+use crate::components::json::JsonValue;
+
+/// Environment variable prefix for configuration overrides
+pub const ENV_PREFIX: &str = "CAR_";
+
+/// Environment variable that points at the config file
+pub const ENV_CONFIG_FILE: &str = "CAR_CONFIG";
+
+/// Environment variable that selects the profile
+pub const ENV_PROFILE: &str = "CAR_PROFILE";
+
+/// All configuration keys with a short description
+pub const CONFIG_KEYS: &[(&str, &str)] = &[
+    ("log_level", "Log level [ERROR|WARN|INFO|DEBUG|TRACE]"),
+    ("log.components", "Per-component log levels as name:LEVEL, e.g. MessageBus:WARN,Engine:DEBUG"),
+    ("log.quiet", "Only print errors, e.g. for headless runs [true|false]"),
+    ("log.file", "JSON Lines file every log entry is also written to (empty = none)"),
+    ("log.file_max_kb", "Size in KB at which the log file is rotated"),
+    ("log.file_keep", "Rotated log files kept as <file>.1 .. <file>.N (0 = truncate)"),
+    ("ticks", "Number of event loop ticks to run"),
+    ("event_loop.tick_rate_ms", "Tick period in milliseconds"),
+    ("event_loop.verbose_timing", "Print per-tick timing [true|false]"),
+    ("event_loop.simulated_clock", "Advance time without sleeping [true|false]"),
+    ("event_loop.time_scale", "Simulated seconds per real second (10 = 10x faster dynamics)"),
+    ("event_loop.time_scale_mode", "Scale the simulated step or the sleep between ticks [step|sleep]"),
+    ("event_loop.parallel_components", "Process independent components on parallel threads [true|false]"),
+    ("event_loop.execution", "Run the core components in one loop or as supervised actors [single|threaded]"),
+    ("event_loop.spin_us", "Busy-wait the last N µs before each tick for lower jitter (0 = sleep only)"),
+    ("event_loop.max_overrun_ms", "Tick overrun in ms that counts as a failed tick (none = only warn)"),
+    ("event_loop.error_policy", "On failed ticks [continue|stop|stop-after:N]"),
+    ("event_loop.overrun_recovery", "After an overrun [restart|catch-up:N] (N = most ticks to catch up)"),
+    ("event_loop.render_every", "Render the dashboard every N ticks"),
+    ("event_loop.deadline_faults", "Report ticks that miss their deadline as safety faults [true|false]"),
+    ("event_loop.console", "Read pause/resume/step [N] commands from stdin while running [true|false]"),
+    ("event_loop.schedule", "Task periods as name:ms, e.g. safety:500 [components|safety|dashboard]"),
+    ("init.policy", "On component initialization failure [abort-all|continue-non-critical|retry]"),
+    ("init.retries", "Initialization attempts after the first failure (retry policy)"),
+    ("init.backoff_ms", "Delay before the first retry in ms, doubled per attempt"),
+    ("watchdog.max_missed", "Cycles a component may miss before the watchdog reports it (0 = off)"),
+    ("watchdog.workflow", "Workflow run when a component misses its deadline (empty = none)"),
+    ("supervisor.enabled", "Restart or degrade failed components according to their policy [true|false]"),
+    ("supervisor.policy", "Reaction to a failed component [restart:N|degrade|shutdown]"),
+    ("supervisor.escalation", "Reaction once a component used up its restarts [degrade|shutdown]"),
+    ("supervisor.components", "Per-component policies as name:policy, e.g. HVAC:degrade,Brakes:restart:1"),
+    ("dashboard.render", "Render the dashboard every tick [true|false]"),
+    ("dashboard.changed_only", "Only reprint the dashboard when its content changed [true|false]"),
+    ("dashboard.mode", "Show the dashboard as a text frame, a full-screen terminal UI or JSON lines [plain|tui|json]"),
+    ("dashboard.json_file", "File the JSON dashboard lines are written to (empty = stdout)"),
+    ("dashboard.max_warnings", "Warnings shown in the dashboard frame, most severe first"),
+    ("dashboard.units", "Units speed, temperature and distance are shown and logged in [metric|imperial]"),
+    ("sim.seed", "Seed for all simulated randomness (random = pick one)"),
+    ("sim.sensor_noise_c", "Engine temperature sensor noise amplitude in °C"),
+    ("powertrain.mode", "What propels the car [combustion|ev|hybrid]"),
+    ("powertrain.battery_kwh", "Traction battery capacity in kWh (ev and hybrid)"),
+    ("powertrain.initial_soc", "Traction battery charge at startup in %"),
+    ("hvac.enabled", "Simulate climate control and its load on the engine [true|false]"),
+    ("hvac.target_c", "Cabin temperature the climate control aims for in °C"),
+    ("hvac.ambient_c", "Outside temperature in °C (the cabin starts at it)"),
+    ("adas.enabled", "Simulate an obstacle ahead and brake automatically before hitting it [true|false]"),
+    ("adas.ttc_threshold_s", "Time-to-collision in s below which the emergency workflow runs"),
+    ("adas.obstacle_m", "Distance in m at which each obstacle appears"),
+    ("adas.obstacle_kmh", "Speed of the obstacle in km/h (0 = standing)"),
+    ("location.enabled", "Track the car's position from speed and steering [true|false]"),
+    ("location.route", "JSON route file with the waypoints to the destination (empty = none)"),
+    ("vehicle.dynamics", "Where the speed comes from [scripted|physics]"),
+    ("vehicle.mass_kg", "Vehicle mass in kg (physics dynamics)"),
+    ("sensors.channels", "Redundant engine temperature and brake pressure sensors (3 = 2-out-of-3 voting)"),
+    ("sensors.tolerance", "Largest distance of a sensor from the voted value (°C or %)"),
+    ("chaos.drop_percent", "Chaos: percentage of bus messages dropped"),
+    ("chaos.delay_percent", "Chaos: percentage of component slots delayed"),
+    ("chaos.fault_percent", "Chaos: per-tick fault injection probability in %"),
+    ("reload.poll_ticks", "Check the config file for changes every N ticks (0 = off)"),
+    ("persistency.path", "File for data kept across restarts (empty = in-memory)"),
+    ("persistency.flush_ticks", "Flush persisted data every N ticks (0 = only at shutdown)"),
+    ("calibration.file", "Calibration file with named safety limit sets (empty = built-in)"),
+    ("calibration.set", "Active calibration set, overrides safety.* (empty = none)"),
+    ("blackbox.path", "Black box recorder file (empty = off)"),
+    ("blackbox.seconds", "Seconds of history kept by the black box"),
+    ("golden.mode", "Golden-output mode [off|record|compare]"),
+    ("golden.path", "Golden file with canonical per-tick snapshots"),
+    ("hardware.backend", "Sensor/actuator backend [simulator|serial]"),
+    ("hardware.port", "Serial port of the bench hardware"),
+    ("hardware.brake_light_pin", "GPIO pin driving the brake light"),
+    ("realtime.enabled", "Pin, prioritize and pre-fault the event-loop thread [true|false]"),
+    ("realtime.cpu", "CPU to pin the event-loop thread to (none = don't pin)"),
+    ("realtime.priority", "SCHED_FIFO priority 1-99 (0 = default scheduler)"),
+    ("realtime.prefault_kb", "Heap to lock and pre-fault in KiB (0 = skip)"),
+    ("safety.max_speed", "Speed limit in km/h"),
+    ("safety.max_temperature", "Engine temperature limit in °C"),
+    ("safety.max_rpm", "Engine RPM limit"),
+    ("safety.min_fuel", "Low fuel threshold in %"),
+    ("safety.max_brake_pressure", "Brake pressure limit in %"),
+    ("safety.max_speed_step", "Largest plausible speed change between safety checks in km/h"),
+    ("safety.max_temperature_step", "Largest plausible temperature change between safety checks in °C"),
+    ("safety.max_battery_temperature", "Traction battery temperature limit in °C"),
+    ("safety.min_battery_soc", "Traction battery deep discharge threshold in %"),
+    ("safety.file", "Safety limits file (JSON), replaces safety.* (empty = none)"),
+    ("safety.emergency_workflow", "Workflow run on Emergency-severity warnings (empty = none)"),
+    ("safety.event_log", "Safety event log written at shutdown, .json or .csv (empty = off)"),
+    ("state_machines.history_log", "State transition history written at shutdown as JSON (empty = off)"),
+];
+
+/// S-CORE style log level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Trace => "TRACE",
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<LogLevel, String> {
+        match s.to_uppercase().as_str() {
+            "ERROR" => Ok(LogLevel::Error),
+            "WARN" => Ok(LogLevel::Warn),
+            "INFO" => Ok(LogLevel::Info),
+            "DEBUG" => Ok(LogLevel::Debug),
+            "TRACE" => Ok(LogLevel::Trace),
+            _ => Err(format!("Invalid log level: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Persistent storage settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersistencyConfig {
+    pub path: String,
+    pub flush_ticks: u64,
+}
+
+impl Default for PersistencyConfig {
+    fn default() -> Self {
+        Self {
+            path: "car_system_state.json".to_string(),
+            flush_ticks: 20,
+        }
+    }
+}
+
+/// Safety calibration settings
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CalibrationConfig {
+    pub file: String,
+    pub set: String,
+}
+
+/// One-shot calibration commands run instead of the demo
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalibrationCommand {
+    /// Show what changes between two sets
+    Diff(String, String),
+    /// Write all sets to a calibration file
+    Export(String),
+}
+
+/// One-shot tool modes run instead of the demo
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolCommand {
+    /// Drive every state machine through all transitions and report coverage
+    TransitionCoverage,
+    /// Run the benchmark suite, optionally writing JSON results
+    Bench(Option<String>),
+    /// Run the scenario with two config files and diff the results
+    DiffRun(String, String),
+    /// Write the requirement traceability matrix (.md or .json)
+    TraceMatrix(String),
+    /// Write the state machine diagrams (.dot or .puml)
+    StateDiagrams(String),
+    /// Fail if the p99 tick duration exceeds a budget in microseconds
+    TickBudget(u64),
+    /// Long headless run that fails on monotonic resource growth
+    Soak,
+    /// Fuzz one parser target (or "all") with mutated inputs
+    Fuzz(String),
+    /// Run a scenario file and check its expectations
+    Scenario(String),
+    /// Re-check a recorded run (golden file or black box) against the limits
+    Replay(String),
+    /// List the built-in workflows and workflow file actions
+    WorkflowList,
+    /// Run one built-in workflow, workflow file or script
+    WorkflowRun(String),
+    /// Dry-run one workflow: evaluate its preconditions without running it
+    WorkflowCheck(String),
+}
+
+/// Black box recorder settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlackBoxConfig {
+    pub path: String,
+    pub seconds: u64,
+}
+
+impl Default for BlackBoxConfig {
+    fn default() -> Self {
+        Self {
+            path: String::new(),
+            seconds: 30,
+        }
+    }
+}
+
+/// Chaos mode settings (all 0 = off)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChaosConfig {
+    pub drop_percent: u8,
+    pub delay_percent: u8,
+    pub fault_percent: u8,
+}
+
+impl ChaosConfig {
+    /// Check whether any chaos is configured
+    pub fn is_enabled(&self) -> bool {
+        self.drop_percent > 0 || self.delay_percent > 0 || self.fault_percent > 0
+    }
+}
+
+/// Golden-output comparison settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoldenConfig {
+    pub mode: GoldenMode,
+    pub path: String,
+}
+
+impl Default for GoldenConfig {
+    fn default() -> Self {
+        Self {
+            mode: GoldenMode::Off,
+            path: String::new(),
+        }
+    }
+}
+
+/// Hardware I/O backend settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct HardwareConfig {
+    pub backend: HardwareBackend,
+    pub port: String,
+    pub brake_light_pin: u8,
+}
+
+impl Default for HardwareConfig {
+    fn default() -> Self {
+        Self {
+            backend: HardwareBackend::Simulator,
+            port: "/dev/ttyUSB0".to_string(),
+            brake_light_pin: 17,
+        }
+    }
+}
+
+/// What happens when a component fails to initialize
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitPolicy {
+    /// Any failure aborts startup
+    AbortAll,
+    /// Non-critical components may stay offline (degraded mode)
+    ContinueNonCritical,
+    /// Retry failed components with exponential backoff, then continue
+    /// without them if they are non-critical
+    RetryWithBackoff,
+}
+
+impl InitPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InitPolicy::AbortAll => "abort-all",
+            InitPolicy::ContinueNonCritical => "continue-non-critical",
+            InitPolicy::RetryWithBackoff => "retry",
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<InitPolicy, String> {
+        match s.trim().to_lowercase().as_str() {
+            "abort-all" | "abort" => Ok(InitPolicy::AbortAll),
+            "continue-non-critical" | "continue" => Ok(InitPolicy::ContinueNonCritical),
+            "retry" | "retry-with-backoff" => Ok(InitPolicy::RetryWithBackoff),
+            _ => Err(format!("Invalid init policy: {} (abort-all|continue-non-critical|retry)", s)),
+        }
+    }
+}
+
+impl fmt::Display for InitPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Component initialization settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct InitConfig {
+    pub policy: InitPolicy,
+    pub retries: u32,
+    pub backoff_ms: u64,
+}
+
+impl Default for InitConfig {
+    fn default() -> Self {
+        Self {
+            policy: InitPolicy::AbortAll,
+            retries: 3,
+            backoff_ms: 100,
+        }
+    }
+}
+
+/// Redundant sensor settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensorConfig {
+    /// Sensors per voted signal (1 = no redundancy)
+    pub channels: usize,
+    pub tolerance: f32,
+}
+
+impl Default for SensorConfig {
+    fn default() -> Self {
+        Self {
+            channels: 1,
+            tolerance: 5.0,
+        }
+    }
+}
+
+/// Propulsion settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowertrainConfig {
+    pub mode: PowertrainMode,
+    pub battery_kwh: f32,
+    /// Battery charge at startup, in %
+    pub initial_soc: f32,
+}
+
+impl Default for PowertrainConfig {
+    fn default() -> Self {
+        Self {
+            mode: PowertrainMode::Combustion,
+            battery_kwh: 60.0,
+            initial_soc: 80.0,
+        }
+    }
+}
+
+/// Climate control settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct HvacConfig {
+    pub enabled: bool,
+    pub target_c: f32,
+    pub ambient_c: f32,
+}
+
+impl Default for HvacConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target_c: 22.0,
+            ambient_c: 30.0,
+        }
+    }
+}
+
+/// Automatic emergency braking settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdasConfig {
+    pub enabled: bool,
+    pub ttc_threshold_s: f32,
+    pub obstacle_m: f32,
+    pub obstacle_kmh: u8,
+}
+
+impl Default for AdasConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ttc_threshold_s: 2.0,
+            obstacle_m: 100.0,
+            obstacle_kmh: 0,
+        }
+    }
+}
+
+/// Odometry and route settings
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LocationConfig {
+    pub enabled: bool,
+    pub route: String,
+}
+
+/// Longitudinal dynamics settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct VehicleConfig {
+    pub dynamics: DynamicsMode,
+    pub mass_kg: f32,
+}
+
+impl Default for VehicleConfig {
+    fn default() -> Self {
+        Self {
+            dynamics: DynamicsMode::Scripted,
+            mass_kg: 1500.0,
+        }
+    }
+}
+
+/// Component liveness supervision settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchdogConfig {
+    pub max_missed: u32,
+    pub workflow: String,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            max_missed: 3,
+            workflow: "degraded".to_string(),
+        }
+    }
+}
+
+/// Where the effective configuration came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigLayer {
+    Default,
+    Profile,
+    File,
+    Environment,
+    CommandLine,
+}
+
+impl fmt::Display for ConfigLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigLayer::Default => write!(f, "default"),
+            ConfigLayer::Profile => write!(f, "profile"),
+            ConfigLayer::File => write!(f, "file"),
+            ConfigLayer::Environment => write!(f, "env"),
+            ConfigLayer::CommandLine => write!(f, "cli"),
+        }
+    }
+}
+
+/// Complete, validated car system configuration
+#[derive(Debug, Clone)]
+pub struct SystemConfig {
+    pub profile: Profile,
+    pub log_level: LogLevel,
+    pub log: LogConfig,
+    pub ticks: u64,
+    pub event_loop: EventLoopConfig,
+    pub safety: SafetyConfig,
+    /// File the safety limits are loaded from at startup (empty = none)
+    pub safety_file: String,
+    /// Workflow run automatically on Emergency-severity warnings (empty = none)
+    pub emergency_workflow: String,
+    /// File the safety event log is exported to at shutdown (empty = none)
+    pub safety_event_log: String,
+    /// File the state machines' transition history is exported to at
+    /// shutdown (empty = none)
+    pub state_history_log: String,
+    /// Render the dashboard each tick (off for headless runs)
+    pub render: bool,
+    /// Skip reprinting identical dashboard frames
+    pub render_changed_only: bool,
+    pub dashboard_mode: DashboardMode,
+    /// File the JSON dashboard output goes to (empty = stdout)
+    pub dashboard_json_file: String,
+    /// Warnings shown in the dashboard frame (the rest is summarized)
+    pub dashboard_max_warnings: usize,
+    /// Units of the dashboard, the terminal UI and log messages
+    pub units: UnitSystem,
+    /// Seed for the simulation RNG (None = chosen at startup)
+    pub seed: Option<u64>,
+    /// Temperature sensor noise amplitude in °C
+    pub sensor_noise: f32,
+    pub powertrain: PowertrainConfig,
+    pub hvac: HvacConfig,
+    pub adas: AdasConfig,
+    pub location: LocationConfig,
+    pub vehicle: VehicleConfig,
+    pub sensors: SensorConfig,
+    pub chaos: ChaosConfig,
+    pub persistency: PersistencyConfig,
+    pub calibration: CalibrationConfig,
+    pub blackbox: BlackBoxConfig,
+    pub golden: GoldenConfig,
+    pub hardware: HardwareConfig,
+    pub realtime: RealtimeConfig,
+    pub init: InitConfig,
+    pub watchdog: WatchdogConfig,
+    pub supervisor: SupervisorConfig,
+    /// How often the config file is polled for hot-reload
+    pub reload_poll_ticks: u64,
+    /// Calibration command requested on the command line
+    pub calibration_command: Option<CalibrationCommand>,
+    pub tool_command: Option<ToolCommand>,
+    /// Config file that was loaded, if any
+    pub config_file: Option<String>,
+    /// Layer that last set each key (for the startup summary)
+    pub origins: Vec<(String, ConfigLayer)>,
+    /// Arguments and CAR_* variables the config was built from (for reload)
+    inputs: (Vec<String>, Vec<(String, String)>),
+}
+
+impl Default for SystemConfig {
+    fn default() -> Self {
+        Self {
+            profile: Profile::Demo,
+            log_level: LogLevel::Info,
+            log: LogConfig::default(),
+            ticks: 30,
+            event_loop: EventLoopConfig::default(),
+            safety: SafetyConfig::default(),
+            safety_file: String::new(),
+            emergency_workflow: "emergency-stop".to_string(),
+            safety_event_log: String::new(),
+            state_history_log: String::new(),
+            render: true,
+            render_changed_only: false,
+            dashboard_mode: DashboardMode::Plain,
+            dashboard_json_file: String::new(),
+            dashboard_max_warnings: DEFAULT_MAX_WARNINGS,
+            units: UnitSystem::Metric,
+            seed: None,
+            sensor_noise: 0.0,
+            powertrain: PowertrainConfig::default(),
+            hvac: HvacConfig::default(),
+            adas: AdasConfig::default(),
+            location: LocationConfig::default(),
+            vehicle: VehicleConfig::default(),
+            sensors: SensorConfig::default(),
+            chaos: ChaosConfig::default(),
+            persistency: PersistencyConfig::default(),
+            calibration: CalibrationConfig::default(),
+            blackbox: BlackBoxConfig::default(),
+            golden: GoldenConfig::default(),
+            hardware: HardwareConfig::default(),
+            realtime: RealtimeConfig::default(),
+            init: InitConfig::default(),
+            watchdog: WatchdogConfig::default(),
+            supervisor: SupervisorConfig::default(),
+            reload_poll_ticks: 10,
+            calibration_command: None,
+            tool_command: None,
+            config_file: None,
+            origins: Vec::new(),
+            inputs: (Vec::new(), Vec::new()),
+        }
+    }
+}
+
+impl SystemConfig {
+    /// Load configuration from the process arguments and environment
+    /// Returns Err("HELP") when --help was requested
+    pub fn load() -> Result<Self, String> {
+        let args: Vec<String> = env::args().skip(1).collect();
+        let vars: Vec<(String, String)> = env::vars().collect();
+        Self::load_from(&args, &vars)
+    }
+
+    /// Load configuration from explicit arguments and environment variables
+    pub fn load_from(args: &[String], vars: &[(String, String)]) -> Result<Self, String> {
+        let cli = parse_args(args)?;
+        let mut config = Self::default();
+
+        // Layer 1: profile preset (CLI selection wins over the environment)
+        let profile = cli.profile.clone().or_else(|| {
+            vars.iter()
+                .find(|(name, _)| name == ENV_PROFILE)
+                .map(|(_, value)| value.clone())
+        });
+        if let Some(name) = profile {
+            config.apply_profile(Profile::parse(&name)?)?;
+        }
+
+        // Layer 2: config file (CLI path wins over the environment)
+        let file = cli.config_file.clone().or_else(|| {
+            vars.iter()
+                .find(|(name, _)| name == ENV_CONFIG_FILE)
+                .map(|(_, value)| value.clone())
+        });
+        if let Some(path) = file {
+            config.apply_file(&path)?;
+        }
+
+        // Layer 3: environment variables
+        for (key, _) in CONFIG_KEYS {
+            let name = env_var_name(key);
+            if let Some((_, value)) = vars.iter().find(|(n, _)| *n == name) {
+                config
+                    .set(key, value, ConfigLayer::Environment)
+                    .map_err(|e| format!("{}: {}", name, e))?;
+            }
+        }
+
+        // Layer 4: command line flags
+        for (key, value) in &cli.overrides {
+            config
+                .set(key, value, ConfigLayer::CommandLine)
+                .map_err(|e| format!("command line: {}", e))?;
+        }
+
+        config.calibration_command = cli.calibration_command.clone();
+        config.tool_command = cli.tool_command.clone();
+        config.validate()?;
+        config.inputs = (
+            args.to_vec(),
+            vars.iter()
+                .filter(|(name, _)| name.starts_with(ENV_PREFIX))
+                .cloned()
+                .collect(),
+        );
+        Ok(config)
+    }
+
+    /// Rebuild the configuration from the same layers (used by hot-reload)
+    pub fn reload(&self) -> Result<Self, String> {
+        Self::load_from(&self.inputs.0, &self.inputs.1)
+    }
+
+    /// Keys whose values differ between two configurations
+    pub fn diff(&self, other: &SystemConfig) -> Vec<(&'static str, String, String)> {
+        CONFIG_KEYS
+            .iter()
+            .filter_map(|(key, _)| {
+                let old = self.value_of(key);
+                let new = other.value_of(key);
+                (old != new).then_some((*key, old, new))
+            })
+            .collect()
+    }
+
+    /// Apply the settings bundle of a profile
+    pub fn apply_profile(&mut self, profile: Profile) -> Result<(), String> {
+        for (key, value) in profile.settings() {
+            self.set(key, value, ConfigLayer::Profile)?;
+        }
+        self.profile = profile;
+        Ok(())
+    }
+
+    /// Apply settings from a JSON config file
+    pub fn apply_file(&mut self, path: &str) -> Result<(), String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read config file '{}': {}", path, e))?;
+        let json = JsonValue::parse(&text).map_err(|e| format!("{}: {}", path, e))?;
+        if json.as_object().is_none() {
+            return Err(format!("{}: top-level value must be an object", path));
+        }
+
+        for (key, value) in json.flatten() {
+            self.set(&key, &value, ConfigLayer::File)
+                .map_err(|e| format!("{}: {}", path, e))?;
+        }
+        self.config_file = Some(path.to_string());
+        Ok(())
+    }
+
+    /// Set a single key from its textual value
+    pub fn set(&mut self, key: &str, value: &str, layer: ConfigLayer) -> Result<(), String> {
+        match key {
+            "log_level" => self.log_level = LogLevel::parse(value)?,
+            "log.components" => self.log.components = LogConfig::parse_components(value)?,
+            "log.quiet" => self.log.quiet = parse_value(key, value)?,
+            "log.file" => self.log.file = value.to_string(),
+            "log.file_max_kb" => self.log.file_max_kb = parse_value(key, value)?,
+            "log.file_keep" => self.log.file_keep = parse_value(key, value)?,
+            "ticks" => self.ticks = parse_value(key, value)?,
+            "event_loop.tick_rate_ms" => self.event_loop.tick_rate_ms = parse_value(key, value)?,
+            "event_loop.verbose_timing" => {
+                self.event_loop.verbose_timing = parse_value(key, value)?
+            }
+            "event_loop.simulated_clock" => {
+                self.event_loop.clock = if parse_value(key, value)? {
+                    ClockSource::Simulated
+                } else {
+                    ClockSource::WallClock
+                }
+            }
+            "event_loop.time_scale" => self.event_loop.time_scale = parse_value(key, value)?,
+            "event_loop.time_scale_mode" => {
+                self.event_loop.time_scale_mode = TimeScaleMode::parse(value)?
+            }
+            "event_loop.parallel_components" => {
+                self.event_loop.parallel_components = parse_value(key, value)?
+            }
+            "event_loop.execution" => self.event_loop.execution = ExecutionMode::parse(value)?,
+            "event_loop.spin_us" => self.event_loop.spin_us = parse_value(key, value)?,
+            "event_loop.max_overrun_ms" => {
+                self.event_loop.max_overrun_ms = match value.trim() {
+                    "none" | "" => None,
+                    _ => Some(parse_value(key, value)?),
+                }
+            }
+            "event_loop.error_policy" => self.event_loop.error_policy = ErrorPolicy::parse(value)?,
+            "event_loop.overrun_recovery" => {
+                self.event_loop.overrun_recovery = OverrunRecovery::parse(value)?
+            }
+            "event_loop.render_every" => self.event_loop.render_every = parse_value(key, value)?,
+            "event_loop.schedule" => self.event_loop.schedule = TaskSchedule::parse(value)?,
+            "event_loop.deadline_faults" => self.event_loop.deadline_faults = parse_value(key, value)?,
+            "event_loop.console" => self.event_loop.console = parse_value(key, value)?,
+            "init.policy" => self.init.policy = InitPolicy::parse(value)?,
+            "init.retries" => self.init.retries = parse_value(key, value)?,
+            "init.backoff_ms" => self.init.backoff_ms = parse_value(key, value)?,
+            "watchdog.max_missed" => self.watchdog.max_missed = parse_value(key, value)?,
+            "watchdog.workflow" => self.watchdog.workflow = value.trim().to_string(),
+            "supervisor.enabled" => self.supervisor.enabled = parse_value(key, value)?,
+            "supervisor.policy" => self.supervisor.policy = RestartPolicy::parse(value)?,
+            "supervisor.escalation" => self.supervisor.escalation = RestartPolicy::parse(value)?,
+            "supervisor.components" => self.supervisor.components = RestartPolicy::parse_list(value)?,
+            "dashboard.render" => self.render = parse_value(key, value)?,
+            "dashboard.changed_only" => self.render_changed_only = parse_value(key, value)?,
+            "dashboard.mode" => self.dashboard_mode = DashboardMode::parse(value)?,
+            "dashboard.json_file" => self.dashboard_json_file = value.to_string(),
+            "dashboard.max_warnings" => self.dashboard_max_warnings = parse_value(key, value)?,
+            "dashboard.units" => self.units = UnitSystem::parse(value)?,
+            "sim.seed" => {
+                self.seed = match value.trim() {
+                    "random" | "" => None,
+                    _ => Some(parse_value(key, value)?),
+                }
+            }
+            "sim.sensor_noise_c" => self.sensor_noise = parse_value(key, value)?,
+            "powertrain.mode" => self.powertrain.mode = PowertrainMode::parse(value)?,
+            "powertrain.battery_kwh" => self.powertrain.battery_kwh = parse_value(key, value)?,
+            "powertrain.initial_soc" => self.powertrain.initial_soc = parse_value(key, value)?,
+            "hvac.enabled" => self.hvac.enabled = parse_value(key, value)?,
+            "hvac.target_c" => self.hvac.target_c = parse_value(key, value)?,
+            "hvac.ambient_c" => self.hvac.ambient_c = parse_value(key, value)?,
+            "adas.enabled" => self.adas.enabled = parse_value(key, value)?,
+            "adas.ttc_threshold_s" => self.adas.ttc_threshold_s = parse_value(key, value)?,
+            "adas.obstacle_m" => self.adas.obstacle_m = parse_value(key, value)?,
+            "adas.obstacle_kmh" => self.adas.obstacle_kmh = parse_value(key, value)?,
+            "location.enabled" => self.location.enabled = parse_value(key, value)?,
+            "location.route" => self.location.route = value.trim().to_string(),
+            "vehicle.dynamics" => self.vehicle.dynamics = DynamicsMode::parse(value)?,
+            "vehicle.mass_kg" => self.vehicle.mass_kg = parse_value(key, value)?,
+            "sensors.channels" => self.sensors.channels = parse_value(key, value)?,
+            "sensors.tolerance" => self.sensors.tolerance = parse_value(key, value)?,
+            "chaos.drop_percent" => self.chaos.drop_percent = parse_value(key, value)?,
+            "chaos.delay_percent" => self.chaos.delay_percent = parse_value(key, value)?,
+            "chaos.fault_percent" => self.chaos.fault_percent = parse_value(key, value)?,
+            "reload.poll_ticks" => self.reload_poll_ticks = parse_value(key, value)?,
+            "persistency.path" => self.persistency.path = value.trim().to_string(),
+            "persistency.flush_ticks" => {
+                self.persistency.flush_ticks = parse_value(key, value)?
+            }
+            "calibration.file" => self.calibration.file = value.trim().to_string(),
+            "calibration.set" => self.calibration.set = value.trim().to_string(),
+            "blackbox.path" => self.blackbox.path = value.trim().to_string(),
+            "blackbox.seconds" => self.blackbox.seconds = parse_value(key, value)?,
+            "golden.mode" => self.golden.mode = GoldenMode::parse(value)?,
+            "golden.path" => self.golden.path = value.trim().to_string(),
+            "hardware.backend" => self.hardware.backend = HardwareBackend::parse(value)?,
+            "hardware.port" => self.hardware.port = value.trim().to_string(),
+            "hardware.brake_light_pin" => {
+                self.hardware.brake_light_pin = parse_value(key, value)?
+            }
+            "realtime.enabled" => self.realtime.enabled = parse_value(key, value)?,
+            "realtime.cpu" => {
+                self.realtime.cpu = match value.trim() {
+                    "none" | "" => None,
+                    _ => Some(parse_value(key, value)?),
+                }
+            }
+            "realtime.priority" => self.realtime.priority = parse_value(key, value)?,
+            "realtime.prefault_kb" => self.realtime.prefault_kb = parse_value(key, value)?,
+            "safety.max_speed" => self.safety.max_speed = parse_value(key, value)?,
+            "safety.max_temperature" => self.safety.max_temperature = parse_value(key, value)?,
+            "safety.max_rpm" => self.safety.max_rpm = parse_value(key, value)?,
+            "safety.min_fuel" => self.safety.min_fuel = parse_value(key, value)?,
+            "safety.max_brake_pressure" => {
+                self.safety.max_brake_pressure = parse_value(key, value)?
+            }
+            "safety.max_speed_step" => self.safety.max_speed_step = parse_value(key, value)?,
+            "safety.max_temperature_step" => {
+                self.safety.max_temperature_step = parse_value(key, value)?
+            }
+            "safety.max_battery_temperature" => {
+                self.safety.max_battery_temperature = parse_value(key, value)?
+            }
+            "safety.min_battery_soc" => self.safety.min_battery_soc = parse_value(key, value)?,
+            "safety.file" => self.safety_file = value.trim().to_string(),
+            "safety.emergency_workflow" => self.emergency_workflow = value.trim().to_string(),
+            "safety.event_log" => self.safety_event_log = value.trim().to_string(),
+            "state_machines.history_log" => self.state_history_log = value.trim().to_string(),
+            _ => return Err(format!("Unknown config key: {}", key)),
+        }
+
+        self.origins.retain(|(k, _)| k != key);
+        self.origins.push((key.to_string(), layer));
+        Ok(())
+    }
+
+    /// Layer that provided the value of a key
+    pub fn origin(&self, key: &str) -> ConfigLayer {
+        self.origins
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, layer)| *layer)
+            .unwrap_or(ConfigLayer::Default)
+    }
+
+    /// Check that the combined configuration makes sense
+    pub fn validate(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+
+        errors.extend(self.event_loop.errors().iter().map(|e| e.to_string()));
+        for task in self.event_loop.schedule.tasks() {
+            if !SCHEDULED_TASKS.contains(&task.name.as_str()) {
+                errors.push(format!(
+                    "event_loop.schedule: unknown task '{}' (known: {})",
+                    task.name,
+                    SCHEDULED_TASKS.join(", ")
+                ));
+            }
+        }
+        errors.extend(self.safety.errors().into_iter().map(|e| format!("safety.{}", e)));
+        if self.log.file_max_kb == 0 {
+            errors.push("log.file_max_kb must be > 0".to_string());
+        }
+        if self.dashboard_max_warnings == 0 {
+            errors.push("dashboard.max_warnings must be > 0".to_string());
+        }
+        if !(self.sensor_noise >= 0.0 && self.sensor_noise <= 10.0) {
+            errors.push("sim.sensor_noise_c must be between 0 and 10 °C".to_string());
+        }
+        if !(self.powertrain.battery_kwh > 0.0 && self.powertrain.battery_kwh.is_finite()) {
+            errors.push("powertrain.battery_kwh must be a finite number > 0".to_string());
+        }
+        if !(0.0..=100.0).contains(&self.powertrain.initial_soc) {
+            errors.push("powertrain.initial_soc must be between 0 and 100%".to_string());
+        }
+        if !(16.0..=30.0).contains(&self.hvac.target_c) {
+            errors.push("hvac.target_c must be between 16 and 30 °C".to_string());
+        }
+        if !(-40.0..=60.0).contains(&self.hvac.ambient_c) {
+            errors.push("hvac.ambient_c must be between -40 and 60 °C".to_string());
+        }
+        if !(self.adas.ttc_threshold_s > 0.0 && self.adas.ttc_threshold_s <= 10.0) {
+            errors.push("adas.ttc_threshold_s must be between 0 and 10 s".to_string());
+        }
+        if !(self.adas.obstacle_m > 0.0 && self.adas.obstacle_m <= 200.0) {
+            errors.push("adas.obstacle_m must be between 0 and 200 m".to_string());
+        }
+        if !(500.0..=40_000.0).contains(&self.vehicle.mass_kg) {
+            errors.push("vehicle.mass_kg must be between 500 and 40000 kg".to_string());
+        }
+        if matches!(self.supervisor.escalation, RestartPolicy::Restart(_)) {
+            errors.push("supervisor.escalation must be degrade or shutdown".to_string());
+        }
+        if self.event_loop.execution == ExecutionMode::Threaded && self.vehicle.dynamics == DynamicsMode::Physics {
+            errors.push("event_loop.execution=threaded needs vehicle.dynamics=scripted".to_string());
+        }
+        if !(1..=MAX_CHANNELS).contains(&self.sensors.channels) {
+            errors.push(format!("sensors.channels must be between 1 and {}", MAX_CHANNELS));
+        }
+        if !(self.sensors.tolerance >= 0.0 && self.sensors.tolerance.is_finite()) {
+            errors.push("sensors.tolerance must be a finite number >= 0".to_string());
+        }
+        for (key, percent) in [
+            ("chaos.drop_percent", self.chaos.drop_percent),
+            ("chaos.delay_percent", self.chaos.delay_percent),
+            ("chaos.fault_percent", self.chaos.fault_percent),
+        ] {
+            if percent > 100 {
+                errors.push(format!("{} must be at most 100%", key));
+            }
+        }
+        if cfg!(not(feature = "recorder")) && !self.blackbox.path.is_empty() {
+            errors.push("blackbox.path is set but the binary was built without the recorder feature".to_string());
+        }
+        if !self.blackbox.path.is_empty() && self.blackbox.seconds == 0 {
+            errors.push("blackbox.seconds must be greater than 0".to_string());
+        }
+        if self.golden.mode != GoldenMode::Off && self.golden.path.is_empty() {
+            errors.push("golden.path is required when golden.mode is not off".to_string());
+        }
+        if self.hardware.backend == HardwareBackend::Serial && self.hardware.port.is_empty() {
+            errors.push("hardware.port is required for the serial backend".to_string());
+        }
+        if self.realtime.priority > 99 {
+            errors.push("realtime.priority must be between 0 and 99".to_string());
+        }
+        if self.init.retries > 10 {
+            errors.push("init.retries must be at most 10".to_string());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Invalid configuration:\n  - {}", errors.join("\n  - ")))
+        }
+    }
+
+    /// Print the effective configuration and where each value came from
+    pub fn print_summary(&self) {
+        println!("⚙️  Configuration (profile: {})", self.profile);
+        if let Some(path) = &self.config_file {
+            println!("   File: {}", path);
+        }
+        for (key, _) in CONFIG_KEYS {
+            println!("   {:<28} = {:<8} ({})", key, self.value_of(key), self.origin(key));
+        }
+    }
+
+    /// Current value of a key in textual form
+    pub fn value_of(&self, key: &str) -> String {
+        match key {
+            "log_level" => self.log_level.to_string(),
+            "log.components" => LogConfig::format_components(&self.log.components),
+            "log.quiet" => self.log.quiet.to_string(),
+            "log.file" => self.log.file.clone(),
+            "log.file_max_kb" => self.log.file_max_kb.to_string(),
+            "log.file_keep" => self.log.file_keep.to_string(),
+            "ticks" => self.ticks.to_string(),
+            "event_loop.tick_rate_ms" => self.event_loop.tick_rate_ms.to_string(),
+            "event_loop.verbose_timing" => self.event_loop.verbose_timing.to_string(),
+            "event_loop.simulated_clock" => self.event_loop.is_simulated().to_string(),
+            "event_loop.time_scale" => self.event_loop.time_scale.to_string(),
+            "event_loop.time_scale_mode" => self.event_loop.time_scale_mode.as_str().to_string(),
+            "event_loop.parallel_components" => self.event_loop.parallel_components.to_string(),
+            "event_loop.execution" => self.event_loop.execution.as_str().to_string(),
+            "event_loop.spin_us" => self.event_loop.spin_us.to_string(),
+            "event_loop.max_overrun_ms" => self
+                .event_loop
+                .max_overrun_ms
+                .map(|ms| ms.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+            "event_loop.error_policy" => self.event_loop.error_policy.to_string(),
+            "event_loop.overrun_recovery" => self.event_loop.overrun_recovery.to_string(),
+            "event_loop.render_every" => self.event_loop.render_every.to_string(),
+            "event_loop.schedule" => self.event_loop.schedule.to_string(),
+            "event_loop.deadline_faults" => self.event_loop.deadline_faults.to_string(),
+            "event_loop.console" => self.event_loop.console.to_string(),
+            "init.policy" => self.init.policy.to_string(),
+            "init.retries" => self.init.retries.to_string(),
+            "init.backoff_ms" => self.init.backoff_ms.to_string(),
+            "watchdog.max_missed" => self.watchdog.max_missed.to_string(),
+            "watchdog.workflow" => self.watchdog.workflow.clone(),
+            "supervisor.enabled" => self.supervisor.enabled.to_string(),
+            "supervisor.policy" => self.supervisor.policy.to_string(),
+            "supervisor.escalation" => self.supervisor.escalation.to_string(),
+            "supervisor.components" => RestartPolicy::format_list(&self.supervisor.components),
+            "dashboard.render" => self.render.to_string(),
+            "dashboard.changed_only" => self.render_changed_only.to_string(),
+            "dashboard.mode" => self.dashboard_mode.as_str().to_string(),
+            "dashboard.json_file" => self.dashboard_json_file.clone(),
+            "dashboard.max_warnings" => self.dashboard_max_warnings.to_string(),
+            "dashboard.units" => self.units.as_str().to_string(),
+            "sim.seed" => self
+                .seed
+                .map(|seed| seed.to_string())
+                .unwrap_or_else(|| "random".to_string()),
+            "sim.sensor_noise_c" => self.sensor_noise.to_string(),
+            "powertrain.mode" => self.powertrain.mode.to_string(),
+            "powertrain.battery_kwh" => self.powertrain.battery_kwh.to_string(),
+            "powertrain.initial_soc" => self.powertrain.initial_soc.to_string(),
+            "hvac.enabled" => self.hvac.enabled.to_string(),
+            "hvac.target_c" => self.hvac.target_c.to_string(),
+            "hvac.ambient_c" => self.hvac.ambient_c.to_string(),
+            "adas.enabled" => self.adas.enabled.to_string(),
+            "adas.ttc_threshold_s" => self.adas.ttc_threshold_s.to_string(),
+            "adas.obstacle_m" => self.adas.obstacle_m.to_string(),
+            "adas.obstacle_kmh" => self.adas.obstacle_kmh.to_string(),
+            "location.enabled" => self.location.enabled.to_string(),
+            "location.route" => self.location.route.clone(),
+            "vehicle.dynamics" => self.vehicle.dynamics.to_string(),
+            "vehicle.mass_kg" => self.vehicle.mass_kg.to_string(),
+            "sensors.channels" => self.sensors.channels.to_string(),
+            "sensors.tolerance" => self.sensors.tolerance.to_string(),
+            "chaos.drop_percent" => self.chaos.drop_percent.to_string(),
+            "chaos.delay_percent" => self.chaos.delay_percent.to_string(),
+            "chaos.fault_percent" => self.chaos.fault_percent.to_string(),
+            "reload.poll_ticks" => self.reload_poll_ticks.to_string(),
+            "persistency.path" => self.persistency.path.clone(),
+            "persistency.flush_ticks" => self.persistency.flush_ticks.to_string(),
+            "calibration.file" => self.calibration.file.clone(),
+            "calibration.set" => self.calibration.set.clone(),
+            "blackbox.path" => self.blackbox.path.clone(),
+            "blackbox.seconds" => self.blackbox.seconds.to_string(),
+            "golden.mode" => self.golden.mode.to_string(),
+            "golden.path" => self.golden.path.clone(),
+            "hardware.backend" => self.hardware.backend.to_string(),
+            "hardware.port" => self.hardware.port.clone(),
+            "hardware.brake_light_pin" => self.hardware.brake_light_pin.to_string(),
+            "realtime.enabled" => self.realtime.enabled.to_string(),
+            "realtime.cpu" => self
+                .realtime
+                .cpu
+                .map(|cpu| cpu.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+            "realtime.priority" => self.realtime.priority.to_string(),
+            "realtime.prefault_kb" => self.realtime.prefault_kb.to_string(),
+            "safety.max_speed" => self.safety.max_speed.to_string(),
+            "safety.max_temperature" => self.safety.max_temperature.to_string(),
+            "safety.max_rpm" => self.safety.max_rpm.to_string(),
+            "safety.min_fuel" => self.safety.min_fuel.to_string(),
+            "safety.max_brake_pressure" => self.safety.max_brake_pressure.to_string(),
+            "safety.max_speed_step" => self.safety.max_speed_step.to_string(),
+            "safety.max_temperature_step" => self.safety.max_temperature_step.to_string(),
+            "safety.max_battery_temperature" => self.safety.max_battery_temperature.to_string(),
+            "safety.min_battery_soc" => self.safety.min_battery_soc.to_string(),
+            "safety.file" => self.safety_file.clone(),
+            "safety.emergency_workflow" => self.emergency_workflow.clone(),
+            "safety.event_log" => self.safety_event_log.clone(),
+            "state_machines.history_log" => self.state_history_log.clone(),
+            _ => String::new(),
+        }
+    }
+
+    pub fn print_help() {
+        println!("🚗 S-CORE Car System");
+        println!();
+        println!("USAGE:");
+        println!("  car_system_example [COMMAND] [OPTIONS]");
+        println!();
+        println!("COMMANDS:");
+        println!("  run                          Run the demo (default)");
+        println!("  scenario <FILE>              Run a scenario file and check its expectations");
+        println!("  replay <LOG>                 Re-check a golden file or black box against the limits");
+        println!("  workflows list               List the built-in workflows and actions");
+        println!("  workflows run <NAME>         Run one workflow on a freshly started car");
+        println!("  workflows run <FILE.json>    Run a workflow file");
+        println!("  workflows run <FILE.rhai>    Run a script as a workflow (scripting feature)");
+        println!("  workflows check <NAME>       Dry-run a workflow: which steps would run or fail");
+        println!("  calibration diff <A> <B>     Show the differences between two calibration sets");
+        println!("  calibration export <FILE>    Export all calibration sets");
+        println!("  bench [FILE]                 Run the benchmark suite, optionally writing JSON");
+        println!("  soak                         Headless run that fails on resource growth");
+        println!("  fuzz <TARGET>                Fuzz a parser [json|snapshot|message|workflow|safety|store|config|cli|scenario|can_signal|all]");
+        println!("  coverage                     Exercise all state machine transitions");
+        println!("  state-diagrams <FILE>        Write the state machine diagrams (.dot|.puml)");
+        println!("  trace-matrix <FILE>          Write the requirement traceability matrix");
+        println!("  tick-budget <US>             Fail if the p99 tick time exceeds US");
+        println!("  diff-run <A> <B>             Run with config files A and B and diff per tick");
+        println!("  The OPTIONS flags of the same name keep working.");
+        println!();
+        println!("OPTIONS:");
+        println!("  -p, --profile <NAME>      Select a profile preset [demo|test|bench|realtime]");
+        println!("  -c, --config <FILE>       Load settings from a JSON config file");
+        println!("  -t, --ticks <N>           Number of event loop ticks to run");
+        println!("  -r, --tick-rate <MS>      Tick period in milliseconds");
+        println!("      --time-scale <X>      Integrate dynamics X times faster than real time");
+        println!("      --seed <N>            Seed for all simulated randomness");
+        println!("  -l, --log-level <LEVEL>   Set log level [ERROR|WARN|INFO|DEBUG|TRACE]");
+        println!("  -q, --quiet               Only print errors");
+        println!("      --log-file <FILE>     Also write the log to a rotating JSON Lines file");
+        println!("      --verbose-timing      Print per-tick timing");
+        println!("      --calibration <SET>   Select a safety calibration set");
+        println!("      --calibration-file <FILE>    Load calibration sets from a file");
+        println!("      --calibration-diff <A> <B>   Show the differences between two sets and exit");
+        println!("      --calibration-export <FILE>  Export all calibration sets and exit");
+        println!("      --chaos               Drop messages, delay components and inject faults");
+        println!("      --powertrain <MODE>   Propulsion [combustion|ev|hybrid]");
+        println!("      --physics             Speed from throttle, brakes and vehicle dynamics");
+        println!("      --threaded            Run each core component on its own supervised thread");
+        println!("      --tui                 Show the dashboard as a full-screen terminal UI");
+        println!("      --json                Print one JSON dashboard object per cycle instead of the log");
+        println!("      --json-file <FILE>    Write the JSON dashboard lines to a file");
+        println!("      --units <SYSTEM>      Show speed, temperature and distance in [metric|imperial] units");
+        println!("      --serial <PORT>       Drive bench hardware over a serial port instead of the simulator");
+        println!("      --realtime            Pin, prioritize and pre-fault the event-loop thread");
+        println!("  -b, --blackbox <FILE>     Record the last seconds of the run to a black box");
+        println!("      --golden-record <FILE>   Record canonical per-tick snapshots");
+        println!("      --golden-compare <FILE>  Compare the run against a golden file");
+        println!("      --transition-coverage    Exercise all state machine transitions and exit");
+        println!("      --state-diagrams <FILE>  Write the state machine diagrams (.dot|.puml) and exit");
+        println!("      --bench               Run the benchmark suite (iterations = ticks) and exit");
+        println!("      --bench-json <FILE>   Run the benchmark suite and write results as JSON");
+        println!("      --diff-run <A> <B>    Run with config files A and B ('-' = none) and diff per tick");
+        println!("      --trace-matrix <FILE>    Write the requirement traceability matrix (.md|.json)");
+        println!("      --tick-budget <US>    Run ticks headless and fail if p99 tick time exceeds US");
+        println!("      --soak                Run ticks headless and fail on monotonic resource growth");
+        println!("      --fuzz <TARGET>       Fuzz a parser [json|snapshot|message|workflow|safety|store|config|cli|scenario|can_signal|all] with N inputs");
+        println!("  -s, --set <KEY=VALUE>     Override any config key");
+        println!("  -h, --help                Print this help");
+        println!();
+        println!("CONFIG KEYS (env: {}<KEY> with '.' → '_', upper-case):", ENV_PREFIX);
+        for (key, description) in CONFIG_KEYS {
+            println!("  {:<28} {}", key, description);
+        }
+        println!();
+        println!("PROFILES (env: {}):", ENV_PROFILE);
+        for profile in Profile::ALL {
+            println!("  {:<28} {}", profile.as_str(), profile.description());
+        }
+        println!();
+        println!("PRECEDENCE:");
+        println!("  defaults < profile < config file < environment < command line");
+    }
+}
+
+/// Environment variable name for a config key
+pub fn env_var_name(key: &str) -> String {
+    format!("{}{}", ENV_PREFIX, key.replace('.', "_").to_uppercase())
+}
+
+fn parse_value<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: '{}'", key, value))
+}
+
+/// Command line arguments before they are layered onto the config
+struct CliArgs {
+    profile: Option<String>,
+    config_file: Option<String>,
+    calibration_command: Option<CalibrationCommand>,
+    tool_command: Option<ToolCommand>,
+    overrides: Vec<(String, String)>,
+}
+
+/// Parse a leading subcommand and its positional arguments into `cli`
+/// Returns the index of the first option after them (0 without one).
+fn parse_subcommand(args: &[String], cli: &mut CliArgs) -> Result<usize, String> {
+    let Some(command) = args.first().filter(|arg| !arg.starts_with('-')) else {
+        return Ok(0);
+    };
+    let mut i = 1;
+    let mut positional = |what: &str| -> Result<String, String> {
+        let value = args
+            .get(i)
+            .filter(|arg| !arg.starts_with('-') || arg.as_str() == "-")
+            .cloned()
+            .ok_or_else(|| format!("{} requires {}", command, what))?;
+        i += 1;
+        Ok(value)
+    };
+
+    match command.as_str() {
+        "run" => {}
+        "scenario" => cli.tool_command = Some(ToolCommand::Scenario(positional("a scenario file")?)),
+        "replay" => cli.tool_command = Some(ToolCommand::Replay(positional("a log file")?)),
+        "workflows" => {
+            cli.tool_command = Some(match positional("'list', 'run <NAME>' or 'check <NAME>'")?.as_str() {
+                "list" => ToolCommand::WorkflowList,
+                "run" => ToolCommand::WorkflowRun(positional("a workflow name")?),
+                "check" => ToolCommand::WorkflowCheck(positional("a workflow name")?),
+                other => return Err(format!("Unknown workflows command: {}", other)),
+            })
+        }
+        "calibration" => {
+            cli.calibration_command = Some(match positional("'diff <A> <B>' or 'export <FILE>'")?.as_str() {
+                "diff" => CalibrationCommand::Diff(positional("two set names")?, positional("two set names")?),
+                "export" => CalibrationCommand::Export(positional("a file")?),
+                other => return Err(format!("Unknown calibration command: {}", other)),
+            })
+        }
+        "bench" => {
+            let output = args.get(1).filter(|arg| !arg.starts_with('-')).cloned();
+            if output.is_some() {
+                i += 1;
+            }
+            cli.tool_command = Some(ToolCommand::Bench(output));
+        }
+        "soak" => cli.tool_command = Some(ToolCommand::Soak),
+        "fuzz" => cli.tool_command = Some(ToolCommand::Fuzz(positional("a target")?)),
+        "coverage" => cli.tool_command = Some(ToolCommand::TransitionCoverage),
+        "state-diagrams" => cli.tool_command = Some(ToolCommand::StateDiagrams(positional("a file")?)),
+        "trace-matrix" => cli.tool_command = Some(ToolCommand::TraceMatrix(positional("a file")?)),
+        "tick-budget" => {
+            let budget = positional("a budget in microseconds")?;
+            cli.tool_command = Some(ToolCommand::TickBudget(parse_value("tick-budget", &budget)?));
+        }
+        "diff-run" => {
+            cli.tool_command = Some(ToolCommand::DiffRun(positional("two config files")?,
+                                                         positional("two config files")?))
+        }
+        other => return Err(format!("Unknown command: {}", other)),
+    }
+    Ok(i)
+}
+
+/// Parse the command line: an optional subcommand, then options
+fn parse_args(args: &[String]) -> Result<CliArgs, String> {
+    let mut cli = CliArgs {
+        profile: None,
+        config_file: None,
+        calibration_command: None,
+        tool_command: None,
+        overrides: Vec::new(),
+    };
+
+    let mut i = parse_subcommand(args, &mut cli)?;
+    while i < args.len() {
+        let flag = args[i].as_str();
+        let mut value = || -> Result<String, String> {
+            i += 1;
+            args.get(i)
+                .cloned()
+                .ok_or_else(|| format!("{} requires a value", flag))
+        };
+
+        match flag {
+            "--profile" | "-p" => cli.profile = Some(value()?),
+            "--config" | "-c" => cli.config_file = Some(value()?),
+            "--ticks" | "-t" => cli.overrides.push(("ticks".to_string(), value()?)),
+            "--tick-rate" | "-r" => cli
+                .overrides
+                .push(("event_loop.tick_rate_ms".to_string(), value()?)),
+            "--time-scale" => cli
+                .overrides
+                .push(("event_loop.time_scale".to_string(), value()?)),
+            "--seed" => cli.overrides.push(("sim.seed".to_string(), value()?)),
+            "--log-level" | "-l" => cli.overrides.push(("log_level".to_string(), value()?)),
+            "--quiet" | "-q" => cli.overrides.push(("log.quiet".to_string(), "true".to_string())),
+            "--log-file" => cli.overrides.push(("log.file".to_string(), value()?)),
+            "--verbose-timing" => cli
+                .overrides
+                .push(("event_loop.verbose_timing".to_string(), "true".to_string())),
+            "--calibration" => cli.overrides.push(("calibration.set".to_string(), value()?)),
+            "--powertrain" => cli.overrides.push(("powertrain.mode".to_string(), value()?)),
+            "--physics" => cli.overrides.push(("vehicle.dynamics".to_string(), "physics".to_string())),
+            "--tui" => cli.overrides.push(("dashboard.mode".to_string(), "tui".to_string())),
+            "--units" => cli.overrides.push(("dashboard.units".to_string(), value()?)),
+            "--json" | "--json-file" => {
+                if flag == "--json-file" {
+                    cli.overrides.push(("dashboard.json_file".to_string(), value()?));
+                }
+                cli.overrides.push(("dashboard.mode".to_string(), "json".to_string()));
+                cli.overrides.push(("dashboard.render".to_string(), "true".to_string()));
+            }
+            "--threaded" => cli
+                .overrides
+                .push(("event_loop.execution".to_string(), "threaded".to_string())),
+            "--calibration-file" => {
+                cli.overrides.push(("calibration.file".to_string(), value()?))
+            }
+            "--calibration-diff" => {
+                let a = value()?;
+                let b = value()?;
+                cli.calibration_command = Some(CalibrationCommand::Diff(a, b));
+            }
+            "--calibration-export" => {
+                cli.calibration_command = Some(CalibrationCommand::Export(value()?))
+            }
+            "--serial" => {
+                cli.overrides.push(("hardware.backend".to_string(), "serial".to_string()));
+                cli.overrides.push(("hardware.port".to_string(), value()?));
+            }
+            "--realtime" => cli
+                .overrides
+                .push(("realtime.enabled".to_string(), "true".to_string())),
+            "--chaos" => {
+                // Moderate preset; a later --set chaos.* overrides it
+                for (key, percent) in [
+                    ("chaos.drop_percent", "10"),
+                    ("chaos.delay_percent", "5"),
+                    ("chaos.fault_percent", "2"),
+                ] {
+                    cli.overrides.push((key.to_string(), percent.to_string()));
+                }
+            }
+            "--blackbox" | "-b" => cli.overrides.push(("blackbox.path".to_string(), value()?)),
+            "--golden-record" | "--golden-compare" => {
+                let mode = flag.trim_start_matches("--golden-");
+                cli.overrides.push(("golden.path".to_string(), value()?));
+                cli.overrides.push(("golden.mode".to_string(), mode.to_string()));
+            }
+            "--transition-coverage" => cli.tool_command = Some(ToolCommand::TransitionCoverage),
+            "--state-diagrams" => cli.tool_command = Some(ToolCommand::StateDiagrams(value()?)),
+            "--bench" => cli.tool_command = Some(ToolCommand::Bench(None)),
+            "--bench-json" => cli.tool_command = Some(ToolCommand::Bench(Some(value()?))),
+            "--diff-run" => {
+                let a = value()?;
+                let b = value()?;
+                cli.tool_command = Some(ToolCommand::DiffRun(a, b));
+            }
+            "--trace-matrix" => cli.tool_command = Some(ToolCommand::TraceMatrix(value()?)),
+            "--tick-budget" => {
+                let budget = value()?;
+                let budget = parse_value("--tick-budget", &budget)?;
+                cli.tool_command = Some(ToolCommand::TickBudget(budget));
+            }
+            "--soak" => cli.tool_command = Some(ToolCommand::Soak),
+            "--fuzz" => cli.tool_command = Some(ToolCommand::Fuzz(value()?)),
+            "--set" | "-s" => {
+                let pair = value()?;
+                let (key, val) = pair
+                    .split_once('=')
+                    .ok_or_else(|| format!("--set expects KEY=VALUE, got '{}'", pair))?;
+                cli.overrides.push((key.trim().to_string(), val.to_string()));
+            }
+            "--help" | "-h" => return Err("HELP".to_string()),
+            _ => return Err(format!("Unknown argument: {}", flag)),
+        }
+        i += 1;
+    }
+
+    Ok(cli)
+}
@@ -0,0 +1,156 @@
+//! Config hot-reload
+//! Demonstrates S-CORE runtime reconfiguration patterns:
+//! - Polling a config file for changes from the event loop
+//! - Applying only settings that are safe to change at runtime
+//! - Rejecting changes that would relax safety limits while driving
+
+use std::fs;
+use std::time::SystemTime;
+
+use crate::components::config::SystemConfig;
+
+/// Polls a config file for modifications every N ticks
+pub struct ConfigWatcher {
+    path: String,
+    /// Polling interval, at least 1
+    poll_ticks: u64,
+    /// False when polling is off (interval 0)
+    enabled: bool,
+    last_modified: Option<SystemTime>,
+}
+
+impl ConfigWatcher {
+    /// Create a watcher for the given file (`poll_ticks` 0 disables polling)
+    pub fn new(path: &str, poll_ticks: u64) -> Self {
+        Self {
+            path: path.to_string(),
+            poll_ticks: poll_ticks.max(1),
+            enabled: poll_ticks > 0,
+            last_modified: modified_time(path),
+        }
+    }
+
+    /// Path being watched
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Change the polling interval (0 disables polling)
+    pub fn set_poll_ticks(&mut self, poll_ticks: u64) {
+        self.poll_ticks = poll_ticks.max(1);
+        self.enabled = poll_ticks > 0;
+    }
+
+    /// Returns true when the file was modified since the last poll
+    /// Only checks the file system on every `poll_ticks`-th tick
+    pub fn poll(&mut self, tick: u64) -> bool {
+        if !self.enabled || !tick.is_multiple_of(self.poll_ticks) {
+            return false;
+        }
+
+        let modified = modified_time(&self.path);
+        if modified != self.last_modified {
+            self.last_modified = modified;
+            return true;
+        }
+        false
+    }
+}
+
+fn modified_time(path: &str) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Result of comparing a reloaded config against the running one
+#[derive(Debug, Default)]
+pub struct ReloadPlan {
+    /// Changes that can be applied now: (key, old value, new value)
+    pub accepted: Vec<(&'static str, String, String)>,
+    /// Changes that were refused: (key, reason)
+    pub rejected: Vec<(&'static str, String)>,
+}
+
+impl ReloadPlan {
+    /// Decide which changes are safe to apply
+    /// `moving` is true while the vehicle has a non-zero speed
+    pub fn new(current: &SystemConfig, reloaded: &SystemConfig, moving: bool) -> Self {
+        let mut plan = Self::default();
+
+        for (key, old, new) in current.diff(reloaded) {
+            match check_change(key, &old, &new, moving) {
+                Ok(()) => plan.accepted.push((key, old, new)),
+                Err(reason) => plan.rejected.push((key, reason)),
+            }
+        }
+
+        plan
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accepted.is_empty() && self.rejected.is_empty()
+    }
+}
+
+/// Check whether a single key may change at runtime
+fn check_change(key: &str, old: &str, new: &str, moving: bool) -> Result<(), String> {
+    match key {
+        "log_level" | "log.components" | "log.quiet" | "event_loop.tick_rate_ms" | "reload.poll_ticks" | "hvac.target_c"
+        | "dashboard.max_warnings" | "dashboard.units" => Ok(()),
+        "safety.max_speed" | "safety.max_temperature" | "safety.max_rpm"
+        | "safety.max_brake_pressure" | "safety.max_speed_step" | "safety.max_temperature_step"
+        | "safety.max_battery_temperature" => {
+            if moving && parse_number(new) > parse_number(old) {
+                Err(format!("cannot raise limit {} → {} while driving", old, new))
+            } else {
+                Ok(())
+            }
+        }
+        "safety.min_fuel" | "safety.min_battery_soc" => {
+            if moving && parse_number(new) < parse_number(old) {
+                Err(format!("cannot lower limit {} → {} while driving", old, new))
+            } else {
+                Ok(())
+            }
+        }
+        _ => Err("requires a restart".to_string()),
+    }
+}
+
+fn parse_number(value: &str) -> f64 {
+    value.parse().unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A watcher on a file that doesn't exist yet, and that file's path
+    fn watcher_on_missing_file(name: &str, poll_ticks: u64) -> (ConfigWatcher, std::path::PathBuf) {
+        let path = std::env::temp_dir().join(format!("score_watcher_{}_{}.json", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        (ConfigWatcher::new(path.to_str().unwrap(), poll_ticks), path)
+    }
+
+    #[test]
+    fn polls_only_every_poll_ticks() {
+        let (mut watcher, path) = watcher_on_missing_file("interval", 4);
+        fs::write(&path, "{}").unwrap();
+        assert!(!watcher.poll(3));
+        assert!(watcher.poll(4));
+        assert!(!watcher.poll(8));
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn zero_poll_ticks_disables_polling() {
+        let (mut watcher, path) = watcher_on_missing_file("disabled", 0);
+        fs::write(&path, "{}").unwrap();
+        assert!((0..10).all(|tick| !watcher.poll(tick)));
+
+        watcher.set_poll_ticks(1);
+        assert!(watcher.poll(10));
+        watcher.set_poll_ticks(0);
+        fs::remove_file(&path).unwrap();
+        assert!(!watcher.poll(11));
+    }
+}
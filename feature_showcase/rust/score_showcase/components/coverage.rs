@@ -0,0 +1,282 @@
+//! Transition coverage exerciser
+//! Demonstrates S-CORE verification practices:
+//! - Systematically drive a state machine through every reachable state
+//! - Attempt every transition from each state, valid or not
+//! - Report a coverage matrix of accepted and rejected transitions
+//!
+//! Invalid attempts must be rejected by the state machine; a declared
+//! transition that is never reached counts as a coverage gap.
+//! Event-driven machines are explored a second time by feeding them
+//! events; a declared transition no event triggers is a gap as well.
+//!
+//! The same declarations are exported as state diagrams (DOT or PlantUML),
+//! so the documentation is regenerated from code instead of drawn by hand.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs;
+
+use crate::components::state_machine::{
+    BrakeStateMachine, ChargingState, EngineStateMachine, EventDriven, SteeringState, TransitionMachine,
+};
+
+/// Outcome of one (from, to) cell of the matrix
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransitionResult {
+    /// Valid transition, taken during exploration
+    Accepted,
+    /// Invalid transition, attempted and refused
+    Rejected,
+    /// Source state was never reached
+    Unreached,
+}
+
+impl TransitionResult {
+    fn symbol(&self) -> &'static str {
+        match self {
+            TransitionResult::Accepted => "✓",
+            TransitionResult::Rejected => "✗",
+            TransitionResult::Unreached => "·",
+        }
+    }
+}
+
+/// Transition coverage matrix of one state machine
+pub struct TransitionCoverage {
+    pub machine: String,
+    pub states: Vec<String>,
+    /// cells[from][to]
+    pub cells: Vec<Vec<TransitionResult>>,
+}
+
+impl TransitionCoverage {
+    /// Explore a state machine breadth-first from `initial`
+    /// `can_transition` is the machine's own guard, so the matrix reflects
+    /// what the implementation enforces rather than what is documented.
+    pub fn explore<S, F>(machine: &str, initial: S, states: &[S], can_transition: F) -> Self
+    where
+        S: Clone + PartialEq + fmt::Display,
+        F: Fn(&S, &S) -> bool,
+    {
+        let n = states.len();
+        let index = |state: &S| states.iter().position(|s| s == state);
+        let mut cells = vec![vec![TransitionResult::Unreached; n]; n];
+        let mut visited = vec![false; n];
+        let mut queue = VecDeque::new();
+
+        if let Some(start) = index(&initial) {
+            visited[start] = true;
+            queue.push_back(start);
+        }
+
+        while let Some(from) = queue.pop_front() {
+            for (to, target) in states.iter().enumerate() {
+                if can_transition(&states[from], target) {
+                    cells[from][to] = TransitionResult::Accepted;
+                    if !visited[to] {
+                        visited[to] = true;
+                        queue.push_back(to);
+                    }
+                } else {
+                    cells[from][to] = TransitionResult::Rejected;
+                }
+            }
+        }
+
+        Self {
+            machine: machine.to_string(),
+            states: states.iter().map(|s| s.to_string()).collect(),
+            cells,
+        }
+    }
+
+    /// Coverage of the engine state machine
+    pub fn engine() -> Self {
+        Self::explore(
+            "EngineStateMachine",
+            EngineStateMachine::Off,
+            &EngineStateMachine::ALL,
+            |from, to| from.can_transition_to(to),
+        )
+    }
+
+    /// Coverage of a machine declared with `StateMachineBuilder`
+    pub fn declared<S>(machine: &TransitionMachine<S>) -> Self
+    where
+        S: Clone + PartialEq + fmt::Debug + fmt::Display,
+    {
+        Self::explore(
+            machine.name(),
+            machine.initial_state().clone(),
+            machine.states(),
+            |from, to| machine.allows(from, to),
+        )
+    }
+
+    /// Coverage reached by feeding `S::EVENTS` to an event-driven machine
+    /// A transition counts as accepted when an event triggers it and the
+    /// declared table allows it.
+    pub fn events<S>(machine: &TransitionMachine<S>) -> Self
+    where
+        S: Clone + PartialEq + fmt::Debug + fmt::Display + EventDriven,
+    {
+        Self::explore(
+            &format!("{} (events)", machine.name()),
+            machine.initial_state().clone(),
+            machine.states(),
+            |from, to| {
+                machine.allows(from, to)
+                    && S::EVENTS.iter().any(|event| from.on_event(event).as_ref() == Some(to))
+            },
+        )
+    }
+
+    /// Transitions of `machine` this matrix did not accept, as "FROM → TO"
+    pub fn missing<S>(&self, machine: &TransitionMachine<S>) -> Vec<String>
+    where
+        S: Clone + PartialEq + fmt::Debug + fmt::Display,
+    {
+        let index = |state: &S| self.states.iter().position(|s| *s == state.to_string());
+        machine
+            .transitions()
+            .iter()
+            .filter(|(from, to)| match (index(from), index(to)) {
+                (Some(from), Some(to)) => self.cells[from][to] != TransitionResult::Accepted,
+                _ => true,
+            })
+            .map(|(from, to)| format!("{} → {}", from, to))
+            .collect()
+    }
+
+    fn count(&self, result: TransitionResult) -> usize {
+        self.cells.iter().flatten().filter(|cell| **cell == result).count()
+    }
+
+    /// Number of valid transitions exercised
+    pub fn accepted(&self) -> usize {
+        self.count(TransitionResult::Accepted)
+    }
+
+    /// Number of invalid attempts that were refused
+    pub fn rejected(&self) -> usize {
+        self.count(TransitionResult::Rejected)
+    }
+
+    /// Percentage of the matrix that was exercised (accepted or rejected)
+    pub fn percent(&self) -> f64 {
+        let total = self.states.len() * self.states.len();
+        if total == 0 {
+            return 100.0;
+        }
+        (self.accepted() + self.rejected()) as f64 * 100.0 / total as f64
+    }
+
+    /// States that could not be reached from the initial state
+    pub fn unreached_states(&self) -> Vec<&str> {
+        self.cells
+            .iter()
+            .zip(&self.states)
+            .filter(|(row, _)| row.iter().all(|cell| *cell == TransitionResult::Unreached))
+            .map(|(_, state)| state.as_str())
+            .collect()
+    }
+
+    /// Print the matrix (rows = from, columns = to)
+    pub fn print(&self) {
+        let width = self.states.iter().map(|s| s.len()).max().unwrap_or(0).max(4);
+
+        println!("🧭 {}", self.machine);
+        print!("  {:<width$} │", "from\\to", width = width);
+        for state in &self.states {
+            print!(" {:^width$}", state, width = width);
+        }
+        println!();
+        println!("  {}─┼{}", "─".repeat(width), "─".repeat((width + 1) * self.states.len()));
+        for (state, row) in self.states.iter().zip(&self.cells) {
+            print!("  {:<width$} │", state, width = width);
+            for cell in row {
+                print!(" {:^width$}", cell.symbol(), width = width);
+            }
+            println!();
+        }
+        println!(
+            "  ✓ {} accepted  ✗ {} rejected  → {:.0}% of transitions exercised",
+            self.accepted(),
+            self.rejected(),
+            self.percent()
+        );
+    }
+}
+
+/// Exercise every declared state machine and print the coverage report
+/// Fails if any state cannot be reached.
+pub fn run_transition_coverage() -> Result<(), String> {
+    println!("🧪 Transition coverage\n");
+
+    let machines = vec![
+        TransitionCoverage::engine(),
+        TransitionCoverage::declared(&BrakeStateMachine::machine()),
+        TransitionCoverage::declared(&SteeringState::machine()),
+        TransitionCoverage::declared(&ChargingState::machine()),
+    ];
+    let mut gaps = Vec::new();
+    for coverage in &machines {
+        coverage.print();
+        println!();
+        for state in coverage.unreached_states() {
+            gaps.push(format!("{}::{}", coverage.machine, state));
+        }
+    }
+
+    // The same machines driven by events instead of method calls
+    event_coverage(&EngineStateMachine::machine(), &mut gaps);
+    event_coverage(&BrakeStateMachine::machine(), &mut gaps);
+    event_coverage(&SteeringState::machine(), &mut gaps);
+
+    if gaps.is_empty() {
+        println!("✅ All states reachable, all transitions exercised");
+        Ok(())
+    } else {
+        Err(format!("coverage gaps: {}", gaps.join(", ")))
+    }
+}
+
+/// Print the event coverage of one machine, adding transitions no event
+/// triggers to `gaps`
+fn event_coverage<S>(machine: &TransitionMachine<S>, gaps: &mut Vec<String>)
+where
+    S: Clone + PartialEq + fmt::Debug + fmt::Display + EventDriven,
+{
+    let coverage = TransitionCoverage::events(machine);
+    coverage.print();
+    println!();
+    for transition in coverage.missing(machine) {
+        gaps.push(format!("{}: no event triggers {}", machine.name(), transition));
+    }
+}
+
+/// Write the diagrams of every declared state machine to one file
+/// The format follows the extension: .dot/.gv (Graphviz) or .puml (PlantUML).
+pub fn run_state_diagrams(path: &str) -> Result<(), String> {
+    let engine = EngineStateMachine::machine();
+    let brakes = BrakeStateMachine::machine();
+    let steering = SteeringState::machine();
+    let battery = ChargingState::machine();
+    let diagrams = if path.ends_with(".dot") || path.ends_with(".gv") {
+        [engine.to_dot(), brakes.to_dot(), steering.to_dot(), battery.to_dot()]
+    } else if path.ends_with(".puml") || path.ends_with(".plantuml") {
+        [engine.to_plantuml(), brakes.to_plantuml(), steering.to_plantuml(), battery.to_plantuml()]
+    } else {
+        return Err(format!("Unknown diagram format '{}' (expected .dot, .gv or .puml)", path));
+    };
+    fs::write(path, diagrams.join("\n")).map_err(|e| format!("Cannot write '{}': {}", path, e))?;
+    println!(
+        "🗺️  State diagrams: {}, {}, {}, {} → {}",
+        engine.name(),
+        brakes.name(),
+        steering.name(),
+        battery.name(),
+        path
+    );
+    Ok(())
+}
@@ -0,0 +1,557 @@
+//! Dashboard component - displays all system states
+//! Demonstrates S-CORE patterns:
+//! - Aggregating state from multiple components
+//! - Display formatting and status reporting
+//! - Warning management: one warning per condition, cleared when the
+//!   condition resolves, most severe shown first (see `WarningSet`)
+//! - Message subscription (Phase 3)
+//! - Speed sparkline from a fixed-size signal history
+//! - Metric or imperial display units (see `UnitSystem`)
+//! - Machine-readable output: one JSON object per cycle instead of the
+//!   text frame, for piping into other tools
+
+use std::fmt;
+#[cfg(feature = "render")]
+use std::fmt::Write as _;
+#[cfg(feature = "render")]
+use std::io::{self, Write as _};
+#[cfg(feature = "render")]
+use std::mem;
+
+use crate::components::{CarComponent, ComponentState, CarMessage, Fault, HealthStatus};
+#[cfg(feature = "render")]
+use crate::components::SystemSnapshot;
+use crate::components::event_loop::DEFAULT_TIME_STEP_S;
+use crate::components::units::UnitSystem;
+use crate::components::warnings::{ClearPolicy, DashboardWarning, WarningSet, WarningSeverity};
+#[cfg(feature = "render")]
+use crate::components::history::History;
+#[cfg(feature = "render")]
+use crate::components::json::JsonValue;
+#[cfg(feature = "render")]
+use crate::components::{console_enabled, LogLevel};
+use crate::log_info;
+
+/// Fuel used per second for each km/h of speed, in %
+#[cfg(feature = "sim_physics")]
+const FUEL_RATE_PERCENT_PER_KMH_S: f32 = 0.0002;
+/// Fuel used per second for each kW of accessory load, in %
+#[cfg(feature = "sim_physics")]
+const FUEL_RATE_PERCENT_PER_KW_S: f32 = 0.001;
+
+/// Number of rendered frames shown in the speed sparkline
+#[cfg(feature = "render")]
+const SPARKLINE_LEN: usize = 30;
+
+/// Speed shown as a full sparkline bar, in km/h
+#[cfg(feature = "render")]
+const SPARKLINE_MAX_KMH: u16 = 140;
+
+#[cfg(feature = "render")]
+const SPARKLINE_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Fuel level below which "Low fuel" is shown, in %
+const LOW_FUEL_PERCENT: u8 = 20;
+
+/// Speed above which "High speed" is shown, in km/h
+const HIGH_SPEED_KMH: u8 = 120;
+
+/// Brake pressure above which "High brake pressure" is shown, in %
+const HIGH_BRAKE_PRESSURE_PERCENT: u8 = 50;
+
+/// Warnings shown in the frame by default
+pub const DEFAULT_MAX_WARNINGS: usize = 5;
+
+/// How the dashboard is shown while the event loop runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DashboardMode {
+    /// Text frame printed below the log output
+    Plain,
+    /// Full-screen terminal UI (see `TuiDashboard`)
+    Tui,
+    /// One JSON object per line, to stdout or `dashboard.json_file`
+    Json,
+}
+
+impl DashboardMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DashboardMode::Plain => "plain",
+            DashboardMode::Tui => "tui",
+            DashboardMode::Json => "json",
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<DashboardMode, String> {
+        match s.trim().to_lowercase().as_str() {
+            "plain" => Ok(DashboardMode::Plain),
+            "tui" => Ok(DashboardMode::Tui),
+            "json" => Ok(DashboardMode::Json),
+            _ => Err(format!("Invalid dashboard mode: {} (plain|tui|json)", s)),
+        }
+    }
+}
+
+impl fmt::Display for DashboardMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Dashboard component - displays all car system information
+pub struct DashboardComponent {
+    state: ComponentState,
+    speed: u8,           // km/h
+    fuel_level: u8,      // 0-100%
+    warnings: WarningSet,
+    /// Warnings shown in the frame, most severe first
+    max_warnings: usize,
+    /// Simulated seconds since start (warning timestamps)
+    elapsed_s: f32,
+    /// Units values are shown in
+    units: UnitSystem,
+    /// Latched faults, shown apart from the warnings of the current tick
+    faults: Vec<Fault>,
+    /// Rendered display frame and the one before it (for change detection)
+    #[cfg(feature = "render")]
+    frame: String,
+    #[cfg(feature = "render")]
+    previous_frame: String,
+    /// Speed of the last rendered frames
+    #[cfg(feature = "render")]
+    speed_history: History<u8, SPARKLINE_LEN>,
+    odometer: f32,       // km
+    /// Consumption not yet applied to the integer fuel level
+    fuel_used: f32,
+    /// The engine propels the car (electric driving burns no fuel)
+    combustion_drive: bool,
+    /// Power drawn by accessories (HVAC), as last reported on the bus
+    accessory_load_w: u16,
+    /// Distance left along the route in m, as last reported on the bus
+    to_destination: Option<f32>,
+    /// Simulated seconds per process() call
+    dt_s: f32,
+}
+
+impl DashboardComponent {
+    /// Create a new dashboard component
+    pub fn new() -> Self {
+        Self {
+            state: ComponentState::Offline,
+            speed: 0,
+            fuel_level: 100,
+            warnings: WarningSet::new(),
+            max_warnings: DEFAULT_MAX_WARNINGS,
+            elapsed_s: 0.0,
+            units: UnitSystem::Metric,
+            faults: Vec::new(),
+            #[cfg(feature = "render")]
+            frame: String::new(),
+            #[cfg(feature = "render")]
+            previous_frame: String::new(),
+            #[cfg(feature = "render")]
+            speed_history: History::new(),
+            odometer: 0.0,
+            fuel_used: 0.0,
+            combustion_drive: true,
+            accessory_load_w: 0,
+            to_destination: None,
+            dt_s: DEFAULT_TIME_STEP_S,
+        }
+    }
+
+    /// Set current speed
+    pub fn set_speed(&mut self, speed: u8) {
+        self.speed = speed.min(200); // Max speed cap
+    }
+
+    /// Set fuel level
+    pub fn set_fuel_level(&mut self, level: u8) {
+        self.fuel_level = level.min(100);
+        self.fuel_used = 0.0;
+    }
+
+    /// Set whether the engine or the battery propels the car
+    pub fn set_combustion_drive(&mut self, combustion: bool) {
+        self.combustion_drive = combustion;
+    }
+
+    /// Check whether the engine propels the car
+    pub fn is_combustion_drive(&self) -> bool {
+        self.combustion_drive
+    }
+
+    /// Power drawn by accessories in W
+    pub fn get_accessory_load(&self) -> u16 {
+        self.accessory_load_w
+    }
+
+    /// Distance left to the destination in m (None = no route)
+    pub fn get_distance_to_destination(&self) -> Option<f32> {
+        self.to_destination
+    }
+
+    /// Set the simulated time covered by each process() call
+    pub fn set_time_step(&mut self, dt_s: f32) {
+        self.dt_s = dt_s;
+    }
+
+    /// Set the units speed, temperature and distance are shown in
+    pub fn set_unit_system(&mut self, units: UnitSystem) {
+        self.units = units;
+    }
+
+    pub fn get_unit_system(&self) -> UnitSystem {
+        self.units
+    }
+
+    /// Set how many warnings the frame shows
+    pub fn set_max_warnings(&mut self, max: usize) {
+        self.max_warnings = max;
+    }
+
+    /// Add a warning that stays until cleared by hand
+    pub fn add_warning(&mut self, severity: WarningSeverity, warning: String) {
+        self.warnings.raise(self.elapsed_s, "CarSystem", "notice", severity, ClearPolicy::Resolved,
+                            format_args!("{}", warning));
+    }
+
+    /// Raise a warning (refreshing it if it is already shown)
+    fn raise(&mut self, source: &'static str, code: &'static str, severity: WarningSeverity, policy: ClearPolicy,
+             message: fmt::Arguments) {
+        self.warnings.raise(self.elapsed_s, source, code, severity, policy, message);
+    }
+
+    /// Clear a warning whose condition resolved
+    fn resolve(&mut self, source: &'static str, code: &'static str) {
+        self.warnings.clear(self.elapsed_s, source, code);
+    }
+
+    /// Clear all warnings
+    pub fn clear_warnings(&mut self) {
+        self.warnings.clear_all(self.elapsed_s);
+    }
+
+    /// Show the latched faults of the safety monitor
+    pub fn set_faults(&mut self, faults: &[Fault]) {
+        if self.faults != faults {
+            self.faults.clear();
+            self.faults.extend_from_slice(faults);
+        }
+    }
+
+    /// Get latched faults
+    pub fn get_faults(&self) -> &[Fault] {
+        &self.faults
+    }
+
+    /// Get current speed
+    pub fn get_speed(&self) -> u8 {
+        self.speed
+    }
+
+    /// Get fuel level
+    pub fn get_fuel_level(&self) -> u8 {
+        self.fuel_level
+    }
+
+    /// Get the messages of the active warnings, most severe first
+    pub fn get_warnings(&self) -> &[String] {
+        self.warnings.messages()
+    }
+
+    /// Get the active warnings with severity, source and timestamps
+    pub fn get_active_warnings(&self) -> &[DashboardWarning] {
+        self.warnings.active()
+    }
+
+    /// Get recently cleared warnings, oldest first
+    pub fn get_cleared_warnings(&self) -> impl Iterator<Item = &DashboardWarning> {
+        self.warnings.cleared()
+    }
+
+    /// Update odometer
+    pub fn update_odometer(&mut self, km: f32) {
+        self.odometer += km;
+    }
+
+    /// Get odometer reading in km
+    pub fn get_odometer(&self) -> f32 {
+        self.odometer
+    }
+
+    /// Restore odometer from persisted data
+    pub fn set_odometer(&mut self, km: f32) {
+        self.odometer = km.max(0.0);
+    }
+
+    /// Process incoming messages (Phase 3: Communication)
+    pub fn process_messages(&mut self, messages: &[CarMessage]) {
+        let units = self.units;
+        for msg in messages {
+            use ClearPolicy::{Resolved, Timeout};
+            use WarningSeverity::{Caution, Critical, Info};
+            match *msg {
+                // Published every cycle while the condition lasts
+                CarMessage::EngineOverheating { temperature } => {
+                    self.raise("Engine", "overheating", Critical, Timeout,
+                               format_args!("Engine overheating: {:.1}{}", units.temperature(temperature),
+                                            units.temperature_unit()));
+                }
+                CarMessage::FuelWarning { level } => {
+                    self.raise("Dashboard", "low_fuel", Caution, Resolved, format_args!("Low fuel: {}%", level));
+                }
+                CarMessage::BrakePressureChange { pressure } if pressure > HIGH_BRAKE_PRESSURE_PERCENT => {
+                    self.raise("Brakes", "high_pressure", Info, Resolved,
+                               format_args!("High brake pressure: {}%", pressure));
+                }
+                CarMessage::BrakePressureChange { .. } => self.resolve("Brakes", "high_pressure"),
+                CarMessage::AbsActivated { .. } => {
+                    self.raise("Brakes", "abs", Info, Resolved, format_args!("ABS active"));
+                }
+                CarMessage::AbsDeactivated => self.resolve("Brakes", "abs"),
+                CarMessage::BrakeFault => {
+                    self.raise("Brakes", "fault", Critical, Resolved, format_args!("Brake fault - ABS unavailable"));
+                }
+                CarMessage::BrakeFaultCleared => self.resolve("Brakes", "fault"),
+                CarMessage::BatteryLow { soc } => {
+                    self.raise("Battery", "low", Caution, Timeout, format_args!("Low battery: {}%", soc));
+                }
+                CarMessage::BatteryOverheating { temperature } => {
+                    self.raise("Battery", "overheating", Critical, Timeout,
+                               format_args!("Battery overheating: {:.1}{}", units.temperature(temperature),
+                                            units.temperature_unit()));
+                }
+                CarMessage::CollisionImminent { distance, .. } => {
+                    self.raise("ObstacleSensor", "collision", Critical, Timeout,
+                               format_args!("Collision imminent: {:.0} {} ahead", units.length(distance),
+                                            units.length_unit()));
+                }
+                CarMessage::HvacLoad { watts } => {
+                    self.accessory_load_w = watts;
+                }
+                CarMessage::PositionUpdate { to_destination, .. } => {
+                    self.to_destination = to_destination;
+                }
+                CarMessage::SpeedUpdate { km_h } if km_h > HIGH_SPEED_KMH => {
+                    self.raise("Dashboard", "high_speed", Caution, Resolved, format_args!("High speed - slow down!"));
+                }
+                CarMessage::SpeedUpdate { .. } => self.resolve("Dashboard", "high_speed"),
+                // Any component, including ones registered at runtime
+                CarMessage::ComponentError { component, ref error } => {
+                    self.raise(component.as_str(), "error", Caution, Timeout,
+                               format_args!("{} error: {}", component, error));
+                }
+                CarMessage::ComponentRestarted { component, attempt } => {
+                    self.resolve(component.as_str(), "error");
+                    self.raise(component.as_str(), "restarted", Info, Timeout,
+                               format_args!("{} restarted ({}x)", component, attempt));
+                }
+                _ => {
+                    // Other messages are logged but don't trigger warnings
+                }
+            }
+        }
+    }
+
+    /// Render the dashboard for a snapshot into the retained frame buffer
+    /// Returns whether the frame differs from the previously rendered one.
+    /// Allocation-free once the buffers have grown to frame size.
+    #[cfg(feature = "render")]
+    pub fn render(&mut self, snapshot: &SystemSnapshot) -> bool {
+        mem::swap(&mut self.frame, &mut self.previous_frame);
+        self.frame.clear();
+
+        self.speed_history.push(snapshot.speed);
+
+        let steering_angle = snapshot.steering_angle;
+        let direction = if steering_angle > 10 { "RIGHT" }
+                        else if steering_angle < -10 { "LEFT" }
+                        else { "CENTER" };
+        let units = self.units;
+        let out = &mut self.frame;
+        // Writing to a String cannot fail
+        let _ = writeln!(out, "┌────────────────────────────────────────────────────────────┐");
+        let _ = writeln!(out, "│                    🚗 CAR DASHBOARD                         │");
+        let _ = writeln!(out, "├────────────────────────────────────────────────────────────┤");
+        let _ = writeln!(out, "│ Speed:        {:>3.0} {:<4}     Fuel:        {:>3}%           │",
+                         units.speed(snapshot.speed as f32), units.speed_unit(), snapshot.fuel_level);
+        let _ = writeln!(out, "│ Engine RPM:   {:>4}         Temp:        {:>4.1}{}        │",
+                         snapshot.rpm, units.temperature(snapshot.temperature), units.temperature_unit());
+        let _ = writeln!(out, "│ Brake Press:  {:>3}%         Steering:    {:>4}° ({:<6}) │",
+                         snapshot.brake_pressure, steering_angle, direction);
+        let _ = writeln!(out, "│ Odometer:     {:>8.1} {}                                        │",
+                         units.distance(snapshot.odometer), units.distance_unit());
+        if let Some(distance) = self.to_destination {
+            let _ = writeln!(out, "│ Destination:  {:>8.2} {} to go                                  │",
+                             units.distance(distance / 1000.0), units.distance_unit());
+        }
+        if let Some(battery) = &snapshot.battery {
+            let _ = writeln!(out, "│ Battery:      {:>5.1}%  {:>5.1}{}  {:>5.1} V  {:<12}        │",
+                             battery.soc, units.temperature(battery.temperature), units.temperature_unit(),
+                             battery.voltage, battery.charging_state);
+        }
+        let _ = write!(out, "│ Speed trend:  ");
+        for &speed in self.speed_history.iter() {
+            let level = speed.min(SPARKLINE_MAX_KMH as u8) as usize * (SPARKLINE_BARS.len() - 1)
+                / SPARKLINE_MAX_KMH as usize;
+            out.push(SPARKLINE_BARS[level]);
+        }
+        let _ = writeln!(out, "{:<1$}│", "", SPARKLINE_LEN + 16 - self.speed_history.len());
+        let _ = writeln!(out, "├────────────────────────────────────────────────────────────┤");
+
+        if !snapshot.warnings.is_empty() {
+            let _ = writeln!(out, "│ ⚠️  WARNINGS:                                                   │");
+            // Snapshot warnings are ordered most severe first
+            for warning in snapshot.warnings.iter().take(self.max_warnings) {
+                let _ = writeln!(out, "│   • {}{:.<54}│", warning, "");
+            }
+            let hidden = snapshot.warnings.len().saturating_sub(self.max_warnings);
+            if hidden > 0 {
+                let _ = writeln!(out, "│   … and {} less severe{:<38}│", hidden, "");
+            }
+        } else {
+            let _ = writeln!(out, "│ ✅ All systems OK                                             │");
+        }
+
+        if !self.faults.is_empty() {
+            let _ = writeln!(out, "├────────────────────────────────────────────────────────────┤");
+            let _ = writeln!(out, "│ 🔒 LATCHED FAULTS (acknowledge to clear):                   │");
+            for fault in &self.faults {
+                let _ = writeln!(out, "│   ■ {}{:.<54}│", fault, "");
+            }
+        }
+
+        let _ = writeln!(out, "└────────────────────────────────────────────────────────────┘");
+
+        self.frame != self.previous_frame
+    }
+
+    /// Print the most recently rendered frame with a single write
+    /// Skipped when the log filter hides the dashboard's INFO output.
+    #[cfg(feature = "render")]
+    pub fn print_frame(&self) {
+        if !console_enabled(LogLevel::Info, "Dashboard") {
+            return;
+        }
+        let _ = io::stdout().lock().write_all(self.frame.as_bytes());
+    }
+
+    /// Render the snapshot as one compact JSON line into the frame buffer
+    /// The snapshot fields plus the latched faults and, with a route, the
+    /// distance to go.
+    #[cfg(feature = "render")]
+    pub fn render_json(&mut self, snapshot: &SystemSnapshot) {
+        let mut json = snapshot.to_json();
+        if let JsonValue::Object(fields) = &mut json {
+            let faults = self.faults.iter().map(|fault| JsonValue::from(fault.to_string())).collect();
+            fields.insert("faults".to_string(), JsonValue::Array(faults));
+            if let Some(distance) = self.to_destination {
+                fields.insert("to_destination_km".to_string(), JsonValue::from(distance / 1000.0));
+            }
+        }
+        self.frame.clear();
+        // Writing to a String cannot fail
+        let _ = writeln!(self.frame, "{}", json);
+    }
+
+    /// Write the most recently rendered frame to `out`, whatever the log
+    /// filter says (machine-readable output)
+    #[cfg(feature = "render")]
+    pub fn write_frame(&self, out: &mut dyn io::Write) -> Result<(), String> {
+        out.write_all(self.frame.as_bytes())
+            .and_then(|_| out.flush())
+            .map_err(|e| format!("Cannot write dashboard output: {}", e))
+    }
+
+    /// Display the shared snapshot of the current tick
+    #[cfg(feature = "render")]
+    pub fn display(&mut self, snapshot: &SystemSnapshot) {
+        self.render(snapshot);
+        self.print_frame();
+    }
+}
+
+impl Default for DashboardComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CarComponent for DashboardComponent {
+    fn name(&self) -> &'static str {
+        "Dashboard"
+    }
+
+    fn initialize(&mut self) -> Result<(), String> {
+        log_info!("Dashboard", "🔧 Dashboard: Initializing component...");
+        self.state = ComponentState::Initializing;
+
+        // Simulate initialization
+        log_info!("Dashboard", "  🔍 Dashboard: Testing display... OK");
+        log_info!("Dashboard", "  🔍 Dashboard: Checking sensors... OK");
+
+        self.state = ComponentState::Online;
+        log_info!("Dashboard", "✅ Dashboard: Initialized (state: {})", self.state);
+        Ok(())
+    }
+
+    fn process(&mut self) -> Result<(), String> {
+        self.elapsed_s += self.dt_s;
+
+        // Fuel consumption grows with speed and accessory load
+        #[cfg(feature = "sim_physics")]
+        if self.combustion_drive {
+            self.fuel_used += FUEL_RATE_PERCENT_PER_KMH_S * self.speed as f32 * self.dt_s;
+            self.fuel_used += FUEL_RATE_PERCENT_PER_KW_S * self.accessory_load_w as f32 / 1000.0 * self.dt_s;
+            let used = self.fuel_used.floor().min(100.0);
+            self.fuel_used -= used;
+            self.fuel_level = self.fuel_level.saturating_sub(used as u8);
+        }
+
+        // Low fuel warning, cleared after refueling
+        if self.fuel_level < LOW_FUEL_PERCENT && self.fuel_level > 0 {
+            let level = self.fuel_level;
+            self.raise("Dashboard", "low_fuel", WarningSeverity::Caution, ClearPolicy::Resolved,
+                       format_args!("Low fuel: {}%", level));
+        } else {
+            self.resolve("Dashboard", "low_fuel");
+        }
+
+        // High speed warning
+        if self.speed > HIGH_SPEED_KMH {
+            self.raise("Dashboard", "high_speed", WarningSeverity::Caution, ClearPolicy::Resolved,
+                       format_args!("High speed - slow down!"));
+        } else {
+            self.resolve("Dashboard", "high_speed");
+        }
+
+        // Event warnings that weren't raised again lately
+        self.warnings.expire(self.elapsed_s);
+
+        Ok(())
+    }
+
+    fn get_state(&self) -> ComponentState {
+        self.state.clone()
+    }
+
+    fn shutdown(&mut self) -> Result<(), String> {
+        log_info!("Dashboard", "🔧 Dashboard: Shutting down...");
+        log_info!("Dashboard", "  📊 Dashboard: Odometer {:.1} {}, fuel {}%", self.units.distance(self.odometer),
+                  self.units.distance_unit(), self.fuel_level);
+        self.state = ComponentState::Offline;
+        log_info!("Dashboard", "✅ Dashboard: Shut down (state: {})", self.state);
+        Ok(())
+    }
+
+    fn health_check(&self) -> HealthStatus {
+        if self.fuel_level > 100 {
+            return HealthStatus::Unhealthy("fuel level above 100%");
+        }
+        if !self.odometer.is_finite() {
+            return HealthStatus::Unhealthy("odometer reading is not a number");
+        }
+        HealthStatus::from_state(&self.state)
+    }
+}
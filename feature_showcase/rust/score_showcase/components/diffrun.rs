@@ -0,0 +1,133 @@
+//! Differential run comparison
+//! Runs the same scenario twice with two configurations and reports, tick
+//! by tick, where the snapshots and safety warnings diverge. Useful to see
+//! what a change of safety limits or calibration actually does.
+//!
+//! Each side is the current configuration with a config file layered on
+//! top ("-" = no changes). Both runs are headless, use the simulated clock
+//! and share one seed, so every difference comes from the configuration.
+
+use std::sync::Arc;
+
+use crate::components::config::SystemConfig;
+use crate::components::harness::{headless_config, TestHarness};
+use crate::components::json::JsonValue;
+use crate::components::rng::SimRng;
+use crate::components::snapshot::SystemSnapshot;
+
+/// Maximum number of differing ticks printed in full
+const MAX_REPORTED_TICKS: usize = 50;
+
+/// Everything observed during one run
+struct RunRecord {
+    snapshots: Vec<Arc<SystemSnapshot>>,
+    /// Safety warning types reported at each tick
+    warnings: Vec<Vec<&'static str>>,
+}
+
+/// Build the configuration of one side of the comparison
+fn side_config(base: &SystemConfig, file: &str) -> Result<SystemConfig, String> {
+    let mut config = base.clone();
+    if file != "-" {
+        config.apply_file(file)?;
+    }
+
+    // Headless and side-effect free regardless of what the layers say
+    let config = headless_config(config);
+    config.validate()?;
+    Ok(config)
+}
+
+fn record_run(config: SystemConfig) -> Result<RunRecord, String> {
+    let ticks = config.ticks;
+    let mut harness = TestHarness::with_config(config)?;
+    harness.start_car()?;
+
+    let mut warnings = Vec::new();
+    for _ in 0..ticks {
+        harness.tick()?;
+        warnings.push(harness.warnings().iter().map(|w| w.type_name()).collect());
+    }
+    Ok(RunRecord {
+        snapshots: harness.snapshots().to_vec(),
+        warnings,
+    })
+}
+
+/// Field-level differences between two snapshots as "field: a → b"
+/// Dashboard warnings are compared separately as sets.
+fn snapshot_diff(a: &SystemSnapshot, b: &SystemSnapshot) -> Vec<String> {
+    let (a, b) = (a.to_json(), b.to_json());
+    let (Some(a), Some(b)) = (a.as_object(), b.as_object()) else {
+        return Vec::new();
+    };
+    a.iter()
+        .filter(|(key, _)| key.as_str() != "tick" && key.as_str() != "warnings")
+        .filter_map(|(key, value)| {
+            let other = b.get(key).unwrap_or(&JsonValue::Null);
+            (value != other).then(|| format!("{}: {} → {}", key, value, other))
+        })
+        .collect()
+}
+
+/// Warnings present on only one side as "+Added" / "-Removed"
+fn warning_diff<S: AsRef<str> + PartialEq>(a: &[S], b: &[S]) -> Vec<String> {
+    let removed = a.iter().filter(|w| !b.contains(w)).map(|w| format!("-{}", w.as_ref()));
+    let added = b.iter().filter(|w| !a.contains(w)).map(|w| format!("+{}", w.as_ref()));
+    removed.chain(added).collect()
+}
+
+/// Run the scenario with both configurations and print the diff report
+/// Returns the number of ticks that differ.
+pub fn run_differential(base: &SystemConfig, file_a: &str, file_b: &str) -> Result<usize, String> {
+    let mut base = base.clone();
+    base.seed = Some(base.seed.unwrap_or_else(SimRng::seed_from_time));
+
+    let config_a = side_config(&base, file_a)?;
+    let config_b = side_config(&base, file_b)?;
+
+    println!("🔀 Differential run: A = {}, B = {}", file_a, file_b);
+    let changes = config_a.diff(&config_b);
+    if changes.is_empty() {
+        println!("   Configurations are identical");
+    }
+    for (key, a, b) in &changes {
+        println!("   {}: {} → {}", key, a, b);
+    }
+
+    let run_a = record_run(config_a)?;
+    let run_b = record_run(config_b)?;
+
+    println!("\n🔀 Per-tick differences (A → B):");
+    let mut differing = 0;
+    for (index, (a, b)) in run_a.snapshots.iter().zip(&run_b.snapshots).enumerate() {
+        let mut diffs = snapshot_diff(a, b);
+        let dashboard = warning_diff(&a.warnings, &b.warnings);
+        if !dashboard.is_empty() {
+            diffs.push(format!("dashboard: {}", dashboard.join(", ")));
+        }
+        let warnings = warning_diff(&run_a.warnings[index], &run_b.warnings[index]);
+        if !warnings.is_empty() {
+            diffs.push(format!("safety: {}", warnings.join(" ")));
+        }
+        if diffs.is_empty() {
+            continue;
+        }
+
+        differing += 1;
+        if differing <= MAX_REPORTED_TICKS {
+            println!("   tick {:>5}: {}", a.tick, diffs.join("; "));
+        }
+    }
+    if differing > MAX_REPORTED_TICKS {
+        println!("   ... {} more differing ticks", differing - MAX_REPORTED_TICKS);
+    }
+
+    println!(
+        "\n📊 {} of {} ticks differ (seed {})",
+        differing,
+        run_a.snapshots.len(),
+        base.seed.unwrap_or_default()
+    );
+    Ok(differing)
+}
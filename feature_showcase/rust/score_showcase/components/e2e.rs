@@ -0,0 +1,296 @@
+//! End-to-end protection of bus messages
+//! Demonstrates S-CORE E2E communication patterns (like AUTOSAR E2E):
+//! - Every routed envelope carries a rolling sequence counter per link
+//!   (sender, receiver, priority) and a CRC over sender, counter and payload
+//! - The receiver verifies both: a CRC mismatch means the message was
+//!   corrupted on the way and it is discarded; a counter jump means
+//!   messages were lost or repeated
+//! - Protection is computed without allocating, so the steady-state
+//!   message path stays allocation-free
+
+use super::messages::{CarMessage, ComponentId, MessagePriority};
+use std::collections::HashMap;
+use std::fmt;
+
+/// E2E protection data of one envelope
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct E2eHeader {
+    /// Rolling sequence counter of the link
+    pub counter: u8,
+    /// CRC-16 over sender, counter and payload
+    pub crc: u16,
+}
+
+impl E2eHeader {
+    /// Protect a message sent by `from` with the link's next `counter`
+    pub fn protect(from: ComponentId, counter: u8, message: &CarMessage) -> Self {
+        Self::protect_payload(from, counter, payload_crc(message))
+    }
+
+    /// Same as `protect`, reusing the payload CRC when one message goes to
+    /// several receivers
+    pub fn protect_payload(from: ComponentId, counter: u8, payload_crc: u16) -> Self {
+        let mut crc = Crc16::new();
+        crc.update(&from.raw().to_le_bytes());
+        crc.update(&[counter]);
+        crc.update(&payload_crc.to_le_bytes());
+        Self { counter, crc: crc.finish() }
+    }
+
+    /// Check the CRC against the received sender and payload
+    pub fn is_intact(&self, from: ComponentId, message: &CarMessage) -> bool {
+        Self::protect(from, self.counter, message).crc == self.crc
+    }
+}
+
+/// CRC-16 of a message payload, over its type name followed by every
+/// field in little-endian byte order (strings length-prefixed)
+pub fn payload_crc(message: &CarMessage) -> u16 {
+    let mut crc = Crc16::new();
+    crc.update_str(message.type_name());
+    match message {
+        CarMessage::EngineStart
+        | CarMessage::EngineStop
+        | CarMessage::BrakeRelease
+        | CarMessage::AbsDeactivated
+        | CarMessage::BrakeFault
+        | CarMessage::BrakeFaultCleared
+        | CarMessage::SteeringCenter => {}
+        CarMessage::EngineOverheating { temperature }
+        | CarMessage::BatteryOverheating { temperature } => crc.update_f32(*temperature),
+        CarMessage::EngineRpmChange { rpm } => crc.update(&rpm.to_le_bytes()),
+        CarMessage::BrakeApply { pressure } | CarMessage::BrakePressureChange { pressure } => {
+            crc.update(&[*pressure])
+        }
+        CarMessage::AbsActivated { pressure, km_h } => crc.update(&[*pressure, *km_h]),
+        CarMessage::SteeringTurn { angle } => crc.update(&angle.to_le_bytes()),
+        CarMessage::SpeedUpdate { km_h } => crc.update(&[*km_h]),
+        CarMessage::FuelWarning { level } => crc.update(&[*level]),
+        CarMessage::BatteryLow { soc } => crc.update(&[*soc]),
+        CarMessage::HvacLoad { watts } => crc.update(&watts.to_le_bytes()),
+        CarMessage::CollisionImminent { distance, ttc } => {
+            crc.update_f32(*distance);
+            crc.update_f32(*ttc);
+        }
+        CarMessage::PositionUpdate { x, y, heading, to_destination } => {
+            crc.update_f32(*x);
+            crc.update_f32(*y);
+            crc.update_f32(*heading);
+            match to_destination {
+                Some(distance) => {
+                    crc.update(&[1]);
+                    crc.update_f32(*distance);
+                }
+                None => crc.update(&[0]),
+            }
+        }
+        CarMessage::ComponentError { component, error } => {
+            crc.update(&component.raw().to_le_bytes());
+            crc.update_str(error);
+        }
+        CarMessage::ComponentRestarted { component, attempt } => {
+            crc.update(&component.raw().to_le_bytes());
+            crc.update(&attempt.to_le_bytes());
+        }
+        CarMessage::ConfigChanged { key, value } => {
+            crc.update_str(key);
+            crc.update_str(value);
+        }
+        CarMessage::SelectCalibration { name } => crc.update_str(name),
+        CarMessage::QuerySignal { signal } => crc.update_str(signal),
+        CarMessage::SignalReport { signal, value } => {
+            crc.update_str(signal);
+            crc.update(&value.to_bits().to_le_bytes());
+        }
+    }
+    crc.finish()
+}
+
+/// CRC-16/CCITT-FALSE (poly 0x1021, init 0xFFFF)
+struct Crc16(u16);
+
+impl Crc16 {
+    fn new() -> Self {
+        Self(0xFFFF)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= (*byte as u16) << 8;
+            for _ in 0..8 {
+                self.0 = if self.0 & 0x8000 != 0 {
+                    (self.0 << 1) ^ 0x1021
+                } else {
+                    self.0 << 1
+                };
+            }
+        }
+    }
+
+    fn update_f32(&mut self, value: f32) {
+        self.update(&value.to_bits().to_le_bytes());
+    }
+
+    /// Length-prefixed, so adjacent strings can't trade bytes
+    fn update_str(&mut self, s: &str) {
+        self.update(&(s.len() as u32).to_le_bytes());
+        self.update(s.as_bytes());
+    }
+
+    fn finish(&self) -> u16 {
+        self.0
+    }
+}
+
+/// What the receiver found wrong with an envelope
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum E2eError {
+    /// CRC mismatch; the message was discarded
+    Corrupted,
+    /// The counter didn't follow the previous one on this link
+    SequenceGap { expected: u8, received: u8 },
+}
+
+impl fmt::Display for E2eError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            E2eError::Corrupted => write!(f, "E2E CRC mismatch, message discarded"),
+            E2eError::SequenceGap { expected, received } => write!(
+                f,
+                "E2E sequence gap, expected counter {} but got {} ({} lost)",
+                expected,
+                received,
+                received.wrapping_sub(*expected)
+            ),
+        }
+    }
+}
+
+/// Sequence counters of the links ending or starting at one side
+/// A link is (peer, priority): queues of different priorities are
+/// delivered out of publish order, so each keeps its own sequence.
+#[derive(Debug, Default)]
+pub struct E2eCounters {
+    counters: HashMap<(ComponentId, ComponentId, MessagePriority), u8>,
+}
+
+impl E2eCounters {
+    /// Counter for the next message on a link (sender side)
+    pub fn next(&mut self, from: ComponentId, to: ComponentId, priority: MessagePriority) -> u8 {
+        let counter = self.counters.entry((from, to, priority)).or_insert(u8::MAX);
+        *counter = counter.wrapping_add(1);
+        *counter
+    }
+
+    /// Forget the sequence of every link ending at `to`
+    /// The next message on each of them starts a new sequence.
+    pub fn restart_links_to(&mut self, to: ComponentId) {
+        self.counters.retain(|(_, receiver, _), _| *receiver != to);
+    }
+
+    /// Verify a received envelope's protection (receiver side)
+    /// The first message of a link sets its sequence; a corrupted one
+    /// still uses up its counter, so it isn't reported as a gap too.
+    pub fn check(&mut self, from: ComponentId, to: ComponentId, message: &CarMessage,
+                 header: E2eHeader) -> Result<(), E2eError> {
+        let key = (from, to, message.priority());
+        let last = self.counters.get(&key).copied();
+        if !header.is_intact(from, message) {
+            if let Some(last) = last {
+                self.counters.insert(key, last.wrapping_add(1));
+            }
+            return Err(E2eError::Corrupted);
+        }
+        self.counters.insert(key, header.counter);
+        match last.map(|last| last.wrapping_add(1)) {
+            Some(expected) if expected != header.counter => {
+                Err(E2eError::SequenceGap { expected, received: header.counter })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FROM: ComponentId = ComponentId::ENGINE;
+    const TO: ComponentId = ComponentId::DASHBOARD;
+
+    fn send(sender: &mut E2eCounters, message: &CarMessage) -> E2eHeader {
+        let counter = sender.next(FROM, TO, message.priority());
+        E2eHeader::protect(FROM, counter, message)
+    }
+
+    #[test]
+    fn intact_messages_in_sequence_pass() {
+        let (mut sender, mut receiver) = (E2eCounters::default(), E2eCounters::default());
+        for rpm in [800, 900, 1000] {
+            let message = CarMessage::EngineRpmChange { rpm };
+            let header = send(&mut sender, &message);
+            assert_eq!(receiver.check(FROM, TO, &message, header), Ok(()));
+        }
+    }
+
+    #[test]
+    fn corrupted_crc_is_discarded_without_a_gap() {
+        let (mut sender, mut receiver) = (E2eCounters::default(), E2eCounters::default());
+        let message = CarMessage::EngineRpmChange { rpm: 800 };
+        let header = send(&mut sender, &message);
+        receiver.check(FROM, TO, &message, header).unwrap();
+
+        let mut header = send(&mut sender, &message);
+        header.crc ^= 0x0100;
+        assert_eq!(receiver.check(FROM, TO, &message, header), Err(E2eError::Corrupted));
+
+        let header = send(&mut sender, &message);
+        assert_eq!(receiver.check(FROM, TO, &message, header), Ok(()));
+    }
+
+    #[test]
+    fn changed_payload_fails_the_crc() {
+        let message = CarMessage::EngineRpmChange { rpm: 800 };
+        let header = E2eHeader::protect(FROM, 0, &message);
+        assert!(header.is_intact(FROM, &message));
+        assert!(!header.is_intact(FROM, &CarMessage::EngineRpmChange { rpm: 801 }));
+        assert!(!header.is_intact(ComponentId::BRAKES, &message));
+
+        let split = |key: &str, value: &str| {
+            payload_crc(&CarMessage::ConfigChanged { key: key.to_string(), value: value.to_string() })
+        };
+        assert_ne!(split("ab", "c"), split("a", "bc"));
+    }
+
+    #[test]
+    fn skipped_counter_is_a_sequence_gap() {
+        let (mut sender, mut receiver) = (E2eCounters::default(), E2eCounters::default());
+        let message = CarMessage::SpeedUpdate { km_h: 50 };
+        let header = send(&mut sender, &message);
+        receiver.check(FROM, TO, &message, header).unwrap();
+
+        let _lost = send(&mut sender, &message);
+        let _lost = send(&mut sender, &message);
+        let header = send(&mut sender, &message);
+        assert_eq!(
+            receiver.check(FROM, TO, &message, header),
+            Err(E2eError::SequenceGap { expected: 1, received: 3 })
+        );
+
+        let header = send(&mut sender, &message);
+        assert_eq!(receiver.check(FROM, TO, &message, header), Ok(()));
+    }
+
+    #[test]
+    fn repeated_counter_is_a_sequence_gap() {
+        let mut receiver = E2eCounters::default();
+        let message = CarMessage::SpeedUpdate { km_h: 50 };
+        let header = E2eHeader::protect(FROM, 7, &message);
+        receiver.check(FROM, TO, &message, header).unwrap();
+
+        assert_eq!(
+            receiver.check(FROM, TO, &message, header),
+            Err(E2eError::SequenceGap { expected: 8, received: 7 })
+        );
+    }
+}
@@ -0,0 +1,366 @@
+//! Engine component - manages engine state, RPM, and temperature
+//! This demonstrates S-CORE patterns:
+//! - Component state management
+//! - Structured logging
+//! - Lifecycle management (initialize -> process -> shutdown)
+//! - Message publishing (Phase 3)
+//! - State machine with valid transitions (Phase 4)
+//! - Optional redundant temperature sensors (see `Voter`)
+//! - Accessory loads (HVAC compressor) arrive as bus messages and raise
+//!   the idle RPM
+//! - RPM follows the throttle with physics dynamics
+
+use crate::components::{CarComponent, ComponentState, CarMessage, HealthStatus};
+use crate::components::state_machine::{EngineStateMachine, StateMachine, TransitionMachine};
+use crate::components::rng::SimRng;
+use crate::components::voter::RedundantSignal;
+use crate::components::event_loop::DEFAULT_TIME_STEP_S;
+use crate::log_info;
+
+/// Temperature rise while running, in °C per second
+#[cfg(feature = "sim_physics")]
+const WARMUP_RATE_C_PER_S: f32 = 0.1;
+/// Temperature the engine settles at
+#[cfg(feature = "sim_physics")]
+const OPERATING_TEMPERATURE_C: f32 = 90.0;
+/// Accessory load that raises the idle speed by one RPM, in W
+#[cfg(feature = "sim_physics")]
+const ACCESSORY_W_PER_RPM: u16 = 20;
+/// RPM above idle per % of throttle
+#[cfg(feature = "sim_physics")]
+const RPM_PER_THROTTLE_PERCENT: u32 = 30;
+
+/// Engine-specific states (using state machine)
+pub type EngineState = EngineStateMachine;
+
+/// Engine component - manages the car's engine
+pub struct EngineComponent {
+    state: ComponentState,
+    engine_state: TransitionMachine<EngineState>,
+    running: bool,
+    rpm: u32,
+    temperature: f32,
+    /// Temperature as reported by the (noisy) sensor
+    measured_temperature: f32,
+    /// Sensor noise amplitude in °C
+    sensor_noise: f32,
+    /// Redundant temperature sensors (none unless configured)
+    temperature_channels: RedundantSignal,
+    rng: SimRng,
+    /// Simulated seconds per process() call
+    dt_s: f32,
+    start_count: u64,
+    /// Power drawn by accessories, as last reported on the bus
+    accessory_load_w: u16,
+    /// Throttle position in % (physics dynamics only)
+    throttle: u8,
+}
+
+impl EngineComponent {
+    /// Create a new engine component
+    pub fn new() -> Self {
+        Self {
+            state: ComponentState::Offline,
+            engine_state: EngineState::machine(),
+            running: false,
+            rpm: 0,
+            temperature: 20.0, // Ambient temperature
+            measured_temperature: 20.0,
+            sensor_noise: 0.0,
+            temperature_channels: RedundantSignal::default(),
+            rng: SimRng::new(0),
+            dt_s: DEFAULT_TIME_STEP_S,
+            start_count: 0,
+            accessory_load_w: 0,
+            throttle: 0,
+        }
+    }
+
+    /// Start the engine (with state machine validation)
+    pub fn start(&mut self) -> Result<(), String> {
+        // Phase 4: Validate state transition using state machine
+        if !self.engine_state.can_transition_to(&EngineState::Starting) {
+            return Err(format!(
+                "Cannot start engine: invalid transition from {} to STARTING",
+                self.get_engine_state()
+            ));
+        }
+
+        log_info!("Engine", "  🔑 Engine: {} → STARTING", self.get_engine_state());
+        self.state = ComponentState::Initializing;
+        self.engine_state.set_state_because(EngineState::Starting, "start requested");
+
+        // Simulate startup delay
+        self.rpm = 500;
+
+        // Complete transition to Running
+        if !self.engine_state.can_transition_to(&EngineState::Running) {
+            return Err(format!(
+                "Cannot complete startup: invalid transition from {} to RUNNING",
+                self.get_engine_state()
+            ));
+        }
+
+        log_info!("Engine", "  🔑 Engine: STARTING → RUNNING");
+        self.state = ComponentState::Online;
+        self.running = true;
+        self.engine_state.set_state_because(EngineState::Running, "startup complete");
+        self.rpm = 800; // Idle RPM
+        self.start_count += 1;
+
+        log_info!("Engine", "  ✅ Engine: Started successfully (state: {})", self.get_engine_state());
+        Ok(())
+    }
+
+    /// Stop the engine (with state machine validation)
+    pub fn stop(&mut self) -> Result<(), String> {
+        // Phase 4: Validate state transition using state machine
+        if !self.engine_state.can_transition_to(&EngineState::Stopping) {
+            return Err(format!(
+                "Cannot stop engine: invalid transition from {} to STOPPING",
+                self.get_engine_state()
+            ));
+        }
+
+        log_info!("Engine", "  🔑 Engine: {} → STOPPING", self.get_engine_state());
+        self.engine_state.set_state_because(EngineState::Stopping, "stop requested");
+        self.running = false;
+        self.rpm = 0;
+
+        // Complete transition to Off
+        if !self.engine_state.can_transition_to(&EngineState::Off) {
+            return Err(format!(
+                "Cannot complete shutdown: invalid transition from {} to OFF",
+                self.get_engine_state()
+            ));
+        }
+
+        log_info!("Engine", "  🔑 Engine: STOPPING → OFF");
+        self.engine_state.set_state_because(EngineState::Off, "shutdown complete");
+        self.state = ComponentState::Offline;
+
+        log_info!("Engine", "  ✅ Engine: Stopped (state: {})", self.get_engine_state());
+        Ok(())
+    }
+
+    /// Get current RPM
+    pub fn get_rpm(&self) -> u32 {
+        self.rpm
+    }
+
+    /// Get current temperature (as measured by the sensor)
+    pub fn get_temperature(&self) -> f32 {
+        self.measured_temperature
+    }
+
+    /// Use a seeded random stream for RPM fluctuation and sensor noise
+    pub fn set_rng(&mut self, rng: SimRng) {
+        self.rng = rng;
+    }
+
+    /// Set the simulated time covered by each process() call
+    pub fn set_time_step(&mut self, dt_s: f32) {
+        self.dt_s = dt_s;
+    }
+
+    /// Set the temperature sensor noise amplitude in °C
+    pub fn set_sensor_noise(&mut self, amplitude: f32) {
+        self.sensor_noise = amplitude.max(0.0);
+    }
+
+    /// Measure the temperature with several sensors (fewer than 2 = one sensor)
+    pub fn set_temperature_channels(&mut self, channels: usize) {
+        self.temperature_channels = RedundantSignal::new(if channels < 2 { 0 } else { channels });
+    }
+
+    /// Readings of the redundant temperature sensors (empty without redundancy)
+    pub fn temperature_readings(&self) -> &[f32] {
+        self.temperature_channels.readings()
+    }
+
+    /// Give one temperature sensor a fixed error in °C (fault injection)
+    pub fn set_temperature_channel_offset(&mut self, channel: usize, offset: f32) -> Result<(), String> {
+        self.temperature_channels.set_offset(channel, offset)
+    }
+
+    /// Override the current RPM (tooling, tests)
+    pub fn set_rpm(&mut self, rpm: u32) {
+        self.rpm = rpm;
+    }
+
+    /// Override the engine temperature, sensor reading included (tooling, tests)
+    pub fn set_temperature(&mut self, celsius: f32) {
+        self.temperature = celsius;
+        self.measured_temperature = celsius;
+    }
+
+    /// Raise the engine temperature instantly (fault injection)
+    pub fn inject_temperature_spike(&mut self, delta: f32) {
+        self.temperature += delta;
+        self.measured_temperature = self.temperature;
+    }
+
+    /// Check if engine is running
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Total number of successful engine starts (service counter)
+    pub fn get_start_count(&self) -> u64 {
+        self.start_count
+    }
+
+    /// Restore the service counter from persisted data
+    pub fn set_start_count(&mut self, count: u64) {
+        self.start_count = count;
+    }
+
+    /// Power drawn by accessories in W
+    pub fn get_accessory_load(&self) -> u16 {
+        self.accessory_load_w
+    }
+
+    /// Set the throttle position in %
+    pub fn set_throttle(&mut self, percent: u8) {
+        self.throttle = percent.min(100);
+    }
+
+    pub fn get_throttle(&self) -> u8 {
+        self.throttle
+    }
+
+    /// Process incoming messages (accessory loads)
+    pub fn process_messages(&mut self, messages: &[CarMessage]) {
+        for msg in messages {
+            if let CarMessage::HvacLoad { watts } = *msg {
+                self.accessory_load_w = watts;
+            }
+        }
+    }
+
+    /// Engine state machine with its transition history
+    pub fn state_machine(&self) -> &TransitionMachine<EngineState> {
+        &self.engine_state
+    }
+
+    /// Stamp the following state transitions with this tick
+    pub fn set_tick(&mut self, tick: u64) {
+        self.engine_state.set_tick(tick);
+    }
+
+    /// Get current engine state (Phase 4: State machine)
+    pub fn get_engine_state(&self) -> &EngineState {
+        self.engine_state.current_state()
+    }
+
+    /// Get messages to publish (Phase 3: Communication)
+    /// Returns messages the engine wants to send to other components
+    pub fn get_messages(&self) -> Vec<CarMessage> {
+        let mut messages = Vec::new();
+        self.get_messages_into(&mut messages);
+        messages
+    }
+
+    /// Append messages to publish to a reused buffer (no allocation once
+    /// the buffer has grown)
+    pub fn get_messages_into(&self, messages: &mut Vec<CarMessage>) {
+        // Check for overheating (lowered to 21.0 so it appears during demo)
+        if self.running && self.measured_temperature > 21.0 {
+            messages.push(CarMessage::EngineOverheating {
+                temperature: self.measured_temperature,
+            });
+        }
+
+        // Report RPM changes
+        if self.running {
+            messages.push(CarMessage::EngineRpmChange { rpm: self.rpm });
+        }
+    }
+
+    /// Plant model: RPM fluctuation, warm-up and sensor noise
+    #[cfg(feature = "sim_physics")]
+    fn simulate_plant(&mut self) {
+        // Simulate RPM fluctuation when running
+        if self.running {
+            // Seeded fluctuation so runs are reproducible
+            let fluctuation = self.rng.below(50);
+            self.rpm = 800
+                + u32::from(self.accessory_load_w / ACCESSORY_W_PER_RPM)
+                + u32::from(self.throttle) * RPM_PER_THROTTLE_PERCENT
+                + fluctuation;
+
+            // Slowly warm up towards operating temperature
+            if self.temperature < OPERATING_TEMPERATURE_C {
+                self.temperature = (self.temperature + WARMUP_RATE_C_PER_S * self.dt_s)
+                    .min(OPERATING_TEMPERATURE_C);
+            }
+        }
+
+        // Sensor reading with optional noise
+        self.measured_temperature = self.temperature + self.rng.noise(self.sensor_noise);
+        let (rng, noise) = (&mut self.rng, self.sensor_noise);
+        self.temperature_channels.measure(self.temperature, || rng.noise(noise));
+    }
+}
+
+impl Default for EngineComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CarComponent for EngineComponent {
+    fn name(&self) -> &'static str {
+        "Engine"
+    }
+
+    fn initialize(&mut self) -> Result<(), String> {
+        log_info!("Engine", "🔧 Engine: Initializing component...");
+        self.state = ComponentState::Initializing;
+
+        // Simulate initialization checks
+        log_info!("Engine", "  🔍 Engine: Checking oil level... OK");
+        log_info!("Engine", "  🔍 Engine: Checking fuel pressure... OK");
+        log_info!("Engine", "  🔍 Engine: Checking ignition system... OK");
+
+        self.state = ComponentState::Online;
+        log_info!("Engine", "✅ Engine: Initialized (state: {})", self.state);
+        Ok(())
+    }
+
+    fn process(&mut self) -> Result<(), String> {
+        #[cfg(feature = "sim_physics")]
+        self.simulate_plant();
+        #[cfg(not(feature = "sim_physics"))]
+        {
+            self.measured_temperature = self.temperature;
+            self.temperature_channels.measure(self.temperature, || 0.0);
+        }
+        Ok(())
+    }
+
+    fn get_state(&self) -> ComponentState {
+        self.state.clone()
+    }
+
+    fn shutdown(&mut self) -> Result<(), String> {
+        log_info!("Engine", "🔧 Engine: Shutting down...");
+        // The shutdown workflow may have stopped the engine already
+        if self.engine_state.can_transition_to(&EngineState::Stopping) {
+            self.stop()?;
+        }
+        self.state = ComponentState::Offline;
+        log_info!("Engine", "✅ Engine: Shut down (state: {})", self.get_engine_state());
+        Ok(())
+    }
+
+    fn health_check(&self) -> HealthStatus {
+        if !self.temperature.is_finite() {
+            return HealthStatus::Unhealthy("temperature reading is not a number");
+        }
+        if self.running != (*self.get_engine_state() == EngineState::Running) {
+            return HealthStatus::Unhealthy("running flag contradicts the state machine");
+        }
+        HealthStatus::from_state(&self.state)
+    }
+}
@@ -0,0 +1,1434 @@
+//! Event loop for continuous real-time processing
+//! This demonstrates S-CORE's event-driven architecture pattern
+//!
+//! Ticks start on a fixed schedule. Waiting for the next start uses a
+//! hybrid sleep: the OS sleep wakes up `spin_us` early and the rest is
+//! busy-waited, trading CPU time for tick-start jitter in the tens of
+//! microseconds instead of the scheduler's millisecond granularity.
+//!
+//! After an overrun the schedule restarts from the moment the tick ended.
+//! With `OverrunRecovery::CatchUp` the loop keeps a fixed timestep
+//! instead (the game-loop accumulator): late ticks run back-to-back until
+//! simulated time has caught up with wall time, bounded so a long stall
+//! drops ticks rather than bursting through all of them.
+//!
+//! Tasks can run at their own rates (`TaskSchedule`): the loop ticks at
+//! the greatest common divisor of all periods and `run_scheduled`
+//! dispatches each task only on the ticks its period falls on.
+//!
+//! A shutdown flag (`shutdown_handle`, set by `install_interrupt_handler`
+//! on Ctrl+C) stops the loop between ticks: the running tick finishes and
+//! no new one starts.
+//!
+//! `time_scale` fast-forwards or slows down the simulation. By default
+//! it stretches the simulated time step of every tick; with
+//! `TimeScaleMode::Sleep` it shortens or lengthens the wall-clock period
+//! instead, so a 10x run computes exactly the ticks of a 1x run.
+//!
+//! A `LoopControl` handle pauses the loop between ticks, resumes it or
+//! lets single ticks through while paused, from any thread (e.g. the
+//! stdin console of `spawn_console`). After a pause the schedule restarts
+//! from the moment the loop continues.
+//!
+//! All loop timing goes through a `TimeSource`. With a simulated clock
+//! (`ClockSource::Simulated`) the loop never sleeps: waiting for the next
+//! tick jumps the clock to it, and a tick takes exactly as long as its
+//! callback advances the clock (`SimClock::advance`), so timing behaviour
+//! like overruns can be tested deterministically.
+//!
+//! Every tick's execution time is measured against its period: the
+//! `TimingReport` keeps worst-case execution time, execution jitter and
+//! deadline misses, and `deadline_miss_handle` lets the application
+//! forward misses as timing faults (e.g. to the `SafetyMonitor`).
+//!
+//! Instead of one closure, independent handlers can be registered per
+//! tick phase with a name and priority (`TickHandlers`, `run_handlers`).
+//!
+//! With the `async_loop` feature, `AsyncEventLoop` runs the same schedule
+//! on a Tokio timer instead of a sleeping thread.
+
+use std::fmt;
+use std::io::{self, BufRead};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use std::thread;
+
+use crate::components::tick_handlers::TickHandlers;
+use crate::{log_error, log_info, log_warn};
+
+/// Simulated seconds per tick at the default tick rate and time scale
+pub const DEFAULT_TIME_STEP_S: f32 = 0.5;
+
+/// Where tick timing comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockSource {
+    /// Ticks start on a wall-clock schedule
+    WallClock,
+    /// Ticks run back-to-back on a simulated clock (tests and benchmarks)
+    Simulated,
+}
+
+impl ClockSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClockSource::WallClock => "wall",
+            ClockSource::Simulated => "simulated",
+        }
+    }
+}
+
+/// What `time_scale` speeds up or slows down
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeScaleMode {
+    /// Each tick covers `time_scale` times its period of simulated time;
+    /// ticks keep their wall-clock period
+    Step,
+    /// Ticks come `time_scale` times as often; each still covers its
+    /// nominal period, so dynamics match an unscaled run tick for tick
+    Sleep,
+}
+
+impl TimeScaleMode {
+    pub fn parse(s: &str) -> Result<TimeScaleMode, String> {
+        match s.trim() {
+            "step" => Ok(TimeScaleMode::Step),
+            "sleep" => Ok(TimeScaleMode::Sleep),
+            other => Err(format!("Invalid time scale mode: {} (step|sleep)", other)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeScaleMode::Step => "step",
+            TimeScaleMode::Sleep => "sleep",
+        }
+    }
+}
+
+/// How the components of the car are run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionMode {
+    /// One loop processes every component in turn
+    Single,
+    /// The core components run as actors on their own threads, supervised
+    /// by the car system (see `ActorRuntime`)
+    Threaded,
+}
+
+impl ExecutionMode {
+    pub fn parse(s: &str) -> Result<ExecutionMode, String> {
+        match s.trim() {
+            "single" => Ok(ExecutionMode::Single),
+            "threaded" => Ok(ExecutionMode::Threaded),
+            other => Err(format!("Invalid execution mode: {} (single|threaded)", other)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExecutionMode::Single => "single",
+            ExecutionMode::Threaded => "threaded",
+        }
+    }
+}
+
+/// Wall-clock period of a `period_ms` tick under a time scale
+fn scaled_period(period_ms: u64, time_scale: f32, mode: TimeScaleMode) -> Duration {
+    match mode {
+        TimeScaleMode::Step => Duration::from_millis(period_ms),
+        TimeScaleMode::Sleep => Duration::from_secs_f64(period_ms as f64 / 1000.0 / time_scale as f64),
+    }
+}
+
+/// What the loop does when a tick fails (callback error or overrun)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Log the error and keep running
+    Continue,
+    /// Stop at the first error
+    Stop,
+    /// Stop after this many failed ticks in a row
+    StopAfter(u32),
+}
+
+impl ErrorPolicy {
+    /// Parse "continue", "stop" or "stop-after:N"
+    pub fn parse(s: &str) -> Result<ErrorPolicy, String> {
+        match s.trim() {
+            "continue" => Ok(ErrorPolicy::Continue),
+            "stop" => Ok(ErrorPolicy::Stop),
+            other => other
+                .strip_prefix("stop-after:")
+                .and_then(|n| n.parse().ok())
+                .map(ErrorPolicy::StopAfter)
+                .ok_or_else(|| format!("Invalid error policy: {} (continue|stop|stop-after:N)", other)),
+        }
+    }
+}
+
+impl fmt::Display for ErrorPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorPolicy::Continue => write!(f, "continue"),
+            ErrorPolicy::Stop => write!(f, "stop"),
+            ErrorPolicy::StopAfter(n) => write!(f, "stop-after:{}", n),
+        }
+    }
+}
+
+/// How the schedule recovers when a tick overran its period
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverrunRecovery {
+    /// Restart the schedule from now; the loop falls behind wall time
+    Restart,
+    /// Keep the fixed timestep: run late ticks back-to-back until the
+    /// loop is back on schedule, at most this many ticks behind (further
+    /// behind, the ticks in excess are dropped)
+    CatchUp(u32),
+}
+
+impl OverrunRecovery {
+    /// Parse "restart" or "catch-up:N"
+    pub fn parse(s: &str) -> Result<OverrunRecovery, String> {
+        match s.trim() {
+            "restart" => Ok(OverrunRecovery::Restart),
+            other => other
+                .strip_prefix("catch-up:")
+                .and_then(|n| n.parse().ok())
+                .map(OverrunRecovery::CatchUp)
+                .ok_or_else(|| format!("Invalid overrun recovery: {} (restart|catch-up:N)", other)),
+        }
+    }
+}
+
+impl fmt::Display for OverrunRecovery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverrunRecovery::Restart => write!(f, "restart"),
+            OverrunRecovery::CatchUp(n) => write!(f, "catch-up:{}", n),
+        }
+    }
+}
+
+/// Invalid event loop setting
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventLoopConfigError {
+    ZeroTickRate,
+    TimeScaleOutOfRange(f32),
+    /// Busy-wait not shorter than the tick period
+    SpinTooLong { spin_us: u64, period_us: u64 },
+    ZeroRenderInterval,
+    /// `StopAfter(0)` would stop before any error
+    ZeroErrorLimit,
+    /// A scheduled task with period 0
+    ZeroTaskPeriod(String),
+    /// `CatchUp(0)` could never catch up
+    ZeroCatchUp,
+}
+
+impl fmt::Display for EventLoopConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventLoopConfigError::ZeroTickRate => {
+                write!(f, "event_loop.tick_rate_ms must be greater than 0")
+            }
+            EventLoopConfigError::TimeScaleOutOfRange(scale) => {
+                write!(f, "event_loop.time_scale must be between 0 and 1000 (got {})", scale)
+            }
+            EventLoopConfigError::SpinTooLong { spin_us, period_us } => write!(
+                f,
+                "event_loop.spin_us must be shorter than the tick period ({} µs >= {} µs)",
+                spin_us, period_us
+            ),
+            EventLoopConfigError::ZeroRenderInterval => {
+                write!(f, "event_loop.render_every must be greater than 0")
+            }
+            EventLoopConfigError::ZeroErrorLimit => {
+                write!(f, "event_loop.error_policy stop-after needs at least 1 error")
+            }
+            EventLoopConfigError::ZeroTaskPeriod(task) => {
+                write!(f, "event_loop.schedule: task '{}' needs a period greater than 0", task)
+            }
+            EventLoopConfigError::ZeroCatchUp => {
+                write!(f, "event_loop.overrun_recovery catch-up needs at least 1 tick")
+            }
+        }
+    }
+}
+
+/// A task running at its own period
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledTask {
+    pub name: String,
+    pub period_ms: u64,
+}
+
+/// Periods of the tasks of a multi-rate loop
+/// Written as "name:period_ms" pairs, e.g. "safety:500,dashboard:1000".
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskSchedule {
+    tasks: Vec<ScheduledTask>,
+}
+
+impl TaskSchedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse "name:period_ms" pairs separated by commas ("" = no tasks)
+    pub fn parse(s: &str) -> Result<TaskSchedule, String> {
+        let mut schedule = TaskSchedule::new();
+        for entry in s.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (name, period) = entry
+                .split_once(':')
+                .ok_or_else(|| format!("Invalid task '{}' (expected name:period_ms)", entry))?;
+            let period_ms = period
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid period for task '{}': {}", name.trim(), period.trim()))?;
+            schedule.set(name.trim(), period_ms);
+        }
+        Ok(schedule)
+    }
+
+    /// Register a task, or change its period
+    pub fn set(&mut self, name: &str, period_ms: u64) {
+        match self.tasks.iter_mut().find(|task| task.name == name) {
+            Some(task) => task.period_ms = period_ms,
+            None => self.tasks.push(ScheduledTask {
+                name: name.to_string(),
+                period_ms,
+            }),
+        }
+    }
+
+    pub fn tasks(&self) -> &[ScheduledTask] {
+        &self.tasks
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    pub fn period_ms(&self, name: &str) -> Option<u64> {
+        self.tasks.iter().find(|task| task.name == name).map(|task| task.period_ms)
+    }
+
+    /// Loop period that lands on every task's period: the greatest common
+    /// divisor of `default_ms` and all task periods
+    pub fn base_period_ms(&self, default_ms: u64) -> u64 {
+        self.tasks.iter().fold(default_ms, |base, task| gcd(base, task.period_ms)).max(1)
+    }
+
+    /// Ticks between two runs of a task on a loop ticking every `base_ms`
+    /// (None = not scheduled)
+    pub fn every(&self, name: &str, base_ms: u64) -> Option<u64> {
+        self.period_ms(name).map(|period_ms| (period_ms / base_ms.max(1)).max(1))
+    }
+
+    /// Tasks due at `tick` of a loop ticking every `base_ms`, in
+    /// registration order
+    pub fn due(&self, tick: u64, base_ms: u64) -> impl Iterator<Item = &str> {
+        let base_ms = base_ms.max(1);
+        self.tasks
+            .iter()
+            .filter(move |task| tick.is_multiple_of((task.period_ms / base_ms).max(1)))
+            .map(|task| task.name.as_str())
+    }
+}
+
+impl fmt::Display for TaskSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, task) in self.tasks.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{}", task.name, task.period_ms)?;
+        }
+        Ok(())
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Event loop configuration
+#[derive(Debug, Clone)]
+pub struct EventLoopConfig {
+    /// Period in milliseconds of everything not in `schedule`
+    pub tick_rate_ms: u64,
+    /// Tasks with their own period; the loop ticks at the greatest common
+    /// divisor of all periods
+    pub schedule: TaskSchedule,
+    /// Whether to print timing info
+    pub verbose_timing: bool,
+    /// Wall-clock schedule or back-to-back simulated ticks
+    pub clock: ClockSource,
+    /// Simulated seconds per wall-clock second (10.0 = ten times faster)
+    pub time_scale: f32,
+    /// Whether `time_scale` stretches the simulated step or the sleep
+    pub time_scale_mode: TimeScaleMode,
+    /// Run independent components of a tick on parallel threads
+    pub parallel_components: bool,
+    /// Single loop or one thread per core component
+    pub execution: ExecutionMode,
+    /// Busy-wait the last microseconds before a tick (0 = sleep only)
+    pub spin_us: u64,
+    /// Longest tolerated overrun of the tick period before it counts as a
+    /// failed tick (None = overruns are only reported)
+    pub max_overrun_ms: Option<u64>,
+    /// Reaction to failed ticks
+    pub error_policy: ErrorPolicy,
+    /// Restart the schedule or catch up after an overrun
+    pub overrun_recovery: OverrunRecovery,
+    /// Render the dashboard every N ticks
+    pub render_every: u64,
+    /// Report deadline misses to the safety monitor as timing faults
+    pub deadline_faults: bool,
+    /// Read pause/resume/step commands from stdin while running
+    pub console: bool,
+}
+
+impl EventLoopConfig {
+    /// Start building a configuration from the defaults
+    pub fn builder() -> EventLoopConfigBuilder {
+        EventLoopConfigBuilder {
+            config: Self::default(),
+        }
+    }
+
+    /// Period of the loop itself, in milliseconds
+    pub fn base_period_ms(&self) -> u64 {
+        self.schedule.base_period_ms(self.tick_rate_ms)
+    }
+
+    /// Simulated time covered by one tick, in seconds
+    pub fn time_step_s(&self) -> f32 {
+        match self.time_scale_mode {
+            TimeScaleMode::Step => self.base_period_ms() as f32 / 1000.0 * self.time_scale,
+            TimeScaleMode::Sleep => self.base_period_ms() as f32 / 1000.0,
+        }
+    }
+
+    /// Wall-clock time between two tick starts
+    pub fn wall_period(&self) -> Duration {
+        scaled_period(self.base_period_ms(), self.time_scale, self.time_scale_mode)
+    }
+
+    /// Ticks between two runs of a task; None if it isn't scheduled
+    pub fn task_every(&self, name: &str) -> Option<u64> {
+        self.schedule.every(name, self.base_period_ms())
+    }
+
+    pub fn is_simulated(&self) -> bool {
+        self.clock == ClockSource::Simulated
+    }
+
+    /// Every invalid setting
+    pub fn errors(&self) -> Vec<EventLoopConfigError> {
+        let mut errors = Vec::new();
+        if self.tick_rate_ms == 0 {
+            errors.push(EventLoopConfigError::ZeroTickRate);
+        }
+        if !(self.time_scale > 0.0 && self.time_scale <= 1000.0) {
+            errors.push(EventLoopConfigError::TimeScaleOutOfRange(self.time_scale));
+        }
+        errors.extend(
+            self.schedule
+                .tasks()
+                .iter()
+                .filter(|task| task.period_ms == 0)
+                .map(|task| EventLoopConfigError::ZeroTaskPeriod(task.name.clone())),
+        );
+        let period_us = self.wall_period().as_micros() as u64;
+        if self.spin_us >= period_us {
+            errors.push(EventLoopConfigError::SpinTooLong {
+                spin_us: self.spin_us,
+                period_us,
+            });
+        }
+        if self.render_every == 0 {
+            errors.push(EventLoopConfigError::ZeroRenderInterval);
+        }
+        if self.error_policy == ErrorPolicy::StopAfter(0) {
+            errors.push(EventLoopConfigError::ZeroErrorLimit);
+        }
+        if self.overrun_recovery == OverrunRecovery::CatchUp(0) {
+            errors.push(EventLoopConfigError::ZeroCatchUp);
+        }
+        errors
+    }
+
+    /// Check the settings, reporting the first invalid one
+    pub fn validate(&self) -> Result<(), EventLoopConfigError> {
+        match self.errors().into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for EventLoopConfig {
+    fn default() -> Self {
+        Self {
+            tick_rate_ms: 500,  // 2 Hz by default
+            schedule: TaskSchedule::new(),
+            verbose_timing: false,
+            clock: ClockSource::WallClock,
+            time_scale: 1.0,
+            time_scale_mode: TimeScaleMode::Step,
+            parallel_components: false,
+            execution: ExecutionMode::Single,
+            spin_us: 0,
+            max_overrun_ms: None,
+            error_policy: ErrorPolicy::Continue,
+            overrun_recovery: OverrunRecovery::Restart,
+            render_every: 1,
+            deadline_faults: false,
+            console: false,
+        }
+    }
+}
+
+/// Event loop configuration builder - validates on `build()`
+///
+/// ```ignore
+/// let config = EventLoopConfig::builder()
+///     .tick_rate_ms(10)
+///     .max_overrun_ms(5)
+///     .error_policy(ErrorPolicy::StopAfter(3))
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct EventLoopConfigBuilder {
+    config: EventLoopConfig,
+}
+
+impl EventLoopConfigBuilder {
+    pub fn tick_rate_ms(mut self, tick_rate_ms: u64) -> Self {
+        self.config.tick_rate_ms = tick_rate_ms;
+        self
+    }
+
+    /// Run a task every `period_ms` instead of every tick
+    pub fn task(mut self, name: &str, period_ms: u64) -> Self {
+        self.config.schedule.set(name, period_ms);
+        self
+    }
+
+    pub fn verbose_timing(mut self, verbose: bool) -> Self {
+        self.config.verbose_timing = verbose;
+        self
+    }
+
+    pub fn clock(mut self, clock: ClockSource) -> Self {
+        self.config.clock = clock;
+        self
+    }
+
+    pub fn time_scale(mut self, time_scale: f32) -> Self {
+        self.config.time_scale = time_scale;
+        self
+    }
+
+    pub fn time_scale_mode(mut self, mode: TimeScaleMode) -> Self {
+        self.config.time_scale_mode = mode;
+        self
+    }
+
+    pub fn parallel_components(mut self, parallel: bool) -> Self {
+        self.config.parallel_components = parallel;
+        self
+    }
+
+    pub fn execution(mut self, mode: ExecutionMode) -> Self {
+        self.config.execution = mode;
+        self
+    }
+
+    pub fn spin_us(mut self, spin_us: u64) -> Self {
+        self.config.spin_us = spin_us;
+        self
+    }
+
+    pub fn max_overrun_ms(mut self, max_overrun_ms: u64) -> Self {
+        self.config.max_overrun_ms = Some(max_overrun_ms);
+        self
+    }
+
+    pub fn error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.config.error_policy = policy;
+        self
+    }
+
+    pub fn overrun_recovery(mut self, recovery: OverrunRecovery) -> Self {
+        self.config.overrun_recovery = recovery;
+        self
+    }
+
+    pub fn render_every(mut self, ticks: u64) -> Self {
+        self.config.render_every = ticks;
+        self
+    }
+
+    pub fn deadline_faults(mut self, enabled: bool) -> Self {
+        self.config.deadline_faults = enabled;
+        self
+    }
+
+    pub fn console(mut self, enabled: bool) -> Self {
+        self.config.console = enabled;
+        self
+    }
+
+    pub fn build(self) -> Result<EventLoopConfig, EventLoopConfigError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+/// Manually advanced clock; clones share the same time
+#[derive(Debug, Clone, Default)]
+pub struct SimClock {
+    now_us: Arc<AtomicU64>,
+}
+
+impl SimClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Time since the clock was created
+    pub fn now(&self) -> Duration {
+        Duration::from_micros(self.now_us.load(Ordering::SeqCst))
+    }
+
+    /// Move the clock forward by `ms` milliseconds
+    pub fn advance(&self, ms: u64) {
+        self.advance_by(Duration::from_millis(ms));
+    }
+
+    pub fn advance_by(&self, step: Duration) {
+        self.now_us.fetch_add(step.as_micros() as u64, Ordering::SeqCst);
+    }
+
+    /// Move the clock forward to `time`; earlier times are ignored
+    pub fn advance_to(&self, time: Duration) {
+        self.now_us.fetch_max(time.as_micros() as u64, Ordering::SeqCst);
+    }
+}
+
+/// Clock the event loop measures and schedules ticks with
+#[derive(Debug, Clone)]
+pub enum TimeSource {
+    /// Monotonic wall clock, counted from the given instant
+    RealTime(Instant),
+    /// Simulated clock; waiting never sleeps
+    Simulated(SimClock),
+}
+
+impl TimeSource {
+    pub fn real_time() -> Self {
+        TimeSource::RealTime(Instant::now())
+    }
+
+    pub fn simulated() -> Self {
+        TimeSource::Simulated(SimClock::new())
+    }
+
+    /// Time source matching a configured clock
+    pub fn for_clock(clock: ClockSource) -> Self {
+        match clock {
+            ClockSource::WallClock => Self::real_time(),
+            ClockSource::Simulated => Self::simulated(),
+        }
+    }
+
+    /// Current time, relative to the source's origin
+    pub fn now(&self) -> Duration {
+        match self {
+            TimeSource::RealTime(origin) => origin.elapsed(),
+            TimeSource::Simulated(clock) => clock.now(),
+        }
+    }
+
+    /// Wait until `deadline`: sleep (spinning the last `spin`) in real
+    /// time, or jump the simulated clock there
+    pub fn wait_until(&self, deadline: Duration, spin: Duration) {
+        match self {
+            TimeSource::RealTime(origin) => hybrid_sleep_until(*origin + deadline, spin),
+            TimeSource::Simulated(clock) => clock.advance_to(deadline),
+        }
+    }
+
+    /// The simulated clock, if this is one
+    pub fn sim_clock(&self) -> Option<&SimClock> {
+        match self {
+            TimeSource::RealTime(_) => None,
+            TimeSource::Simulated(clock) => Some(clock),
+        }
+    }
+}
+
+/// Sleep until `deadline`, busy-waiting the final `spin` of it
+pub fn hybrid_sleep_until(deadline: Instant, spin: Duration) {
+    let now = Instant::now();
+    if deadline <= now {
+        return;
+    }
+    let remaining = deadline - now;
+    if remaining > spin {
+        thread::sleep(remaining - spin);
+    }
+    while Instant::now() < deadline {
+        std::hint::spin_loop();
+    }
+}
+
+/// How late ticks started compared to their schedule
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JitterStats {
+    pub samples: u64,
+    pub total: Duration,
+    pub max: Duration,
+}
+
+impl JitterStats {
+    pub fn record(&mut self, lateness: Duration) {
+        self.samples += 1;
+        self.total += lateness;
+        self.max = self.max.max(lateness);
+    }
+
+    pub fn mean(&self) -> Duration {
+        if self.samples == 0 {
+            return Duration::ZERO;
+        }
+        self.total / self.samples as u32
+    }
+}
+
+/// Pause state shared by a `LoopControl` and its clones
+#[derive(Debug, Default)]
+struct ControlState {
+    paused: bool,
+    /// Ticks allowed to run while paused
+    steps: u64,
+}
+
+/// Shared pause/resume/step control of an event loop
+/// Clones control the same loop, so one can be handed to another thread.
+#[derive(Debug, Clone, Default)]
+pub struct LoopControl {
+    state: Arc<(Mutex<ControlState>, Condvar)>,
+}
+
+impl LoopControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hold the loop before its next tick
+    pub fn pause(&self) {
+        self.update(|state| state.paused = true);
+    }
+
+    /// Continue on schedule, dropping steps not yet taken
+    pub fn resume(&self) {
+        self.update(|state| {
+            state.paused = false;
+            state.steps = 0;
+        });
+    }
+
+    /// Let `ticks` more ticks run while paused (pauses a running loop
+    /// after them)
+    pub fn step(&self, ticks: u64) {
+        self.update(|state| {
+            state.paused = true;
+            state.steps += ticks;
+        });
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.lock().paused
+    }
+
+    /// Check whether the next tick may run; uses up a step if paused
+    pub fn try_pass(&self) -> bool {
+        let mut state = self.lock();
+        if !state.paused {
+            return true;
+        }
+        if state.steps > 0 {
+            state.steps -= 1;
+            return true;
+        }
+        false
+    }
+
+    /// Wait up to `timeout` for a pause, resume or step
+    pub fn wait_for_change(&self, timeout: Duration) {
+        let (_, changed) = &*self.state;
+        let state = self.lock();
+        // Waking up early or late only costs another poll
+        let _ = changed.wait_timeout(state, timeout);
+    }
+
+    fn update(&self, change: impl FnOnce(&mut ControlState)) {
+        change(&mut self.lock());
+        self.state.1.notify_all();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ControlState> {
+        // The state stays consistent even if a holder panicked
+        self.state.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Apply one console command to `control`
+/// Commands: `p`/`pause`, `r`/`resume`, `s`/`step [N]`.
+pub fn apply_console_command(control: &LoopControl, line: &str) -> Result<(), String> {
+    let mut words = line.split_whitespace();
+    match words.next() {
+        None => Ok(()),
+        Some("p" | "pause") => {
+            control.pause();
+            Ok(())
+        }
+        Some("r" | "resume") => {
+            control.resume();
+            Ok(())
+        }
+        Some("s" | "step") => {
+            let ticks = match words.next() {
+                Some(n) => n.parse().map_err(|_| format!("Invalid step count '{}'", n))?,
+                None => 1,
+            };
+            control.step(ticks);
+            Ok(())
+        }
+        Some(other) => Err(format!("Unknown command '{}' (use pause, resume or step [N])", other)),
+    }
+}
+
+/// Control the loop from stdin on a background thread, one command per
+/// line (see `apply_console_command`); ends with stdin
+pub fn spawn_console(control: LoopControl) -> Result<(), String> {
+    thread::Builder::new()
+        .name("loop-console".to_string())
+        .spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if let Err(e) = apply_console_command(&control, &line) {
+                    log_warn!("EventLoop", "⚠️  {}", e);
+                }
+            }
+        })
+        .map(drop)
+        .map_err(|e| format!("Cannot start the loop console: {}", e))
+}
+
+/// Execution time of the ticks against their period
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimingReport {
+    pub ticks: u64,
+    pub total_execution: Duration,
+    /// Shortest tick (execution jitter is `wcet - bcet`)
+    pub bcet: Duration,
+    /// Worst-case execution time and the tick it was measured in
+    pub wcet: Duration,
+    pub wcet_tick: u64,
+    /// Ticks that took longer than their period
+    pub deadline_misses: u64,
+    pub last_miss_tick: Option<u64>,
+    /// Late ticks run back-to-back to catch up (`OverrunRecovery::CatchUp`)
+    pub catch_up_ticks: u64,
+    /// Ticks given up because the loop fell too far behind
+    pub dropped_ticks: u64,
+    /// Tick start jitter (wall-clock runs only)
+    pub start_jitter: JitterStats,
+}
+
+impl TimingReport {
+    /// Record the execution time of one tick with the period it had
+    pub fn record(&mut self, tick: u64, execution: Duration, period: Duration) {
+        if self.ticks == 0 || execution < self.bcet {
+            self.bcet = execution;
+        }
+        if self.ticks == 0 || execution > self.wcet {
+            self.wcet = execution;
+            self.wcet_tick = tick;
+        }
+        self.ticks += 1;
+        self.total_execution += execution;
+        if execution > period {
+            self.deadline_misses += 1;
+            self.last_miss_tick = Some(tick);
+        }
+    }
+
+    pub fn mean_execution(&self) -> Duration {
+        if self.ticks == 0 {
+            return Duration::ZERO;
+        }
+        self.total_execution / self.ticks as u32
+    }
+
+    /// Spread between the fastest and the slowest tick
+    pub fn execution_jitter(&self) -> Duration {
+        self.wcet.saturating_sub(self.bcet)
+    }
+}
+
+impl fmt::Display for TimingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Execution: mean {} µs, WCET {} µs (tick {}), jitter {} µs",
+               self.mean_execution().as_micros(), self.wcet.as_micros(), self.wcet_tick,
+               self.execution_jitter().as_micros())?;
+        write!(f, "\n   Deadline Misses: {}", self.deadline_misses)?;
+        if let Some(tick) = self.last_miss_tick {
+            write!(f, " (last in tick {})", tick)?;
+        }
+        if self.catch_up_ticks > 0 || self.dropped_ticks > 0 {
+            write!(f, "\n   Catch-up: {} late tick(s) run, {} dropped", self.catch_up_ticks, self.dropped_ticks)?;
+        }
+        if self.start_jitter.samples > 0 {
+            write!(f, "\n   Start Jitter: mean {} µs, max {} µs",
+                   self.start_jitter.mean().as_micros(), self.start_jitter.max.as_micros())?;
+        }
+        Ok(())
+    }
+}
+
+/// Event loop - runs continuously at a fixed tick rate
+pub struct EventLoop {
+    running: bool,
+    config: EventLoopConfig,
+    tick_count: u64,
+    time: TimeSource,
+    start_time: Option<Duration>,
+    /// Live tick rate, shared so it can change while the loop runs
+    tick_rate_ms: Arc<AtomicU64>,
+    /// Scheduled start of the next tick (None = start immediately)
+    next_start: Option<Duration>,
+    timing: TimingReport,
+    /// Deadline misses since start, shared so the application can react
+    deadline_misses: Arc<AtomicU64>,
+    /// Failed ticks in a row (for `ErrorPolicy::StopAfter`)
+    consecutive_errors: u32,
+    /// Why the error policy stopped the loop, if it did
+    stop_reason: Option<String>,
+    /// Set from another thread or a signal handler to stop after the
+    /// current tick
+    shutdown: Arc<AtomicBool>,
+    control: LoopControl,
+}
+
+impl EventLoop {
+    /// Create a new event loop
+    pub fn new(config: EventLoopConfig) -> Self {
+        Self {
+            running: false,
+            tick_rate_ms: Arc::new(AtomicU64::new(config.base_period_ms())),
+            time: TimeSource::for_clock(config.clock),
+            config,
+            tick_count: 0,
+            start_time: None,
+            next_start: None,
+            timing: TimingReport::default(),
+            deadline_misses: Arc::new(AtomicU64::new(0)),
+            consecutive_errors: 0,
+            stop_reason: None,
+            shutdown: Arc::new(AtomicBool::new(false)),
+            control: LoopControl::new(),
+        }
+    }
+
+    /// Check if the event loop is running
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Get current tick count
+    pub fn tick_count(&self) -> u64 {
+        self.tick_count
+    }
+
+    /// Get a handle to the live tick rate
+    /// Storing a new value changes the sleep period from the next tick on
+    pub fn tick_rate_handle(&self) -> Arc<AtomicU64> {
+        Arc::clone(&self.tick_rate_ms)
+    }
+
+    /// Get a handle to the shutdown flag; storing true stops the loop once
+    /// the current tick has finished
+    pub fn shutdown_handle(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.shutdown)
+    }
+
+    /// Use an existing shutdown flag (e.g. one shared by several loops)
+    pub fn set_shutdown_handle(&mut self, shutdown: Arc<AtomicBool>) {
+        self.shutdown = shutdown;
+    }
+
+    /// Get a handle to pause, resume and step the loop from another thread
+    pub fn control_handle(&self) -> LoopControl {
+        self.control.clone()
+    }
+
+    /// Use an existing control handle
+    pub fn set_control_handle(&mut self, control: LoopControl) {
+        self.control = control;
+    }
+
+    /// Hold the loop before its next tick
+    pub fn pause(&self) {
+        self.control.pause();
+    }
+
+    /// Continue a paused loop on a fresh schedule
+    pub fn resume(&self) {
+        self.control.resume();
+    }
+
+    /// Run one more tick while paused
+    pub fn step(&self) {
+        self.control.step(1);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.control.is_paused()
+    }
+
+    /// Check whether a shutdown was requested
+    pub fn shutdown_requested(&self) -> bool {
+        self.shutdown.load(Ordering::SeqCst)
+    }
+
+    /// Current tick rate in milliseconds
+    pub fn tick_rate_ms(&self) -> u64 {
+        self.tick_rate_ms.load(Ordering::Relaxed).max(1)
+    }
+
+    /// Time between two tick starts at the current tick rate (shorter or
+    /// longer than the tick rate with `TimeScaleMode::Sleep`)
+    pub fn tick_period(&self) -> Duration {
+        scaled_period(self.tick_rate_ms(), self.config.time_scale, self.config.time_scale_mode)
+    }
+
+    /// Tick start jitter measured so far (wall-clock runs only)
+    pub fn jitter(&self) -> JitterStats {
+        self.timing.start_jitter
+    }
+
+    /// Execution times, jitter and deadline misses since start
+    pub fn timing_report(&self) -> TimingReport {
+        self.timing
+    }
+
+    /// Get a handle to the deadline miss count since start
+    pub fn deadline_miss_handle(&self) -> Arc<AtomicU64> {
+        Arc::clone(&self.deadline_misses)
+    }
+
+    /// Count deadline misses in an existing counter
+    pub fn set_deadline_miss_handle(&mut self, deadline_misses: Arc<AtomicU64>) {
+        self.deadline_misses = deadline_misses;
+    }
+
+    pub fn config(&self) -> &EventLoopConfig {
+        &self.config
+    }
+
+    /// Why the error policy stopped the loop (None = not stopped by errors)
+    pub fn stop_reason(&self) -> Option<&str> {
+        self.stop_reason.as_deref()
+    }
+
+    /// Replace the clock (e.g. to share one `SimClock` with a test)
+    pub fn set_time_source(&mut self, time: TimeSource) {
+        self.time = time;
+    }
+
+    pub fn time_source(&self) -> &TimeSource {
+        &self.time
+    }
+
+    /// Current time of the loop's clock
+    pub fn now(&self) -> Duration {
+        self.time.now()
+    }
+
+    /// Get elapsed time since start
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.start_time.map(|start| self.now().saturating_sub(start))
+    }
+
+    /// Start the event loop
+    pub fn start(&mut self) {
+        self.running = true;
+        self.start_time = Some(self.now());
+        self.tick_count = 0;
+        self.next_start = None;
+        self.timing = TimingReport::default();
+        self.deadline_misses.store(0, Ordering::Relaxed);
+        self.consecutive_errors = 0;
+        self.stop_reason = None;
+
+        log_info!("EventLoop", "\n━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        log_info!("EventLoop", "🔄 Event Loop Started");
+        log_info!("EventLoop", "   Tick Rate: {} ms ({} Hz)", self.tick_rate_ms(), 1000 / self.tick_rate_ms());
+        for task in self.config.schedule.tasks() {
+            log_info!("EventLoop", "   Task {}: every {} ms", task.name, task.period_ms);
+        }
+        if self.config.time_scale != 1.0 {
+            match self.config.time_scale_mode {
+                TimeScaleMode::Step => log_info!("EventLoop", "   Time Scale: {}x", self.config.time_scale),
+                TimeScaleMode::Sleep => log_info!("EventLoop", "   Time Scale: {}x (a tick every {:.2} ms)",
+                                                 self.config.time_scale,
+                                                 self.tick_period().as_secs_f64() * 1000.0),
+            }
+        }
+        if let OverrunRecovery::CatchUp(max_ticks) = self.config.overrun_recovery {
+            log_info!("EventLoop", "   Overrun Recovery: catch up at most {} ticks", max_ticks);
+        }
+        if self.config.spin_us > 0 && !self.config.is_simulated() {
+            log_info!("EventLoop", "   Hybrid Sleep: last {} µs spun", self.config.spin_us);
+        }
+        log_info!("EventLoop", "   Press Ctrl+C to stop");
+        log_info!("EventLoop", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
+    }
+
+    /// Stop the event loop
+    pub fn stop(&mut self) {
+        self.running = false;
+
+        if let Some(elapsed) = self.elapsed() {
+            log_info!("EventLoop", "\n━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+            log_info!("EventLoop", "🛑 Event Loop Stopped");
+            log_info!("EventLoop", "   Total Ticks: {}", self.tick_count);
+            let simulated = if self.time.sim_clock().is_some() { " (simulated)" } else { "" };
+            log_info!("EventLoop", "   Total Time: {:.2}s{}", elapsed.as_secs_f64(), simulated);
+            if self.config.verbose_timing || self.timing.deadline_misses > 0 {
+                log_info!("EventLoop", "   {}", self.timing);
+            } else if self.timing.start_jitter.samples > 0 {
+                let jitter = self.timing.start_jitter;
+                log_info!("EventLoop", "   Start Jitter: mean {} µs, max {} µs",
+                         jitter.mean().as_micros(), jitter.max.as_micros());
+            }
+            log_info!("EventLoop", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
+        }
+    }
+
+    /// Run one tick of the event loop
+    /// Returns the duration the tick took
+    pub fn tick<F>(&mut self, mut callback: F) -> Duration
+    where
+        F: FnMut(u64) -> Result<(), String>,
+    {
+        let tick_start = self.now();
+
+        // Call the callback with current tick number
+        let result = callback(self.tick_count);
+        self.complete_tick(result, tick_start)
+    }
+
+    /// Count a tick that started at `tick_start` (loop clock time) and
+    /// apply the error policy to its result
+    /// Returns the duration the tick took
+    pub(crate) fn complete_tick(&mut self, result: Result<(), String>, tick_start: Duration) -> Duration {
+        self.tick_count += 1;
+
+        let tick_duration = self.now().saturating_sub(tick_start);
+        let period = self.tick_period();
+        let misses = self.timing.deadline_misses;
+        self.timing.record(self.tick_count - 1, tick_duration, period);
+        if self.timing.deadline_misses > misses {
+            self.deadline_misses.fetch_add(1, Ordering::Relaxed);
+        }
+
+        // Print timing if verbose
+        if self.config.verbose_timing {
+            log_info!("EventLoop", "   [Timing] Tick {} took: {:.2}ms", self.tick_count - 1, tick_duration.as_secs_f64() * 1000.0);
+        }
+
+        let result = result.and_then(|()| self.check_overrun(tick_duration));
+        self.apply_error_policy(result);
+        tick_duration
+    }
+
+    /// Fail a tick that overran its period by more than `max_overrun_ms`
+    fn check_overrun(&self, tick_duration: Duration) -> Result<(), String> {
+        let Some(max_overrun_ms) = self.config.max_overrun_ms else {
+            return Ok(());
+        };
+        let period = self.tick_period();
+        let overrun = tick_duration.saturating_sub(period);
+        if overrun > Duration::from_millis(max_overrun_ms) {
+            Err(format!("overran its {} ms period by {:.2} ms (max {} ms)",
+                        period.as_millis(), overrun.as_secs_f64() * 1000.0, max_overrun_ms))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Count a failed tick and stop the loop if the policy says so
+    fn apply_error_policy(&mut self, result: Result<(), String>) {
+        let Err(e) = result else {
+            self.consecutive_errors = 0;
+            return;
+        };
+        log_error!("EventLoop", "❌ Error in tick {}: {}", self.tick_count - 1, e);
+        self.consecutive_errors += 1;
+
+        let stop = match self.config.error_policy {
+            ErrorPolicy::Continue => false,
+            ErrorPolicy::Stop => true,
+            ErrorPolicy::StopAfter(limit) => self.consecutive_errors >= limit,
+        };
+        if stop {
+            let reason = format!("{} failed tick(s) in a row, last at tick {}: {}",
+                                 self.consecutive_errors, self.tick_count - 1, e);
+            log_error!("EventLoop", "🛑 Error policy '{}': stopping the event loop", self.config.error_policy);
+            self.stop_reason = Some(reason);
+            self.running = false;
+        }
+    }
+
+    /// Count a tick that starts `behind` its fixed-timestep slot
+    /// Returns false if that is more than `max_ticks` periods; the ticks in
+    /// excess are dropped and the schedule has to restart from now.
+    pub(crate) fn catch_up(&mut self, behind: Duration, period: Duration, max_ticks: u32) -> bool {
+        if behind <= period * max_ticks {
+            self.timing.catch_up_ticks += 1;
+            return true;
+        }
+        let dropped = (behind.as_nanos() / period.as_nanos().max(1)) as u64;
+        log_warn!("EventLoop", "⚠️  Warning: {} tick(s) behind schedule - dropping {} to catch up", dropped + 1, dropped);
+        self.timing.dropped_ticks += dropped;
+        false
+    }
+
+    /// Record how late a tick started compared to its schedule
+    pub(crate) fn record_jitter(&mut self, lateness: Duration) {
+        self.timing.start_jitter.record(lateness);
+    }
+
+    /// Stop the loop if a shutdown was requested
+    /// Returns whether the loop is still running.
+    pub(crate) fn keep_running(&mut self) -> bool {
+        if self.running && self.shutdown_requested() {
+            log_info!("EventLoop", "\n🛑 Shutdown requested - stopping after tick {}", self.tick_count.saturating_sub(1));
+            self.running = false;
+        }
+        self.running
+    }
+
+    /// Block while the loop is paused, letting single steps through
+    /// A shutdown request ends the pause. Returns whether the loop was
+    /// held; the schedule then restarts from now.
+    pub(crate) fn hold_while_paused(&mut self) -> bool {
+        if self.control.try_pass() {
+            return false;
+        }
+        log_info!("EventLoop", "\n⏸️  Event loop paused before tick {}", self.tick_count);
+        while !self.control.try_pass() && !self.shutdown_requested() {
+            // Signal handlers can't notify the condvar, so poll for shutdown
+            self.control.wait_for_change(Duration::from_millis(50));
+        }
+        if self.control.is_paused() {
+            log_info!("EventLoop", "⏭️  Step: tick {}", self.tick_count);
+        } else {
+            log_info!("EventLoop", "▶️  Event loop resumed");
+        }
+        self.next_start = None;
+        true
+    }
+
+    /// Wait for the scheduled start of the next tick and record how late
+    /// it actually starts (a simulated clock is never late)
+    fn wait_for_next_tick(&mut self) {
+        if let Some(deadline) = self.next_start {
+            self.time.wait_until(deadline, Duration::from_micros(self.config.spin_us));
+            if self.time.sim_clock().is_none() {
+                self.record_jitter(self.now().saturating_sub(deadline));
+            }
+        }
+    }
+
+    /// Schedule the next tick one period after the current one started
+    /// Returns false if the current tick overran the period; the schedule
+    /// then restarts from now, or with `OverrunRecovery::CatchUp` stays on
+    /// its fixed timestep so the next ticks start late until caught up.
+    fn schedule_next_tick(&mut self, tick_start: Duration) -> bool {
+        let period = self.tick_period();
+        let now = self.now();
+        if let OverrunRecovery::CatchUp(max_ticks) = self.config.overrun_recovery {
+            // Measure from the scheduled start, not the actual one, so
+            // start jitter doesn't accumulate into drift
+            let deadline = self.next_start.unwrap_or(tick_start) + period;
+            if deadline > now {
+                self.next_start = Some(deadline);
+                return true;
+            }
+            self.next_start = if self.catch_up(now - deadline, period, max_ticks) {
+                Some(deadline)
+            } else {
+                Some(now)
+            };
+            return false;
+        }
+
+        let deadline = tick_start + period;
+        if deadline > now {
+            self.next_start = Some(deadline);
+            true
+        } else {
+            self.next_start = Some(now);
+            false
+        }
+    }
+
+    /// Run the event loop with a callback
+    /// The callback receives the tick number and should return Result<(), String>
+    pub fn run<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(u64) -> Result<(), String>,
+    {
+        self.start();
+
+        while self.keep_running() {
+            self.hold_while_paused();
+            self.wait_for_next_tick();
+            if !self.keep_running() {
+                break;
+            }
+
+            // Run the tick
+            let tick_start = self.now();
+            let tick_duration = self.tick(&mut callback);
+
+            if !self.schedule_next_tick(tick_start) {
+                // Tick took longer than target - warn
+                log_warn!("EventLoop", "⚠️  Warning: Tick {} took {:.2}ms (target: {:.2}ms) - can't keep up!",
+                    self.tick_count - 1,
+                    tick_duration.as_secs_f64() * 1000.0,
+                    self.tick_period().as_secs_f64() * 1000.0
+                );
+            }
+        }
+
+        self.stop();
+    }
+
+    /// Run for a fixed number of ticks, calling `dispatch` with the tick
+    /// number and name of each scheduled task due in that tick
+    pub fn run_scheduled<F>(&mut self, num_ticks: u64, mut dispatch: F)
+    where
+        F: FnMut(u64, &str) -> Result<(), String>,
+    {
+        let schedule = self.config.schedule.clone();
+        let base_ms = self.config.base_period_ms();
+        self.run_for(num_ticks, |tick_num| {
+            for task in schedule.due(tick_num, base_ms) {
+                dispatch(tick_num, task).map_err(|e| format!("{}: {}", task, e))?;
+            }
+            Ok(())
+        });
+    }
+
+    /// Run for a fixed number of ticks, running the registered handlers
+    /// of every phase on `context` (see `TickHandlers`)
+    pub fn run_handlers<C>(&mut self, num_ticks: u64, handlers: &mut TickHandlers<'_, C>, context: &mut C) {
+        self.run_for(num_ticks, |tick_num| handlers.run_tick(context, tick_num));
+    }
+
+    /// Run for a fixed number of ticks (for testing/demos)
+    pub fn run_for<F>(&mut self, num_ticks: u64, mut callback: F)
+    where
+        F: FnMut(u64) -> Result<(), String>,
+    {
+        self.start();
+
+        for _ in 0..num_ticks {
+            if !self.keep_running() {
+                break;
+            }
+
+            self.hold_while_paused();
+            self.wait_for_next_tick();
+            if !self.keep_running() {
+                break;
+            }
+            let tick_start = self.now();
+            self.tick(&mut callback);
+            self.schedule_next_tick(tick_start);
+        }
+
+        self.stop();
+    }
+}
+
+impl Default for EventLoop {
+    fn default() -> Self {
+        Self::new(EventLoopConfig::default())
+    }
+}
+
+/// Flag set by the interrupt handler
+static INTERRUPT_FLAG: OnceLock<Arc<AtomicBool>> = OnceLock::new();
+
+/// Set `shutdown` when the process gets Ctrl+C (SIGINT)
+/// A second Ctrl+C terminates the process immediately. Only the first
+/// call installs a handler; later calls fail.
+pub fn install_interrupt_handler(shutdown: Arc<AtomicBool>) -> Result<(), String> {
+    INTERRUPT_FLAG
+        .set(shutdown)
+        .map_err(|_| "An interrupt handler is already installed".to_string())?;
+    os::install_sigint_handler()
+}
+
+#[cfg(unix)]
+mod os {
+    use std::os::raw::c_int;
+    use std::sync::atomic::Ordering;
+
+    const SIGINT: c_int = 2;
+    const SIG_DFL: usize = 0;
+    const SIG_ERR: usize = !0;
+
+    extern "C" {
+        fn signal(signum: c_int, handler: usize) -> usize;
+    }
+
+    /// Only async-signal-safe work: an atomic store and re-arming the
+    /// default action for the next Ctrl+C
+    extern "C" fn on_sigint(_signum: c_int) {
+        if let Some(flag) = super::INTERRUPT_FLAG.get() {
+            flag.store(true, Ordering::SeqCst);
+        }
+        unsafe { signal(SIGINT, SIG_DFL) };
+    }
+
+    pub fn install_sigint_handler() -> Result<(), String> {
+        let handler: extern "C" fn(c_int) = on_sigint;
+        if unsafe { signal(SIGINT, handler as usize) } == SIG_ERR {
+            return Err(format!("signal(SIGINT) failed: {}", std::io::Error::last_os_error()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(unix))]
+mod os {
+    pub fn install_sigint_handler() -> Result<(), String> {
+        Err("Ctrl+C handling is not supported on this platform".to_string())
+    }
+}
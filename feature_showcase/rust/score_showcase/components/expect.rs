@@ -0,0 +1,134 @@
+//! Scenario assertion DSL
+//! Lets safety and behavior requirements be written as executable checks
+//! that read like the requirement itself:
+//!
+//! ```ignore
+//! expect!(harness, at_tick 40, speed <= 120)?;
+//! expect!(harness, always, rpm < 6000)?;
+//! expect_warning!(harness, Overheating within 10 ticks)?;
+//! expect_no_warning!(harness, EngineStateInvalid within 50 ticks)?;
+//! ```
+//!
+//! Signal names are the fields of `SystemSnapshot`; warning names are the
+//! `SafetyWarning::type_name()` values. Every check returns
+//! `Result<(), String>` with a message naming the failed requirement.
+
+use crate::components::harness::TestHarness;
+use crate::components::snapshot::SystemSnapshot;
+
+/// Check a signal condition at one tick
+/// Runs the harness forward if the tick has not been reached yet.
+#[macro_export]
+macro_rules! expect {
+    ($harness:expr, at_tick $tick:expr, $field:ident $op:tt $value:expr) => {
+        $harness.expect_at(
+            $tick,
+            concat!(stringify!($field), " ", stringify!($op), " ", stringify!($value)),
+            |s| s.$field $op $value,
+        )
+    };
+    ($harness:expr, always, $field:ident $op:tt $value:expr) => {
+        $harness.expect_always(
+            concat!(stringify!($field), " ", stringify!($op), " ", stringify!($value)),
+            |s| s.$field $op $value,
+        )
+    };
+}
+
+/// Check that a safety warning is raised within the next N ticks
+#[macro_export]
+macro_rules! expect_warning {
+    ($harness:expr, $warning:ident within $ticks:literal ticks) => {
+        $harness.expect_warning_within(stringify!($warning), $ticks)
+    };
+}
+
+/// Check that a safety warning is not raised during the next N ticks
+#[macro_export]
+macro_rules! expect_no_warning {
+    ($harness:expr, $warning:ident within $ticks:literal ticks) => {
+        $harness.expect_no_warning_within(stringify!($warning), $ticks)
+    };
+}
+
+impl TestHarness {
+    /// Check `condition` against the snapshot of `tick`
+    pub fn expect_at<F>(&mut self, tick: u64, requirement: &str, condition: F) -> Result<(), String>
+    where
+        F: Fn(&SystemSnapshot) -> bool,
+    {
+        while self.current_tick() <= tick {
+            self.tick()?;
+        }
+
+        let snapshot = self
+            .snapshots()
+            .iter()
+            .find(|s| s.tick == tick)
+            .ok_or_else(|| format!("no snapshot recorded for tick {}", tick))?;
+        if condition(snapshot) {
+            Ok(())
+        } else {
+            Err(format!(
+                "expectation failed at tick {}: {}\n  snapshot: {}",
+                tick,
+                requirement,
+                snapshot.to_canonical()
+            ))
+        }
+    }
+
+    /// Check `condition` against every snapshot recorded so far
+    pub fn expect_always<F>(&self, requirement: &str, condition: F) -> Result<(), String>
+    where
+        F: Fn(&SystemSnapshot) -> bool,
+    {
+        match self.snapshots().iter().find(|s| !condition(s)) {
+            None => Ok(()),
+            Some(snapshot) => Err(format!(
+                "expectation failed at tick {}: always {}\n  snapshot: {}",
+                snapshot.tick,
+                requirement,
+                snapshot.to_canonical()
+            )),
+        }
+    }
+
+    /// Run up to `ticks` ticks until a warning of the given type is reported
+    pub fn expect_warning_within(&mut self, warning: &str, ticks: u64) -> Result<(), String> {
+        let start = self.current_tick();
+        for _ in 0..ticks {
+            self.tick()?;
+            if self.has_warning(warning) {
+                return Ok(());
+            }
+        }
+        Err(format!(
+            "expectation failed: {} warning within {} ticks (ticks {}..{})",
+            warning,
+            ticks,
+            start,
+            self.current_tick()
+        ))
+    }
+
+    /// Run `ticks` ticks and fail if a warning of the given type is reported
+    pub fn expect_no_warning_within(&mut self, warning: &str, ticks: u64) -> Result<(), String> {
+        for _ in 0..ticks {
+            self.tick()?;
+            if self.has_warning(warning) {
+                return Err(format!(
+                    "expectation failed at tick {}: no {} warning within {} ticks",
+                    self.current_tick() - 1,
+                    warning,
+                    ticks
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn has_warning(&self, warning: &str) -> bool {
+        self.warnings().iter().any(|w| w.type_name() == warning)
+    }
+}
@@ -0,0 +1,374 @@
+//! Fuzz targets for external input parsers
+//! Demonstrates S-CORE robustness patterns:
+//! - Every parser of external input is a fuzz target taking raw bytes
+//! - Malformed input must produce an error, never a panic
+//! - A built-in mutation fuzzer drives the targets from a seed corpus
+//!
+//! The targets are plain `fn(&[u8])`, so they can also be wrapped by an
+//! external coverage-guided fuzzer (e.g. `fuzz_target!(|d| fuzz_json(d))`).
+//! The built-in driver is seeded, so a crash is reproduced with `--seed`.
+
+use std::panic;
+
+use crate::components::config::{ConfigLayer, SafetyConfig, SystemConfig};
+use crate::components::fixedpoint::SIGNALS;
+use crate::components::json::JsonValue;
+use crate::components::message_bus::Envelope;
+use crate::components::messages::{CarMessage, ComponentRegistry};
+use crate::components::migration::{store_version, MigrationPipeline};
+use crate::components::rng::SimRng;
+use crate::components::scenario::Scenario;
+use crate::components::snapshot::SystemSnapshot;
+use crate::components::workflow::Workflow;
+use crate::components::workflow_file::ActionRegistry;
+
+/// JSON parser: valid documents must survive a write/parse round trip
+pub fn fuzz_json(data: &[u8]) {
+    let text = String::from_utf8_lossy(data);
+    if let Ok(value) = JsonValue::parse(&text) {
+        let written = value.to_string();
+        if let Err(e) = JsonValue::parse(&written) {
+            panic!("round trip failed for {}: {}", written, e);
+        }
+    }
+}
+
+/// Snapshot deserializer, including migration of v1 snapshots
+pub fn fuzz_snapshot(data: &[u8]) {
+    let text = String::from_utf8_lossy(data);
+    if let Ok(json) = JsonValue::parse(&text) {
+        let _ = SystemSnapshot::from_json(&json);
+        if let Ok((migrated, _)) = MigrationPipeline::snapshot().migrate(1, json) {
+            let _ = SystemSnapshot::from_json(&migrated);
+        }
+    }
+}
+
+/// Bus messages and envelopes: anything that reads must survive a round trip
+pub fn fuzz_message(data: &[u8]) {
+    let text = String::from_utf8_lossy(data);
+    if let Ok(json) = JsonValue::parse(&text) {
+        let registry = ComponentRegistry::new();
+        if let Ok(message) = CarMessage::from_json(&json, &registry) {
+            let written = message.to_json();
+            if CarMessage::from_json(&written, &registry).as_ref() != Ok(&message) {
+                panic!("message round trip failed for {}", written);
+            }
+        }
+        if let Ok(envelope) = Envelope::from_json(&json, &registry) {
+            let written = envelope.to_json();
+            if Envelope::from_json(&written, &registry).as_ref() != Ok(&envelope) {
+                panic!("envelope round trip failed for {}", written);
+            }
+        }
+    }
+}
+
+/// Workflow files: steps are built but never run
+/// The schedule must contain every step once, after its prerequisites.
+pub fn fuzz_workflow(data: &[u8]) {
+    let text = String::from_utf8_lossy(data);
+    let Ok(json) = JsonValue::parse(&text) else {
+        return;
+    };
+    let Ok(workflow) = Workflow::from_json(&json, &ActionRegistry::new()) else {
+        return;
+    };
+    let mut wave_of = vec![None; workflow.step_count()];
+    for (wave, steps) in workflow.schedule().iter().enumerate() {
+        for &index in steps {
+            if wave_of[index].replace(wave).is_some() {
+                panic!("step {} scheduled twice", index);
+            }
+        }
+    }
+    for (index, wave) in wave_of.iter().enumerate() {
+        let wave = wave.unwrap_or_else(|| panic!("step {} not scheduled", index));
+        if workflow.prerequisites(index).iter().any(|&p| wave_of[p] >= Some(wave)) {
+            panic!("step {} scheduled before a prerequisite", index);
+        }
+    }
+}
+
+/// Safety limit files: accepted limits must validate and survive a round trip
+pub fn fuzz_safety(data: &[u8]) {
+    let text = String::from_utf8_lossy(data);
+    if let Ok(limits) = JsonValue::parse(&text).and_then(|json| SafetyConfig::from_json(&json)) {
+        if let Err(e) = limits.validate() {
+            panic!("accepted invalid limits: {}", e);
+        }
+        if SafetyConfig::from_json(&limits.to_json()).as_ref() != Ok(&limits) {
+            panic!("round trip failed for {}", limits.to_json());
+        }
+    }
+}
+
+/// Persisted store documents of any version
+pub fn fuzz_store(data: &[u8]) {
+    let text = String::from_utf8_lossy(data);
+    if let Ok(json) = JsonValue::parse(&text) {
+        if let Ok(version) = store_version(&json) {
+            let _ = MigrationPipeline::store().migrate(version, json);
+        }
+    }
+}
+
+/// Config files: every key/value is applied and the result validated
+pub fn fuzz_config(data: &[u8]) {
+    let text = String::from_utf8_lossy(data);
+    if let Ok(json) = JsonValue::parse(&text) {
+        let mut config = SystemConfig::default();
+        for (key, value) in json.flatten() {
+            let _ = config.set(&key, &value, ConfigLayer::File);
+        }
+        let _ = config.validate();
+    }
+}
+
+/// Command line arguments (whitespace separated)
+/// Flags that open files are skipped so fuzzing never touches the disk.
+pub fn fuzz_cli(data: &[u8]) {
+    let text = String::from_utf8_lossy(data);
+    let args: Vec<String> = text.split_whitespace().map(str::to_string).collect();
+    if args.iter().any(|a| a == "-c" || a == "--config") {
+        return;
+    }
+    let _ = SystemConfig::load_from(&args, &[]);
+}
+
+/// Scenario files: accepted scenarios are turned into a configuration,
+/// never run
+pub fn fuzz_scenario(data: &[u8]) {
+    let text = String::from_utf8_lossy(data);
+    if let Ok(scenario) = Scenario::parse(&text) {
+        let _ = scenario.config(&SystemConfig::default());
+    }
+}
+
+/// CAN signal decoding: raw values read from the payload (4 bytes each,
+/// little endian, signals in `SIGNALS` order) must decode within the
+/// signal's range and encode back to the same raw value
+pub fn fuzz_can_signal(data: &[u8]) {
+    for (chunk, scale) in data.chunks(4).zip(SIGNALS.iter().cycle()) {
+        let mut bytes = [0; 4];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+        let signal = scale.from_raw(i32::from_le_bytes(bytes));
+        if !(scale.raw_min()..=scale.raw_max()).contains(&signal.raw) {
+            panic!("{} raw {} outside its range", scale.name, signal.raw);
+        }
+        let encoded = scale.encode(signal.physical());
+        if encoded.raw != signal.raw {
+            panic!("{} raw {} decodes to {} but encodes to {}", scale.name, signal.raw, signal.physical(), encoded.raw);
+        }
+    }
+}
+
+/// A fuzz target: name, entry point and seed corpus
+pub type FuzzTarget = (&'static str, fn(&[u8]), &'static [&'static str]);
+
+/// All fuzz targets with their seed corpus
+pub const FUZZ_TARGETS: &[FuzzTarget] = &[
+    (
+        "json",
+        fuzz_json,
+        &[r#"{"a":[1,2.5,-3e2],"b":{"c":"é\n"},"d":true,"e":null}"#, "[]", "\"x\""],
+    ),
+    (
+        "snapshot",
+        fuzz_snapshot,
+        &[
+            r#"{"brake_pressure":0,"engine_state":"RUNNING","fuel_level":85,"odometer":0.5,"rpm":809,"speed_kmh":5,"steering_angle":0,"temperature_c":20.05,"tick":0,"warnings":[]}"#,
+            r#"{"speed":5,"temperature":20.05,"tick":3,"warnings":["Low fuel (12%)"]}"#,
+            r#"{"brake_pressure":0,"engine_state":"OFF","fuel_level":100,"odometer":1.5,"rpm":0,"speed_kmh":30,"steering_angle":0,"temperature_c":20,"tick":7,"warnings":[],"battery":{"soc":79.9,"temperature_c":20.1,"voltage":383.5,"charging_state":"DISCHARGING"}}"#,
+        ],
+    ),
+    (
+        "message",
+        fuzz_message,
+        &[
+            r#"{"type":"EngineStart"}"#,
+            r#"{"type":"EngineStop"}"#,
+            r#"{"type":"EngineOverheating","temperature":104.5}"#,
+            r#"{"type":"EngineRpmChange","rpm":3200}"#,
+            r#"{"type":"BrakeApply","pressure":80}"#,
+            r#"{"type":"BrakeRelease"}"#,
+            r#"{"type":"BrakePressureChange","pressure":35}"#,
+            r#"{"type":"AbsActivated","pressure":96,"km_h":72}"#,
+            r#"{"type":"BrakeFault"}"#,
+            r#"{"type":"SteeringTurn","angle":-15}"#,
+            r#"{"type":"SteeringCenter"}"#,
+            r#"{"type":"SpeedUpdate","km_h":88}"#,
+            r#"{"type":"FuelWarning","level":9}"#,
+            r#"{"type":"BatteryLow","soc":14}"#,
+            r#"{"type":"BatteryOverheating","temperature":57.5}"#,
+            r#"{"type":"HvacLoad","watts":1800}"#,
+            r#"{"type":"CollisionImminent","distance":24.5,"ttc":1.2}"#,
+            r#"{"type":"PositionUpdate","x":120.5,"y":-40,"heading":270,"to_destination":850}"#,
+            r#"{"type":"ComponentError","component":"Brakes","error":"sensor timeout"}"#,
+            r#"{"type":"ComponentRestarted","component":"HVAC","attempt":2}"#,
+            r#"{"type":"ConfigChanged","key":"safety.max_speed","value":"110"}"#,
+            r#"{"type":"SelectCalibration","name":"sport"}"#,
+            r#"{"type":"QuerySignal","signal":"rpm"}"#,
+            r#"{"type":"SignalReport","signal":"speed","value":42.25}"#,
+            r#"{"cycle":12,"from":"Engine","message":{"type":"SpeedUpdate","km_h":50},"published_us":24031,"ttl_us":500000,"e2e_counter":3,"e2e_crc":4660}"#,
+        ],
+    ),
+    (
+        "workflow",
+        fuzz_workflow,
+        &[
+            r#"{"name":"Start Car","steps":[{"name":"Start Engine","action":"engine.start","requirement":"REQ-WF-001","retry":{"attempts":3,"backoff_ms":100}},{"name":"Fuel","action":"dashboard.set_fuel","args":{"level":85}}]}"#,
+            r#"{"name":"Stop","steps":[{"name":"Brake","action":"brakes.apply","args":{"pressure":100},"timeout_ms":50},{"name":"Set","action":"signal.set","args":{"signal":"speed","value":0}},{"name":"Say","action":"announce","args":{"message":"done"}}]}"#,
+            r#"{"name":"Park","steps":[{"name":"Hold","action":"brakes.apply","args":{"pressure":60}},{"name":"Center","action":"steering.center","after":[]},{"name":"Off","action":"engine.stop","after":["Hold","Center"]}]}"#,
+        ],
+    ),
+    (
+        "safety",
+        fuzz_safety,
+        &[
+            r#"{"max_speed":110,"max_temperature":95,"max_rpm":6000,"min_fuel":15,"max_brake_pressure":80}"#,
+            r#"{"max_speed":50,"max_temperature":87.5}"#,
+            r#"{"max_speed":90,"filters":{"SpeedExceeded":{"debounce":3,"hysteresis":5},"LowFuel":{"debounce":2}}}"#,
+        ],
+    ),
+    (
+        "store",
+        fuzz_store,
+        &[
+            r#"{"version":2,"data":{"odometer_km":12.5,"faults.latched":["Overheating"]}}"#,
+            r#"{"odometer_km":3,"service.engine_starts":2}"#,
+        ],
+    ),
+    (
+        "config",
+        fuzz_config,
+        &[
+            r#"{"ticks":10,"safety":{"max_speed":100,"max_temperature":90.5},"log_level":"DEBUG"}"#,
+            r#"{"golden":{"mode":"compare","path":""},"chaos":{"drop_percent":200}}"#,
+        ],
+    ),
+    (
+        "cli",
+        fuzz_cli,
+        &[
+            "-p test -t 10 --seed 3 -s safety.max_rpm=5000",
+            "--diff-run a b --calibration-diff x y --chaos --tick-budget 10",
+        ],
+    ),
+    (
+        "scenario",
+        fuzz_scenario,
+        &[
+            r#"{"name":"Motorway limit","ticks":100,"settings":{"sim.seed":3,"safety":{"max_speed":110}},"expect":[{"signal":"rpm","max":6000},{"tick":40,"signal":"speed","min":50,"max":130},{"warning":"SpeedExceeded"},{"warning":"Overheating","raised":false}]}"#,
+            r#"{"ticks":20,"actions":[{"tick":5,"script":"set(\"brake_pressure\", 80)"}]}"#,
+        ],
+    ),
+    (
+        "can_signal",
+        fuzz_can_signal,
+        &["\0\0\0\0\0\0\0\0", "\u{7f}\u{7}\0\0d\0\0\0\u{1}\u{8}\0\0", "\u{ff}\u{ff}\u{ff}\u{ff}"],
+    ),
+];
+
+/// Apply one random mutation to an input
+fn mutate(input: &mut Vec<u8>, corpus: &[&str], rng: &mut SimRng) {
+    const INTERESTING: &[u8] = b"{}[]\",:\\-+.eE0123456789 \nnutfa\xff";
+    let len = input.len() as u32;
+    match rng.below(6) {
+        // Flip a bit
+        0 if len > 0 => {
+            let pos = rng.below(len) as usize;
+            input[pos] ^= 1 << rng.below(8);
+        }
+        // Insert an interesting byte
+        1 => {
+            let pos = rng.below(len + 1) as usize;
+            let byte = INTERESTING[rng.below(INTERESTING.len() as u32) as usize];
+            input.insert(pos, byte);
+        }
+        // Delete a range
+        2 if len > 0 => {
+            let start = rng.below(len) as usize;
+            let end = (start + 1 + rng.below(8) as usize).min(input.len());
+            input.drain(start..end);
+        }
+        // Duplicate a chunk (grows nesting and lists)
+        3 if len > 0 => {
+            let start = rng.below(len) as usize;
+            let end = (start + 1 + rng.below(16) as usize).min(input.len());
+            let chunk: Vec<u8> = input[start..end].to_vec();
+            let pos = rng.below(input.len() as u32 + 1) as usize;
+            input.splice(pos..pos, chunk);
+        }
+        // Truncate
+        4 if len > 0 => input.truncate(rng.below(len) as usize),
+        // Splice in the tail of another seed
+        _ => {
+            let other = corpus[rng.below(corpus.len() as u32) as usize].as_bytes();
+            let from = rng.below(other.len() as u32 + 1) as usize;
+            let pos = rng.below(len + 1) as usize;
+            input.truncate(pos);
+            input.extend_from_slice(&other[from..]);
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// Run `iterations` mutated inputs through each selected target
+/// Returns an error describing the first crashing input of each target.
+pub fn run_fuzz(target: &str, iterations: u64, seed: u64) -> Result<(), String> {
+    let selected: Vec<_> = FUZZ_TARGETS
+        .iter()
+        .filter(|(name, _, _)| target == "all" || *name == target)
+        .collect();
+    if selected.is_empty() {
+        let names: Vec<&str> = FUZZ_TARGETS.iter().map(|(name, _, _)| *name).collect();
+        return Err(format!("Unknown fuzz target: {} (expected all|{})", target, names.join("|")));
+    }
+
+    println!("🐛 Fuzzing {} target(s), {} inputs each (seed {})\n", selected.len(), iterations, seed);
+
+    // Panics are expected findings - keep the default hook from printing them
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut crashes = Vec::new();
+    for (name, target, corpus) in selected {
+        let mut rng = SimRng::new(seed).fork(name);
+        let mut found = None;
+        for _ in 0..iterations {
+            let mut input = corpus[rng.below(corpus.len() as u32) as usize].as_bytes().to_vec();
+            for _ in 0..=rng.below(4) {
+                mutate(&mut input, corpus, &mut rng);
+            }
+            if let Err(payload) = panic::catch_unwind(|| target(&input)) {
+                found = Some((String::from_utf8_lossy(&input).into_owned(), panic_message(&*payload)));
+                break;
+            }
+        }
+
+        match found {
+            None => println!("   ✅ {:<12} no panics", name),
+            Some((input, message)) => {
+                println!("   ❌ {:<12} panicked: {}", name, message);
+                println!("      input: {:?}", input);
+                crashes.push(*name);
+            }
+        }
+    }
+
+    panic::set_hook(previous_hook);
+
+    if crashes.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("fuzz targets panicked: {}", crashes.join(", ")))
+    }
+}
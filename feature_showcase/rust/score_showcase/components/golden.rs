@@ -0,0 +1,191 @@
+//! Golden-output comparison
+//! Demonstrates regression testing against a stored reference run:
+//! - Record mode writes canonical per-tick snapshots to a golden file
+//! - Compare mode checks a run against the golden file and fails with a diff
+//!
+//! The first line of a golden file is a header with the snapshot format
+//! version; files recorded by older binaries are migrated before comparing.
+
+use std::fmt;
+use std::fs;
+
+use crate::components::json::{object, JsonValue};
+use crate::components::migration::{MigrationPipeline, SNAPSHOT_VERSION};
+use crate::components::snapshot::SystemSnapshot;
+
+/// Maximum number of differing lines shown in a diff report
+const MAX_DIFF_LINES: usize = 10;
+
+/// Golden file mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoldenMode {
+    Off,
+    Record,
+    Compare,
+}
+
+impl GoldenMode {
+    pub fn parse(s: &str) -> Result<GoldenMode, String> {
+        match s.to_lowercase().as_str() {
+            "off" => Ok(GoldenMode::Off),
+            "record" => Ok(GoldenMode::Record),
+            "compare" => Ok(GoldenMode::Compare),
+            _ => Err(format!("Invalid golden mode: {} (expected off|record|compare)", s)),
+        }
+    }
+}
+
+impl fmt::Display for GoldenMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GoldenMode::Off => write!(f, "off"),
+            GoldenMode::Record => write!(f, "record"),
+            GoldenMode::Compare => write!(f, "compare"),
+        }
+    }
+}
+
+/// Collects canonical snapshots during a run and records/compares them
+pub struct GoldenRecorder {
+    mode: GoldenMode,
+    path: String,
+    lines: Vec<String>,
+}
+
+impl GoldenRecorder {
+    /// Create a recorder for the given mode and golden file
+    pub fn new(mode: GoldenMode, path: &str) -> Self {
+        Self {
+            mode,
+            path: path.to_string(),
+            lines: Vec::new(),
+        }
+    }
+
+    pub fn mode(&self) -> GoldenMode {
+        self.mode
+    }
+
+    /// Add the snapshot of one tick
+    pub fn record(&mut self, snapshot: &SystemSnapshot) {
+        if self.mode != GoldenMode::Off {
+            self.lines.push(snapshot.to_canonical());
+        }
+    }
+
+    /// Write the golden file (record mode) or compare against it (compare mode)
+    pub fn finish(&self) -> Result<(), String> {
+        match self.mode {
+            GoldenMode::Off => Ok(()),
+            GoldenMode::Record => {
+                let header = object([
+                    ("format", JsonValue::from("snapshot")),
+                    ("version", JsonValue::from(SNAPSHOT_VERSION)),
+                ]);
+                let mut text = header.to_string();
+                for line in &self.lines {
+                    text.push('\n');
+                    text.push_str(line);
+                }
+                text.push('\n');
+                fs::write(&self.path, text)
+                    .map_err(|e| format!("Cannot write golden file '{}': {}", self.path, e))?;
+                println!("📼 Golden file recorded: {} ({} ticks)", self.path, self.lines.len());
+                Ok(())
+            }
+            GoldenMode::Compare => {
+                let text = fs::read_to_string(&self.path)
+                    .map_err(|e| format!("Cannot read golden file '{}': {}", self.path, e))?;
+                let expected = read_golden_lines(&text).map_err(|e| format!("{}: {}", self.path, e))?;
+                let expected: Vec<&str> = expected.iter().map(String::as_str).collect();
+                let actual: Vec<&str> = self.lines.iter().map(String::as_str).collect();
+
+                let diff = diff_lines(&expected, &actual);
+                if diff.is_empty() {
+                    println!("✅ Golden comparison passed: {} ticks match {}", actual.len(), self.path);
+                    Ok(())
+                } else {
+                    println!("❌ Golden comparison failed against {}:", self.path);
+                    print!("{}", diff);
+                    Err(format!("Golden comparison failed against {}", self.path))
+                }
+            }
+        }
+    }
+}
+
+/// Snapshot lines of a golden file, migrated to the current format
+pub fn read_golden_lines(text: &str) -> Result<Vec<String>, String> {
+    let mut lines = text.lines().peekable();
+
+    // Files without a header were written before versioning (v1)
+    let mut version = 1;
+    if let Some(first) = lines.peek() {
+        if let Ok(header) = JsonValue::parse(first) {
+            if header.get("format").and_then(JsonValue::as_str) == Some("snapshot") {
+                version = header
+                    .get("version")
+                    .and_then(JsonValue::as_u64)
+                    .ok_or("golden header has no valid version")?;
+                lines.next();
+            }
+        }
+    }
+
+    if version == SNAPSHOT_VERSION {
+        return Ok(lines.map(str::to_string).collect());
+    }
+
+    let pipeline = MigrationPipeline::snapshot();
+    let mut migrated = Vec::new();
+    let mut steps = Vec::new();
+    for (index, line) in lines.enumerate() {
+        let json = JsonValue::parse(line).map_err(|e| format!("line {}: {}", index + 2, e))?;
+        let (json, applied) = pipeline.migrate(version, json)?;
+        steps = applied;
+        migrated.push(json.to_string());
+    }
+    for step in &steps {
+        println!("🔄 Migrated golden file snapshots ({})", step);
+    }
+    Ok(migrated)
+}
+
+/// Line-by-line diff report (empty when identical)
+pub fn diff_lines(expected: &[&str], actual: &[&str]) -> String {
+    let mut report = String::new();
+    let mut shown = 0;
+    let mut total = 0;
+
+    for i in 0..expected.len().max(actual.len()) {
+        let want = expected.get(i).copied();
+        let got = actual.get(i).copied();
+        if want == got {
+            continue;
+        }
+
+        total += 1;
+        if shown < MAX_DIFF_LINES {
+            report.push_str(&format!("@@ line {} @@\n", i + 1));
+            if let Some(line) = want {
+                report.push_str(&format!("- {}\n", line));
+            }
+            if let Some(line) = got {
+                report.push_str(&format!("+ {}\n", line));
+            }
+            shown += 1;
+        }
+    }
+
+    if total > shown {
+        report.push_str(&format!("... {} more differing lines\n", total - shown));
+    }
+    if expected.len() != actual.len() {
+        report.push_str(&format!(
+            "expected {} ticks, got {}\n",
+            expected.len(),
+            actual.len()
+        ));
+    }
+    report
+}
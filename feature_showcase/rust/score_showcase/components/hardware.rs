@@ -0,0 +1,237 @@
+//! Hardware I/O abstraction for hardware-in-the-loop (HIL) testing
+//! Demonstrates S-CORE hardware abstraction patterns:
+//! - Control logic reads sensors and drives actuators through one trait
+//! - The simulator is the default backend
+//! - A serial/GPIO backend points the same logic at bench hardware
+//!
+//! The serial/GPIO backend is a stub: it writes actuator commands as text
+//! frames (`BRK 50`, `STR -30`, `GPIO 17 1`) to the configured port and
+//! loops sensor reads back from the simulation until a real transport and
+//! sensor protocol are wired up.
+
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+
+use crate::components::config::HardwareConfig;
+use crate::components::fixedpoint::TEMPERATURE;
+
+/// Available hardware backends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HardwareBackend {
+    Simulator,
+    Serial,
+}
+
+impl HardwareBackend {
+    pub fn parse(s: &str) -> Result<HardwareBackend, String> {
+        match s.to_lowercase().as_str() {
+            "simulator" | "sim" => Ok(HardwareBackend::Simulator),
+            "serial" => Ok(HardwareBackend::Serial),
+            _ => Err(format!("Invalid hardware backend: {} (expected simulator|serial)", s)),
+        }
+    }
+}
+
+impl fmt::Display for HardwareBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HardwareBackend::Simulator => write!(f, "simulator"),
+            HardwareBackend::Serial => write!(f, "serial"),
+        }
+    }
+}
+
+/// One reading of all sensors
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SensorFrame {
+    pub speed: u8,
+    pub temperature: f32,
+    pub rpm: u32,
+    pub fuel_level: u8,
+}
+
+impl SensorFrame {
+    /// Round physical signals to their CAN resolution
+    pub fn quantized(&self) -> SensorFrame {
+        SensorFrame {
+            temperature: TEMPERATURE.quantize(self.temperature),
+            ..*self
+        }
+    }
+}
+
+/// Sensor inputs and actuator outputs of the vehicle
+pub trait HardwareIo {
+    /// Backend name for logs
+    fn name(&self) -> String;
+
+    /// Receive the simulated plant state of this tick
+    /// Backends reading real sensors ignore it.
+    fn update_simulation(&mut self, _frame: &SensorFrame) {}
+
+    fn read_speed(&mut self) -> Result<u8, String>;
+    fn read_temperature(&mut self) -> Result<f32, String>;
+    fn read_rpm(&mut self) -> Result<u32, String>;
+    fn read_fuel_level(&mut self) -> Result<u8, String>;
+
+    /// Drive the brake actuator (pressure in %)
+    fn set_brake_output(&mut self, pressure: u8) -> Result<(), String>;
+
+    /// Drive the steering actuator (angle in degrees)
+    fn set_steering_output(&mut self, angle: i16) -> Result<(), String>;
+
+    /// Read all sensors at once
+    fn read_sensors(&mut self) -> Result<SensorFrame, String> {
+        Ok(SensorFrame {
+            speed: self.read_speed()?,
+            temperature: self.read_temperature()?,
+            rpm: self.read_rpm()?,
+            fuel_level: self.read_fuel_level()?,
+        })
+    }
+}
+
+/// Default backend: sensors return the simulated plant state
+#[derive(Debug, Default)]
+pub struct SimulatorIo {
+    frame: SensorFrame,
+    brake_output: u8,
+    steering_output: i16,
+}
+
+impl SimulatorIo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Last commanded brake pressure
+    pub fn brake_output(&self) -> u8 {
+        self.brake_output
+    }
+
+    /// Last commanded steering angle
+    pub fn steering_output(&self) -> i16 {
+        self.steering_output
+    }
+}
+
+impl HardwareIo for SimulatorIo {
+    fn name(&self) -> String {
+        "simulator".to_string()
+    }
+
+    fn update_simulation(&mut self, frame: &SensorFrame) {
+        self.frame = *frame;
+    }
+
+    fn read_speed(&mut self) -> Result<u8, String> {
+        Ok(self.frame.speed)
+    }
+
+    fn read_temperature(&mut self) -> Result<f32, String> {
+        Ok(self.frame.temperature)
+    }
+
+    fn read_rpm(&mut self) -> Result<u32, String> {
+        Ok(self.frame.rpm)
+    }
+
+    fn read_fuel_level(&mut self) -> Result<u8, String> {
+        Ok(self.frame.fuel_level)
+    }
+
+    fn set_brake_output(&mut self, pressure: u8) -> Result<(), String> {
+        self.brake_output = pressure;
+        Ok(())
+    }
+
+    fn set_steering_output(&mut self, angle: i16) -> Result<(), String> {
+        self.steering_output = angle;
+        Ok(())
+    }
+}
+
+/// Stub bench-hardware backend over a serial port with a GPIO brake light
+pub struct SerialGpioIo {
+    port: String,
+    brake_light_pin: u8,
+    writer: File,
+    /// Sensor values looped back from the simulation
+    loopback: SensorFrame,
+    frames_sent: u64,
+}
+
+impl SerialGpioIo {
+    /// Open the serial port for writing
+    /// The port must already exist (device node, pipe or file).
+    pub fn open(port: &str, brake_light_pin: u8) -> Result<Self, String> {
+        let writer = OpenOptions::new()
+            .append(true)
+            .open(port)
+            .map_err(|e| format!("Cannot open serial port '{}': {}", port, e))?;
+        Ok(Self {
+            port: port.to_string(),
+            brake_light_pin,
+            writer,
+            loopback: SensorFrame::default(),
+            frames_sent: 0,
+        })
+    }
+
+    /// Number of command frames written to the port
+    pub fn frames_sent(&self) -> u64 {
+        self.frames_sent
+    }
+
+    fn send(&mut self, frame: &str) -> Result<(), String> {
+        writeln!(self.writer, "{}", frame)
+            .map_err(|e| format!("Serial write to '{}' failed: {}", self.port, e))?;
+        self.frames_sent += 1;
+        Ok(())
+    }
+}
+
+impl HardwareIo for SerialGpioIo {
+    fn name(&self) -> String {
+        format!("serial ({}, brake light GPIO {})", self.port, self.brake_light_pin)
+    }
+
+    fn update_simulation(&mut self, frame: &SensorFrame) {
+        self.loopback = *frame;
+    }
+
+    fn read_speed(&mut self) -> Result<u8, String> {
+        Ok(self.loopback.speed)
+    }
+
+    fn read_temperature(&mut self) -> Result<f32, String> {
+        Ok(self.loopback.temperature)
+    }
+
+    fn read_rpm(&mut self) -> Result<u32, String> {
+        Ok(self.loopback.rpm)
+    }
+
+    fn read_fuel_level(&mut self) -> Result<u8, String> {
+        Ok(self.loopback.fuel_level)
+    }
+
+    fn set_brake_output(&mut self, pressure: u8) -> Result<(), String> {
+        self.send(&format!("BRK {}", pressure))?;
+        let pin = self.brake_light_pin;
+        self.send(&format!("GPIO {} {}", pin, u8::from(pressure > 0)))
+    }
+
+    fn set_steering_output(&mut self, angle: i16) -> Result<(), String> {
+        self.send(&format!("STR {}", angle))
+    }
+}
+
+/// Create the backend selected in the configuration
+pub fn open_backend(config: &HardwareConfig) -> Result<Box<dyn HardwareIo>, String> {
+    match config.backend {
+        HardwareBackend::Simulator => Ok(Box::new(SimulatorIo::new())),
+        HardwareBackend::Serial => Ok(Box::new(SerialGpioIo::open(&config.port, config.brake_light_pin)?)),
+    }
+}
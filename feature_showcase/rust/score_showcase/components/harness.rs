@@ -0,0 +1,122 @@
+//! Headless test harness
+//! Drives a complete CarSystem tick by tick without an event loop, so
+//! integration tests can run full scenarios deterministically - no sleeping
+//! and no parsing of stdout.
+//!
+//! ```ignore
+//! let mut harness = TestHarness::new_system_with_sim_clock()?;
+//! harness.start_car()?;
+//! harness.tick_n(40)?;
+//! assert!(harness.last_snapshot().unwrap().speed <= 130);
+//! ```
+
+use std::sync::Arc;
+
+use crate::components::config::SystemConfig;
+use crate::components::event_loop::ClockSource;
+use crate::components::messages::{CarMessage, ComponentId};
+use crate::components::profile::Profile;
+use crate::components::safety::SafetyWarning;
+use crate::components::snapshot::SystemSnapshot;
+use crate::components::system::CarSystem;
+
+/// Make a configuration headless and side-effect free: simulated clock, no
+/// rendering, persistency, recording, golden files, hardware or reloading
+pub fn headless_config(mut config: SystemConfig) -> SystemConfig {
+    config.event_loop.clock = ClockSource::Simulated;
+    config.render = false;
+    config.persistency.path.clear();
+    config.blackbox.path.clear();
+    config.golden = Default::default();
+    config.hardware = Default::default();
+    config.config_file = None;
+    config.reload_poll_ticks = 0;
+    config.tool_command = None;
+    config
+}
+
+/// Deterministic driver for a full car system
+pub struct TestHarness {
+    pub system: CarSystem,
+    tick: u64,
+    /// Shared per-tick snapshots (kept alive, so each tick copies on write)
+    snapshots: Vec<Arc<SystemSnapshot>>,
+}
+
+impl TestHarness {
+    /// Create an initialized system using the `test` profile
+    /// (simulated clock, no rendering, in-memory persistency)
+    pub fn new_system_with_sim_clock() -> Result<Self, String> {
+        let mut config = SystemConfig::default();
+        config.apply_profile(Profile::Test)?;
+        Self::with_config(config)
+    }
+
+    /// Create an initialized test-profile system with a specific seed
+    pub fn with_seed(seed: u64) -> Result<Self, String> {
+        let mut config = SystemConfig::default();
+        config.apply_profile(Profile::Test)?;
+        config.seed = Some(seed);
+        Self::with_config(config)
+    }
+
+    /// Create an initialized system from an explicit configuration
+    pub fn with_config(config: SystemConfig) -> Result<Self, String> {
+        config.validate()?;
+        let mut system = CarSystem::with_config(config);
+        system.initialize()?;
+
+        Ok(Self {
+            system,
+            tick: 0,
+            snapshots: Vec::new(),
+        })
+    }
+
+    /// Run the "Start Car" workflow
+    pub fn start_car(&mut self) -> Result<(), String> {
+        CarSystem::create_start_workflow().execute(&mut self.system).result()
+    }
+
+    /// Advance the simulation by one tick
+    pub fn tick(&mut self) -> Result<&SystemSnapshot, String> {
+        let snapshot = self.system.tick(self.tick)?;
+        self.tick += 1;
+        self.snapshots.push(snapshot);
+        Ok(&self.snapshots[self.snapshots.len() - 1])
+    }
+
+    /// Advance the simulation by `n` ticks
+    pub fn tick_n(&mut self, n: u64) -> Result<(), String> {
+        for _ in 0..n {
+            self.tick()?;
+        }
+        Ok(())
+    }
+
+    /// Number of ticks run so far
+    pub fn current_tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Publish a message on the bus as if it came from outside the system
+    /// It is delivered during the next tick.
+    pub fn inject(&mut self, message: CarMessage) {
+        self.system.message_bus.publish(ComponentId::EXTERNAL, message);
+    }
+
+    /// Snapshot of the most recent tick
+    pub fn last_snapshot(&self) -> Option<&SystemSnapshot> {
+        self.snapshots.last().map(|snapshot| &**snapshot)
+    }
+
+    /// All snapshots recorded so far, one per tick
+    pub fn snapshots(&self) -> &[Arc<SystemSnapshot>] {
+        &self.snapshots
+    }
+
+    /// Warnings from the most recent safety check
+    pub fn warnings(&self) -> &[SafetyWarning] {
+        self.system.last_warnings()
+    }
+}
@@ -0,0 +1,99 @@
+//! Bounded signal history
+//! Demonstrates S-CORE deterministic memory patterns:
+//! - Capacity fixed at compile time through a const generic
+//! - Storage inline in the owner, no allocation after construction
+//! - O(1) push that overwrites the oldest sample when full
+//!
+//! Used for the dashboard speed sparkline; meant for any "last N samples"
+//! signal history (rate-of-change checks, transition logs).
+
+/// The last `N` values pushed, oldest first
+#[derive(Debug, Clone)]
+pub struct History<T: Copy + Default, const N: usize> {
+    items: [T; N],
+    /// Index of the oldest value
+    start: usize,
+    len: usize,
+}
+
+impl<T: Copy + Default, const N: usize> History<T, N> {
+    pub fn new() -> Self {
+        assert!(N > 0, "History capacity must be greater than 0");
+        Self {
+            items: [T::default(); N],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Append a value
+    /// Returns the value that was dropped to make room, if any.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if self.len < N {
+            self.items[(self.start + self.len) % N] = value;
+            self.len += 1;
+            None
+        } else {
+            let evicted = std::mem::replace(&mut self.items[self.start], value);
+            self.start = (self.start + 1) % N;
+            Some(evicted)
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Value `index` steps after the oldest one
+    pub fn get(&self, index: usize) -> Option<T> {
+        (index < self.len).then(|| self.items[(self.start + index) % N])
+    }
+
+    /// Most recently pushed value
+    pub fn latest(&self) -> Option<T> {
+        self.len.checked_sub(1).and_then(|last| self.get(last))
+    }
+
+    pub fn oldest(&self) -> Option<T> {
+        self.get(0)
+    }
+
+    /// The values as two slices, oldest first
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let end = self.start + self.len;
+        if end <= N {
+            (&self.items[self.start..end], &[])
+        } else {
+            (&self.items[self.start..], &self.items[..end - N])
+        }
+    }
+
+    /// Iterate oldest to newest
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        let (older, newer) = self.as_slices();
+        older.iter().chain(newer)
+    }
+
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+}
+
+impl<T: Copy + Default, const N: usize> Default for History<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,197 @@
+//! HVAC component - cabin climate control
+//! Demonstrates S-CORE patterns:
+//! - Physical model: cabin temperature drifting towards ambient, pulled
+//!   towards the target by the fan and the AC compressor
+//! - Inter-component coupling through the message bus: the HVAC publishes
+//!   its electrical load (`HvacLoad`); the engine subscribes to that type
+//!   only and raises its idle speed, the dashboard burns more fuel. No
+//!   component reads another one's fields.
+//! - Runtime reconfiguration: a reloaded `hvac.target_c` arrives as a
+//!   `ConfigChanged` message
+
+use crate::components::{CarComponent, ComponentState, CarMessage, HealthStatus};
+use crate::components::event_loop::DEFAULT_TIME_STEP_S;
+use crate::components::units::display_units;
+use crate::log_info;
+
+/// Highest fan speed step
+pub const MAX_FAN_SPEED: u8 = 5;
+
+/// Cabin temperature change per second and fan step while cooling or
+/// heating, in °C
+const CONDITIONING_C_PER_S_PER_STEP: f32 = 0.1;
+
+/// Share of the cabin-to-ambient difference leaking in per second
+const LEAK_PER_S: f32 = 0.01;
+
+/// Distance from the target that switches the AC on, in °C
+const AC_ON_ABOVE_C: f32 = 1.0;
+
+/// Distance from the target within which fan and AC stop, in °C
+const COMFORT_BAND_C: f32 = 0.5;
+
+/// Electrical power of the AC compressor, in W
+const COMPRESSOR_W: u16 = 1500;
+
+/// Electrical power per fan step, in W
+const FAN_W_PER_STEP: u16 = 60;
+
+/// HVAC component - keeps the cabin at the target temperature
+pub struct HvacComponent {
+    state: ComponentState,
+    cabin_temperature: f32,
+    target_temperature: f32,
+    ambient_temperature: f32,
+    /// 0 (off) to `MAX_FAN_SPEED`
+    fan_speed: u8,
+    ac_on: bool,
+    /// Simulated seconds per process() call
+    dt_s: f32,
+}
+
+impl HvacComponent {
+    /// Create a climate control; the cabin starts at ambient temperature
+    pub fn new(target_temperature: f32, ambient_temperature: f32) -> Self {
+        Self {
+            state: ComponentState::Offline,
+            cabin_temperature: ambient_temperature,
+            target_temperature,
+            ambient_temperature,
+            fan_speed: 0,
+            ac_on: false,
+            dt_s: DEFAULT_TIME_STEP_S,
+        }
+    }
+
+    /// Set the simulated time covered by each process() call
+    pub fn set_time_step(&mut self, dt_s: f32) {
+        self.dt_s = dt_s;
+    }
+
+    pub fn set_target_temperature(&mut self, celsius: f32) {
+        self.target_temperature = celsius;
+    }
+
+    pub fn get_target_temperature(&self) -> f32 {
+        self.target_temperature
+    }
+
+    pub fn get_cabin_temperature(&self) -> f32 {
+        self.cabin_temperature
+    }
+
+    pub fn get_fan_speed(&self) -> u8 {
+        self.fan_speed
+    }
+
+    pub fn is_ac_on(&self) -> bool {
+        self.ac_on
+    }
+
+    /// Electrical power drawn by fan and compressor, in W
+    pub fn get_load(&self) -> u16 {
+        self.fan_speed as u16 * FAN_W_PER_STEP + if self.ac_on { COMPRESSOR_W } else { 0 }
+    }
+
+    /// Process incoming messages (reloaded target temperature)
+    pub fn process_messages(&mut self, messages: &[CarMessage]) {
+        for msg in messages {
+            if let CarMessage::ConfigChanged { key, value } = msg {
+                if key == "hvac.target_c" {
+                    if let Ok(celsius) = value.parse() {
+                        self.set_target_temperature(celsius);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Get messages to publish (Phase 3: Communication)
+    pub fn get_messages(&self) -> Vec<CarMessage> {
+        let mut messages = Vec::new();
+        self.get_messages_into(&mut messages);
+        messages
+    }
+
+    /// Append messages to publish to a reused buffer
+    /// The load is reported every cycle, so a lost message is corrected
+    /// by the next one.
+    pub fn get_messages_into(&self, messages: &mut Vec<CarMessage>) {
+        if self.state == ComponentState::Online {
+            messages.push(CarMessage::HvacLoad { watts: self.get_load() });
+        }
+    }
+
+    /// Pick fan speed and AC state for the current cabin temperature
+    fn control(&mut self) {
+        let error = self.cabin_temperature - self.target_temperature;
+        if error > AC_ON_ABOVE_C {
+            self.ac_on = true;
+        } else if error <= COMFORT_BAND_C {
+            self.ac_on = false;
+        }
+        self.fan_speed = if error.abs() <= COMFORT_BAND_C {
+            0
+        } else {
+            (error.abs().ceil() as u8).min(MAX_FAN_SPEED)
+        };
+    }
+}
+
+impl CarComponent for HvacComponent {
+    fn name(&self) -> &'static str {
+        "HVAC"
+    }
+
+    fn initialize(&mut self) -> Result<(), String> {
+        log_info!("HVAC", "🔧 HVAC: Initializing component...");
+        self.state = ComponentState::Initializing;
+
+        log_info!("HVAC", "  🔍 HVAC: Testing blower motor... OK");
+        log_info!("HVAC", "  🔍 HVAC: Checking refrigerant pressure... OK");
+        let units = display_units();
+        log_info!("HVAC", "  🌡️  HVAC: Cabin {:.1}{}, target {:.1}{}", units.temperature(self.cabin_temperature),
+                  units.temperature_unit(), units.temperature(self.target_temperature), units.temperature_unit());
+
+        self.state = ComponentState::Online;
+        log_info!("HVAC", "✅ HVAC: Initialized (state: {})", self.state);
+        Ok(())
+    }
+
+    fn process(&mut self) -> Result<(), String> {
+        self.control();
+
+        // Cooling needs the compressor; heating uses engine waste heat
+        let error = self.cabin_temperature - self.target_temperature;
+        let conditioning = if error > 0.0 && !self.ac_on {
+            0.0
+        } else {
+            -error.signum() * CONDITIONING_C_PER_S_PER_STEP * self.fan_speed as f32
+        };
+        let leak = (self.ambient_temperature - self.cabin_temperature) * LEAK_PER_S;
+        self.cabin_temperature += (conditioning + leak) * self.dt_s;
+        Ok(())
+    }
+
+    fn get_state(&self) -> ComponentState {
+        self.state.clone()
+    }
+
+    fn shutdown(&mut self) -> Result<(), String> {
+        log_info!("HVAC", "🔧 HVAC: Shutting down...");
+        self.fan_speed = 0;
+        self.ac_on = false;
+        self.state = ComponentState::Offline;
+        let units = display_units();
+        log_info!("HVAC", "✅ HVAC: Shut down at cabin {:.1}{} (state: {})", units.temperature(self.cabin_temperature),
+                  units.temperature_unit(), self.state);
+        Ok(())
+    }
+
+    fn health_check(&self) -> HealthStatus {
+        if !self.cabin_temperature.is_finite() {
+            return HealthStatus::Unhealthy("cabin temperature reading is not a number");
+        }
+        HealthStatus::from_state(&self.state)
+    }
+}
@@ -0,0 +1,255 @@
+//! Location component - dead-reckoning odometry along a route
+//! Demonstrates S-CORE patterns:
+//! - Sensor fusion light: speed and steering angle integrated into an
+//!   (x, y, heading) pose with a kinematic bicycle model
+//! - Route data kept in a file and loaded at initialization
+//! - Message publishing: `PositionUpdate` with the distance left to the
+//!   destination, shown by the dashboard
+//!
+//! Coordinates are meters east (x) and north (y) of the start; heading is
+//! a compass heading in degrees (0 = north, 90 = east).
+//!
+//! ```json
+//! { "waypoints": [[0, 400], [300, 700], [300, 1500]] }
+//! ```
+//!
+//! A waypoint counts as reached within `WAYPOINT_RADIUS_M`; the distance
+//! to the destination is the straight line to the next waypoint plus the
+//! rest of the route.
+
+use std::f32::consts::PI;
+use std::fs;
+
+use crate::components::{CarComponent, ComponentState, CarMessage, HealthStatus};
+use crate::components::event_loop::DEFAULT_TIME_STEP_S;
+use crate::components::json::JsonValue;
+use crate::components::units::display_units;
+use crate::log_info;
+
+/// Distance between front and rear axle, in m
+const WHEELBASE_M: f32 = 2.7;
+
+/// Steering wheel degrees per degree of road wheel angle
+const STEERING_RATIO: f32 = 15.0;
+
+/// A waypoint closer than this counts as reached, in m
+pub const WAYPOINT_RADIUS_M: f32 = 25.0;
+
+/// Position and heading of the car
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Pose {
+    pub x: f32,
+    pub y: f32,
+    /// Compass heading in degrees, 0 to 360
+    pub heading: f32,
+}
+
+impl Pose {
+    fn distance_to(&self, (x, y): (f32, f32)) -> f32 {
+        (x - self.x).hypot(y - self.y)
+    }
+}
+
+/// Waypoints to drive through, the last one being the destination
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Route {
+    pub waypoints: Vec<(f32, f32)>,
+}
+
+impl Route {
+    /// Read a route from a JSON object with a `waypoints` array of [x, y]
+    pub fn from_json(json: &JsonValue) -> Result<Route, String> {
+        let waypoints = json
+            .get("waypoints")
+            .and_then(JsonValue::as_array)
+            .ok_or("route needs a 'waypoints' array")?;
+        let mut route = Route::default();
+        for (index, waypoint) in waypoints.iter().enumerate() {
+            let point = match waypoint.as_array().map(Vec::as_slice) {
+                Some([x, y]) => x.as_f64().zip(y.as_f64()),
+                _ => None,
+            };
+            let (x, y) = point.ok_or_else(|| format!("waypoint {} must be [x, y]", index))?;
+            route.waypoints.push((x as f32, y as f32));
+        }
+        if route.waypoints.is_empty() {
+            return Err("route has no waypoints".to_string());
+        }
+        Ok(route)
+    }
+
+    /// Load a route from a JSON file
+    pub fn load(path: &str) -> Result<Route, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("Cannot read route '{}': {}", path, e))?;
+        JsonValue::parse(&text)
+            .and_then(|json| Self::from_json(&json))
+            .map_err(|e| format!("{}: {}", path, e))
+    }
+}
+
+/// Location component - tracks where the car is
+pub struct LocationComponent {
+    state: ComponentState,
+    pose: Pose,
+    speed: u8,
+    steering_angle: i16,
+    /// Route file loaded at initialization (empty = none)
+    route_file: String,
+    route: Route,
+    /// Index of the next waypoint to reach
+    next_waypoint: usize,
+    /// Simulated seconds per process() call
+    dt_s: f32,
+}
+
+impl LocationComponent {
+    /// Create a location component at the origin, heading north
+    pub fn new() -> Self {
+        Self {
+            state: ComponentState::Offline,
+            pose: Pose::default(),
+            speed: 0,
+            steering_angle: 0,
+            route_file: String::new(),
+            route: Route::default(),
+            next_waypoint: 0,
+            dt_s: DEFAULT_TIME_STEP_S,
+        }
+    }
+
+    /// Load this route file at initialization
+    pub fn set_route_file(&mut self, path: &str) {
+        self.route_file = path.to_string();
+    }
+
+    /// Drive along a route from its first waypoint
+    pub fn set_route(&mut self, route: Route) {
+        self.route = route;
+        self.next_waypoint = 0;
+    }
+
+    pub fn route(&self) -> &Route {
+        &self.route
+    }
+
+    /// Set the simulated time covered by each process() call
+    pub fn set_time_step(&mut self, dt_s: f32) {
+        self.dt_s = dt_s;
+    }
+
+    /// Set the speed (km/h) and steering wheel angle (degrees, positive =
+    /// right) to integrate in the next cycle
+    pub fn set_motion(&mut self, km_h: u8, steering_angle: i16) {
+        self.speed = km_h;
+        self.steering_angle = steering_angle;
+    }
+
+    pub fn pose(&self) -> Pose {
+        self.pose
+    }
+
+    /// Remaining distance along the route, in m (None = no route)
+    pub fn distance_to_destination(&self) -> Option<f32> {
+        if self.route.waypoints.is_empty() {
+            return None;
+        }
+        let remaining = &self.route.waypoints[self.next_waypoint.min(self.route.waypoints.len())..];
+        let Some(&next) = remaining.first() else {
+            return Some(0.0);
+        };
+        let rest: f32 = remaining.windows(2).map(|leg| (leg[1].0 - leg[0].0).hypot(leg[1].1 - leg[0].1)).sum();
+        Some(self.pose.distance_to(next) + rest)
+    }
+
+    /// Get messages to publish (Phase 3: Communication)
+    pub fn get_messages(&self) -> Vec<CarMessage> {
+        let mut messages = Vec::new();
+        self.get_messages_into(&mut messages);
+        messages
+    }
+
+    /// Append messages to publish to a reused buffer
+    pub fn get_messages_into(&self, messages: &mut Vec<CarMessage>) {
+        if self.state == ComponentState::Online {
+            messages.push(CarMessage::PositionUpdate {
+                x: self.pose.x,
+                y: self.pose.y,
+                heading: self.pose.heading,
+                to_destination: self.distance_to_destination(),
+            });
+        }
+    }
+}
+
+impl Default for LocationComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CarComponent for LocationComponent {
+    fn name(&self) -> &'static str {
+        "Location"
+    }
+
+    fn initialize(&mut self) -> Result<(), String> {
+        log_info!("Location", "🔧 Location: Initializing component...");
+        self.state = ComponentState::Initializing;
+
+        if !self.route_file.is_empty() {
+            let route = Route::load(&self.route_file)?;
+            self.set_route(route);
+        }
+        log_info!("Location", "  🔍 Location: Acquiring satellites... OK");
+        if let Some(distance) = self.distance_to_destination() {
+            let units = display_units();
+            log_info!("Location", "  🗺️  Location: Route with {} waypoints, {:.2} {} to destination",
+                     self.route.waypoints.len(), units.distance(distance / 1000.0), units.distance_unit());
+        }
+
+        self.state = ComponentState::Online;
+        log_info!("Location", "✅ Location: Initialized (state: {})", self.state);
+        Ok(())
+    }
+
+    fn process(&mut self) -> Result<(), String> {
+        // Kinematic bicycle model: the yaw rate follows from speed and road wheel angle
+        let speed_m_s = self.speed as f32 / 3.6;
+        let wheel_angle = (self.steering_angle as f32 / STEERING_RATIO).to_radians();
+        let heading = self.pose.heading.to_radians() + speed_m_s * wheel_angle.tan() / WHEELBASE_M * self.dt_s;
+        self.pose.heading = heading.rem_euclid(2.0 * PI).to_degrees();
+        self.pose.x += speed_m_s * heading.sin() * self.dt_s;
+        self.pose.y += speed_m_s * heading.cos() * self.dt_s;
+
+        while let Some(&waypoint) = self.route.waypoints.get(self.next_waypoint) {
+            if self.pose.distance_to(waypoint) > WAYPOINT_RADIUS_M {
+                break;
+            }
+            self.next_waypoint += 1;
+            if self.next_waypoint == self.route.waypoints.len() {
+                log_info!("Location", "  🏁 Location: Destination reached");
+            } else {
+                log_info!("Location", "  📍 Location: Waypoint {}/{} reached", self.next_waypoint, self.route.waypoints.len());
+            }
+        }
+        Ok(())
+    }
+
+    fn get_state(&self) -> ComponentState {
+        self.state.clone()
+    }
+
+    fn shutdown(&mut self) -> Result<(), String> {
+        log_info!("Location", "🔧 Location: Shutting down...");
+        self.state = ComponentState::Offline;
+        log_info!("Location", "✅ Location: Shut down at ({:.0} m, {:.0} m) (state: {})", self.pose.x, self.pose.y, self.state);
+        Ok(())
+    }
+
+    fn health_check(&self) -> HealthStatus {
+        if !(self.pose.x.is_finite() && self.pose.y.is_finite() && self.pose.heading.is_finite()) {
+            return HealthStatus::Unhealthy("pose is not a number");
+        }
+        HealthStatus::from_state(&self.state)
+    }
+}
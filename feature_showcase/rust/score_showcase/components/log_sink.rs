@@ -0,0 +1,116 @@
+//! Log sinks - where log entries go besides the console
+//! Demonstrates S-CORE logging patterns:
+//! - Sink abstraction: the logger hands every entry that passes its filter
+//!   to the registered sinks
+//! - JSON Lines file sink: one object per entry (timestamp, level,
+//!   component, message, structured fields) that tools can parse line by line
+//! - Size-based rotation: `<file>` is renamed to `<file>.1` (older files
+//!   move up, the oldest is dropped) once the next entry would exceed the limit
+//!
+//! ```json
+//! {"component":"Brakes","fields":{"pressure":80},"level":"INFO","message":"🛞 Brakes: Applied at 80% pressure","timestamp_ms":1760000000000}
+//! ```
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{LineWriter, Write};
+use std::path::Path;
+
+use crate::components::logging::LogEntry;
+
+/// Destination for log entries
+pub trait LogSink: Send {
+    fn write(&mut self, entry: &LogEntry) -> Result<(), String>;
+
+    /// Make everything written so far durable
+    fn flush(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Appends entries to a JSON Lines file and rotates it by size
+pub struct JsonLinesSink {
+    path: String,
+    file: LineWriter<File>,
+    /// Bytes in the current file
+    size: u64,
+    max_bytes: u64,
+    /// Rotated files kept; 0 = truncate instead of rotating
+    keep: u32,
+}
+
+impl JsonLinesSink {
+    /// Append to `path`, rotating once it would grow beyond `max_bytes`
+    pub fn create(path: &str, max_bytes: u64, keep: u32) -> Result<Self, String> {
+        let file = open_append(path)?;
+        let size = file
+            .metadata()
+            .map_err(|e| format!("Cannot read log file '{}': {}", path, e))?
+            .len();
+        Ok(Self {
+            path: path.to_string(),
+            file: LineWriter::new(file),
+            size,
+            max_bytes,
+            keep,
+        })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Start a new file, shifting the old ones to `.1`, `.2`, ...
+    fn rotate(&mut self) -> Result<(), String> {
+        self.file.flush().map_err(|e| format!("Cannot flush log file '{}': {}", self.path, e))?;
+        for index in (1..self.keep).rev() {
+            let from = rotated_path(&self.path, index);
+            if Path::new(&from).exists() {
+                fs::rename(&from, rotated_path(&self.path, index + 1))
+                    .map_err(|e| format!("Cannot rotate log file '{}': {}", from, e))?;
+            }
+        }
+        if self.keep > 0 {
+            fs::rename(&self.path, rotated_path(&self.path, 1))
+                .map_err(|e| format!("Cannot rotate log file '{}': {}", self.path, e))?;
+        } else {
+            fs::remove_file(&self.path).map_err(|e| format!("Cannot truncate log file '{}': {}", self.path, e))?;
+        }
+        self.file = LineWriter::new(open_append(&self.path)?);
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl LogSink for JsonLinesSink {
+    fn write(&mut self, entry: &LogEntry) -> Result<(), String> {
+        let line = format!("{}\n", entry.to_json());
+        if self.size > 0 && self.size + line.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        self.file
+            .write_all(line.as_bytes())
+            .map_err(|e| format!("Cannot write log file '{}': {}", self.path, e))?;
+        self.size += line.len() as u64;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        self.file
+            .flush()
+            .and_then(|_| self.file.get_ref().sync_all())
+            .map_err(|e| format!("Cannot flush log file '{}': {}", self.path, e))
+    }
+}
+
+/// Path of the `index`-th rotated file
+fn rotated_path(path: &str, index: u32) -> String {
+    format!("{}.{}", path, index)
+}
+
+fn open_append(path: &str) -> Result<File, String> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Cannot open log file '{}': {}", path, e))
+}
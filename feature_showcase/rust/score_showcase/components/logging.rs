@@ -0,0 +1,334 @@
+//! Structured logging
+//! Demonstrates S-CORE logging patterns:
+//! - Log entries with level, component and timestamp
+//! - Per-component loggers filtered by a minimum level
+//! - One process-wide filter shared by all car components: a global level,
+//!   per-component overrides and a quiet mode that only lets errors through
+//!
+//! Components log through the `log_*!` macros with their name:
+//!
+//! - Log sinks: entries that pass the filter also go to the registered
+//!   sinks (e.g. a JSON Lines file), with structured fields
+//!
+//! ```ignore
+//! log_info!("Engine", "🔧 Engine: Initializing component...");
+//! log_warn!("Brakes", "  🛞 Brakes: FAULT - {}", reason);
+//! log_info!("Brakes", pressure = pressure; "  🛞 Brakes: Applied at {}% pressure", pressure);
+//! ```
+//!
+//! Messages that pass the filter are printed as written, so the plain
+//! console output doesn't change with the default settings. Quiet mode only
+//! silences the console; sinks still get every entry that passes the filter.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::components::config::LogLevel;
+use crate::components::json::{object, JsonValue};
+use crate::components::log_sink::LogSink;
+
+/// Log an ERROR message for a component
+#[macro_export]
+macro_rules! log_error {
+    ($($arg:tt)+) => { $crate::score_log!(Error, $($arg)+) };
+}
+
+/// Log a WARN message for a component
+#[macro_export]
+macro_rules! log_warn {
+    ($($arg:tt)+) => { $crate::score_log!(Warn, $($arg)+) };
+}
+
+/// Log an INFO message for a component
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)+) => { $crate::score_log!(Info, $($arg)+) };
+}
+
+/// Log a DEBUG message for a component
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)+) => { $crate::score_log!(Debug, $($arg)+) };
+}
+
+/// Log a TRACE message for a component
+#[macro_export]
+macro_rules! log_trace {
+    ($($arg:tt)+) => { $crate::score_log!(Trace, $($arg)+) };
+}
+
+/// Shared body of the `log_*!` macros
+/// Fields are only converted when the entry passes the filter.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! score_log {
+    ($level:ident, $component:expr, $($key:ident = $value:expr),+ ; $($arg:tt)+) => {{
+        let level = $crate::components::LogLevel::$level;
+        if $crate::components::log_enabled(level, $component) {
+            let fields = [$((stringify!($key), $crate::components::JsonValue::from($value))),+];
+            $crate::components::write_log_fields(level, $component, &fields, format_args!($($arg)+));
+        }
+    }};
+    ($level:ident, $component:expr, $($arg:tt)+) => {
+        $crate::components::write_log($crate::components::LogLevel::$level, $component, format_args!($($arg)+))
+    };
+}
+
+/// Log settings besides the global `log_level`
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogConfig {
+    /// Per-component levels overriding the global level
+    pub components: Vec<(String, LogLevel)>,
+    /// Only print errors, whatever the levels say (headless runs)
+    pub quiet: bool,
+    /// JSON Lines file every entry is also written to (empty = none)
+    pub file: String,
+    /// Size in KB at which the log file is rotated
+    pub file_max_kb: u64,
+    /// Rotated log files kept (`<file>.1` is the newest)
+    pub file_keep: u32,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            components: Vec::new(),
+            quiet: false,
+            file: String::new(),
+            file_max_kb: 1024,
+            file_keep: 3,
+        }
+    }
+}
+
+impl LogConfig {
+    /// Parse "name:LEVEL" pairs separated by commas ("" = none)
+    pub fn parse_components(s: &str) -> Result<Vec<(String, LogLevel)>, String> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (name, level) = entry
+                    .split_once(':')
+                    .ok_or_else(|| format!("Invalid component log level '{}' (expected name:LEVEL)", entry))?;
+                Ok((name.trim().to_string(), LogLevel::parse(level.trim())?))
+            })
+            .collect()
+    }
+
+    /// Format pairs as `parse_components` reads them
+    pub fn format_components(components: &[(String, LogLevel)]) -> String {
+        let entries: Vec<String> = components.iter().map(|(name, level)| format!("{}:{}", name, level)).collect();
+        entries.join(",")
+    }
+}
+
+/// The process-wide filter behind the `log_*!` macros
+struct LogFilter {
+    level: LogLevel,
+    components: Vec<(String, LogLevel)>,
+    quiet: bool,
+    /// Console output paused while something else owns the terminal
+    paused: bool,
+}
+
+static FILTER: RwLock<LogFilter> = RwLock::new(LogFilter {
+    level: LogLevel::Info,
+    components: Vec::new(),
+    quiet: false,
+    paused: false,
+});
+
+/// Set the process-wide filter used by all components
+pub fn configure_logging(level: LogLevel, config: &LogConfig) {
+    let mut filter = FILTER.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+    filter.level = level;
+    filter.components.clone_from(&config.components);
+    filter.quiet = config.quiet;
+}
+
+/// Pause console output below ERROR while something else owns stdout
+/// (a full-screen view, JSON output); errors go to stderr meanwhile and
+/// sinks keep receiving entries
+pub fn pause_console(paused: bool) {
+    FILTER.write().unwrap_or_else(|poisoned| poisoned.into_inner()).paused = paused;
+}
+
+impl LogFilter {
+    fn passes(&self, level: LogLevel, component: &str) -> bool {
+        let min_level = self
+            .components
+            .iter()
+            .find(|(name, _)| name == component)
+            .map_or(self.level, |(_, level)| *level);
+        level <= min_level
+    }
+
+    fn prints(&self, level: LogLevel) -> bool {
+        !(self.quiet || self.paused) || level == LogLevel::Error
+    }
+}
+
+fn filter() -> std::sync::RwLockReadGuard<'static, LogFilter> {
+    FILTER.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Handle of a registered sink, used to remove it again
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogSinkId(u64);
+
+static SINKS: Mutex<Vec<(LogSinkId, Box<dyn LogSink>)>> = Mutex::new(Vec::new());
+
+/// Lets the macros skip the sink lock while no sink is registered
+static HAS_SINKS: AtomicBool = AtomicBool::new(false);
+
+static NEXT_SINK_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Send every entry that passes the filter to `sink` as well
+pub fn add_log_sink(sink: Box<dyn LogSink>) -> LogSinkId {
+    let id = LogSinkId(NEXT_SINK_ID.fetch_add(1, Ordering::Relaxed));
+    let mut sinks = SINKS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    sinks.push((id, sink));
+    HAS_SINKS.store(true, Ordering::Relaxed);
+    id
+}
+
+/// Flush and unregister a sink
+pub fn remove_log_sink(id: LogSinkId) -> Result<(), String> {
+    let mut sinks = SINKS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let Some(index) = sinks.iter().position(|(sink_id, _)| *sink_id == id) else {
+        return Ok(());
+    };
+    let (_, mut sink) = sinks.remove(index);
+    HAS_SINKS.store(!sinks.is_empty(), Ordering::Relaxed);
+    sink.flush()
+}
+
+/// Whether a message of `level` from `component` would be printed or
+/// written to a sink
+pub fn log_enabled(level: LogLevel, component: &str) -> bool {
+    let filter = filter();
+    filter.passes(level, component) && (filter.prints(level) || HAS_SINKS.load(Ordering::Relaxed))
+}
+
+/// Whether a message of `level` from `component` would be printed
+pub fn console_enabled(level: LogLevel, component: &str) -> bool {
+    let filter = filter();
+    filter.passes(level, component) && filter.prints(level)
+}
+
+/// Print a message if it passes the filter (used by the `log_*!` macros)
+pub fn write_log(level: LogLevel, component: &str, message: fmt::Arguments) {
+    write_log_fields(level, component, &[], message);
+}
+
+/// Print a message with structured fields and pass it to the sinks
+/// The console only shows the message; sinks get the fields too.
+pub fn write_log_fields(level: LogLevel, component: &str, fields: &[(&str, JsonValue)], message: fmt::Arguments) {
+    let (passes, prints, paused) = {
+        let filter = filter();
+        (filter.passes(level, component), filter.prints(level), filter.paused)
+    };
+    if !passes {
+        return;
+    }
+    if prints && paused {
+        eprintln!("{}", message);
+    } else if prints {
+        println!("{}", message);
+    }
+    if !HAS_SINKS.load(Ordering::Relaxed) {
+        return;
+    }
+    let mut entry = LogEntry::new(level, component, message.to_string().trim());
+    if entry.message.is_empty() {
+        // Blank spacer lines only make sense on the console
+        return;
+    }
+    entry.fields = fields.iter().map(|(key, value)| (key.to_string(), value.clone())).collect();
+    let mut sinks = SINKS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut failed = Vec::new();
+    for (id, sink) in sinks.iter_mut() {
+        if let Err(e) = sink.write(&entry) {
+            eprintln!("⚠️  Log sink failed, removing it: {}", e);
+            failed.push(*id);
+        }
+    }
+    sinks.retain(|(id, _)| !failed.contains(id));
+    HAS_SINKS.store(!sinks.is_empty(), Ordering::Relaxed);
+}
+
+/// S-CORE style structured log entry
+pub struct LogEntry {
+    pub level: LogLevel,
+    pub component: String,
+    pub message: String,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// Structured values attached to the message
+    pub fields: Vec<(String, JsonValue)>,
+}
+
+impl LogEntry {
+    pub fn new(level: LogLevel, component: &str, message: &str) -> Self {
+        Self {
+            level,
+            component: component.to_string(),
+            message: message.to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_millis() as u64)
+                .unwrap_or(0),
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn format(&self) -> String {
+        format!("[{:?}] {}: {}", self.level, self.component, self.message)
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        object([
+            ("timestamp_ms", JsonValue::from(self.timestamp)),
+            ("level", JsonValue::from(self.level.as_str())),
+            ("component", JsonValue::from(self.component.as_str())),
+            ("message", JsonValue::from(self.message.as_str())),
+            ("fields", JsonValue::Object(self.fields.iter().cloned().collect())),
+        ])
+    }
+}
+
+/// S-CORE style logger
+pub struct ScoreLogger {
+    min_level: LogLevel,
+    component: String,
+}
+
+impl ScoreLogger {
+    pub fn new(component: &str, min_level: LogLevel) -> Self {
+        Self {
+            min_level,
+            component: component.to_string(),
+        }
+    }
+
+    /// Print a message if `level` passes the logger's minimum level
+    pub fn log(&self, level: LogLevel, message: &str) {
+        if level <= self.min_level {
+            let entry = LogEntry::new(level, &self.component, message);
+            println!("{}", entry.format());
+        }
+    }
+
+    pub fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    pub fn error(&self, message: &str) { self.log(LogLevel::Error, message); }
+    pub fn warn(&self, message: &str) { self.log(LogLevel::Warn, message); }
+    pub fn info(&self, message: &str) { self.log(LogLevel::Info, message); }
+    pub fn debug(&self, message: &str) { self.log(LogLevel::Debug, message); }
+    pub fn trace(&self, message: &str) { self.log(LogLevel::Trace, message); }
+}
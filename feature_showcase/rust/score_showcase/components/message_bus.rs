@@ -0,0 +1,1218 @@
+//! Message bus for component communication
+//! This is the central hub that routes messages between components
+//! Similar to S-CORE's communication module
+//!
+//! Queues keep their capacity and `receive_all_into` drains into a caller
+//! buffer, so once warmed up the steady-state path doesn't allocate.
+//! Messages with `Copy` payloads are cloned to subscribers for free; the
+//! `String` payloads are reserved for rare system events.
+//!
+//! Each component has one queue per `MessagePriority`; receivers get
+//! safety-relevant messages before routine updates without any sorting.
+//!
+//! A component subscribes to all messages (`subscribe_all`) or only to
+//! the message types it needs (`subscribe`, by `CarMessage::type_name`).
+//!
+//! Besides broadcast, `send_to` delivers a message to one component only,
+//! whether or not it is subscribed. A component can also send a request to
+//! one component and get a correlated reply: `request` returns a
+//! `RequestId`, the addressee takes its requests with `take_requests_into`
+//! and answers with `reply`, and the requester polls its reply queue with
+//! `poll_reply`. Requests not answered within their timeout (in bus
+//! cycles, see `advance_cycle`) are completed with a timeout.
+//!
+//! Routed messages travel in an `Envelope` stamped with sender, bus cycle
+//! and publish time; receiving one records its delivery latency in
+//! per-message-type statistics (`stats`).
+//!
+//! A message can carry a time to live (`publish_with_ttl`, or per message
+//! type with `set_ttl`). Messages older than their TTL when they would be
+//! received are purged instead and counted (`expired_count`), so a receiver
+//! that fell behind never acts on stale values.
+//!
+//! With a replay capacity set (`set_replay_capacity`), the bus keeps the
+//! last N broadcast envelopes so a component joining late can catch up
+//! with `replay_since`. Directed messages and requests are not kept.
+//!
+//! Every routed envelope is E2E protected (see `e2e`): a rolling sequence
+//! counter per link and a CRC over sender, counter and payload. Receiving
+//! verifies both; a corrupted message is discarded, and a corruption or a
+//! sequence gap raises a `ComponentError` naming the sender, delivered to
+//! the receiver with its next receive (`e2e_error_count`).
+//!
+//! Messages that reach no queue (no subscriber, or a subscriber without
+//! a registered queue) and directed messages or requests to unknown
+//! components are kept in a bounded dead-letter queue for inspection
+//! instead of vanishing.
+//!
+//! `SharedMessageBus` wraps the bus for components running on their own
+//! threads: each component gets a `BusHandle` it can move to its thread
+//! to publish, receive (optionally blocking) and answer requests.
+
+use super::e2e::{payload_crc, E2eCounters, E2eError, E2eHeader};
+use super::messages::{CarMessage, ComponentId, MessagePriority};
+use crate::log_info;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Correlates a reply with its request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RequestId(u64);
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// A request waiting for the component it is addressed to
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    pub id: RequestId,
+    pub from: ComponentId,
+    pub message: CarMessage,
+}
+
+/// Outcome of a request as seen by the requester
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplyStatus {
+    /// Not answered yet
+    Pending,
+    /// Answered; the reply has been taken from the reply queue
+    Ready(CarMessage),
+    /// Not answered within its timeout
+    TimedOut,
+    /// Never sent, or its outcome was already taken
+    Unknown,
+}
+
+/// Bookkeeping for a request that hasn't been answered yet
+#[derive(Debug, Clone, Copy)]
+struct PendingRequest {
+    from: ComponentId,
+    to: ComponentId,
+    /// Last bus cycle in which a reply is accepted
+    deadline: u64,
+}
+
+/// A routed message with its routing metadata
+#[derive(Debug, Clone, PartialEq)]
+pub struct Envelope {
+    pub from: ComponentId,
+    pub message: CarMessage,
+    /// Bus cycle in which it was published
+    pub cycle: u64,
+    /// Publish time, relative to the creation of the bus
+    pub published: Duration,
+    /// Maximum age at receive time; older envelopes are purged
+    pub ttl: Option<Duration>,
+    /// Sequence counter and CRC, verified at receive time
+    pub e2e: E2eHeader,
+}
+
+impl Envelope {
+    /// Check whether the envelope is past its TTL at `now` (time since the
+    /// bus was created)
+    pub fn is_expired(&self, now: Duration) -> bool {
+        self.ttl
+            .is_some_and(|ttl| now.saturating_sub(self.published) > ttl)
+    }
+}
+
+/// Delivery latency statistics of one message type
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatencyStats {
+    pub message_type: &'static str,
+    pub count: u64,
+    pub min: Duration,
+    pub max: Duration,
+    total: Duration,
+}
+
+impl LatencyStats {
+    fn new(message_type: &'static str) -> Self {
+        Self {
+            message_type,
+            count: 0,
+            min: Duration::MAX,
+            max: Duration::ZERO,
+            total: Duration::ZERO,
+        }
+    }
+
+    fn record(&mut self, latency: Duration) {
+        self.count += 1;
+        self.min = self.min.min(latency);
+        self.max = self.max.max(latency);
+        self.total += latency;
+    }
+
+    pub fn avg(&self) -> Duration {
+        match self.total.as_nanos().checked_div(u128::from(self.count)) {
+            // The mean is never larger than the total, so it fits
+            Some(nanos) => Duration::from_nanos(nanos as u64),
+            None => Duration::ZERO,
+        }
+    }
+}
+
+/// Latency statistics of all message types received so far
+#[derive(Debug, Default)]
+struct LatencyTracker {
+    /// One entry per message type, in order of first delivery
+    stats: Vec<LatencyStats>,
+}
+
+impl LatencyTracker {
+    /// Record a delivery at `now` (time since the bus was created)
+    fn record(&mut self, envelope: &Envelope, now: Duration) {
+        let message_type = envelope.message.type_name();
+        let latency = now.saturating_sub(envelope.published);
+        match self.stats.iter_mut().find(|stats| stats.message_type == message_type) {
+            Some(stats) => stats.record(latency),
+            None => {
+                let mut stats = LatencyStats::new(message_type);
+                stats.record(latency);
+                self.stats.push(stats);
+            }
+        }
+    }
+}
+
+/// Receive-side state shared by all mailboxes during one receive
+struct Intake<'a> {
+    receiver: ComponentId,
+    /// Time since the bus was created
+    now: Duration,
+    /// Purged envelopes, see `MessageBus::expired_count`
+    expired: &'a mut u64,
+    e2e: &'a mut E2eCounters,
+    /// E2E violations found, with the sender
+    errors: &'a mut Vec<(ComponentId, E2eError)>,
+}
+
+/// Pending messages of one component, one queue per priority
+#[derive(Debug, Default)]
+struct Mailbox {
+    /// Indexed by `MessagePriority as usize`
+    queues: [VecDeque<Envelope>; 4],
+}
+
+impl Mailbox {
+    fn push(&mut self, envelope: Envelope) {
+        self.queues[envelope.message.priority() as usize].push_back(envelope);
+    }
+
+    fn len(&self) -> usize {
+        self.queues.iter().map(VecDeque::len).sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.queues.iter().all(VecDeque::is_empty)
+    }
+
+    /// Next intact, unexpired message of one priority
+    /// Every envelope is E2E checked first; corrupted ones are discarded,
+    /// expired ones purged and counted.
+    fn pop_priority(&mut self, priority: MessagePriority, intake: &mut Intake) -> Option<Envelope> {
+        let queue = &mut self.queues[priority as usize];
+        while let Some(envelope) = queue.pop_front() {
+            if let Err(error) = intake.e2e.check(envelope.from, intake.receiver, &envelope.message, envelope.e2e) {
+                intake.errors.push((envelope.from, error));
+                if error == E2eError::Corrupted {
+                    continue;
+                }
+            }
+            if !envelope.is_expired(intake.now) {
+                return Some(envelope);
+            }
+            *intake.expired += 1;
+        }
+        None
+    }
+
+    /// Next message, highest priority first
+    fn pop(&mut self, intake: &mut Intake) -> Option<Envelope> {
+        MessagePriority::DELIVERY_ORDER
+            .iter()
+            .find_map(|priority| self.pop_priority(*priority, intake))
+    }
+
+    /// Move up to `max` intact, unexpired envelopes to `deliver`, highest
+    /// priority first
+    fn drain(&mut self, max: usize, intake: &mut Intake, mut deliver: impl FnMut(Envelope)) -> usize {
+        let mut moved = 0;
+        for priority in MessagePriority::DELIVERY_ORDER {
+            while moved < max {
+                match self.pop_priority(priority, intake) {
+                    Some(envelope) => deliver(envelope),
+                    None => break,
+                }
+                moved += 1;
+            }
+        }
+        moved
+    }
+
+    fn clear(&mut self) {
+        self.queues.iter_mut().for_each(VecDeque::clear);
+    }
+}
+
+/// The last broadcast envelopes, oldest dropped first
+#[derive(Debug, Default)]
+struct ReplayBuffer {
+    envelopes: VecDeque<Envelope>,
+    /// 0 = replay disabled
+    capacity: usize,
+}
+
+impl ReplayBuffer {
+    fn push(&mut self, envelope: Envelope) {
+        if self.envelopes.len() == self.capacity {
+            self.envelopes.pop_front();
+        }
+        self.envelopes.push_back(envelope);
+    }
+}
+
+/// Dead letters kept before the oldest ones are dropped
+pub const DEAD_LETTER_CAPACITY: usize = 256;
+
+/// Why a message could not be delivered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadLetterReason {
+    /// Published, but no other component is subscribed
+    NoSubscribers,
+    /// A subscriber has no registered queue
+    QueueNotRegistered(ComponentId),
+    /// A request or directed message was addressed to an unregistered
+    /// component
+    UnknownRecipient(ComponentId),
+}
+
+impl fmt::Display for DeadLetterReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeadLetterReason::NoSubscribers => write!(f, "no subscribers"),
+            DeadLetterReason::QueueNotRegistered(id) => write!(f, "{} has no registered queue", id),
+            DeadLetterReason::UnknownRecipient(id) => write!(f, "{} is not registered", id),
+        }
+    }
+}
+
+/// A message that could not be delivered
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeadLetter {
+    pub from: ComponentId,
+    pub message: CarMessage,
+    pub reason: DeadLetterReason,
+    /// Bus cycle in which it was published (see `MessageBus::advance_cycle`)
+    pub cycle: u64,
+    /// Time since the bus was created
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for DeadLetter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cycle {} (+{:.3}s) [{}] {}: {}",
+               self.cycle, self.elapsed.as_secs_f64(), self.from, self.message.format(), self.reason)
+    }
+}
+
+/// Bounded queue of dead letters, oldest dropped first
+#[derive(Debug, Default)]
+struct DeadLetterQueue {
+    letters: VecDeque<DeadLetter>,
+    dropped: u64,
+}
+
+impl DeadLetterQueue {
+    fn push(&mut self, letter: DeadLetter, trace: bool) {
+        if trace {
+            log_info!("MessageBus", "  📭 Undeliverable: {}", letter);
+        }
+        if self.letters.len() == DEAD_LETTER_CAPACITY {
+            self.letters.pop_front();
+            self.dropped += 1;
+        }
+        self.letters.push_back(letter);
+    }
+}
+
+/// Message types a component receives
+#[derive(Debug, Clone, PartialEq)]
+pub enum Subscription {
+    All,
+    Types(Vec<&'static str>),
+}
+
+impl Subscription {
+    pub fn wants(&self, message: &CarMessage) -> bool {
+        match self {
+            Subscription::All => true,
+            Subscription::Types(types) => types.contains(&message.type_name()),
+        }
+    }
+}
+
+/// Message bus - central communication hub
+/// Components publish messages, and subscribed components receive them
+pub struct MessageBus {
+    /// Message queues for each component
+    queues: HashMap<ComponentId, Mailbox>,
+    /// Subscriptions: which component wants which message types
+    subscriptions: HashMap<ComponentId, Subscription>,
+    /// Print every published message
+    trace: bool,
+    /// Requests addressed to each component
+    requests: HashMap<ComponentId, VecDeque<Request>>,
+    /// Replies (or timeouts) waiting for each requester
+    replies: HashMap<ComponentId, VecDeque<(RequestId, Option<CarMessage>)>>,
+    pending: HashMap<RequestId, PendingRequest>,
+    next_request: u64,
+    cycle: u64,
+    dead_letters: DeadLetterQueue,
+    latency: LatencyTracker,
+    started: Instant,
+    /// Default TTL per message type
+    ttls: Vec<(&'static str, Duration)>,
+    /// Messages purged because they outlived their TTL
+    expired: u64,
+    replay: ReplayBuffer,
+    /// Sequence counters of the sending side of each link
+    e2e_sent: E2eCounters,
+    /// Last counter received on each link
+    e2e_received: E2eCounters,
+    /// Violations found by the current receive (reused buffer)
+    e2e_found: Vec<(ComponentId, E2eError)>,
+    e2e_errors: u64,
+}
+
+impl MessageBus {
+    /// Create a new message bus
+    pub fn new() -> Self {
+        Self {
+            queues: HashMap::new(),
+            subscriptions: HashMap::new(),
+            trace: true,
+            requests: HashMap::new(),
+            replies: HashMap::new(),
+            pending: HashMap::new(),
+            next_request: 1,
+            cycle: 0,
+            dead_letters: DeadLetterQueue::default(),
+            latency: LatencyTracker::default(),
+            started: Instant::now(),
+            ttls: Vec::new(),
+            expired: 0,
+            replay: ReplayBuffer::default(),
+            e2e_sent: E2eCounters::default(),
+            e2e_received: E2eCounters::default(),
+            e2e_found: Vec::new(),
+            e2e_errors: 0,
+        }
+    }
+
+    /// Enable or disable printing of published messages
+    pub fn set_trace(&mut self, enabled: bool) {
+        self.trace = enabled;
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.trace
+    }
+
+    /// Register a component (create its message queue)
+    pub fn register_component(&mut self, component_id: ComponentId) {
+        self.queues.entry(component_id).or_default();
+        log_info!("MessageBus", "  📡 MessageBus: Registered {}", component_id.as_str());
+    }
+
+    /// Subscribe a component to all messages
+    pub fn subscribe_all(&mut self, component_id: ComponentId) {
+        self.subscriptions.insert(component_id, Subscription::All);
+        log_info!("MessageBus", "  📡 MessageBus: {} subscribed to ALL messages", component_id.as_str());
+    }
+
+    /// Subscribe a component to one message type (see `CarMessage::type_name`)
+    /// Has no effect on a component already subscribed to all messages.
+    pub fn subscribe(&mut self, component_id: ComponentId, message_type: &'static str) {
+        let subscription = self.subscriptions.entry(component_id).or_insert(Subscription::Types(Vec::new()));
+        if let Subscription::Types(types) = subscription {
+            if !types.contains(&message_type) {
+                types.push(message_type);
+            }
+        }
+        log_info!("MessageBus", "  📡 MessageBus: {} subscribed to {}", component_id.as_str(), message_type);
+    }
+
+    /// What a component is subscribed to, if anything
+    pub fn subscription(&self, component_id: ComponentId) -> Option<&Subscription> {
+        self.subscriptions.get(&component_id)
+    }
+
+    /// Set the TTL of every later message of a type (`None` = no TTL)
+    pub fn set_ttl(&mut self, message_type: &'static str, ttl: Option<Duration>) {
+        self.ttls.retain(|(known, _)| *known != message_type);
+        if let Some(ttl) = ttl {
+            self.ttls.push((message_type, ttl));
+        }
+    }
+
+    /// TTL a message gets when published without an explicit one
+    pub fn ttl_of(&self, message: &CarMessage) -> Option<Duration> {
+        ttl_of(&self.ttls, message)
+    }
+
+    /// Messages purged at receive time because they outlived their TTL
+    pub fn expired_count(&self) -> u64 {
+        self.expired
+    }
+
+    /// E2E violations (corruptions and sequence gaps) found so far
+    pub fn e2e_error_count(&self) -> u64 {
+        self.e2e_errors
+    }
+
+    /// Publish a message from a component
+    /// The message bus routes it to all subscribed components
+    pub fn publish(&mut self, from: ComponentId, message: CarMessage) {
+        let ttl = self.ttl_of(&message);
+        self.route(from, message, ttl);
+    }
+
+    /// Publish a message that is dropped if not received within `ttl`
+    pub fn publish_with_ttl(&mut self, from: ComponentId, message: CarMessage, ttl: Duration) {
+        self.route(from, message, Some(ttl));
+    }
+
+    fn route(&mut self, from: ComponentId, message: CarMessage, ttl: Option<Duration>) {
+        if self.trace {
+            log_info!("MessageBus", "  📨 [{}] → {}", from.as_str(), message.format());
+        }
+
+        // Add message to all subscribers' queues
+        let published = self.started.elapsed();
+        let payload = payload_crc(&message);
+        let mut subscribed = false;
+        for (component_id, subscription) in &self.subscriptions {
+            if *component_id != from && subscription.wants(&message) {
+                // Don't send back to sender
+                subscribed = true;
+                match self.queues.get_mut(component_id) {
+                    Some(mailbox) => mailbox.push(Envelope {
+                        from,
+                        message: message.clone(),
+                        cycle: self.cycle,
+                        published,
+                        ttl,
+                        e2e: E2eHeader::protect_payload(
+                            from,
+                            self.e2e_sent.next(from, *component_id, message.priority()),
+                            payload,
+                        ),
+                    }),
+                    None => self.dead_letters.push(
+                        dead_letter(from, &message, DeadLetterReason::QueueNotRegistered(*component_id),
+                                    self.cycle, self.started),
+                        self.trace,
+                    ),
+                }
+            }
+        }
+        if !subscribed {
+            let letter = dead_letter(from, &message, DeadLetterReason::NoSubscribers, self.cycle, self.started);
+            self.dead_letters.push(letter, self.trace);
+        }
+        if self.replay.capacity > 0 {
+            self.replay.push(Envelope {
+                from,
+                message,
+                cycle: self.cycle,
+                published,
+                ttl,
+                e2e: E2eHeader::protect_payload(from, 0, payload),
+            });
+        }
+    }
+
+    /// Publish a batch of messages from one component, draining `messages`
+    /// Subscribers receive them in order; the buffer keeps its capacity.
+    pub fn publish_batch(&mut self, from: ComponentId, messages: &mut Vec<CarMessage>) {
+        if self.trace {
+            for message in messages.iter() {
+                log_info!("MessageBus", "  📨 [{}] → {}", from.as_str(), message.format());
+            }
+        }
+
+        let published = self.started.elapsed();
+        for message in messages.iter() {
+            let ttl = ttl_of(&self.ttls, message);
+            let mut subscribed = false;
+            for (component_id, subscription) in &self.subscriptions {
+                if *component_id == from || !subscription.wants(message) {
+                    continue;
+                }
+                subscribed = true;
+                match self.queues.get_mut(component_id) {
+                    Some(mailbox) => {
+                        let counter = self.e2e_sent.next(from, *component_id, message.priority());
+                        mailbox.push(Envelope {
+                            from,
+                            message: message.clone(),
+                            cycle: self.cycle,
+                            published,
+                            ttl,
+                            e2e: E2eHeader::protect(from, counter, message),
+                        });
+                    }
+                    None => {
+                        let reason = DeadLetterReason::QueueNotRegistered(*component_id);
+                        let letter = dead_letter(from, message, reason, self.cycle, self.started);
+                        self.dead_letters.push(letter, self.trace);
+                    }
+                }
+            }
+            if !subscribed {
+                let letter = dead_letter(from, message, DeadLetterReason::NoSubscribers, self.cycle, self.started);
+                self.dead_letters.push(letter, self.trace);
+            }
+        }
+        if self.replay.capacity > 0 {
+            for message in messages.iter() {
+                self.replay.push(Envelope {
+                    from,
+                    message: message.clone(),
+                    cycle: self.cycle,
+                    published,
+                    ttl: ttl_of(&self.ttls, message),
+                    e2e: E2eHeader::protect(from, 0, message),
+                });
+            }
+        }
+        messages.clear();
+    }
+
+    /// Send a message to one component only
+    /// The addressee gets it even if it isn't subscribed; other subscribers
+    /// never see it.
+    pub fn send_to(&mut self, from: ComponentId, to: ComponentId, message: CarMessage) -> Result<(), String> {
+        let ttl = self.ttl_of(&message);
+        let Some(mailbox) = self.queues.get_mut(&to) else {
+            let letter = dead_letter(from, &message, DeadLetterReason::UnknownRecipient(to), self.cycle, self.started);
+            self.dead_letters.push(letter, self.trace);
+            return Err(format!("Cannot send to unregistered component {}", to));
+        };
+        if self.trace {
+            log_info!("MessageBus", "  📨 [{}] → {}: {}", from, to, message.format());
+        }
+        let e2e = E2eHeader::protect(from, self.e2e_sent.next(from, to, message.priority()), &message);
+        mailbox.push(Envelope {
+            from,
+            message,
+            cycle: self.cycle,
+            published: self.started.elapsed(),
+            ttl,
+            e2e,
+        });
+        Ok(())
+    }
+
+    /// Check if a component has pending messages
+    pub fn has_messages(&self, component_id: ComponentId) -> bool {
+        self.queues
+            .get(&component_id)
+            .map(|q| !q.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Get the number of pending messages for a component
+    pub fn pending_count(&self, component_id: ComponentId) -> usize {
+        self.queues
+            .get(&component_id)
+            .map(|q| q.len())
+            .unwrap_or(0)
+    }
+
+    /// Receive next message for a component, highest priority first
+    pub fn receive(&mut self, component_id: ComponentId) -> Option<CarMessage> {
+        let now = self.started.elapsed();
+        let mailbox = self.queues.get_mut(&component_id)?;
+        let mut intake = Intake {
+            receiver: component_id,
+            now,
+            expired: &mut self.expired,
+            e2e: &mut self.e2e_received,
+            errors: &mut self.e2e_found,
+        };
+        let envelope = mailbox.pop(&mut intake);
+        self.raise_e2e_errors(component_id);
+        let envelope = envelope?;
+        self.latency.record(&envelope, now);
+        Some(envelope.message)
+    }
+
+    /// Receive the next critical message for a component, leaving all
+    /// other messages queued
+    pub fn receive_critical(&mut self, component_id: ComponentId) -> Option<CarMessage> {
+        let now = self.started.elapsed();
+        let mailbox = self.queues.get_mut(&component_id)?;
+        let mut intake = Intake {
+            receiver: component_id,
+            now,
+            expired: &mut self.expired,
+            e2e: &mut self.e2e_received,
+            errors: &mut self.e2e_found,
+        };
+        let envelope = mailbox.pop_priority(MessagePriority::Critical, &mut intake);
+        self.raise_e2e_errors(component_id);
+        let envelope = envelope?;
+        self.latency.record(&envelope, now);
+        Some(envelope.message)
+    }
+
+    /// Receive all pending messages for a component in priority order
+    pub fn receive_all(&mut self, component_id: ComponentId) -> Vec<CarMessage> {
+        let mut result = Vec::with_capacity(self.pending_count(component_id));
+        self.receive_all_into(component_id, &mut result);
+        result
+    }
+
+    /// Append all pending messages for a component to a reused buffer
+    pub fn receive_all_into(&mut self, component_id: ComponentId, out: &mut Vec<CarMessage>) {
+        self.receive_batch(component_id, out, usize::MAX);
+    }
+
+    /// Move up to `max` pending messages for a component to `into`,
+    /// highest priority first
+    /// Returns the number of messages moved.
+    pub fn receive_batch(&mut self, component_id: ComponentId, into: &mut Vec<CarMessage>, max: usize) -> usize {
+        let Some(mailbox) = self.queues.get_mut(&component_id) else {
+            return 0;
+        };
+        let now = self.started.elapsed();
+        let mut intake = Intake {
+            receiver: component_id,
+            now,
+            expired: &mut self.expired,
+            e2e: &mut self.e2e_received,
+            errors: &mut self.e2e_found,
+        };
+        let latency = &mut self.latency;
+        let moved = mailbox.drain(max, &mut intake, |envelope| {
+            latency.record(&envelope, now);
+            into.push(envelope.message);
+        });
+        self.raise_e2e_errors(component_id);
+        moved
+    }
+
+    /// Append all pending messages for a component with their envelopes,
+    /// in priority order
+    pub fn receive_envelopes_into(&mut self, component_id: ComponentId, out: &mut Vec<Envelope>) {
+        let Some(mailbox) = self.queues.get_mut(&component_id) else {
+            return;
+        };
+        let now = self.started.elapsed();
+        let mut intake = Intake {
+            receiver: component_id,
+            now,
+            expired: &mut self.expired,
+            e2e: &mut self.e2e_received,
+            errors: &mut self.e2e_found,
+        };
+        let latency = &mut self.latency;
+        mailbox.drain(usize::MAX, &mut intake, |envelope| {
+            latency.record(&envelope, now);
+            out.push(envelope);
+        });
+        self.raise_e2e_errors(component_id);
+    }
+
+    /// Queue a `ComponentError` for each E2E violation the last receive of
+    /// `receiver` found; allocation-free while there are none
+    fn raise_e2e_errors(&mut self, receiver: ComponentId) {
+        if self.e2e_found.is_empty() {
+            return;
+        }
+        let mut found = std::mem::take(&mut self.e2e_found);
+        for (sender, error) in found.drain(..) {
+            self.e2e_errors += 1;
+            if self.trace {
+                log_info!("MessageBus", "  🛡️  [{}] ← {}: {}", receiver, sender, error);
+            }
+            let message = CarMessage::ComponentError { component: sender, error: error.to_string() };
+            let counter = self.e2e_sent.next(receiver, receiver, message.priority());
+            if let Some(mailbox) = self.queues.get_mut(&receiver) {
+                mailbox.push(Envelope {
+                    from: receiver,
+                    e2e: E2eHeader::protect(receiver, counter, &message),
+                    message,
+                    cycle: self.cycle,
+                    published: self.started.elapsed(),
+                    ttl: None,
+                });
+            }
+        }
+        self.e2e_found = found;
+    }
+
+    /// Flip a CRC bit of a component's next pending message, as a
+    /// transmission error would (fault injection)
+    /// Returns whether a message was pending.
+    pub fn corrupt_pending(&mut self, component_id: ComponentId) -> bool {
+        let next = self.queues.get_mut(&component_id).and_then(|mailbox| {
+            mailbox.queues.iter_mut().rev().find_map(|queue| queue.front_mut())
+        });
+        match next {
+            Some(envelope) => {
+                envelope.e2e.crc ^= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drop a component's next pending message unseen, as a lost frame
+    /// would (fault injection)
+    /// Returns whether a message was pending.
+    pub fn lose_pending(&mut self, component_id: ComponentId) -> bool {
+        self.queues
+            .get_mut(&component_id)
+            .and_then(|mailbox| mailbox.queues.iter_mut().rev().find_map(VecDeque::pop_front))
+            .is_some()
+    }
+
+    /// Delivery latency per message type, in order of first delivery
+    pub fn stats(&self) -> &[LatencyStats] {
+        &self.latency.stats
+    }
+
+    /// Forget all latency statistics
+    pub fn reset_stats(&mut self) {
+        self.latency.stats.clear();
+    }
+
+    /// Print the delivery latency table
+    pub fn print_stats(&self) {
+        log_info!("MessageBus", "📊 Message bus delivery latency:");
+        log_info!("MessageBus", "   {:<20} {:>8} {:>10} {:>10} {:>10}", "message", "count", "min", "avg", "max");
+        for stats in self.stats() {
+            log_info!("MessageBus", "   {:<20} {:>8} {:>7.1} µs {:>7.1} µs {:>7.1} µs",
+                     stats.message_type, stats.count,
+                     stats.min.as_secs_f64() * 1e6, stats.avg().as_secs_f64() * 1e6, stats.max.as_secs_f64() * 1e6);
+        }
+    }
+
+    /// Clear all messages for a component
+    /// Its incoming links restart their E2E sequence, so the discarded
+    /// messages aren't reported as a sequence gap on the next receive.
+    pub fn clear(&mut self, component_id: ComponentId) {
+        if let Some(mailbox) = self.queues.get_mut(&component_id) {
+            mailbox.clear();
+            self.e2e_received.restart_links_to(component_id);
+        }
+    }
+
+    /// Get total pending messages across all components
+    pub fn total_pending(&self) -> usize {
+        self.queues.values().map(|q| q.len()).sum()
+    }
+
+    /// Send a request to one component
+    /// The reply must arrive within `timeout_cycles` calls of `advance_cycle`.
+    pub fn request(&mut self, from: ComponentId, to: ComponentId, message: CarMessage,
+                   timeout_cycles: u64) -> Result<RequestId, String> {
+        if !self.queues.contains_key(&to) {
+            let letter = dead_letter(from, &message, DeadLetterReason::UnknownRecipient(to), self.cycle, self.started);
+            self.dead_letters.push(letter, self.trace);
+            return Err(format!("Cannot send request to unregistered component {}", to));
+        }
+        let id = RequestId(self.next_request);
+        self.next_request += 1;
+        if self.trace {
+            log_info!("MessageBus", "  📨 [{}] → {} {}: {}", from, to, id, message.format());
+        }
+        self.pending.insert(id, PendingRequest {
+            from,
+            to,
+            deadline: self.cycle + timeout_cycles,
+        });
+        self.requests.entry(to).or_default().push_back(Request { id, from, message });
+        Ok(id)
+    }
+
+    /// Move all requests addressed to a component to `into`
+    pub fn take_requests_into(&mut self, component_id: ComponentId, into: &mut Vec<Request>) {
+        if let Some(queue) = self.requests.get_mut(&component_id) {
+            into.extend(queue.drain(..));
+        }
+    }
+
+    /// Answer a request; only the addressee may reply, and only once
+    pub fn reply(&mut self, id: RequestId, from: ComponentId, message: CarMessage) -> Result<(), String> {
+        let request = match self.pending.get(&id) {
+            Some(request) if request.to == from => *request,
+            Some(request) => {
+                return Err(format!("{} cannot answer request {} addressed to {}", from, id, request.to))
+            }
+            None => return Err(format!("Request {} is not pending (answered or timed out)", id)),
+        };
+        self.pending.remove(&id);
+        if self.trace {
+            log_info!("MessageBus", "  📨 [{}] ↩ {} {}: {}", from, request.from, id, message.format());
+        }
+        self.replies.entry(request.from).or_default().push_back((id, Some(message)));
+        Ok(())
+    }
+
+    /// Take the outcome of a request from the requester's reply queue
+    pub fn poll_reply(&mut self, requester: ComponentId, id: RequestId) -> ReplyStatus {
+        if let Some(queue) = self.replies.get_mut(&requester) {
+            if let Some(index) = queue.iter().position(|(reply_id, _)| *reply_id == id) {
+                return match queue.remove(index) {
+                    Some((_, Some(message))) => ReplyStatus::Ready(message),
+                    _ => ReplyStatus::TimedOut,
+                };
+            }
+        }
+        match self.pending.get(&id) {
+            Some(request) if request.from == requester => ReplyStatus::Pending,
+            _ => ReplyStatus::Unknown,
+        }
+    }
+
+    /// Keep the last `capacity` broadcast envelopes for replay (0 = off)
+    /// The buffer is allocated here, so recording never allocates.
+    pub fn set_replay_capacity(&mut self, capacity: usize) {
+        let replay = &mut self.replay;
+        while replay.envelopes.len() > capacity {
+            replay.envelopes.pop_front();
+        }
+        replay.envelopes.reserve_exact(capacity.saturating_sub(replay.envelopes.len()));
+        replay.capacity = capacity;
+    }
+
+    /// Kept broadcast envelopes published in bus cycle `cycle` or later,
+    /// oldest first
+    /// Replaying doesn't remove them and doesn't record delivery latency.
+    pub fn replay_since(&self, cycle: u64) -> impl Iterator<Item = &Envelope> {
+        self.replay.envelopes.iter().filter(move |envelope| envelope.cycle >= cycle)
+    }
+
+    /// Undelivered messages, oldest first
+    pub fn dead_letters(&self) -> impl Iterator<Item = &DeadLetter> {
+        self.dead_letters.letters.iter()
+    }
+
+    /// Number of dead letters currently kept
+    pub fn dead_letter_count(&self) -> usize {
+        self.dead_letters.letters.len()
+    }
+
+    /// Dead letters dropped because the queue was full
+    pub fn dropped_dead_letters(&self) -> u64 {
+        self.dead_letters.dropped
+    }
+
+    /// Take all kept dead letters, oldest first
+    pub fn drain_dead_letters(&mut self) -> Vec<DeadLetter> {
+        self.dead_letters.letters.drain(..).collect()
+    }
+
+    /// Withdraw a request that hasn't been answered yet
+    pub fn cancel(&mut self, id: RequestId) {
+        if let Some(request) = self.pending.remove(&id) {
+            if let Some(queue) = self.requests.get_mut(&request.to) {
+                queue.retain(|queued| queued.id != id);
+            }
+        }
+    }
+
+    /// Number of requests still waiting for a reply
+    pub fn pending_requests(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// End a bus cycle: requests past their deadline time out
+    /// Allocation-free while no request is pending.
+    pub fn advance_cycle(&mut self) {
+        self.cycle += 1;
+        if self.pending.is_empty() {
+            return;
+        }
+        let cycle = self.cycle;
+        let expired: Vec<(RequestId, PendingRequest)> = self
+            .pending
+            .iter()
+            .filter(|(_, request)| request.deadline < cycle)
+            .map(|(id, request)| (*id, *request))
+            .collect();
+        for (id, request) in expired {
+            self.pending.remove(&id);
+            if let Some(queue) = self.requests.get_mut(&request.to) {
+                queue.retain(|queued| queued.id != id);
+            }
+            if self.trace {
+                log_info!("MessageBus", "  ⏱️  Request {} from {} to {} timed out", id, request.from, request.to);
+            }
+            self.replies.entry(request.from).or_default().push_back((id, None));
+        }
+    }
+}
+
+fn ttl_of(ttls: &[(&'static str, Duration)], message: &CarMessage) -> Option<Duration> {
+    let message_type = message.type_name();
+    ttls.iter()
+        .find(|(known, _)| *known == message_type)
+        .map(|(_, ttl)| *ttl)
+}
+
+fn dead_letter(from: ComponentId, message: &CarMessage, reason: DeadLetterReason, cycle: u64,
+               started: Instant) -> DeadLetter {
+    DeadLetter {
+        from,
+        message: message.clone(),
+        reason,
+        cycle,
+        elapsed: started.elapsed(),
+    }
+}
+
+impl Default for MessageBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Message bus shared by components running on their own threads
+/// A `MessageBus` behind a mutex, with a condition variable so handles can
+/// block until a message arrives. Clones share the same bus.
+#[derive(Clone, Default)]
+pub struct SharedMessageBus {
+    inner: Arc<SharedBus>,
+}
+
+#[derive(Default)]
+struct SharedBus {
+    bus: Mutex<MessageBus>,
+    arrived: Condvar,
+}
+
+impl SharedBus {
+    /// Lock the bus; a thread that panicked while holding it leaves the
+    /// queues consistent, so poisoning is ignored
+    fn lock(&self) -> MutexGuard<'_, MessageBus> {
+        self.bus.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl SharedMessageBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Share an already configured bus
+    pub fn from_bus(bus: MessageBus) -> Self {
+        Self {
+            inner: Arc::new(SharedBus {
+                bus: Mutex::new(bus),
+                arrived: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Register a component and get its handle
+    pub fn register(&self, component_id: ComponentId) -> BusHandle {
+        self.inner.lock().register_component(component_id);
+        self.handle(component_id)
+    }
+
+    /// Handle of an already registered component
+    pub fn handle(&self, component_id: ComponentId) -> BusHandle {
+        BusHandle {
+            id: component_id,
+            inner: Arc::clone(&self.inner),
+        }
+    }
+
+    /// Exclusive access to the underlying bus (setup, diagnostics)
+    pub fn lock(&self) -> MutexGuard<'_, MessageBus> {
+        self.inner.lock()
+    }
+}
+
+/// One component's access to a shared bus
+/// `Send` and cheap to clone, so it can move to the component's thread.
+#[derive(Clone)]
+pub struct BusHandle {
+    id: ComponentId,
+    inner: Arc<SharedBus>,
+}
+
+impl BusHandle {
+    pub fn id(&self) -> ComponentId {
+        self.id
+    }
+
+    /// Subscribe this component to all messages
+    pub fn subscribe_all(&self) {
+        self.inner.lock().subscribe_all(self.id);
+    }
+
+    /// Subscribe this component to one message type
+    pub fn subscribe(&self, message_type: &'static str) {
+        self.inner.lock().subscribe(self.id, message_type);
+    }
+
+    /// Publish a message from this component and wake waiting receivers
+    pub fn publish(&self, message: CarMessage) {
+        self.inner.lock().publish(self.id, message);
+        self.inner.arrived.notify_all();
+    }
+
+    /// Publish a batch of messages under one lock, draining `messages`
+    pub fn publish_batch(&self, messages: &mut Vec<CarMessage>) {
+        self.inner.lock().publish_batch(self.id, messages);
+        self.inner.arrived.notify_all();
+    }
+
+    /// Send a message to one component only and wake waiting receivers
+    pub fn send_to(&self, to: ComponentId, message: CarMessage) -> Result<(), String> {
+        self.inner.lock().send_to(self.id, to, message)?;
+        self.inner.arrived.notify_all();
+        Ok(())
+    }
+
+    /// Publish a message that is dropped if not received within `ttl`
+    pub fn publish_with_ttl(&self, message: CarMessage, ttl: Duration) {
+        self.inner.lock().publish_with_ttl(self.id, message, ttl);
+        self.inner.arrived.notify_all();
+    }
+
+    /// Next pending message, without waiting
+    pub fn receive(&self) -> Option<CarMessage> {
+        self.inner.lock().receive(self.id)
+    }
+
+    /// Next critical message, without waiting
+    pub fn receive_critical(&self) -> Option<CarMessage> {
+        self.inner.lock().receive_critical(self.id)
+    }
+
+    /// Append all pending messages to a reused buffer
+    pub fn receive_all_into(&self, out: &mut Vec<CarMessage>) {
+        self.inner.lock().receive_all_into(self.id, out);
+    }
+
+    /// Next message, waiting up to `timeout` for one to arrive
+    pub fn receive_timeout(&self, timeout: Duration) -> Option<CarMessage> {
+        let deadline = Instant::now() + timeout;
+        let mut bus = self.inner.lock();
+        loop {
+            if let Some(message) = bus.receive(self.id) {
+                return Some(message);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+            bus = self
+                .inner
+                .arrived
+                .wait_timeout(bus, deadline - now)
+                .map(|(bus, _)| bus)
+                .unwrap_or_else(|e| e.into_inner().0);
+        }
+    }
+
+    /// Send a request from this component to another one
+    pub fn request(&self, to: ComponentId, message: CarMessage, timeout_cycles: u64) -> Result<RequestId, String> {
+        let id = self.inner.lock().request(self.id, to, message, timeout_cycles)?;
+        self.inner.arrived.notify_all();
+        Ok(id)
+    }
+
+    /// Move all requests addressed to this component to `into`
+    pub fn take_requests_into(&self, into: &mut Vec<Request>) {
+        self.inner.lock().take_requests_into(self.id, into);
+    }
+
+    /// Answer a request addressed to this component
+    pub fn reply(&self, id: RequestId, message: CarMessage) -> Result<(), String> {
+        self.inner.lock().reply(id, self.id, message)?;
+        self.inner.arrived.notify_all();
+        Ok(())
+    }
+
+    /// Outcome of a request sent by this component
+    pub fn poll_reply(&self, id: RequestId) -> ReplyStatus {
+        self.inner.lock().poll_reply(self.id, id)
+    }
+
+    /// Copies of the kept broadcast envelopes since bus cycle `cycle`
+    pub fn replay_since(&self, cycle: u64) -> Vec<Envelope> {
+        self.inner.lock().replay_since(cycle).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bus_with_dashboard() -> MessageBus {
+        let mut bus = MessageBus::new();
+        bus.set_trace(false);
+        bus.register_component(ComponentId::DASHBOARD);
+        bus.subscribe_all(ComponentId::DASHBOARD);
+        bus
+    }
+
+    #[test]
+    fn latency_average_counts_past_u32() {
+        let mut stats = LatencyStats::new("SpeedUpdate");
+        assert_eq!(stats.avg(), Duration::ZERO);
+        stats.record(Duration::from_micros(3));
+        stats.record(Duration::from_micros(5));
+        assert_eq!(stats.avg(), Duration::from_micros(4));
+
+        stats.count = u64::from(u32::MAX) + 2;
+        stats.total = Duration::from_nanos(2 * stats.count);
+        assert_eq!(stats.avg(), Duration::from_nanos(2));
+    }
+
+    #[test]
+    fn clear_does_not_cause_a_sequence_gap() {
+        let mut bus = bus_with_dashboard();
+        bus.publish(ComponentId::ENGINE, CarMessage::EngineRpmChange { rpm: 800 });
+        assert!(bus.receive(ComponentId::DASHBOARD).is_some());
+
+        for rpm in [900, 1000, 1100] {
+            bus.publish(ComponentId::ENGINE, CarMessage::EngineRpmChange { rpm });
+        }
+        bus.clear(ComponentId::DASHBOARD);
+        bus.publish(ComponentId::ENGINE, CarMessage::EngineRpmChange { rpm: 1200 });
+
+        assert_eq!(bus.receive_all(ComponentId::DASHBOARD), vec![CarMessage::EngineRpmChange { rpm: 1200 }]);
+        assert_eq!(bus.e2e_error_count(), 0);
+    }
+
+    #[test]
+    fn lost_messages_are_still_a_sequence_gap() {
+        let mut bus = bus_with_dashboard();
+        bus.publish(ComponentId::ENGINE, CarMessage::EngineRpmChange { rpm: 800 });
+        assert!(bus.receive(ComponentId::DASHBOARD).is_some());
+
+        bus.publish(ComponentId::ENGINE, CarMessage::EngineRpmChange { rpm: 900 });
+        assert!(bus.lose_pending(ComponentId::DASHBOARD));
+        bus.publish(ComponentId::ENGINE, CarMessage::EngineRpmChange { rpm: 1000 });
+
+        assert_eq!(bus.receive_all(ComponentId::DASHBOARD), vec![CarMessage::EngineRpmChange { rpm: 1000 }]);
+        assert_eq!(bus.e2e_error_count(), 1);
+        assert!(matches!(bus.receive(ComponentId::DASHBOARD),
+                         Some(CarMessage::ComponentError { component, .. }) if component == ComponentId::ENGINE));
+    }
+}
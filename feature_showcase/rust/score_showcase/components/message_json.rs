@@ -0,0 +1,420 @@
+//! JSON form of bus messages
+//! Demonstrates S-CORE message serialization patterns:
+//! - Every message has a stable, self-describing JSON form, so it can be
+//!   logged, replayed or sent to another process
+//! - Messages are tagged with their type name; payload fields keep the
+//!   names they have in `CarMessage`
+//! - Component IDs are written by name; reading resolves the name through
+//!   a registry, so dynamic components keep their ID on the reading side
+//!
+//! ```json
+//! {"from":"Engine","cycle":12,"published_us":24031,
+//!  "message":{"type":"EngineOverheating","temperature":104.5},"ttl_us":null,
+//!  "e2e_counter":7,"e2e_crc":40261}
+//! ```
+//!
+//! The crate has no serde dependency; this uses the crate's own JSON layer
+//! like snapshots and calibration books do.
+
+use std::time::Duration;
+
+use crate::components::json::{object, JsonValue};
+use crate::components::e2e::E2eHeader;
+use crate::components::message_bus::Envelope;
+use crate::components::messages::{CarMessage, ComponentId, ComponentRegistry};
+
+/// Integer field within the range of its Rust type
+fn integer(json: &JsonValue, key: &str, min: f64, max: f64) -> Result<f64, String> {
+    let value = json
+        .get(key)
+        .and_then(JsonValue::as_f64)
+        .ok_or_else(|| format!("message field '{}' missing or not a number", key))?;
+    if value.fract() == 0.0 && value >= min && value <= max {
+        Ok(value)
+    } else {
+        Err(format!("message field '{}' out of range: {}", key, value))
+    }
+}
+
+fn text(json: &JsonValue, key: &str) -> Result<String, String> {
+    json.get(key)
+        .and_then(JsonValue::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| format!("message field '{}' missing or not a string", key))
+}
+
+fn number(json: &JsonValue, key: &str) -> Result<f64, String> {
+    json.get(key)
+        .and_then(JsonValue::as_f64)
+        .ok_or_else(|| format!("message field '{}' missing or not a number", key))
+}
+
+impl ComponentId {
+    pub fn to_json(&self) -> JsonValue {
+        JsonValue::from(self.as_str())
+    }
+
+    /// Resolve a component name written by `to_json`
+    pub fn from_json(json: &JsonValue, registry: &ComponentRegistry) -> Result<ComponentId, String> {
+        let name = json.as_str().ok_or("component must be a name")?;
+        registry
+            .lookup(name)
+            .ok_or_else(|| format!("unknown component: {}", name))
+    }
+}
+
+impl CarMessage {
+    pub fn to_json(&self) -> JsonValue {
+        let tag = ("type", JsonValue::from(self.type_name()));
+        match self {
+            CarMessage::EngineStart
+            | CarMessage::EngineStop
+            | CarMessage::BrakeRelease
+            | CarMessage::AbsDeactivated
+            | CarMessage::BrakeFault
+            | CarMessage::BrakeFaultCleared
+            | CarMessage::SteeringCenter => object([tag]),
+            CarMessage::EngineOverheating { temperature } => {
+                object([tag, ("temperature", JsonValue::from(*temperature))])
+            }
+            CarMessage::EngineRpmChange { rpm } => object([tag, ("rpm", JsonValue::from(*rpm))]),
+            CarMessage::BrakeApply { pressure } | CarMessage::BrakePressureChange { pressure } => {
+                object([tag, ("pressure", JsonValue::from(*pressure))])
+            }
+            CarMessage::AbsActivated { pressure, km_h } => object([
+                tag,
+                ("pressure", JsonValue::from(*pressure)),
+                ("km_h", JsonValue::from(*km_h)),
+            ]),
+            CarMessage::SteeringTurn { angle } => object([tag, ("angle", JsonValue::from(*angle))]),
+            CarMessage::SpeedUpdate { km_h } => object([tag, ("km_h", JsonValue::from(*km_h))]),
+            CarMessage::FuelWarning { level } => object([tag, ("level", JsonValue::from(*level))]),
+            CarMessage::BatteryLow { soc } => object([tag, ("soc", JsonValue::from(*soc))]),
+            CarMessage::BatteryOverheating { temperature } => {
+                object([tag, ("temperature", JsonValue::from(*temperature))])
+            }
+            CarMessage::HvacLoad { watts } => object([tag, ("watts", JsonValue::from(*watts))]),
+            CarMessage::CollisionImminent { distance, ttc } => object([
+                tag,
+                ("distance", JsonValue::from(*distance)),
+                ("ttc", JsonValue::from(*ttc)),
+            ]),
+            CarMessage::PositionUpdate { x, y, heading, to_destination } => object([
+                tag,
+                ("x", JsonValue::from(*x)),
+                ("y", JsonValue::from(*y)),
+                ("heading", JsonValue::from(*heading)),
+                ("to_destination", to_destination.map_or(JsonValue::Null, JsonValue::from)),
+            ]),
+            CarMessage::ComponentError { component, error } => object([
+                tag,
+                ("component", component.to_json()),
+                ("error", JsonValue::from(error.as_str())),
+            ]),
+            CarMessage::ComponentRestarted { component, attempt } => object([
+                tag,
+                ("component", component.to_json()),
+                ("attempt", JsonValue::from(*attempt)),
+            ]),
+            CarMessage::ConfigChanged { key, value } => object([
+                tag,
+                ("key", JsonValue::from(key.as_str())),
+                ("value", JsonValue::from(value.as_str())),
+            ]),
+            CarMessage::SelectCalibration { name } => {
+                object([tag, ("name", JsonValue::from(name.as_str()))])
+            }
+            CarMessage::QuerySignal { signal } => {
+                object([tag, ("signal", JsonValue::from(signal.as_str()))])
+            }
+            CarMessage::SignalReport { signal, value } => object([
+                tag,
+                ("signal", JsonValue::from(signal.as_str())),
+                ("value", JsonValue::from(*value)),
+            ]),
+        }
+    }
+
+    /// Read a message written by `to_json`
+    /// Component names in the payload are resolved through `registry`.
+    pub fn from_json(json: &JsonValue, registry: &ComponentRegistry) -> Result<CarMessage, String> {
+        let message_type = json
+            .get("type")
+            .and_then(JsonValue::as_str)
+            .ok_or("message field 'type' missing or not a string")?;
+        let message = match message_type {
+            "EngineStart" => CarMessage::EngineStart,
+            "EngineStop" => CarMessage::EngineStop,
+            "EngineOverheating" => CarMessage::EngineOverheating {
+                temperature: number(json, "temperature")? as f32,
+            },
+            "EngineRpmChange" => CarMessage::EngineRpmChange {
+                rpm: integer(json, "rpm", 0.0, u32::MAX as f64)? as u32,
+            },
+            "BrakeApply" => CarMessage::BrakeApply {
+                pressure: integer(json, "pressure", 0.0, u8::MAX as f64)? as u8,
+            },
+            "BrakeRelease" => CarMessage::BrakeRelease,
+            "BrakePressureChange" => CarMessage::BrakePressureChange {
+                pressure: integer(json, "pressure", 0.0, u8::MAX as f64)? as u8,
+            },
+            "AbsActivated" => CarMessage::AbsActivated {
+                pressure: integer(json, "pressure", 0.0, u8::MAX as f64)? as u8,
+                km_h: integer(json, "km_h", 0.0, u8::MAX as f64)? as u8,
+            },
+            "AbsDeactivated" => CarMessage::AbsDeactivated,
+            "BrakeFault" => CarMessage::BrakeFault,
+            "BrakeFaultCleared" => CarMessage::BrakeFaultCleared,
+            "SteeringTurn" => CarMessage::SteeringTurn {
+                angle: integer(json, "angle", i16::MIN as f64, i16::MAX as f64)? as i16,
+            },
+            "SteeringCenter" => CarMessage::SteeringCenter,
+            "SpeedUpdate" => CarMessage::SpeedUpdate {
+                km_h: integer(json, "km_h", 0.0, u8::MAX as f64)? as u8,
+            },
+            "FuelWarning" => CarMessage::FuelWarning {
+                level: integer(json, "level", 0.0, u8::MAX as f64)? as u8,
+            },
+            "BatteryLow" => CarMessage::BatteryLow {
+                soc: integer(json, "soc", 0.0, u8::MAX as f64)? as u8,
+            },
+            "BatteryOverheating" => CarMessage::BatteryOverheating {
+                temperature: number(json, "temperature")? as f32,
+            },
+            "HvacLoad" => CarMessage::HvacLoad {
+                watts: integer(json, "watts", 0.0, u16::MAX as f64)? as u16,
+            },
+            "CollisionImminent" => CarMessage::CollisionImminent {
+                distance: number(json, "distance")? as f32,
+                ttc: number(json, "ttc")? as f32,
+            },
+            "PositionUpdate" => CarMessage::PositionUpdate {
+                x: number(json, "x")? as f32,
+                y: number(json, "y")? as f32,
+                heading: number(json, "heading")? as f32,
+                to_destination: match json.get("to_destination") {
+                    None | Some(JsonValue::Null) => None,
+                    Some(_) => Some(number(json, "to_destination")? as f32),
+                },
+            },
+            "ComponentError" => CarMessage::ComponentError {
+                component: ComponentId::from_json(
+                    json.get("component").ok_or("message field 'component' missing")?,
+                    registry,
+                )?,
+                error: text(json, "error")?,
+            },
+            "ComponentRestarted" => CarMessage::ComponentRestarted {
+                component: ComponentId::from_json(
+                    json.get("component").ok_or("message field 'component' missing")?,
+                    registry,
+                )?,
+                attempt: integer(json, "attempt", 0.0, u32::MAX as f64)? as u32,
+            },
+            "ConfigChanged" => CarMessage::ConfigChanged {
+                key: text(json, "key")?,
+                value: text(json, "value")?,
+            },
+            "SelectCalibration" => CarMessage::SelectCalibration {
+                name: text(json, "name")?,
+            },
+            "QuerySignal" => CarMessage::QuerySignal {
+                signal: text(json, "signal")?,
+            },
+            "SignalReport" => CarMessage::SignalReport {
+                signal: text(json, "signal")?,
+                value: number(json, "value")?,
+            },
+            other => return Err(format!("unknown message type: {}", other)),
+        };
+        Ok(message)
+    }
+}
+
+impl Envelope {
+    /// JSON form; publish time and TTL are written in microseconds
+    pub fn to_json(&self) -> JsonValue {
+        object([
+            ("from", self.from.to_json()),
+            ("cycle", JsonValue::from(self.cycle)),
+            ("published_us", JsonValue::from(self.published.as_micros() as u64)),
+            ("message", self.message.to_json()),
+            (
+                "ttl_us",
+                self.ttl.map_or(JsonValue::Null, |ttl| JsonValue::from(ttl.as_micros() as u64)),
+            ),
+            ("e2e_counter", JsonValue::from(self.e2e.counter)),
+            ("e2e_crc", JsonValue::from(self.e2e.crc as u32)),
+        ])
+    }
+
+    /// Read an envelope written by `to_json`
+    pub fn from_json(json: &JsonValue, registry: &ComponentRegistry) -> Result<Envelope, String> {
+        Ok(Envelope {
+            from: ComponentId::from_json(json.get("from").ok_or("envelope field 'from' missing")?, registry)?,
+            message: CarMessage::from_json(json.get("message").ok_or("envelope field 'message' missing")?, registry)?,
+            cycle: json
+                .get("cycle")
+                .and_then(JsonValue::as_u64)
+                .ok_or("envelope field 'cycle' missing or not a non-negative integer")?,
+            published: Duration::from_micros(
+                json.get("published_us")
+                    .and_then(JsonValue::as_u64)
+                    .ok_or("envelope field 'published_us' missing or not a non-negative integer")?,
+            ),
+            ttl: match json.get("ttl_us") {
+                None | Some(JsonValue::Null) => None,
+                Some(ttl) => Some(Duration::from_micros(
+                    ttl.as_u64().ok_or("envelope field 'ttl_us' must be a non-negative integer or null")?,
+                )),
+            },
+            e2e: E2eHeader {
+                counter: integer(json, "e2e_counter", 0.0, u8::MAX as f64)? as u8,
+                crc: integer(json, "e2e_crc", 0.0, u16::MAX as f64)? as u16,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One message of every variant; `variant_index` makes adding a
+    /// variant without a sample here a compile error
+    fn every_variant(sensor: ComponentId) -> Vec<CarMessage> {
+        vec![
+            CarMessage::EngineStart,
+            CarMessage::EngineStop,
+            CarMessage::EngineOverheating { temperature: 104.5 },
+            CarMessage::EngineRpmChange { rpm: 3200 },
+            CarMessage::BrakeApply { pressure: 80 },
+            CarMessage::BrakeRelease,
+            CarMessage::BrakePressureChange { pressure: 35 },
+            CarMessage::AbsActivated { pressure: 96, km_h: 72 },
+            CarMessage::AbsDeactivated,
+            CarMessage::BrakeFault,
+            CarMessage::BrakeFaultCleared,
+            CarMessage::SteeringTurn { angle: -15 },
+            CarMessage::SteeringCenter,
+            CarMessage::SpeedUpdate { km_h: 88 },
+            CarMessage::FuelWarning { level: 9 },
+            CarMessage::BatteryLow { soc: 14 },
+            CarMessage::BatteryOverheating { temperature: 57.3 },
+            CarMessage::HvacLoad { watts: 1800 },
+            CarMessage::CollisionImminent { distance: 23.7, ttc: 1.15 },
+            CarMessage::PositionUpdate { x: -120.25, y: 88.1, heading: 271.5, to_destination: Some(1234.5) },
+            CarMessage::ComponentError { component: sensor, error: "sensor \"timeout\"\n".to_string() },
+            CarMessage::ComponentRestarted { component: ComponentId::BRAKES, attempt: 2 },
+            CarMessage::ConfigChanged { key: "safety.max_speed".to_string(), value: "110".to_string() },
+            CarMessage::SelectCalibration { name: "sport".to_string() },
+            CarMessage::QuerySignal { signal: "engine.rpm".to_string() },
+            CarMessage::SignalReport { signal: "engine.rpm".to_string(), value: 812.5 },
+        ]
+    }
+
+    const VARIANTS: usize = 26;
+
+    fn variant_index(message: &CarMessage) -> usize {
+        match message {
+            CarMessage::EngineStart => 0,
+            CarMessage::EngineStop => 1,
+            CarMessage::EngineOverheating { .. } => 2,
+            CarMessage::EngineRpmChange { .. } => 3,
+            CarMessage::BrakeApply { .. } => 4,
+            CarMessage::BrakeRelease => 5,
+            CarMessage::BrakePressureChange { .. } => 6,
+            CarMessage::AbsActivated { .. } => 7,
+            CarMessage::AbsDeactivated => 8,
+            CarMessage::BrakeFault => 9,
+            CarMessage::BrakeFaultCleared => 10,
+            CarMessage::SteeringTurn { .. } => 11,
+            CarMessage::SteeringCenter => 12,
+            CarMessage::SpeedUpdate { .. } => 13,
+            CarMessage::FuelWarning { .. } => 14,
+            CarMessage::BatteryLow { .. } => 15,
+            CarMessage::BatteryOverheating { .. } => 16,
+            CarMessage::HvacLoad { .. } => 17,
+            CarMessage::CollisionImminent { .. } => 18,
+            CarMessage::PositionUpdate { .. } => 19,
+            CarMessage::ComponentError { .. } => 20,
+            CarMessage::ComponentRestarted { .. } => 21,
+            CarMessage::ConfigChanged { .. } => 22,
+            CarMessage::SelectCalibration { .. } => 23,
+            CarMessage::QuerySignal { .. } => 24,
+            CarMessage::SignalReport { .. } => 25,
+        }
+    }
+
+    #[test]
+    fn samples_cover_every_variant() {
+        let mut covered = [false; VARIANTS];
+        for message in every_variant(ComponentId::ENGINE) {
+            covered[variant_index(&message)] = true;
+        }
+        let missing: Vec<usize> = (0..VARIANTS).filter(|&index| !covered[index]).collect();
+        assert!(missing.is_empty(), "variants without a sample: {:?}", missing);
+    }
+
+    #[test]
+    fn every_variant_round_trips() {
+        let mut registry = ComponentRegistry::new();
+        let sensor = registry.register("Lidar");
+        for message in every_variant(sensor) {
+            let written = message.to_json();
+            let text = written.to_string();
+            let read = CarMessage::from_json(&JsonValue::parse(&text).unwrap(), &registry);
+            assert_eq!(read, Ok(message.clone()), "round trip of {}", text);
+            assert_eq!(written.get("type").and_then(JsonValue::as_str), Some(message.type_name()));
+        }
+    }
+
+    #[test]
+    fn position_without_route_round_trips() {
+        let registry = ComponentRegistry::new();
+        let message = CarMessage::PositionUpdate { x: 1.5, y: -2.0, heading: 90.0, to_destination: None };
+        let written = message.to_json();
+        assert_eq!(CarMessage::from_json(&written, &registry), Ok(message));
+    }
+
+    #[test]
+    fn envelope_of_every_variant_round_trips() {
+        let mut registry = ComponentRegistry::new();
+        let sensor = registry.register("Lidar");
+        for (index, message) in every_variant(sensor).into_iter().enumerate() {
+            let envelope = Envelope {
+                from: if index % 2 == 0 { ComponentId::ENGINE } else { sensor },
+                message,
+                cycle: index as u64 * 7,
+                published: Duration::from_micros(24_031 + index as u64),
+                ttl: if index % 3 == 0 { None } else { Some(Duration::from_millis(250)) },
+                e2e: E2eHeader { counter: index as u8, crc: 40_261 - index as u16 },
+            };
+            let text = envelope.to_json().to_string();
+            let read = Envelope::from_json(&JsonValue::parse(&text).unwrap(), &registry);
+            assert_eq!(read, Ok(envelope), "round trip of {}", text);
+        }
+    }
+
+    #[test]
+    fn unknown_component_is_rejected() {
+        let mut writer = ComponentRegistry::new();
+        let message = CarMessage::ComponentRestarted { component: writer.register("Lidar"), attempt: 1 };
+        let read = CarMessage::from_json(&message.to_json(), &ComponentRegistry::new());
+        assert_eq!(read, Err("unknown component: Lidar".to_string()));
+    }
+
+    #[test]
+    fn out_of_range_fields_are_rejected() {
+        let registry = ComponentRegistry::new();
+        for text in [
+            r#"{"type":"BrakeApply","pressure":256}"#,
+            r#"{"type":"SteeringTurn","angle":1.5}"#,
+            r#"{"type":"SpeedUpdate"}"#,
+            r#"{"type":"Teleport"}"#,
+        ] {
+            let json = JsonValue::parse(text).unwrap();
+            assert!(CarMessage::from_json(&json, &registry).is_err(), "accepted {}", text);
+        }
+    }
+}
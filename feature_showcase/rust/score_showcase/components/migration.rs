@@ -0,0 +1,129 @@
+//! Versioned data formats and migrations
+//! Demonstrates S-CORE persistency versioning patterns:
+//! - Every persisted format carries a version number
+//! - Older data is upgraded step by step through explicit migrations
+//! - Data written by a newer binary is refused with a clear error
+//!
+//! Formats:
+//! - `store` v1: flat JSON object of keys (no version field)
+//! - `store` v2: `{"version": 2, "data": {...}}` keeps metadata apart from keys
+//! - `snapshot` v1: `speed` / `temperature` fields without units
+//! - `snapshot` v2: unit-suffixed `speed_kmh` / `temperature_c` fields
+
+use crate::components::json::JsonValue;
+
+/// Current version of the KvStore file format
+pub const STORE_VERSION: u64 = 2;
+
+/// Current version of the snapshot format (golden files)
+pub const SNAPSHOT_VERSION: u64 = 2;
+
+/// One upgrade step from `from` to `from + 1`
+pub struct Migration {
+    pub from: u64,
+    pub description: &'static str,
+    pub apply: fn(JsonValue) -> Result<JsonValue, String>,
+}
+
+/// Ordered list of migrations for one data format
+pub struct MigrationPipeline {
+    format: &'static str,
+    current: u64,
+    migrations: Vec<Migration>,
+}
+
+impl MigrationPipeline {
+    /// Migrations for the KvStore file
+    pub fn store() -> Self {
+        Self {
+            format: "store",
+            current: STORE_VERSION,
+            migrations: vec![Migration {
+                from: 1,
+                description: "wrap keys in a versioned 'data' object",
+                apply: store_v1_to_v2,
+            }],
+        }
+    }
+
+    /// Migrations for per-tick snapshots
+    pub fn snapshot() -> Self {
+        Self {
+            format: "snapshot",
+            current: SNAPSHOT_VERSION,
+            migrations: vec![Migration {
+                from: 1,
+                description: "rename speed/temperature to unit-suffixed fields",
+                apply: snapshot_v1_to_v2,
+            }],
+        }
+    }
+
+    pub fn current_version(&self) -> u64 {
+        self.current
+    }
+
+    /// Upgrade a value from `version` to the current version
+    /// Returns the migrated value and the descriptions of the applied steps
+    pub fn migrate(&self, version: u64, value: JsonValue) -> Result<(JsonValue, Vec<String>), String> {
+        if version > self.current {
+            return Err(format!(
+                "{} data is version {}, but this binary only supports up to version {} - \
+                 it was written by a newer car_system",
+                self.format, version, self.current
+            ));
+        }
+        if version == 0 {
+            return Err(format!("{} data has invalid version 0", self.format));
+        }
+
+        let mut value = value;
+        let mut applied = Vec::new();
+        for step in version..self.current {
+            let migration = self
+                .migrations
+                .iter()
+                .find(|m| m.from == step)
+                .ok_or_else(|| format!("no {} migration from version {}", self.format, step))?;
+            value = (migration.apply)(value)
+                .map_err(|e| format!("{} migration v{}→v{} failed: {}", self.format, step, step + 1, e))?;
+            applied.push(format!("v{}→v{}: {}", step, step + 1, migration.description));
+        }
+
+        Ok((value, applied))
+    }
+}
+
+/// Version of a KvStore document (unversioned documents are v1)
+pub fn store_version(json: &JsonValue) -> Result<u64, String> {
+    match json.get("version") {
+        None => Ok(1),
+        Some(v) => v.as_u64().ok_or_else(|| "store 'version' must be an integer".to_string()),
+    }
+}
+
+fn store_v1_to_v2(value: JsonValue) -> Result<JsonValue, String> {
+    match value {
+        JsonValue::Object(map) => {
+            let mut doc = std::collections::BTreeMap::new();
+            doc.insert("version".to_string(), JsonValue::from(2u64));
+            doc.insert("data".to_string(), JsonValue::Object(map));
+            Ok(JsonValue::Object(doc))
+        }
+        _ => Err("store must be a JSON object".to_string()),
+    }
+}
+
+fn snapshot_v1_to_v2(value: JsonValue) -> Result<JsonValue, String> {
+    match value {
+        JsonValue::Object(mut map) => {
+            for (old, new) in [("speed", "speed_kmh"), ("temperature", "temperature_c")] {
+                if let Some(field) = map.remove(old) {
+                    map.insert(new.to_string(), field);
+                }
+            }
+            Ok(JsonValue::Object(map))
+        }
+        _ => Err("snapshot must be a JSON object".to_string()),
+    }
+}
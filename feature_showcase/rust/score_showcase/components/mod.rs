@@ -0,0 +1,191 @@
+//! Car component definitions
+//! This module defines the common interface that all car components implement
+//!
+//! Crate features (Bazel `crate_features`):
+//! - `render`: dashboard frame rendering and printing
+//! - `recorder`: black box recorder (`blackbox.path`)
+//! - `sim_physics`: simulated plant (RPM fluctuation, engine warm-up,
+//!   sensor noise, fuel consumption); without it values only change
+//!   through components and the hardware backend
+//! - `fixed_point`: fixed-point signal encoding for safety checks
+//! - `scripting`: rhai scripts as workflow steps and scenario actions;
+//!   needs the `rhai` crate, which no target in this workspace pulls in
+//!   yet
+//! - `async_loop`: `AsyncEventLoop` and `CarSystem::run_event_loop_async`
+//!   on a Tokio runtime, next to async I/O; needs the `tokio` crate, which
+//!   no target pulls in yet either
+//!
+//! The minimal footprint profile (`car_system_example_minimal`) enables
+//! none of them: `CarSystem` runs the core components, message bus, event
+//! loop, safety monitor and workflows, and prints only log lines. Network
+//! bridges (web, MQTT, gRPC) are not part of this crate.
+//!
+//! The library target is built once per feature combination, all under the
+//! crate name `score_showcase` (`score_showcase`, `score_showcase_fixed_point`,
+//! `score_showcase_minimal`).
+
+mod car_core;
+mod engine;
+mod brakes;
+mod steering;
+mod dashboard;
+mod warnings;
+mod units;
+mod battery;
+mod hvac;
+mod obstacle;
+mod location;
+mod throttle;
+mod vehicle_dynamics;
+pub mod message_bus;
+mod e2e;
+mod message_json;
+pub mod event_loop;
+mod tick_handlers;
+mod actor_runtime;
+#[cfg(feature = "async_loop")]
+mod async_event_loop;
+pub mod workflow;
+mod workflow_file;
+mod system;
+mod json;
+mod config;
+mod config_watcher;
+mod persistency;
+mod snapshot;
+mod golden;
+mod profile;
+#[cfg(feature = "recorder")]
+mod blackbox;
+mod calibration;
+mod safety_file;
+mod safety_log;
+mod migration;
+mod harness;
+mod expect;
+mod rng;
+mod coverage;
+mod bench;
+mod chaos;
+mod diffrun;
+mod trace;
+mod soak;
+mod fuzz;
+mod hardware;
+mod spsc;
+mod realtime;
+mod supervisor;
+mod history;
+mod arena;
+mod logging;
+mod log_sink;
+mod scenario;
+mod replay;
+mod signal;
+#[cfg(feature = "scripting")]
+mod scripting;
+#[cfg(feature = "render")]
+mod tui;
+
+pub use engine::EngineComponent;
+pub use brakes::BrakesComponent;
+pub use steering::SteeringComponent;
+pub use dashboard::{DashboardComponent, DashboardMode, DEFAULT_MAX_WARNINGS};
+pub use units::{
+    celsius_to_fahrenheit, display_units, km_to_miles, kmh_to_mph, meters_to_feet, set_display_units, UnitSystem,
+    KM_PER_MILE, M_PER_FOOT,
+};
+pub use warnings::{ClearPolicy, DashboardWarning, WarningSet, WarningSeverity, WARNING_HOLD_S};
+pub use battery::{traction_power_kw, BatteryComponent, PowerSplit, PowertrainMode};
+pub use hvac::HvacComponent;
+pub use obstacle::ObstacleSensor;
+pub use location::{LocationComponent, Pose, Route};
+pub use throttle::ThrottleComponent;
+pub use vehicle_dynamics::{DynamicsMode, VehicleDynamics};
+pub use car_core::{
+    faults, fixedpoint, goals, messages, safety, state_machine, voter, watchdog, CarComponent,
+    ComponentState, HealthStatus,
+};
+pub use messages::{CarMessage, ComponentId, ComponentRegistry, MessagePriority};
+pub use e2e::{payload_crc, E2eCounters, E2eError, E2eHeader};
+pub use message_bus::{
+    BusHandle, DeadLetter, DeadLetterReason, Envelope, LatencyStats, MessageBus, ReplyStatus, Request,
+    RequestId, SharedMessageBus, Subscription, DEAD_LETTER_CAPACITY,
+};
+pub use state_machine::{
+    BrakeStateMachine, ChargingState, EngineStateMachine, EventDriven, StateMachine, StateMachineBuilder,
+    SteeringState, TransitionMachine,
+};
+pub use event_loop::{
+    apply_console_command, install_interrupt_handler, spawn_console, ClockSource, ErrorPolicy, EventLoop,
+    EventLoopConfig, EventLoopConfigBuilder, EventLoopConfigError, ExecutionMode, JitterStats, LoopControl, OverrunRecovery,
+    ScheduledTask, SimClock, TaskSchedule, TimeScaleMode, TimeSource, TimingReport, DEFAULT_TIME_STEP_S,
+};
+pub use tick_handlers::{TickHandlers, TickPhase};
+pub use actor_runtime::{ActorLinks, ActorRuntime, ActorStatus, ComponentActor, TickOutcome};
+#[cfg(feature = "async_loop")]
+pub use async_event_loop::AsyncEventLoop;
+pub use faults::{Fault, FaultStore};
+pub use goals::{Asil, GoalReport, SafetyGoal, SAFETY_GOALS};
+pub use voter::{RedundantSignal, Vote, Voter};
+pub use watchdog::Watchdog;
+pub use safety_log::{SafetyEvent, SafetyEventLog};
+pub use safety::{SafetyMonitor, SafetyWarning, SafetySeverity, WarningFilter};
+pub use workflow::{
+    print_workflow_event, DryRunReport, DryRunVerdict, ParallelTask, Precondition, RetryPolicy, StepReport,
+    StepStatus, TaskAction, TaskFn, Workflow, WorkflowBuilder, WorkflowEvent, WorkflowRegistry, WorkflowReport,
+    WorkflowStep,
+};
+pub use workflow_file::{ActionFactory, ActionRegistry, BUILTIN_ACTIONS};
+pub use system::{
+    check_workflow, find_workflow, list_workflows, run_workflow, CarSystem, CarSystemBuilder, REQUEST_TIMEOUT_CYCLES,
+    SCHEDULED_TASKS, WORKFLOWS,
+};
+pub use json::JsonValue;
+pub use config::{
+    AdasConfig, BlackBoxConfig, CalibrationCommand, CalibrationConfig, ChaosConfig, ConfigLayer, GoldenConfig,
+    HardwareConfig, HvacConfig, InitConfig, InitPolicy, LocationConfig, LogLevel, PersistencyConfig,
+    PowertrainConfig, SafetyConfig, SystemConfig, SensorConfig, ToolCommand, VehicleConfig, WatchdogConfig,
+};
+pub use config_watcher::{ConfigWatcher, ReloadPlan};
+pub use persistency::{keys as persistency_keys, KvStore};
+pub use snapshot::{BatteryReading, SystemSnapshot};
+pub use golden::{GoldenMode, GoldenRecorder};
+pub use profile::Profile;
+#[cfg(feature = "recorder")]
+pub use blackbox::BlackBox;
+pub use calibration::CalibrationBook;
+pub use migration::{Migration, MigrationPipeline, SNAPSHOT_VERSION, STORE_VERSION};
+pub use harness::TestHarness;
+pub use rng::SimRng;
+pub use bench::{measure_tick_budget, run_benchmarks, run_tick_budget, BenchResult, TickBudgetReport};
+pub use chaos::{ChaosFault, ChaosMonkey, ChaosStats};
+pub use diffrun::run_differential;
+pub use spsc::{spsc_ring, Consumer, Producer};
+pub use hardware::{open_backend, HardwareBackend, HardwareIo, SensorFrame, SerialGpioIo, SimulatorIo};
+pub use fuzz::{run_fuzz, FuzzTarget, FUZZ_TARGETS};
+pub use fixedpoint::{FixedSignal, SignalScale, BRAKE_PRESSURE, SIGNALS, STEERING_ANGLE, TEMPERATURE};
+pub use realtime::{setup_realtime_thread, RealtimeConfig, RealtimeReport, StepResult};
+pub use supervisor::{RestartPolicy, Supervisor, SupervisorAction, SupervisorConfig};
+pub use history::History;
+pub use arena::BumpArena;
+pub use logging::{
+    add_log_sink, configure_logging, console_enabled, log_enabled, pause_console, remove_log_sink, write_log,
+    write_log_fields,
+    LogConfig, LogEntry, LogSinkId, ScoreLogger,
+};
+pub use log_sink::{JsonLinesSink, LogSink};
+pub use scenario::{run_scenario, Expectation, Scenario, ScenarioAction};
+pub use replay::{read_recorded_snapshots, run_replay};
+pub use signal::{SignalId, SignalValue};
+#[cfg(feature = "scripting")]
+pub use scripting::{script_workflow, Script};
+pub use soak::{
+    allocation_count, live_heap_bytes, message_path_allocations, run_soak, CountingAllocator,
+};
+#[cfg(feature = "render")]
+pub use soak::render_allocations;
+#[cfg(feature = "render")]
+pub use tui::{display_width, TuiDashboard};
+pub use trace::{run_trace_matrix, TraceEntry, TraceMatrix, REQUIREMENTS, VERIFICATIONS};
+pub use coverage::{run_state_diagrams, run_transition_coverage, TransitionCoverage, TransitionResult};
@@ -0,0 +1,166 @@
+//! Obstacle sensor - forward distance sensing for automatic emergency braking
+//! Demonstrates S-CORE ADAS patterns:
+//! - Sensor: simulated (noisy) distance readings to the obstacle ahead
+//! - Decision: time-to-collision from distance and closing speed
+//! - Actuation through the bus: below the TTC threshold the sensor
+//!   publishes `CollisionImminent`, and the car system runs its emergency
+//!   workflow in response
+//!
+//! The scenario is one obstacle ahead of the car at a fixed speed. The
+//! demo's speed profile doesn't react to braking, so the car may pass it;
+//! once passed or out of range, the road ahead is clear.
+
+use crate::components::{CarComponent, ComponentState, CarMessage, HealthStatus};
+use crate::components::event_loop::DEFAULT_TIME_STEP_S;
+use crate::components::rng::SimRng;
+use crate::components::units::display_units;
+use crate::log_info;
+
+/// Farthest distance the sensor sees, in m
+const MAX_RANGE_M: f32 = 200.0;
+
+/// Distance reading noise amplitude, in m
+const NOISE_M: f32 = 0.5;
+
+/// Obstacle sensor component - measures the distance to the obstacle ahead
+pub struct ObstacleSensor {
+    state: ComponentState,
+    /// True distance to the obstacle, in m (None = road clear)
+    distance: Option<f32>,
+    /// Distance as reported by the (noisy) sensor, in m
+    measured_distance: Option<f32>,
+    obstacle_speed: u8,
+    vehicle_speed: u8,
+    /// Publish `CollisionImminent` below this time-to-collision, in s
+    ttc_threshold: f32,
+    rng: SimRng,
+    /// Simulated seconds per process() call
+    dt_s: f32,
+}
+
+impl ObstacleSensor {
+    /// Create a sensor with an obstacle `distance` m ahead moving at
+    /// `obstacle_speed` km/h
+    pub fn new(distance: f32, obstacle_speed: u8, ttc_threshold: f32) -> Self {
+        Self {
+            state: ComponentState::Offline,
+            distance: Some(distance),
+            measured_distance: Some(distance),
+            obstacle_speed,
+            vehicle_speed: 0,
+            ttc_threshold,
+            rng: SimRng::new(0),
+            dt_s: DEFAULT_TIME_STEP_S,
+        }
+    }
+
+    /// Use a seeded RNG for the reading noise
+    pub fn set_rng(&mut self, rng: SimRng) {
+        self.rng = rng;
+    }
+
+    /// Set the simulated time covered by each process() call
+    pub fn set_time_step(&mut self, dt_s: f32) {
+        self.dt_s = dt_s;
+    }
+
+    /// Set the speed of the car the sensor is mounted on
+    pub fn set_vehicle_speed(&mut self, km_h: u8) {
+        self.vehicle_speed = km_h;
+    }
+
+    /// Last distance reading, in m (None = no obstacle in range)
+    pub fn get_distance(&self) -> Option<f32> {
+        self.measured_distance
+    }
+
+    /// Time until the car reaches the obstacle at the current speeds, in s
+    /// (None = not closing in)
+    pub fn time_to_collision(&self) -> Option<f32> {
+        let distance = self.measured_distance?;
+        let closing_m_s = self.closing_speed();
+        if closing_m_s > 0.0 {
+            Some(distance / closing_m_s)
+        } else {
+            None
+        }
+    }
+
+    /// Speed at which the car approaches the obstacle, in m/s
+    fn closing_speed(&self) -> f32 {
+        (self.vehicle_speed as f32 - self.obstacle_speed as f32) / 3.6
+    }
+
+    /// Get messages to publish (Phase 3: Communication)
+    pub fn get_messages(&self) -> Vec<CarMessage> {
+        let mut messages = Vec::new();
+        self.get_messages_into(&mut messages);
+        messages
+    }
+
+    /// Append messages to publish to a reused buffer
+    pub fn get_messages_into(&self, messages: &mut Vec<CarMessage>) {
+        if self.state != ComponentState::Online {
+            return;
+        }
+        if let (Some(distance), Some(ttc)) = (self.measured_distance, self.time_to_collision()) {
+            if ttc < self.ttc_threshold {
+                messages.push(CarMessage::CollisionImminent { distance, ttc });
+            }
+        }
+    }
+}
+
+impl CarComponent for ObstacleSensor {
+    fn name(&self) -> &'static str {
+        "ObstacleSensor"
+    }
+
+    fn initialize(&mut self) -> Result<(), String> {
+        log_info!("ObstacleSensor", "🔧 ObstacleSensor: Initializing component...");
+        self.state = ComponentState::Initializing;
+
+        log_info!("ObstacleSensor", "  🔍 ObstacleSensor: Calibrating radar... OK");
+        if let Some(distance) = self.distance {
+            let units = display_units();
+            log_info!("ObstacleSensor", "  📏 ObstacleSensor: Obstacle {:.0} {} ahead at {:.0} {}, braking below {:.1}s TTC",
+                     units.length(distance), units.length_unit(), units.speed(self.obstacle_speed as f32),
+                     units.speed_unit(), self.ttc_threshold);
+        }
+
+        self.state = ComponentState::Online;
+        log_info!("ObstacleSensor", "✅ ObstacleSensor: Initialized (state: {})", self.state);
+        Ok(())
+    }
+
+    fn process(&mut self) -> Result<(), String> {
+        let closing_m_s = self.closing_speed();
+        self.distance = self
+            .distance
+            .map(|distance| distance - closing_m_s * self.dt_s)
+            .filter(|distance| (0.0..=MAX_RANGE_M).contains(distance));
+        if self.distance.is_none() && self.measured_distance.is_some() {
+            log_info!("ObstacleSensor", "  📏 ObstacleSensor: Road ahead clear");
+        }
+        self.measured_distance = self.distance.map(|distance| (distance + self.rng.noise(NOISE_M)).max(0.0));
+        Ok(())
+    }
+
+    fn get_state(&self) -> ComponentState {
+        self.state.clone()
+    }
+
+    fn shutdown(&mut self) -> Result<(), String> {
+        log_info!("ObstacleSensor", "🔧 ObstacleSensor: Shutting down...");
+        self.state = ComponentState::Offline;
+        log_info!("ObstacleSensor", "✅ ObstacleSensor: Shut down (state: {})", self.state);
+        Ok(())
+    }
+
+    fn health_check(&self) -> HealthStatus {
+        if self.measured_distance.is_some_and(|distance| !distance.is_finite()) {
+            return HealthStatus::Unhealthy("distance reading is not a number");
+        }
+        HealthStatus::from_state(&self.state)
+    }
+}
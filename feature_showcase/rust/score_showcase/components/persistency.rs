@@ -0,0 +1,173 @@
+//! Key-value persistency for data that must survive restarts
+//! Demonstrates S-CORE persistency patterns (like the KVS module):
+//! - Values loaded at startup and flushed at shutdown / periodically
+//! - Atomic writes (temp file + rename) so a crash never leaves a torn file
+//! - Typed accessors over a flat key space
+//! - Versioned file format, older files are migrated on load
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::components::json::JsonValue;
+use crate::components::migration::{store_version, MigrationPipeline, STORE_VERSION};
+use crate::log_info;
+
+/// Well-known persistency keys
+pub mod keys {
+    pub const ODOMETER_KM: &str = "odometer_km";
+    pub const ENGINE_STARTS: &str = "service.engine_starts";
+    pub const BRAKE_APPLICATIONS: &str = "service.brake_applications";
+    pub const OPERATING_TICKS: &str = "service.operating_ticks";
+    pub const LATCHED_FAULTS: &str = "faults.latched";
+}
+
+/// Persistent key-value store backed by a single JSON file
+pub struct KvStore {
+    path: Option<PathBuf>,
+    values: BTreeMap<String, JsonValue>,
+    dirty: bool,
+}
+
+impl KvStore {
+    /// Create a store that is never written to disk
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            values: BTreeMap::new(),
+            dirty: false,
+        }
+    }
+
+    /// Open a store, loading existing values if the file exists
+    pub fn open(path: &str) -> Result<Self, String> {
+        let mut store = Self::in_memory();
+        store.path = Some(PathBuf::from(path));
+
+        if Path::new(path).exists() {
+            let text = fs::read_to_string(path)
+                .map_err(|e| format!("Cannot read store '{}': {}", path, e))?;
+            let json = JsonValue::parse(&text).map_err(|e| format!("{}: {}", path, e))?;
+            let version = store_version(&json).map_err(|e| format!("{}: {}", path, e))?;
+            let (json, applied) = MigrationPipeline::store()
+                .migrate(version, json)
+                .map_err(|e| format!("{}: {}", path, e))?;
+
+            match json.get("data") {
+                Some(JsonValue::Object(map)) => store.values = map.clone(),
+                _ => return Err(format!("{}: store is missing its 'data' object", path)),
+            }
+            for step in &applied {
+                log_info!("Persistency", "  🔄 Migrated {} ({})", path, step);
+            }
+            // Rewrite migrated files in the current format on the next flush
+            store.dirty = !applied.is_empty();
+        }
+
+        Ok(store)
+    }
+
+    /// Path of the backing file, if any
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Check for unsaved changes
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        self.values.get(key)
+    }
+
+    pub fn get_f64(&self, key: &str) -> Option<f64> {
+        self.get(key).and_then(JsonValue::as_f64)
+    }
+
+    pub fn get_u64(&self, key: &str) -> Option<u64> {
+        self.get(key).and_then(JsonValue::as_u64)
+    }
+
+    /// Set a value (marks the store dirty only if it changed)
+    pub fn set(&mut self, key: &str, value: JsonValue) {
+        if self.values.get(key) != Some(&value) {
+            self.values.insert(key.to_string(), value);
+            self.dirty = true;
+        }
+    }
+
+    /// Increment an integer counter, starting from 0
+    pub fn increment(&mut self, key: &str) -> u64 {
+        let next = self.get_u64(key).unwrap_or(0) + 1;
+        self.set(key, JsonValue::from(next));
+        next
+    }
+
+    /// Add a string to a set-like array (no duplicates)
+    /// Returns true when the entry was new
+    pub fn insert_unique(&mut self, key: &str, entry: &str) -> bool {
+        let mut items = self
+            .get(key)
+            .and_then(JsonValue::as_array)
+            .cloned()
+            .unwrap_or_default();
+        if items.iter().any(|item| item.as_str() == Some(entry)) {
+            return false;
+        }
+        items.push(JsonValue::from(entry));
+        self.set(key, JsonValue::Array(items));
+        true
+    }
+
+    /// String entries of an array value
+    pub fn get_strings(&self, key: &str) -> Vec<String> {
+        self.get(key)
+            .and_then(JsonValue::as_array)
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Remove a key
+    pub fn remove(&mut self, key: &str) {
+        if self.values.remove(key).is_some() {
+            self.dirty = true;
+        }
+    }
+
+    /// Write all values to disk atomically
+    /// Writes a sibling temp file first, then renames it over the target
+    pub fn flush(&mut self) -> Result<(), String> {
+        let path = match &self.path {
+            Some(path) => path.clone(),
+            None => {
+                self.dirty = false;
+                return Ok(());
+            }
+        };
+        if !self.dirty {
+            return Ok(());
+        }
+
+        let mut tmp = path.clone().into_os_string();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+
+        let mut doc = BTreeMap::new();
+        doc.insert("version".to_string(), JsonValue::from(STORE_VERSION));
+        doc.insert("data".to_string(), JsonValue::Object(self.values.clone()));
+        let text = JsonValue::Object(doc).to_pretty_string();
+        fs::write(&tmp, text + "\n")
+            .map_err(|e| format!("Cannot write '{}': {}", tmp.display(), e))?;
+        fs::rename(&tmp, &path)
+            .map_err(|e| format!("Cannot replace '{}': {}", path.display(), e))?;
+
+        self.dirty = false;
+        Ok(())
+    }
+}
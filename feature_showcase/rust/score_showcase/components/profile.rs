@@ -0,0 +1,103 @@
+//! Runtime profile presets
+//! A profile is a named bundle of config settings so the same binary can
+//! serve interactive demos, CI tests and performance measurements.
+//! Profiles are applied on top of the defaults and below every other layer,
+//! so a config file, environment variable or CLI flag can still override them.
+
+use std::fmt;
+
+/// Named runtime profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// Interactive demo: real-time ticks with the dashboard rendered
+    Demo,
+    /// CI tests: simulated clock, no rendering, nothing written to disk
+    Test,
+    /// Performance measurement: many simulated ticks, minimal output
+    Bench,
+    /// Real-time run: fast wall-clock ticks with timing reports
+    Realtime,
+}
+
+impl Profile {
+    /// All available profiles
+    pub const ALL: [Profile; 4] = [Profile::Demo, Profile::Test, Profile::Bench, Profile::Realtime];
+
+    pub fn parse(s: &str) -> Result<Profile, String> {
+        match s.to_lowercase().as_str() {
+            "demo" => Ok(Profile::Demo),
+            "test" => Ok(Profile::Test),
+            "bench" => Ok(Profile::Bench),
+            "realtime" => Ok(Profile::Realtime),
+            _ => Err(format!("Unknown profile: {} (expected demo|test|bench|realtime)", s)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Profile::Demo => "demo",
+            Profile::Test => "test",
+            Profile::Bench => "bench",
+            Profile::Realtime => "realtime",
+        }
+    }
+
+    /// One-line description for --help
+    pub fn description(&self) -> &'static str {
+        match self {
+            Profile::Demo => "500 ms ticks, dashboard rendered (default)",
+            Profile::Test => "simulated clock, fixed seed, no rendering, in-memory persistency",
+            Profile::Bench => "10000 simulated ticks, errors only",
+            Profile::Realtime => "10 ms wall-clock ticks on a pinned SCHED_FIFO thread, hybrid sleep, timing reports, no rendering",
+        }
+    }
+
+    /// Config settings of this profile as (key, value) pairs
+    pub fn settings(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Profile::Demo => &[
+                ("ticks", "30"),
+                ("event_loop.tick_rate_ms", "500"),
+                ("event_loop.simulated_clock", "false"),
+                ("event_loop.spin_us", "0"),
+                ("dashboard.render", "true"),
+                ("log_level", "INFO"),
+            ],
+            Profile::Test => &[
+                ("ticks", "100"),
+                ("sim.seed", "1"),
+                ("event_loop.simulated_clock", "true"),
+                ("dashboard.render", "false"),
+                ("log_level", "WARN"),
+                ("persistency.path", ""),
+                ("reload.poll_ticks", "0"),
+            ],
+            Profile::Bench => &[
+                ("ticks", "10000"),
+                ("sim.seed", "1"),
+                ("event_loop.simulated_clock", "true"),
+                ("event_loop.verbose_timing", "false"),
+                ("dashboard.render", "false"),
+                ("log_level", "ERROR"),
+                ("persistency.path", ""),
+                ("reload.poll_ticks", "0"),
+            ],
+            Profile::Realtime => &[
+                ("ticks", "1000"),
+                ("event_loop.tick_rate_ms", "10"),
+                ("event_loop.simulated_clock", "false"),
+                ("event_loop.spin_us", "1000"),
+                ("event_loop.verbose_timing", "true"),
+                ("dashboard.render", "false"),
+                ("log_level", "WARN"),
+                ("realtime.enabled", "true"),
+            ],
+        }
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
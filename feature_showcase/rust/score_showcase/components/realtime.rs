@@ -0,0 +1,242 @@
+//! Real-time thread setup
+//! Demonstrates S-CORE real-time execution patterns:
+//! - Pin the event-loop thread to one CPU (no migration jitter)
+//! - Raise it to a fixed real-time priority (SCHED_FIFO on Linux)
+//! - Lock and pre-fault memory so the loop takes no page faults
+//!
+//! Each step is optional and independent. Missing privileges (e.g. no
+//! CAP_SYS_NICE for SCHED_FIFO) are reported and the run continues with
+//! the default scheduling, so the same binary works on a developer laptop
+//! and on a tuned target.
+
+use std::hint::black_box;
+
+/// Real-time setup settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealtimeConfig {
+    /// Apply the setup before the event loop starts
+    pub enabled: bool,
+    /// CPU to pin the event-loop thread to (None = don't pin)
+    pub cpu: Option<usize>,
+    /// SCHED_FIFO priority (1-99, 0 = keep the default scheduler)
+    pub priority: u8,
+    /// Heap to pre-fault in KiB (0 = skip)
+    pub prefault_kb: usize,
+}
+
+impl Default for RealtimeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            cpu: Some(0),
+            priority: 50,
+            prefault_kb: 8 * 1024,
+        }
+    }
+}
+
+/// Stack reserved by pre-faulting
+const PREFAULT_STACK_BYTES: usize = 256 * 1024;
+
+/// Page size assumed when touching memory
+const PAGE_SIZE: usize = 4096;
+
+/// Outcome of one setup step
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepResult {
+    Applied(String),
+    Skipped,
+    Failed(String),
+}
+
+/// Outcome of all setup steps
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealtimeReport {
+    pub affinity: StepResult,
+    pub scheduling: StepResult,
+    pub memory_lock: StepResult,
+    pub prefault: StepResult,
+}
+
+impl RealtimeReport {
+    /// Check whether every requested step took effect
+    pub fn fully_applied(&self) -> bool {
+        ![&self.affinity, &self.scheduling, &self.memory_lock, &self.prefault]
+            .iter()
+            .any(|step| matches!(step, StepResult::Failed(_)))
+    }
+
+    pub fn print(&self) {
+        println!("⏱️  Real-time setup:");
+        for (name, step) in [
+            ("CPU affinity", &self.affinity),
+            ("Scheduling", &self.scheduling),
+            ("Memory lock", &self.memory_lock),
+            ("Pre-fault", &self.prefault),
+        ] {
+            match step {
+                StepResult::Applied(detail) => println!("   ✅ {:<13} {}", name, detail),
+                StepResult::Skipped => println!("   ➖ {:<13} not requested", name),
+                StepResult::Failed(reason) => {
+                    println!("   ⚠️  {:<13} {} (continuing without)", name, reason)
+                }
+            }
+        }
+    }
+}
+
+/// Apply the real-time setup to the calling thread
+/// Never fails: steps that can't be applied are reported in the result.
+pub fn setup_realtime_thread(config: &RealtimeConfig) -> RealtimeReport {
+    let affinity = match config.cpu {
+        None => StepResult::Skipped,
+        Some(cpu) => match os::pin_to_cpu(cpu) {
+            Ok(()) => StepResult::Applied(format!("pinned to CPU {}", cpu)),
+            Err(e) => StepResult::Failed(e),
+        },
+    };
+
+    let scheduling = if config.priority == 0 {
+        StepResult::Skipped
+    } else {
+        match os::set_fifo_priority(config.priority) {
+            Ok(()) => StepResult::Applied(format!("SCHED_FIFO priority {}", config.priority)),
+            Err(e) => StepResult::Failed(e),
+        }
+    };
+
+    let (memory_lock, prefault) = if config.prefault_kb == 0 {
+        (StepResult::Skipped, StepResult::Skipped)
+    } else {
+        // Lock first so the pages touched below stay resident
+        let memory_lock = match os::lock_memory() {
+            Ok(()) => StepResult::Applied("current and future pages locked".to_string()),
+            Err(e) => StepResult::Failed(e),
+        };
+        prefault_stack();
+        prefault_heap(config.prefault_kb * 1024);
+        let prefault = StepResult::Applied(format!(
+            "{} KiB stack, {} KiB heap",
+            PREFAULT_STACK_BYTES / 1024,
+            config.prefault_kb
+        ));
+        (memory_lock, prefault)
+    };
+
+    RealtimeReport {
+        affinity,
+        scheduling,
+        memory_lock,
+        prefault,
+    }
+}
+
+/// Touch the stack the event loop will grow into
+#[inline(never)]
+fn prefault_stack() {
+    let mut stack = [0u8; PREFAULT_STACK_BYTES];
+    for page in stack.iter_mut().step_by(PAGE_SIZE) {
+        *page = 1;
+    }
+    black_box(&stack);
+}
+
+/// Touch heap pages so the allocator hands out resident memory later
+fn prefault_heap(bytes: usize) {
+    let mut heap = vec![0u8; bytes];
+    for page in heap.iter_mut().step_by(PAGE_SIZE) {
+        *page = 1;
+    }
+    black_box(&heap);
+}
+
+#[cfg(target_os = "linux")]
+mod os {
+    use std::io;
+    use std::os::raw::c_int;
+
+    const SCHED_FIFO: c_int = 1;
+    const MCL_CURRENT: c_int = 1;
+    const MCL_FUTURE: c_int = 2;
+    const CPU_SETSIZE: usize = 1024;
+    const RLIMIT_MEMLOCK: c_int = 8;
+    const RLIM_INFINITY: u64 = !0;
+
+    #[repr(C)]
+    struct SchedParam {
+        sched_priority: c_int,
+    }
+
+    #[repr(C)]
+    struct Rlimit {
+        rlim_cur: u64,
+        rlim_max: u64,
+    }
+
+    #[repr(C)]
+    struct CpuSet {
+        bits: [u64; CPU_SETSIZE / 64],
+    }
+
+    extern "C" {
+        fn sched_setaffinity(pid: c_int, size: usize, mask: *const CpuSet) -> c_int;
+        fn sched_setscheduler(pid: c_int, policy: c_int, param: *const SchedParam) -> c_int;
+        fn mlockall(flags: c_int) -> c_int;
+        fn getrlimit(resource: c_int, rlim: *mut Rlimit) -> c_int;
+        fn geteuid() -> u32;
+    }
+
+    fn check(result: c_int, what: &str) -> Result<(), String> {
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(format!("{} failed: {}", what, io::Error::last_os_error()))
+        }
+    }
+
+    pub fn pin_to_cpu(cpu: usize) -> Result<(), String> {
+        if cpu >= CPU_SETSIZE {
+            return Err(format!("CPU {} out of range", cpu));
+        }
+        let mut set = CpuSet { bits: [0; CPU_SETSIZE / 64] };
+        set.bits[cpu / 64] |= 1 << (cpu % 64);
+        // pid 0 = calling thread
+        check(unsafe { sched_setaffinity(0, std::mem::size_of::<CpuSet>(), &set) },
+              "sched_setaffinity")
+    }
+
+    pub fn set_fifo_priority(priority: u8) -> Result<(), String> {
+        let param = SchedParam { sched_priority: priority as c_int };
+        check(unsafe { sched_setscheduler(0, SCHED_FIFO, &param) }, "sched_setscheduler")
+    }
+
+    pub fn lock_memory() -> Result<(), String> {
+        // Under a finite RLIMIT_MEMLOCK, locking future pages would make
+        // allocations fail once the limit is reached - refuse up front
+        // unless running as root (CAP_IPC_LOCK bypasses the limit)
+        let mut limit = Rlimit { rlim_cur: 0, rlim_max: 0 };
+        check(unsafe { getrlimit(RLIMIT_MEMLOCK, &mut limit) }, "getrlimit")?;
+        if limit.rlim_cur != RLIM_INFINITY && unsafe { geteuid() } != 0 {
+            return Err(format!("RLIMIT_MEMLOCK is {} KiB, locking needs it unlimited",
+                               limit.rlim_cur / 1024));
+        }
+        check(unsafe { mlockall(MCL_CURRENT | MCL_FUTURE) }, "mlockall")
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod os {
+    const UNSUPPORTED: &str = "not supported on this platform";
+
+    pub fn pin_to_cpu(_cpu: usize) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn set_fifo_priority(_priority: u8) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn lock_memory() -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+}
@@ -0,0 +1,92 @@
+//! Replay of recorded runs
+//! Demonstrates S-CORE offline analysis patterns:
+//! - Recorded snapshots (golden files, black box recordings) read back
+//!   tick by tick
+//! - Every snapshot re-checked against the current safety limits, so a
+//!   recorded drive shows what changed limits would have reported
+//!
+//! The log format is detected from its first line. Black box recordings
+//! need the `recorder` feature.
+
+use std::fs;
+
+use crate::components::config::SystemConfig;
+use crate::components::golden::read_golden_lines;
+use crate::components::json::JsonValue;
+use crate::components::safety::SafetyMonitor;
+use crate::components::snapshot::SystemSnapshot;
+
+/// First bytes of a black box file
+const BLACKBOX_MAGIC: &str = "SCORE-BLACKBOX";
+
+/// Snapshots of a golden file or black box recording, oldest first
+pub fn read_recorded_snapshots(path: &str) -> Result<Vec<SystemSnapshot>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("Cannot read log '{}': {}", path, e))?;
+    if text.starts_with(BLACKBOX_MAGIC) {
+        return read_blackbox_snapshots(path);
+    }
+
+    read_golden_lines(&text)
+        .map_err(|e| format!("{}: {}", path, e))?
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            JsonValue::parse(line)
+                .and_then(|json| SystemSnapshot::from_json(&json))
+                .map_err(|e| format!("{}: snapshot {}: {}", path, index + 1, e))
+        })
+        .collect()
+}
+
+#[cfg(feature = "recorder")]
+fn read_blackbox_snapshots(path: &str) -> Result<Vec<SystemSnapshot>, String> {
+    crate::components::blackbox::BlackBox::read_records(path)?
+        .iter()
+        .filter(|record| record.get("kind").and_then(JsonValue::as_str) == Some("snapshot"))
+        .filter_map(|record| record.get("data"))
+        .map(SystemSnapshot::from_json)
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("{}: {}", path, e))
+}
+
+#[cfg(not(feature = "recorder"))]
+fn read_blackbox_snapshots(path: &str) -> Result<Vec<SystemSnapshot>, String> {
+    Err(format!("{} is a black box recording, but the binary was built without the recorder feature", path))
+}
+
+/// Print a recorded run and re-check it against the configured safety limits
+pub fn run_replay(config: &SystemConfig, path: &str) -> Result<(), String> {
+    let snapshots = read_recorded_snapshots(path)?;
+    let mut monitor = SafetyMonitor::with_config(&config.safety);
+    println!("⏪ Replaying {} ({} ticks)", path, snapshots.len());
+    let units = config.units;
+    println!("   Limits: Speed={}{}, Temp={}{}, RPM={}\n",
+             units.speed(monitor.max_speed() as f32).round(), units.speed_unit(),
+             (units.temperature(monitor.max_temperature()) * 10.0).round() / 10.0, units.temperature_unit(),
+             monitor.max_rpm());
+
+    let mut ticks_with_warnings = 0;
+    for snapshot in &snapshots {
+        let warnings = monitor.check_snapshot(snapshot);
+        let names: Vec<&str> = warnings.iter().map(|w| w.type_name()).collect();
+        if !names.is_empty() {
+            ticks_with_warnings += 1;
+        }
+        println!(
+            "  [{:>5}] {:>3} km/h {:>5} rpm {:>6.1}°C fuel {:>3}% brake {:>3}% {:<9} {}",
+            snapshot.tick,
+            snapshot.speed,
+            snapshot.rpm,
+            snapshot.temperature,
+            snapshot.fuel_level,
+            snapshot.brake_pressure,
+            snapshot.engine_state,
+            if names.is_empty() { "✅".to_string() } else { format!("⚠️  {}", names.join(", ")) }
+        );
+    }
+
+    println!("\n⏪ Replay complete: {} of {} ticks raise warnings under the current limits",
+             ticks_with_warnings, snapshots.len());
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! Seeded random number generation for the simulation
+//! All randomness in the car system flows from one seed so that any run
+//! can be reproduced exactly by passing the same seed again.
+//! Each component gets its own stream forked from the system seed, so adding
+//! a random draw in one component does not shift the values seen by another.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Small, fast PRNG (SplitMix64) - deterministic for a given seed
+#[derive(Debug, Clone)]
+pub struct SimRng {
+    seed: u64,
+    state: u64,
+}
+
+impl SimRng {
+    /// Create a generator from a seed
+    pub fn new(seed: u64) -> Self {
+        Self { seed, state: seed }
+    }
+
+    /// A seed derived from the current time (for non-reproducible runs)
+    pub fn seed_from_time() -> u64 {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        // Scramble so consecutive runs get very different seeds
+        SimRng::new(nanos).next_u64() % 1_000_000_000
+    }
+
+    /// Seed this generator was created with
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Derive an independent generator for a named stream
+    pub fn fork(&self, stream: &str) -> SimRng {
+        // FNV-1a hash of the stream name mixed into the seed
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in stream.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        SimRng::new(self.seed ^ hash)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform integer in `0..upper` (returns 0 when upper is 0)
+    pub fn below(&mut self, upper: u32) -> u32 {
+        if upper == 0 {
+            return 0;
+        }
+        (self.next_u64() % upper as u64) as u32
+    }
+
+    /// Uniform float in `[0, 1)`
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniform float in `[-amplitude, amplitude)`
+    pub fn noise(&mut self, amplitude: f32) -> f32 {
+        (self.next_f32() * 2.0 - 1.0) * amplitude
+    }
+
+    /// True with the given probability (0.0 - 1.0)
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+}
@@ -0,0 +1,188 @@
+//! Safety limit files
+//! Demonstrates S-CORE parameter validation patterns:
+//! - Safety limits kept as data, reviewed and versioned apart from code
+//! - Every file is range-checked field by field, then validated as a whole
+//! - Errors name the file and the offending field
+//!
+//! ```json
+//! { "max_speed": 110, "max_temperature": 95, "max_rpm": 6000,
+//!   "min_fuel": 15, "max_brake_pressure": 80,
+//!   "max_speed_step": 30, "max_temperature_step": 20,
+//!   "max_battery_temperature": 60, "min_battery_soc": 10,
+//!   "filters": { "SpeedExceeded": { "debounce": 3, "hysteresis": 5 } } }
+//! ```
+//!
+//! Fields missing from the file keep their default. `filters` sets the
+//! debounce and hysteresis of warning types (see `WarningFilter`).
+//!
+//! The format is JSON because that's what the crate can parse without
+//! dependencies; the same object is one set of a calibration file.
+
+use std::fs;
+
+use crate::components::json::{object, JsonValue};
+use crate::components::safety::{SafetyConfig, SafetyMonitor, SafetyWarning, WarningFilter};
+
+impl SafetyConfig {
+    /// Read limits from a JSON object and validate them
+    pub fn from_json(json: &JsonValue) -> Result<SafetyConfig, String> {
+        let fields = json.as_object().ok_or("safety limits must be an object")?;
+        let mut limits = SafetyConfig::default();
+
+        for (field, value) in fields {
+            if field == "filters" {
+                limits.filters = filters_from_json(value)?;
+                continue;
+            }
+            let number = value
+                .as_f64()
+                .ok_or_else(|| format!("{} must be a number", field))?;
+            let int = |max: f64| -> Result<f64, String> {
+                if number < 0.0 || number > max || number.fract() != 0.0 {
+                    Err(format!("{} out of range: {}", field, number))
+                } else {
+                    Ok(number)
+                }
+            };
+            match field.as_str() {
+                "max_speed" => limits.max_speed = int(u8::MAX as f64)? as u8,
+                "max_temperature" => limits.max_temperature = number as f32,
+                "max_rpm" => limits.max_rpm = int(u32::MAX as f64)? as u32,
+                "min_fuel" => limits.min_fuel = int(100.0)? as u8,
+                "max_brake_pressure" => limits.max_brake_pressure = int(100.0)? as u8,
+                "max_speed_step" => limits.max_speed_step = int(u8::MAX as f64)? as u8,
+                "max_temperature_step" => limits.max_temperature_step = number as f32,
+                "max_battery_temperature" => limits.max_battery_temperature = number as f32,
+                "min_battery_soc" => limits.min_battery_soc = int(100.0)? as u8,
+                _ => return Err(format!("unknown field '{}'", field)),
+            }
+        }
+
+        limits.validate()?;
+        Ok(limits)
+    }
+
+    /// JSON form; `filters` is only written if there are any
+    pub fn to_json(&self) -> JsonValue {
+        let mut fields = vec![
+            ("max_speed", JsonValue::from(self.max_speed)),
+            ("max_temperature", JsonValue::from(self.max_temperature)),
+            ("max_rpm", JsonValue::from(self.max_rpm)),
+            ("min_fuel", JsonValue::from(self.min_fuel)),
+            ("max_brake_pressure", JsonValue::from(self.max_brake_pressure)),
+            ("max_speed_step", JsonValue::from(self.max_speed_step)),
+            ("max_temperature_step", JsonValue::from(self.max_temperature_step)),
+            ("max_battery_temperature", JsonValue::from(self.max_battery_temperature)),
+            ("min_battery_soc", JsonValue::from(self.min_battery_soc)),
+        ];
+        if !self.filters.is_empty() {
+            let filters = self.filters.iter().map(|(name, filter)| {
+                let filter = object([
+                    ("debounce", JsonValue::from(filter.debounce)),
+                    ("hysteresis", JsonValue::from(filter.hysteresis)),
+                ]);
+                (*name, filter)
+            });
+            fields.push(("filters", object(filters)));
+        }
+        object(fields)
+    }
+
+    /// Load limits from a JSON file
+    pub fn load(path: &str) -> Result<SafetyConfig, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read safety limits '{}': {}", path, e))?;
+        JsonValue::parse(&text)
+            .and_then(|json| Self::from_json(&json))
+            .map_err(|e| format!("{}: {}", path, e))
+    }
+
+    /// Every limit that makes no sense, by field name
+    pub fn errors(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.max_speed == 0 {
+            errors.push("max_speed must be greater than 0".to_string());
+        }
+        if !(self.max_temperature > 0.0 && self.max_temperature < 200.0) {
+            errors.push("max_temperature must be between 0 and 200 °C".to_string());
+        }
+        if self.max_rpm == 0 {
+            errors.push("max_rpm must be greater than 0".to_string());
+        }
+        if self.min_fuel > 100 {
+            errors.push("min_fuel must be at most 100%".to_string());
+        }
+        if self.max_brake_pressure > 100 {
+            errors.push("max_brake_pressure must be at most 100%".to_string());
+        }
+        if self.max_speed_step == 0 {
+            errors.push("max_speed_step must be greater than 0".to_string());
+        }
+        if !(self.max_temperature_step > 0.0 && self.max_temperature_step.is_finite()) {
+            errors.push("max_temperature_step must be a finite number > 0".to_string());
+        }
+        if !(self.max_battery_temperature > 0.0 && self.max_battery_temperature < 100.0) {
+            errors.push("max_battery_temperature must be between 0 and 100 °C".to_string());
+        }
+        if self.min_battery_soc > 100 {
+            errors.push("min_battery_soc must be at most 100%".to_string());
+        }
+        for (name, filter) in &self.filters {
+            if filter.debounce == 0 {
+                errors.push(format!("filters.{}.debounce must be at least 1", name));
+            }
+            if !(filter.hysteresis >= 0.0 && filter.hysteresis.is_finite()) {
+                errors.push(format!("filters.{}.hysteresis must be a finite number >= 0", name));
+            }
+        }
+        errors
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let errors = self.errors();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+}
+
+/// `{ "<WarningType>": { "debounce": N, "hysteresis": X } }`, both optional
+fn filters_from_json(json: &JsonValue) -> Result<Vec<(&'static str, WarningFilter)>, String> {
+    let types = json.as_object().ok_or("filters must be an object")?;
+    let mut filters = Vec::new();
+    for (type_name, fields) in types {
+        let name = SafetyWarning::all_types()
+            .iter()
+            .map(SafetyWarning::type_name)
+            .find(|known| known == type_name)
+            .ok_or_else(|| format!("filters: unknown warning type '{}'", type_name))?;
+        let fields = fields
+            .as_object()
+            .ok_or_else(|| format!("filters.{} must be an object", name))?;
+        let mut filter = WarningFilter::NONE;
+        for (field, value) in fields {
+            let number = value
+                .as_f64()
+                .ok_or_else(|| format!("filters.{}.{} must be a number", name, field))?;
+            match field.as_str() {
+                "debounce" if number.fract() == 0.0 && (0.0..=u8::MAX as f64).contains(&number) => {
+                    filter.debounce = number as u8
+                }
+                "debounce" => return Err(format!("filters.{}.debounce out of range: {}", name, number)),
+                "hysteresis" => filter.hysteresis = number as f32,
+                _ => return Err(format!("filters.{}: unknown field '{}'", name, field)),
+            }
+        }
+        filters.push((name, filter));
+    }
+    Ok(filters)
+}
+
+impl SafetyMonitor {
+    /// Create a monitor with the limits of a safety limits file
+    pub fn from_config(path: &str) -> Result<SafetyMonitor, String> {
+        SafetyConfig::load(path).map(|limits| SafetyMonitor::with_config(&limits))
+    }
+}
@@ -0,0 +1,189 @@
+//! Safety event log
+//! Demonstrates S-CORE safety event recording patterns:
+//! - Every raised warning is recorded with tick, timestamp, severity and
+//!   the values that triggered it
+//! - A warning is recorded when it is raised, not again on every check
+//!   while it stays active
+//! - The log is exported as JSON or CSV at shutdown for later analysis
+
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::components::json::{object, JsonValue};
+use crate::components::safety::{SafetySeverity, SafetyWarning};
+
+/// One raised safety warning
+#[derive(Debug, Clone, PartialEq)]
+pub struct SafetyEvent {
+    pub tick: u64,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    pub warning: SafetyWarning,
+}
+
+impl SafetyEvent {
+    pub fn severity(&self) -> SafetySeverity {
+        self.warning.severity()
+    }
+
+    /// Signal values carried by the warning, by name
+    pub fn values(&self) -> Vec<(&'static str, JsonValue)> {
+        match &self.warning {
+            SafetyWarning::SpeedExceeded { current, max } => {
+                vec![("current", JsonValue::from(*current)), ("max", JsonValue::from(*max))]
+            }
+            SafetyWarning::Overheating { current, max } => {
+                vec![("current", JsonValue::from(*current)), ("max", JsonValue::from(*max))]
+            }
+            SafetyWarning::HighRPM { current, max } => {
+                vec![("current", JsonValue::from(*current)), ("max", JsonValue::from(*max))]
+            }
+            SafetyWarning::LowFuel { level } => vec![("level", JsonValue::from(*level))],
+            SafetyWarning::BrakePressureTooHigh { pressure } => vec![("pressure", JsonValue::from(*pressure))],
+            SafetyWarning::EngineStateInvalid { state } => vec![("state", JsonValue::from(*state))],
+            SafetyWarning::SensorImplausible { signal, change, max } => vec![
+                ("signal", JsonValue::from(*signal)),
+                ("change", JsonValue::from(*change)),
+                ("max", JsonValue::from(*max)),
+            ],
+            SafetyWarning::ComponentUnresponsive { component, missed } => vec![
+                ("component", JsonValue::from(*component)),
+                ("missed", JsonValue::from(*missed)),
+            ],
+            SafetyWarning::SensorDisagreement { signal, spread, tolerance } => vec![
+                ("signal", JsonValue::from(*signal)),
+                ("spread", JsonValue::from(*spread)),
+                ("tolerance", JsonValue::from(*tolerance)),
+            ],
+            SafetyWarning::DeadlineMissed { missed, period_ms } => vec![
+                ("missed", JsonValue::from(*missed)),
+                ("period_ms", JsonValue::from(*period_ms)),
+            ],
+            SafetyWarning::ComponentUnhealthy { component, health } => vec![
+                ("component", JsonValue::from(*component)),
+                ("health", JsonValue::from(health.to_string())),
+            ],
+            SafetyWarning::BatteryOverheating { current, max } => {
+                vec![("current", JsonValue::from(*current)), ("max", JsonValue::from(*max))]
+            }
+            SafetyWarning::BatteryDeepDischarge { soc, min } => {
+                vec![("soc", JsonValue::from(*soc)), ("min", JsonValue::from(*min))]
+            }
+        }
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        object([
+            ("tick", JsonValue::from(self.tick)),
+            ("timestamp_ms", JsonValue::from(self.timestamp)),
+            ("type", JsonValue::from(self.warning.type_name())),
+            ("severity", JsonValue::from(format!("{:?}", self.severity()))),
+            ("requirement", JsonValue::from(self.warning.requirement())),
+            ("goal", JsonValue::from(self.warning.goal().id)),
+            ("asil", JsonValue::from(self.warning.goal().asil.to_string())),
+            ("values", object(self.values())),
+        ])
+    }
+
+    /// CSV row matching `SafetyEventLog::CSV_HEADER`
+    fn to_csv(&self) -> String {
+        let values: Vec<String> = self
+            .values()
+            .into_iter()
+            .map(|(name, value)| match value {
+                JsonValue::String(text) => format!("{}={}", name, text),
+                value => format!("{}={}", name, value.to_pretty_string()),
+            })
+            .collect();
+        format!(
+            "{},{},{},{:?},{},{},{},{}",
+            self.tick,
+            self.timestamp,
+            self.warning.type_name(),
+            self.severity(),
+            self.warning.requirement(),
+            self.warning.goal().id,
+            self.warning.goal().asil,
+            csv_field(&values.join(";"))
+        )
+    }
+}
+
+/// Quote a CSV field if it needs it
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Raised safety warnings of one run, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct SafetyEventLog {
+    events: Vec<SafetyEvent>,
+    /// Warning types active in the last check
+    active: Vec<&'static str>,
+}
+
+impl SafetyEventLog {
+    pub const CSV_HEADER: &'static str = "tick,timestamp_ms,type,severity,requirement,goal,asil,values";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the warnings of one check; only newly raised ones become events
+    pub fn record(&mut self, tick: u64, warnings: &[SafetyWarning]) {
+        for warning in warnings {
+            if !self.active.contains(&warning.type_name()) {
+                self.events.push(SafetyEvent {
+                    tick,
+                    timestamp: SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map(|elapsed| elapsed.as_millis() as u64)
+                        .unwrap_or(0),
+                    warning: warning.clone(),
+                });
+            }
+        }
+        self.active.clear();
+        self.active.extend(warnings.iter().map(SafetyWarning::type_name));
+    }
+
+    pub fn events(&self) -> &[SafetyEvent] {
+        &self.events
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        JsonValue::Array(self.events.iter().map(SafetyEvent::to_json).collect())
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(Self::CSV_HEADER);
+        out.push('\n');
+        for event in &self.events {
+            out.push_str(&event.to_csv());
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Write as CSV (`.csv`) or JSON (anything else)
+    pub fn export(&self, path: &str) -> Result<(), String> {
+        let text = if path.ends_with(".csv") {
+            self.to_csv()
+        } else {
+            self.to_json().to_pretty_string() + "\n"
+        };
+        fs::write(path, text).map_err(|e| format!("Cannot write '{}': {}", path, e))
+    }
+}
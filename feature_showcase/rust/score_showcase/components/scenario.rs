@@ -0,0 +1,332 @@
+//! Scenario files
+//! Demonstrates S-CORE scenario-based verification patterns:
+//! - A drive described as data: settings, length and expectations
+//! - Runs headless on the simulated clock, so results are reproducible
+//! - Every failed expectation is reported, not just the first
+//!
+//! ```json
+//! {
+//!   "name": "Motorway limit",
+//!   "ticks": 100,
+//!   "settings": { "sim.seed": 3, "safety": { "max_speed": 110 } },
+//!   "expect": [
+//!     { "signal": "rpm", "max": 6000 },
+//!     { "tick": 40, "signal": "speed", "min": 50, "max": 130 },
+//!     { "warning": "SpeedExceeded" },
+//!     { "warning": "Overheating", "raised": false }
+//!   ]
+//! }
+//! ```
+//!
+//! A signal expectation without `tick` must hold at every tick. A warning
+//! expectation checks whether the safety monitor raised that warning type
+//! at least once (`raised`, default true).
+//!
+//! `"actions": [{ "tick": 20, "script": "set(\"brake_pressure\", 80)" }]`
+//! runs rhai scripts before the given ticks (needs the `scripting` feature).
+
+use std::fs;
+
+use crate::components::config::{ConfigLayer, SystemConfig};
+use crate::components::harness::{headless_config, TestHarness};
+use crate::components::json::JsonValue;
+#[cfg(feature = "scripting")]
+use crate::components::scripting::Script;
+use crate::components::signal::SignalId;
+use crate::components::snapshot::SystemSnapshot;
+
+/// One checkable statement about a run
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expectation {
+    /// A signal stays within bounds (at one tick, or at every tick)
+    Signal {
+        tick: Option<u64>,
+        signal: SignalId,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    /// A warning type is (or is never) raised during the run
+    Warning { warning: String, raised: bool },
+}
+
+impl Expectation {
+    fn from_json(json: &JsonValue) -> Result<Self, String> {
+        if let Some(warning) = json.get("warning").and_then(JsonValue::as_str) {
+            let raised = match json.get("raised") {
+                None => true,
+                Some(value) => value.as_bool().ok_or("'raised' must be true or false")?,
+            };
+            return Ok(Expectation::Warning {
+                warning: warning.to_string(),
+                raised,
+            });
+        }
+
+        let signal = json
+            .get("signal")
+            .and_then(JsonValue::as_str)
+            .ok_or("expectation needs a 'signal' or a 'warning'")?;
+        let signal = SignalId::parse(signal)?;
+        let bound = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(value) => value
+                .as_f64()
+                .map(Some)
+                .ok_or_else(|| format!("'{}' must be a number", key)),
+        };
+        let (min, max) = (bound("min")?, bound("max")?);
+        if min.is_none() && max.is_none() {
+            return Err(format!("expectation on '{}' needs a 'min' or a 'max'", signal));
+        }
+        let tick = match json.get("tick") {
+            None => None,
+            Some(value) => Some(value.as_u64().ok_or("'tick' must be a non-negative integer")?),
+        };
+        Ok(Expectation::Signal {
+            tick,
+            signal,
+            min,
+            max,
+        })
+    }
+
+    /// Human-readable form for reports
+    pub fn describe(&self) -> String {
+        match self {
+            Expectation::Signal { tick, signal, min, max } => {
+                let when = tick.map_or("always".to_string(), |tick| format!("at tick {}", tick));
+                match (min, max) {
+                    (Some(min), Some(max)) => format!("{} {} <= {} <= {}", when, min, signal, max),
+                    (Some(min), None) => format!("{} {} >= {}", when, signal, min),
+                    (None, Some(max)) => format!("{} {} <= {}", when, signal, max),
+                    (None, None) => format!("{} {}", when, signal),
+                }
+            }
+            Expectation::Warning { warning, raised: true } => format!("{} raised", warning),
+            Expectation::Warning { warning, raised: false } => format!("{} never raised", warning),
+        }
+    }
+
+    /// Check against a finished run; Err describes the violation
+    fn check(&self, snapshots: &[&SystemSnapshot], warnings: &[&'static str]) -> Result<(), String> {
+        match self {
+            Expectation::Signal { tick, signal, min, max } => {
+                let in_bounds = |value: f64| {
+                    min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+                };
+                let checked: Vec<&SystemSnapshot> = match tick {
+                    Some(tick) => {
+                        let snapshot = snapshots
+                            .iter()
+                            .find(|s| s.tick == *tick)
+                            .ok_or_else(|| format!("tick {} is past the end of the run", tick))?;
+                        vec![*snapshot]
+                    }
+                    None => snapshots.to_vec(),
+                };
+                for snapshot in checked {
+                    let value = snapshot.signal(*signal).as_f64();
+                    if !in_bounds(value) {
+                        return Err(format!("tick {}: {} = {}", snapshot.tick, signal, value));
+                    }
+                }
+                Ok(())
+            }
+            Expectation::Warning { warning, raised } => {
+                if warnings.contains(&warning.as_str()) == *raised {
+                    Ok(())
+                } else if *raised {
+                    Err("never raised".to_string())
+                } else {
+                    Err("raised".to_string())
+                }
+            }
+        }
+    }
+}
+
+/// A script run before a tick of the scenario
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioAction {
+    pub tick: u64,
+    pub script: String,
+}
+
+impl ScenarioAction {
+    fn from_json(json: &JsonValue) -> Result<Self, String> {
+        Ok(ScenarioAction {
+            tick: json
+                .get("tick")
+                .and_then(JsonValue::as_u64)
+                .ok_or("'tick' missing or not a non-negative integer")?,
+            script: json
+                .get("script")
+                .and_then(JsonValue::as_str)
+                .ok_or("'script' missing or not a string")?
+                .to_string(),
+        })
+    }
+}
+
+/// A drive described as data
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scenario {
+    pub name: String,
+    pub ticks: u64,
+    /// Config key/value pairs applied on top of the base configuration
+    pub settings: Vec<(String, String)>,
+    pub expectations: Vec<Expectation>,
+    pub actions: Vec<ScenarioAction>,
+}
+
+impl Scenario {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let json = JsonValue::parse(text)?;
+        if json.as_object().is_none() {
+            return Err("top-level value must be an object".to_string());
+        }
+
+        let ticks = json
+            .get("ticks")
+            .and_then(JsonValue::as_u64)
+            .ok_or("'ticks' missing or not a non-negative integer")?;
+        let settings = match json.get("settings") {
+            None => Vec::new(),
+            Some(settings) if settings.as_object().is_some() => settings.flatten(),
+            Some(_) => return Err("'settings' must be an object".to_string()),
+        };
+        let expectations = match json.get("expect") {
+            None => Vec::new(),
+            Some(JsonValue::Array(items)) => items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    Expectation::from_json(item).map_err(|e| format!("expect[{}]: {}", index, e))
+                })
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err("'expect' must be an array".to_string()),
+        };
+        let actions = match json.get("actions") {
+            None => Vec::new(),
+            Some(JsonValue::Array(items)) => items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    ScenarioAction::from_json(item).map_err(|e| format!("actions[{}]: {}", index, e))
+                })
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err("'actions' must be an array".to_string()),
+        };
+
+        Ok(Scenario {
+            name: json
+                .get("name")
+                .and_then(JsonValue::as_str)
+                .unwrap_or("unnamed")
+                .to_string(),
+            ticks,
+            settings,
+            expectations,
+            actions,
+        })
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read scenario '{}': {}", path, e))?;
+        Self::parse(&text).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    /// Headless configuration for this scenario on top of `base`
+    pub fn config(&self, base: &SystemConfig) -> Result<SystemConfig, String> {
+        let mut config = base.clone();
+        for (key, value) in &self.settings {
+            config.set(key, value, ConfigLayer::File)?;
+        }
+        config.ticks = self.ticks;
+        let config = headless_config(config);
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// Run a scenario file and report every expectation
+pub fn run_scenario(base: &SystemConfig, path: &str) -> Result<(), String> {
+    let scenario = Scenario::load(path)?;
+    let config = scenario.config(base)?;
+    println!("🎬 Scenario '{}': {} ticks, {} settings, {} expectations",
+             scenario.name, scenario.ticks, scenario.settings.len(), scenario.expectations.len());
+
+    let actions = compile_actions(&scenario)?;
+
+    let mut harness = TestHarness::with_config(config)?;
+    harness.start_car()?;
+    let mut warnings: Vec<&'static str> = Vec::new();
+    for tick in 0..scenario.ticks {
+        for (at, script) in &actions {
+            if *at == tick {
+                script.run(&mut harness.system)?;
+            }
+        }
+        harness.tick()?;
+        for warning in harness.warnings() {
+            if !warnings.contains(&warning.type_name()) {
+                warnings.push(warning.type_name());
+            }
+        }
+    }
+
+    let snapshots: Vec<&SystemSnapshot> = harness.snapshots().iter().map(|s| s.as_ref()).collect();
+    let mut failed = 0;
+    for expectation in &scenario.expectations {
+        match expectation.check(&snapshots, &warnings) {
+            Ok(()) => println!("   ✅ {}", expectation.describe()),
+            Err(reason) => {
+                failed += 1;
+                println!("   ❌ {} ({})", expectation.describe(), reason);
+            }
+        }
+    }
+
+    if failed == 0 {
+        println!("✅ Scenario '{}' passed", scenario.name);
+        Ok(())
+    } else {
+        Err(format!("Scenario '{}': {} of {} expectations failed",
+                    scenario.name, failed, scenario.expectations.len()))
+    }
+}
+
+#[cfg(feature = "scripting")]
+fn compile_actions(scenario: &Scenario) -> Result<Vec<(u64, Script)>, String> {
+    scenario
+        .actions
+        .iter()
+        .enumerate()
+        .map(|(index, action)| {
+            let name = format!("actions[{}]", index);
+            Script::compile(&name, &action.script).map(|script| (action.tick, script))
+        })
+        .collect()
+}
+
+/// Without the scripting feature only scenarios without actions can run
+#[cfg(not(feature = "scripting"))]
+fn compile_actions(scenario: &Scenario) -> Result<Vec<(u64, NoScript)>, String> {
+    if scenario.actions.is_empty() {
+        Ok(Vec::new())
+    } else {
+        Err(format!("Scenario '{}' has script actions, but the binary was built without the scripting feature",
+                    scenario.name))
+    }
+}
+
+#[cfg(not(feature = "scripting"))]
+enum NoScript {}
+
+#[cfg(not(feature = "scripting"))]
+impl NoScript {
+    fn run(&self, _system: &mut crate::components::system::CarSystem) -> Result<(), String> {
+        match *self {}
+    }
+}
@@ -0,0 +1,135 @@
+//! Scripted workflow steps and scenario actions
+//! Demonstrates S-CORE configurable procedure patterns:
+//! - Demo procedures written as small rhai scripts, changed without
+//!   recompiling the car system
+//! - Scripts see the car only through the typed signal API (`get` and
+//!   `set` by signal name) plus `log`
+//! - Scripts are compiled once; each run reads the current signal values
+//!   and applies its `set` calls in order when the script has finished
+//!
+//! ```text
+//! if get("speed") > 100.0 {
+//!     set("brake_pressure", 60);
+//! }
+//! log(`rpm: ${get("rpm")}`);
+//! ```
+//!
+//! Needs the `scripting` feature, which depends on the `rhai` crate.
+
+use std::cell::RefCell;
+use std::fs;
+use std::rc::Rc;
+
+use rhai::{Engine, EvalAltResult, AST};
+
+use crate::components::signal::{SignalId, SignalValue};
+use crate::components::system::CarSystem;
+use crate::components::workflow::{Workflow, WorkflowBuilder};
+use crate::log_info;
+
+/// Signal values visible to a script run and the writes it requested
+#[derive(Default)]
+struct ScriptState {
+    values: [f64; SignalId::ALL.len()],
+    writes: Vec<(SignalId, f64)>,
+}
+
+fn signal_index(id: SignalId) -> usize {
+    SignalId::ALL.iter().position(|known| *known == id).unwrap_or(0)
+}
+
+/// A compiled script bound to the signal API
+pub struct Script {
+    name: String,
+    engine: Engine,
+    ast: AST,
+    state: Rc<RefCell<ScriptState>>,
+}
+
+impl Script {
+    /// Compile a script; syntax errors are reported here, not when it runs
+    pub fn compile(name: &str, source: &str) -> Result<Self, String> {
+        let state = Rc::new(RefCell::new(ScriptState::default()));
+        let mut engine = Engine::new();
+
+        let reads = Rc::clone(&state);
+        engine.register_fn("get", move |name: &str| -> Result<f64, Box<EvalAltResult>> {
+            let id = SignalId::parse(name)?;
+            Ok(reads.borrow().values[signal_index(id)])
+        });
+        let writes = Rc::clone(&state);
+        engine.register_fn("set", move |name: &str, value: f64| -> Result<(), Box<EvalAltResult>> {
+            let id = SignalId::parse(name)?;
+            writes.borrow_mut().writes.push((id, value));
+            Ok(())
+        });
+        // Integer literals (`set("speed", 50)`) are i64 in rhai
+        let writes = Rc::clone(&state);
+        engine.register_fn("set", move |name: &str, value: i64| -> Result<(), Box<EvalAltResult>> {
+            let id = SignalId::parse(name)?;
+            writes.borrow_mut().writes.push((id, value as f64));
+            Ok(())
+        });
+        engine.register_fn("log", |message: &str| log_info!("Script", "  📜 {}", message));
+
+        let ast = engine
+            .compile(source)
+            .map_err(|e| format!("Script '{}': {}", name, e))?;
+        Ok(Self {
+            name: name.to_string(),
+            engine,
+            ast,
+            state,
+        })
+    }
+
+    /// Compile a script file, named after its path
+    pub fn load(path: &str) -> Result<Self, String> {
+        let source = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read script '{}': {}", path, e))?;
+        Self::compile(path, &source)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Run the script against the current state of a car system
+    pub fn run(&self, system: &mut CarSystem) -> Result<(), String> {
+        {
+            let mut state = self.state.borrow_mut();
+            for id in SignalId::ALL {
+                state.values[signal_index(id)] = system.get_signal(id).as_f64();
+            }
+            state.writes.clear();
+        }
+
+        self.engine
+            .run_ast(&self.ast)
+            .map_err(|e| format!("Script '{}': {}", self.name, e))?;
+
+        let writes = std::mem::take(&mut self.state.borrow_mut().writes);
+        for (id, value) in writes {
+            system
+                .set_signal(id, SignalValue::F32(value as f32))
+                .map_err(|e| format!("Script '{}': {}", self.name, e))?;
+        }
+        Ok(())
+    }
+}
+
+impl WorkflowBuilder {
+    /// Add a step whose action is a script
+    pub fn script_step(&mut self, name: &str, description: &str, source: &str) -> Result<&mut Self, String> {
+        let script = Script::compile(name, source)?;
+        Ok(self.step_fn(name, description, move |system| script.run(system)))
+    }
+}
+
+/// One-step workflow running a script file (`workflows run FILE.rhai`)
+pub fn script_workflow(path: &str) -> Result<Workflow, String> {
+    let script = Script::load(path)?;
+    let mut builder = WorkflowBuilder::new(path, "Scripted workflow");
+    builder.step_fn(path, "Run script", move |system| script.run(system));
+    Ok(builder.build())
+}
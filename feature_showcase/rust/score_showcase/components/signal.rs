@@ -0,0 +1,185 @@
+//! Typed signal access
+//! Demonstrates S-CORE signal abstraction patterns:
+//! - Every observable value of the car addressed by one `SignalId`
+//! - Values carry their native type (`SignalValue`) instead of strings
+//! - One get/set API on `CarSystem` for tooling layers (REST, VSS
+//!   mapping, recorders, tests) instead of per-component getters
+//!
+//! Setting a signal writes the owning component directly; the simulation
+//! (demo driver, plant model, hardware backend) may change it again on
+//! the next tick.
+
+use std::fmt;
+
+use crate::components::messages::ComponentId;
+use crate::components::snapshot::SystemSnapshot;
+use crate::components::system::CarSystem;
+
+/// Identifies one signal of the car
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignalId {
+    Speed,
+    Rpm,
+    Temperature,
+    FuelLevel,
+    BrakePressure,
+    SteeringAngle,
+    Odometer,
+}
+
+impl SignalId {
+    pub const ALL: [SignalId; 7] = [
+        SignalId::Speed,
+        SignalId::Rpm,
+        SignalId::Temperature,
+        SignalId::FuelLevel,
+        SignalId::BrakePressure,
+        SignalId::SteeringAngle,
+        SignalId::Odometer,
+    ];
+
+    /// Name as used in snapshots, scenarios and the CLI
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignalId::Speed => "speed",
+            SignalId::Rpm => "rpm",
+            SignalId::Temperature => "temperature",
+            SignalId::FuelLevel => "fuel_level",
+            SignalId::BrakePressure => "brake_pressure",
+            SignalId::SteeringAngle => "steering_angle",
+            SignalId::Odometer => "odometer",
+        }
+    }
+
+    pub fn unit(&self) -> &'static str {
+        match self {
+            SignalId::Speed => "km/h",
+            SignalId::Rpm => "rpm",
+            SignalId::Temperature => "°C",
+            SignalId::FuelLevel | SignalId::BrakePressure => "%",
+            SignalId::SteeringAngle => "°",
+            SignalId::Odometer => "km",
+        }
+    }
+
+    /// Component that owns the signal and answers queries for it
+    pub fn owner(&self) -> ComponentId {
+        match self {
+            SignalId::Rpm | SignalId::Temperature => ComponentId::ENGINE,
+            SignalId::BrakePressure => ComponentId::BRAKES,
+            SignalId::SteeringAngle => ComponentId::STEERING,
+            SignalId::Speed | SignalId::FuelLevel | SignalId::Odometer => ComponentId::DASHBOARD,
+        }
+    }
+
+    pub fn parse(name: &str) -> Result<SignalId, String> {
+        SignalId::ALL
+            .into_iter()
+            .find(|id| id.as_str() == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = SignalId::ALL.iter().map(SignalId::as_str).collect();
+                format!("Unknown signal '{}' (known: {})", name, names.join(", "))
+            })
+    }
+}
+
+impl fmt::Display for SignalId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Value of a signal in its native type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SignalValue {
+    U8(u8),
+    U32(u32),
+    I16(i16),
+    F32(f32),
+}
+
+impl SignalValue {
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            SignalValue::U8(v) => v as f64,
+            SignalValue::U32(v) => v as f64,
+            SignalValue::I16(v) => v as f64,
+            SignalValue::F32(v) => v as f64,
+        }
+    }
+
+    /// Integer value if it fits in `min..=max` without rounding
+    fn integer(&self, id: SignalId, min: f64, max: f64) -> Result<f64, String> {
+        let value = self.as_f64();
+        if value.fract() == 0.0 && (min..=max).contains(&value) {
+            Ok(value)
+        } else {
+            Err(format!("Value {} out of range for {} ({}..={})", self, id, min, max))
+        }
+    }
+}
+
+impl fmt::Display for SignalValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignalValue::U8(v) => write!(f, "{}", v),
+            SignalValue::U32(v) => write!(f, "{}", v),
+            SignalValue::I16(v) => write!(f, "{}", v),
+            SignalValue::F32(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl SystemSnapshot {
+    /// Value of a signal at this tick
+    pub fn signal(&self, id: SignalId) -> SignalValue {
+        match id {
+            SignalId::Speed => SignalValue::U8(self.speed),
+            SignalId::Rpm => SignalValue::U32(self.rpm),
+            SignalId::Temperature => SignalValue::F32(self.temperature),
+            SignalId::FuelLevel => SignalValue::U8(self.fuel_level),
+            SignalId::BrakePressure => SignalValue::U8(self.brake_pressure),
+            SignalId::SteeringAngle => SignalValue::I16(self.steering_angle),
+            SignalId::Odometer => SignalValue::F32(self.odometer),
+        }
+    }
+}
+
+impl CarSystem {
+    /// Current value of a signal, read from its owning component
+    pub fn get_signal(&self, id: SignalId) -> SignalValue {
+        match id {
+            SignalId::Speed => SignalValue::U8(self.dashboard.get_speed()),
+            SignalId::Rpm => SignalValue::U32(self.engine.get_rpm()),
+            SignalId::Temperature => SignalValue::F32(self.engine.get_temperature()),
+            SignalId::FuelLevel => SignalValue::U8(self.dashboard.get_fuel_level()),
+            SignalId::BrakePressure => SignalValue::U8(self.brakes.get_pressure()),
+            SignalId::SteeringAngle => SignalValue::I16(self.steering.get_angle()),
+            SignalId::Odometer => SignalValue::F32(self.dashboard.get_odometer()),
+        }
+    }
+
+    /// Write a signal to its owning component
+    /// Any numeric variant is accepted if the value fits the signal's range.
+    pub fn set_signal(&mut self, id: SignalId, value: SignalValue) -> Result<(), String> {
+        match id {
+            SignalId::Speed => self.dashboard.set_speed(value.integer(id, 0.0, u8::MAX as f64)? as u8),
+            SignalId::Rpm => self.engine.set_rpm(value.integer(id, 0.0, u32::MAX as f64)? as u32),
+            SignalId::Temperature => self.engine.set_temperature(value.as_f64() as f32),
+            SignalId::FuelLevel => self.dashboard.set_fuel_level(value.integer(id, 0.0, 100.0)? as u8),
+            SignalId::BrakePressure => match value.integer(id, 0.0, 100.0)? as u8 {
+                0 => self.brakes.release(),
+                pressure => self.brakes.apply(pressure)?,
+            },
+            SignalId::SteeringAngle => self.steering.turn(value.integer(id, -90.0, 90.0)? as i16)?,
+            SignalId::Odometer => {
+                let km = value.as_f64();
+                if km.is_nan() || km < 0.0 {
+                    return Err(format!("Value {} out of range for {}", value, id));
+                }
+                self.dashboard.set_odometer(km as f32)
+            }
+        }
+        Ok(())
+    }
+}
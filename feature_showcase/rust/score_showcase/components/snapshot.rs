@@ -0,0 +1,189 @@
+//! Per-tick system snapshots
+//! A snapshot captures every observable signal of the car at one tick, so
+//! runs can be recorded, compared and inspected without parsing stdout.
+//!
+//! `CarSystem` publishes one `Arc<SystemSnapshot>` per tick that the
+//! dashboard, safety monitor, black box and harness all read. Updates are
+//! copy-on-write: a reader holding an older snapshot keeps it unchanged.
+
+use crate::components::json::{object, JsonValue};
+use crate::components::safety::{SafetyInputs, SafetyMonitor, SafetyWarning};
+use crate::components::state_machine::{ChargingState, EngineStateMachine};
+
+/// Observable state of the whole car system at one tick
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SystemSnapshot {
+    pub tick: u64,
+    pub speed: u8,
+    pub rpm: u32,
+    pub temperature: f32,
+    pub fuel_level: u8,
+    pub brake_pressure: u8,
+    pub steering_angle: i16,
+    pub odometer: f32,
+    /// Interned state name (see `EngineStateMachine::as_str`)
+    pub engine_state: &'static str,
+    pub warnings: Vec<String>,
+    /// Traction battery (EV and hybrid powertrains only)
+    pub battery: Option<BatteryReading>,
+}
+
+/// Traction battery signals at one tick
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BatteryReading {
+    /// State of charge in %
+    pub soc: f32,
+    pub temperature: f32,
+    pub voltage: f32,
+    /// Interned state name (see `ChargingState::as_str`)
+    pub charging_state: &'static str,
+}
+
+impl SystemSnapshot {
+    /// Check whether the engine was running at this tick
+    pub fn engine_running(&self) -> bool {
+        self.engine_state == EngineStateMachine::Running.as_str()
+    }
+
+    /// Convert to a JSON object (current snapshot format version)
+    /// `battery` is only written for cars that have one.
+    pub fn to_json(&self) -> JsonValue {
+        let mut fields = vec![
+            ("tick", JsonValue::from(self.tick)),
+            ("speed_kmh", JsonValue::from(self.speed)),
+            ("rpm", JsonValue::from(self.rpm)),
+            ("temperature_c", JsonValue::from(round(self.temperature, 2))),
+            ("fuel_level", JsonValue::from(self.fuel_level)),
+            ("brake_pressure", JsonValue::from(self.brake_pressure)),
+            ("steering_angle", JsonValue::from(self.steering_angle)),
+            ("odometer", JsonValue::from(round(self.odometer, 2))),
+            ("engine_state", JsonValue::from(self.engine_state)),
+            (
+                "warnings",
+                JsonValue::Array(self.warnings.iter().map(|w| JsonValue::from(w.as_str())).collect()),
+            ),
+        ];
+        if let Some(battery) = &self.battery {
+            fields.push((
+                "battery",
+                object([
+                    ("soc", JsonValue::from(round(battery.soc, 2))),
+                    ("temperature_c", JsonValue::from(round(battery.temperature, 2))),
+                    ("voltage", JsonValue::from(round(battery.voltage, 2))),
+                    ("charging_state", JsonValue::from(battery.charging_state)),
+                ]),
+            ));
+        }
+        object(fields)
+    }
+
+    /// Read a snapshot from its JSON object (current format version)
+    pub fn from_json(json: &JsonValue) -> Result<SystemSnapshot, String> {
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(JsonValue::as_f64)
+                .ok_or_else(|| format!("snapshot field '{}' missing or not a number", key))
+        };
+        let integer = |key: &str, max: f64| {
+            let value = number(key)?;
+            if value.fract() == 0.0 && value.abs() <= max {
+                Ok(value)
+            } else {
+                Err(format!("snapshot field '{}' out of range: {}", key, value))
+            }
+        };
+
+        let engine_state = json
+            .get("engine_state")
+            .and_then(JsonValue::as_str)
+            .ok_or("snapshot field 'engine_state' missing or not a string")?;
+        let engine_state = EngineStateMachine::intern(engine_state)
+            .ok_or_else(|| format!("snapshot field 'engine_state' unknown: {}", engine_state))?;
+
+        let warnings = match json.get("warnings") {
+            Some(JsonValue::Array(items)) => items
+                .iter()
+                .map(|item| item.as_str().map(str::to_string))
+                .collect::<Option<Vec<_>>>()
+                .ok_or("snapshot warnings must be strings")?,
+            _ => return Err("snapshot field 'warnings' missing or not an array".to_string()),
+        };
+
+        let battery = match json.get("battery") {
+            Some(battery) => Some(BatteryReading::from_json(battery)?),
+            None => None,
+        };
+
+        Ok(SystemSnapshot {
+            tick: integer("tick", u64::MAX as f64)?.max(0.0) as u64,
+            speed: integer("speed_kmh", u8::MAX as f64)?.max(0.0) as u8,
+            rpm: integer("rpm", u32::MAX as f64)?.max(0.0) as u32,
+            temperature: number("temperature_c")? as f32,
+            fuel_level: integer("fuel_level", u8::MAX as f64)?.max(0.0) as u8,
+            brake_pressure: integer("brake_pressure", u8::MAX as f64)?.max(0.0) as u8,
+            steering_angle: integer("steering_angle", i16::MAX as f64)? as i16,
+            odometer: number("odometer")? as f32,
+            engine_state,
+            warnings,
+            battery,
+        })
+    }
+
+    /// Canonical normalized form: one line, sorted keys, floats rounded to
+    /// two decimals, warnings sorted. Two runs with the same behavior
+    /// produce byte-identical lines.
+    pub fn to_canonical(&self) -> String {
+        let mut normalized = self.clone();
+        normalized.warnings.sort();
+        normalized.to_json().to_string()
+    }
+}
+
+impl BatteryReading {
+    fn from_json(json: &JsonValue) -> Result<BatteryReading, String> {
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(JsonValue::as_f64)
+                .map(|value| value as f32)
+                .ok_or_else(|| format!("snapshot field 'battery.{}' missing or not a number", key))
+        };
+        let charging_state = json
+            .get("charging_state")
+            .and_then(JsonValue::as_str)
+            .ok_or("snapshot field 'battery.charging_state' missing or not a string")?;
+        Ok(BatteryReading {
+            soc: number("soc")?,
+            temperature: number("temperature_c")?,
+            voltage: number("voltage")?,
+            charging_state: ChargingState::intern(charging_state)
+                .ok_or_else(|| format!("snapshot field 'battery.charging_state' unknown: {}", charging_state))?,
+        })
+    }
+}
+
+/// Round to a fixed number of decimals so float noise doesn't cause diffs
+fn round(value: f32, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value as f64 * factor).round() / factor
+}
+
+impl SafetyInputs {
+    pub fn from_snapshot(snapshot: &SystemSnapshot) -> Self {
+        Self {
+            speed: snapshot.speed,
+            temperature: snapshot.temperature,
+            rpm: snapshot.rpm,
+            fuel: snapshot.fuel_level,
+            brake_pressure: snapshot.brake_pressure,
+            engine_running: snapshot.engine_running(),
+        }
+    }
+}
+
+impl SafetyMonitor {
+    /// Check all rules against the shared snapshot of a tick
+    /// Incremental and filtered: see `check_filtered`.
+    pub fn check_snapshot(&mut self, snapshot: &SystemSnapshot) -> Vec<SafetyWarning> {
+        self.check_filtered(&SafetyInputs::from_snapshot(snapshot))
+    }
+}
@@ -0,0 +1,202 @@
+//! Soak testing with leak and growth detection
+//! Demonstrates S-CORE long-run verification patterns:
+//! - Run many simulated hours without sleeping
+//! - Sample growth indicators (queues, warning lists, heap usage)
+//! - Report indicators that grow monotonically as failures
+//! - Verify the steady-state message and render paths perform no heap
+//!   allocation
+//!
+//! Heap usage is measured by `CountingAllocator`; the binary installs it
+//! as the global allocator. Without it the heap indicators stay at 0.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::components::config::SystemConfig;
+use crate::components::event_loop::ClockSource;
+use crate::components::persistency::keys;
+use crate::components::system::CarSystem;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator wrapper that counts allocations and live bytes
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            LIVE_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+            LIVE_BYTES.fetch_add(new_size, Ordering::Relaxed);
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        new_ptr
+    }
+}
+
+/// Bytes currently allocated through `CountingAllocator`
+pub fn live_heap_bytes() -> usize {
+    LIVE_BYTES.load(Ordering::Relaxed)
+}
+
+/// Total allocations made through `CountingAllocator`
+pub fn allocation_count() -> usize {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+/// Number of samples taken over a soak run
+const SAMPLES: u64 = 20;
+
+/// Cycles run before counting message path allocations
+/// Long enough for buffers to grow and the engine to reach operating
+/// temperature, after which the dashboard sees no new warning texts.
+const WARMUP_CYCLES: u64 = 2000;
+
+/// Cycles over which message path allocations are counted
+const MEASURED_CYCLES: u64 = 1000;
+
+/// Growth indicators sampled during the run (name, value)
+/// A fixed-size array so sampling itself doesn't allocate.
+fn sample(car: &CarSystem) -> [(&'static str, usize); 5] {
+    [
+        ("bus_pending_messages", car.message_bus.total_pending()),
+        ("dashboard_warnings", car.dashboard.get_warnings().len()),
+        ("safety_warnings", car.last_warnings().len()),
+        ("persisted_faults", car.store.get_strings(keys::LATCHED_FAULTS).len()),
+        ("live_heap_bytes", live_heap_bytes()),
+    ]
+}
+
+/// Count heap allocations of `process_cycle` in steady state
+/// Components, bus and dashboard reuse their buffers, so this is 0
+/// unless something on the message path allocates per cycle.
+pub fn message_path_allocations(config: &SystemConfig) -> Result<usize, String> {
+    let mut car = CarSystem::with_config(config.clone());
+    car.message_bus.set_trace(false);
+    car.engine.start()?;
+
+    for _ in 0..WARMUP_CYCLES {
+        car.process_cycle(60)?;
+    }
+    let before = allocation_count();
+    for _ in 0..MEASURED_CYCLES {
+        car.process_cycle(60)?;
+    }
+    Ok(allocation_count() - before)
+}
+
+/// Count heap allocations of rendering the dashboard frame in steady state
+/// Only `DashboardComponent::render` is counted, not the tick around it.
+#[cfg(feature = "render")]
+pub fn render_allocations(config: &SystemConfig) -> Result<usize, String> {
+    let mut car = CarSystem::with_config(config.clone());
+    car.message_bus.set_trace(false);
+    car.engine.start()?;
+
+    let mut allocations = 0;
+    for tick in 0..WARMUP_CYCLES + MEASURED_CYCLES {
+        let snapshot = car.tick(tick)?;
+        let before = allocation_count();
+        car.dashboard.render(&snapshot);
+        if tick >= WARMUP_CYCLES {
+            allocations += allocation_count() - before;
+        }
+    }
+    Ok(allocations)
+}
+
+/// Check whether every sample is larger than the one before
+fn grows_monotonically(values: &[usize]) -> bool {
+    values.len() >= 3 && values.windows(2).all(|pair| pair[1] > pair[0])
+}
+
+/// Run `config.ticks` headless ticks and fail on monotonic growth
+/// The first sample is taken after one interval so start-up allocation
+/// and warm-up (e.g. engine reaching operating temperature) don't count.
+pub fn run_soak(config: &SystemConfig) -> Result<(), String> {
+    let mut config = config.clone();
+    config.event_loop.clock = ClockSource::Simulated;
+    config.render = false;
+    config.persistency.path.clear();
+    config.blackbox.path.clear();
+    config.hardware = Default::default();
+
+    let ticks = config.ticks;
+    let interval = (ticks / (SAMPLES + 1)).max(1);
+    let simulated_hours = ticks as f64 * config.event_loop.time_step_s() as f64 / 3600.0;
+
+    let mut car = CarSystem::with_config(config.clone());
+    car.initialize()?;
+    car.message_bus.set_trace(false);
+    CarSystem::create_start_workflow().execute_quiet(&mut car)?;
+
+    println!("🧪 Soak test: {} ticks ({:.1} simulated hours), sampling every {} ticks",
+             ticks, simulated_hours, interval);
+
+    let allocations_before = allocation_count();
+    let mut history = Vec::with_capacity(SAMPLES as usize + 1);
+    for tick in 0..ticks {
+        car.tick(tick)?;
+        if tick > 0 && tick % interval == 0 {
+            history.push(sample(&car));
+        }
+    }
+    let allocations = allocation_count() - allocations_before;
+    let path_allocations = message_path_allocations(&config)?;
+
+    println!("\n🧪 Soak results ({} samples, {} allocations, {:.1} per tick)",
+             history.len(), allocations, allocations as f64 / ticks.max(1) as f64);
+
+    let mut failures = Vec::new();
+    if let Some(first) = history.first() {
+        for (index, (name, _)) in first.iter().enumerate() {
+            let values: Vec<usize> = history.iter().map(|s| s[index].1).collect();
+            let growing = grows_monotonically(&values);
+            println!("   {} {:<22} first {:>10}  last {:>10}",
+                     if growing { "❌" } else { "✅" },
+                     name, values[0], values[values.len() - 1]);
+            if growing {
+                failures.push(*name);
+            }
+        }
+    }
+
+    println!("   {} {:<22} {} allocations over {} steady-state cycles",
+             if path_allocations == 0 { "✅" } else { "❌" },
+             "message_path", path_allocations, MEASURED_CYCLES);
+    if path_allocations > 0 {
+        failures.push("message_path");
+    }
+    #[cfg(feature = "render")]
+    {
+        let render_allocations = render_allocations(&config)?;
+        println!("   {} {:<22} {} allocations over {} steady-state frames",
+                 if render_allocations == 0 { "✅" } else { "❌" },
+                 "dashboard_render", render_allocations, MEASURED_CYCLES);
+        if render_allocations > 0 {
+            failures.push("dashboard_render");
+        }
+    }
+
+    if failures.is_empty() {
+        println!("✅ No monotonic growth or steady-state allocation detected");
+        Ok(())
+    } else {
+        Err(format!("soak check failed: {}", failures.join(", ")))
+    }
+}
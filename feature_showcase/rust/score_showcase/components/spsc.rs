@@ -0,0 +1,206 @@
+//! Lock-free single-producer/single-consumer ring buffers
+//! Demonstrates S-CORE real-time communication patterns:
+//! - Fixed capacity chosen at compile time, no allocation after creation
+//! - Wait-free publish: `push` never blocks, a full ring is reported
+//! - Batched consume: one synchronisation per batch, not per message
+//!
+//! One ring connects exactly one sender to one receiver (e.g. Engine →
+//! Dashboard), so each side owns its index and only reads the other's.
+//! The threaded execution mode (`ActorRuntime`) connects its actors with
+//! these rings; the single threaded event loop keeps using the `MessageBus`.
+
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Keeps the two indices on separate cache lines (no false sharing)
+#[repr(align(64))]
+struct CachePadded(AtomicUsize);
+
+struct Ring<T, const N: usize> {
+    slots: [UnsafeCell<MaybeUninit<T>>; N],
+    /// Next slot to read, written only by the consumer
+    head: CachePadded,
+    /// Next slot to write, written only by the producer
+    tail: CachePadded,
+}
+
+// Safety: a slot is only accessed by the side that currently owns it, and
+// ownership is handed over through the acquire/release index updates.
+unsafe impl<T: Send, const N: usize> Sync for Ring<T, N> {}
+
+impl<T, const N: usize> Drop for Ring<T, N> {
+    fn drop(&mut self) {
+        let head = *self.head.0.get_mut();
+        let tail = *self.tail.0.get_mut();
+        for offset in 0..tail.wrapping_sub(head) {
+            let index = head.wrapping_add(offset) % N;
+            unsafe { self.slots[index].get_mut().assume_init_drop() };
+        }
+    }
+}
+
+/// Sending half of a ring
+pub struct Producer<T, const N: usize> {
+    ring: Arc<Ring<T, N>>,
+}
+
+/// Receiving half of a ring
+pub struct Consumer<T, const N: usize> {
+    ring: Arc<Ring<T, N>>,
+}
+
+/// Create a ring holding up to `N` messages
+pub fn spsc_ring<T: Send, const N: usize>() -> (Producer<T, N>, Consumer<T, N>) {
+    assert!(N > 0, "SPSC ring capacity must be greater than 0");
+    let ring = Arc::new(Ring {
+        slots: std::array::from_fn(|_| UnsafeCell::new(MaybeUninit::uninit())),
+        head: CachePadded(AtomicUsize::new(0)),
+        tail: CachePadded(AtomicUsize::new(0)),
+    });
+    (Producer { ring: ring.clone() }, Consumer { ring })
+}
+
+impl<T: Send, const N: usize> Producer<T, N> {
+    /// Publish a message without blocking
+    /// Returns the message back if the ring is full.
+    pub fn push(&mut self, message: T) -> Result<(), T> {
+        let tail = self.ring.tail.0.load(Ordering::Relaxed);
+        let head = self.ring.head.0.load(Ordering::Acquire);
+        if tail.wrapping_sub(head) == N {
+            return Err(message);
+        }
+        unsafe { (*self.ring.slots[tail % N].get()).write(message) };
+        self.ring.tail.0.store(tail.wrapping_add(1), Ordering::Release);
+        Ok(())
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<T: Send, const N: usize> Consumer<T, N> {
+    /// Take the oldest message, if any
+    pub fn pop(&mut self) -> Option<T> {
+        let head = self.ring.head.0.load(Ordering::Relaxed);
+        let tail = self.ring.tail.0.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        let message = unsafe { (*self.ring.slots[head % N].get()).assume_init_read() };
+        self.ring.head.0.store(head.wrapping_add(1), Ordering::Release);
+        Some(message)
+    }
+
+    /// Move all available messages to `out` with a single index update
+    /// Returns the number of messages received.
+    pub fn drain_into(&mut self, out: &mut Vec<T>) -> usize {
+        let head = self.ring.head.0.load(Ordering::Relaxed);
+        let tail = self.ring.tail.0.load(Ordering::Acquire);
+        let count = tail.wrapping_sub(head);
+        for offset in 0..count {
+            let index = head.wrapping_add(offset) % N;
+            out.push(unsafe { (*self.ring.slots[index].get()).assume_init_read() });
+        }
+        self.ring.head.0.store(tail, Ordering::Release);
+        count
+    }
+
+    /// Number of messages waiting
+    pub fn len(&self) -> usize {
+        let tail = self.ring.tail.0.load(Ordering::Acquire);
+        tail.wrapping_sub(self.ring.head.0.load(Ordering::Relaxed))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn push_on_a_full_ring_returns_the_message() {
+        let (mut producer, mut consumer) = spsc_ring::<u32, 2>();
+        assert_eq!(producer.push(1), Ok(()));
+        assert_eq!(producer.push(2), Ok(()));
+        assert_eq!(producer.push(3), Err(3));
+        assert_eq!(consumer.len(), 2);
+
+        assert_eq!(consumer.pop(), Some(1));
+        assert_eq!(producer.push(3), Ok(()));
+        assert_eq!((consumer.pop(), consumer.pop(), consumer.pop()), (Some(2), Some(3), None));
+    }
+
+    #[test]
+    fn drain_into_reads_across_the_wrap_point() {
+        let (mut producer, mut consumer) = spsc_ring::<u32, 4>();
+        for message in 1..=3 {
+            producer.push(message).unwrap();
+        }
+        assert_eq!((consumer.pop(), consumer.pop()), (Some(1), Some(2)));
+        // Slots 3, 0 and 1: the ring now wraps around its end
+        for message in 4..=6 {
+            producer.push(message).unwrap();
+        }
+
+        let mut out = vec![0];
+        assert_eq!(consumer.drain_into(&mut out), 4);
+        assert_eq!(out, [0, 3, 4, 5, 6]);
+        assert!(consumer.is_empty());
+        assert_eq!(consumer.drain_into(&mut out), 0);
+    }
+
+    #[test]
+    fn dropping_a_ring_drops_the_messages_left_in_it() {
+        let message = Arc::new(());
+        let (mut producer, mut consumer) = spsc_ring::<Arc<()>, 3>();
+        for _ in 0..3 {
+            producer.push(Arc::clone(&message)).unwrap();
+        }
+        drop(consumer.pop());
+        drop(consumer.pop());
+        producer.push(Arc::clone(&message)).unwrap();
+        producer.push(Arc::clone(&message)).unwrap();
+        assert_eq!(Arc::strong_count(&message), 4);
+
+        drop(producer);
+        assert_eq!(Arc::strong_count(&message), 4, "the consumer still holds the ring");
+        drop(consumer);
+        assert_eq!(Arc::strong_count(&message), 1);
+    }
+
+    #[test]
+    fn messages_cross_threads_in_order() {
+        const MESSAGES: u64 = 100_000;
+        let (mut producer, mut consumer) = spsc_ring::<u64, 8>();
+
+        let sender = thread::spawn(move || {
+            for mut message in 0..MESSAGES {
+                while let Err(full) = producer.push(message) {
+                    message = full;
+                    thread::yield_now();
+                }
+            }
+        });
+
+        let mut received = Vec::with_capacity(MESSAGES as usize);
+        while received.len() < MESSAGES as usize {
+            // Alternate single and batched receives
+            match consumer.pop() {
+                Some(message) => received.push(message),
+                None => thread::yield_now(),
+            }
+            consumer.drain_into(&mut received);
+        }
+        sender.join().unwrap();
+
+        assert!(received.iter().copied().eq(0..MESSAGES), "messages lost, repeated or reordered");
+        assert!(consumer.is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+//! Steering component - manages steering angle
+//! Demonstrates S-CORE patterns:
+//! - Input validation (angle bounds checking)
+//! - Automatic state correction (return to center)
+//! - State machine (centered → turned → returning)
+//! - Message publishing (Phase 3)
+
+use crate::components::{CarComponent, ComponentState, CarMessage, HealthStatus};
+use crate::components::state_machine::{StateMachine, SteeringState, TransitionMachine};
+use crate::log_info;
+
+/// Steering component - manages the car's steering system
+pub struct SteeringComponent {
+    state: ComponentState,
+    steering_state: TransitionMachine<SteeringState>,
+    angle: i16, // -90 to +90 degrees (negative = left, positive = right)
+}
+
+impl SteeringComponent {
+    /// Create a new steering component
+    pub fn new() -> Self {
+        Self {
+            state: ComponentState::Offline,
+            steering_state: SteeringState::machine(),
+            angle: 0,
+        }
+    }
+
+    /// Turn steering wheel to specified angle
+    /// Negative values turn left, positive values turn right
+    pub fn turn(&mut self, angle: i16) -> Result<(), String> {
+        if !(-90..=90).contains(&angle) {
+            return Err("Angle must be between -90 and +90 degrees".to_string());
+        }
+
+        let direction = if angle > self.angle {
+            "right"
+        } else if angle < self.angle {
+            "left"
+        } else {
+            "centered"
+        };
+
+        self.enter(if angle == 0 { SteeringState::Centered } else { SteeringState::Turned }, "turned")?;
+        self.angle = angle;
+        log_info!("Steering", "  🔄 Steering: Turn {} to {}°", direction, angle);
+        Ok(())
+    }
+
+    /// Center the steering wheel
+    pub fn center(&mut self) {
+        if self.steering_state.can_transition_to(&SteeringState::Centered) {
+            log_info!("Steering", "  🔄 Steering: Returning to center");
+            self.steering_state.set_state_because(SteeringState::Centered, "centered");
+            self.angle = 0;
+        }
+    }
+
+    /// Move the state machine to `state` (staying in a state is always allowed)
+    fn enter(&mut self, state: SteeringState, reason: &'static str) -> Result<(), String> {
+        if *self.steering_state.current_state() == state {
+            return Ok(());
+        }
+        self.steering_state.transition_because(state, reason)
+    }
+
+    /// Steering state machine with its transition history
+    pub fn state_machine(&self) -> &TransitionMachine<SteeringState> {
+        &self.steering_state
+    }
+
+    /// Stamp the following state transitions with this tick
+    pub fn set_tick(&mut self, tick: u64) {
+        self.steering_state.set_tick(tick);
+    }
+
+    /// Get current steering state
+    pub fn get_steering_state(&self) -> &SteeringState {
+        self.steering_state.current_state()
+    }
+
+    /// Get current steering angle
+    pub fn get_angle(&self) -> i16 {
+        self.angle
+    }
+
+    /// Get direction as string
+    pub fn get_direction(&self) -> &'static str {
+        if self.angle > 10 {
+            "RIGHT"
+        } else if self.angle < -10 {
+            "LEFT"
+        } else {
+            "CENTER"
+        }
+    }
+
+    /// Get messages to publish (Phase 3: Communication)
+    pub fn get_messages(&self) -> Vec<CarMessage> {
+        let mut messages = Vec::new();
+        self.get_messages_into(&mut messages);
+        messages
+    }
+
+    /// Append messages to publish to a reused buffer
+    pub fn get_messages_into(&self, messages: &mut Vec<CarMessage>) {
+        // Report steering angle when not centered
+        if self.angle != 0 {
+            messages.push(CarMessage::SteeringTurn { angle: self.angle });
+        }
+    }
+}
+
+impl Default for SteeringComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CarComponent for SteeringComponent {
+    fn name(&self) -> &'static str {
+        "Steering"
+    }
+
+    fn initialize(&mut self) -> Result<(), String> {
+        log_info!("Steering", "🔧 Steering: Initializing component...");
+        self.state = ComponentState::Initializing;
+
+        // Simulate initialization checks
+        log_info!("Steering", "  🔍 Steering: Checking power steering... OK");
+        log_info!("Steering", "  🔍 Steering: Calibrating center position... OK");
+
+        self.state = ComponentState::Online;
+        log_info!("Steering", "✅ Steering: Initialized (state: {})", self.state);
+        Ok(())
+    }
+
+    fn process(&mut self) -> Result<(), String> {
+        // Slowly return to center (power steering assist)
+        // This simulates automatic centering behavior
+        const CENTERING_RATE: i16 = 2;
+
+        if self.angle > 0 {
+            self.angle = (self.angle - CENTERING_RATE).max(0);
+        } else if self.angle < 0 {
+            self.angle = (self.angle + CENTERING_RATE).min(0);
+        }
+        let state = if self.angle == 0 { SteeringState::Centered } else { SteeringState::Returning };
+        self.enter(state, "power steering")?;
+
+        Ok(())
+    }
+
+    fn get_state(&self) -> ComponentState {
+        self.state.clone()
+    }
+
+    fn shutdown(&mut self) -> Result<(), String> {
+        log_info!("Steering", "🔧 Steering: Shutting down...");
+        self.center();
+        self.state = ComponentState::Offline;
+        log_info!("Steering", "✅ Steering: Shut down (state: {})", self.get_steering_state());
+        Ok(())
+    }
+
+    fn health_check(&self) -> HealthStatus {
+        if self.angle < -90 || self.angle > 90 {
+            return HealthStatus::Unhealthy("angle outside ±90°");
+        }
+        HealthStatus::from_state(&self.state)
+    }
+}
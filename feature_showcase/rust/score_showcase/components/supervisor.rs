@@ -0,0 +1,174 @@
+//! Component supervisor - restart policies for failed components
+//! Demonstrates S-CORE lifecycle management patterns:
+//! - Failure detection: a component in the error state, an unhealthy
+//!   `health_check` or a watchdog expiry (missed heartbeats) counts as failed
+//! - Restart policies per component: restart up to N times and then
+//!   escalate, degrade (take the component offline and run the
+//!   degraded-mode workflow) or shut the car down
+//! - Every restart is published as a `ComponentRestarted` event
+//!
+//! Restarts are counted over the whole run. A critical component can't be
+//! left offline, so degrading one shuts the car down instead.
+
+use std::fmt;
+
+/// What the supervisor does when a component fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// Shut the component down and initialize it again, at most N times;
+    /// further failures escalate
+    Restart(u32),
+    /// Take the component offline and run the degraded-mode workflow
+    Degrade,
+    /// Stop the car
+    Shutdown,
+}
+
+impl RestartPolicy {
+    pub fn parse(s: &str) -> Result<RestartPolicy, String> {
+        match s.trim() {
+            "degrade" => Ok(RestartPolicy::Degrade),
+            "shutdown" => Ok(RestartPolicy::Shutdown),
+            other => other
+                .strip_prefix("restart:")
+                .and_then(|count| count.trim().parse().ok())
+                .map(RestartPolicy::Restart)
+                .ok_or_else(|| format!("Invalid restart policy: {} (restart:N|degrade|shutdown)", other)),
+        }
+    }
+
+    /// Parse "name:policy" pairs separated by commas ("" = none)
+    pub fn parse_list(s: &str) -> Result<Vec<(String, RestartPolicy)>, String> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (name, policy) = entry
+                    .split_once(':')
+                    .ok_or_else(|| format!("Invalid component policy '{}' (expected name:policy)", entry))?;
+                Ok((name.trim().to_string(), RestartPolicy::parse(policy)?))
+            })
+            .collect()
+    }
+
+    /// Format pairs as `parse_list` reads them
+    pub fn format_list(policies: &[(String, RestartPolicy)]) -> String {
+        let entries: Vec<String> = policies.iter().map(|(name, policy)| format!("{}:{}", name, policy)).collect();
+        entries.join(",")
+    }
+}
+
+impl fmt::Display for RestartPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RestartPolicy::Restart(count) => write!(f, "restart:{}", count),
+            RestartPolicy::Degrade => write!(f, "degrade"),
+            RestartPolicy::Shutdown => write!(f, "shutdown"),
+        }
+    }
+}
+
+/// Restart policies of the components
+#[derive(Debug, Clone, PartialEq)]
+pub struct SupervisorConfig {
+    pub enabled: bool,
+    /// Policy of every component not listed in `components`
+    pub policy: RestartPolicy,
+    /// Reaction once a component has used up its restarts (degrade or shutdown)
+    pub escalation: RestartPolicy,
+    /// Per-component policies
+    pub components: Vec<(String, RestartPolicy)>,
+}
+
+impl Default for SupervisorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            policy: RestartPolicy::Restart(3),
+            escalation: RestartPolicy::Degrade,
+            components: Vec::new(),
+        }
+    }
+}
+
+/// Reaction to one component failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupervisorAction {
+    /// Restart the component; `attempt` counts from 1 up to `max`
+    Restart { attempt: u32, max: u32 },
+    Degrade,
+    Shutdown,
+}
+
+/// Decides how to react to component failures
+#[derive(Debug, Clone)]
+pub struct Supervisor {
+    config: SupervisorConfig,
+    /// Restarts per component so far
+    restarts: Vec<(&'static str, u32)>,
+    /// Components degraded or shut down for; their failures are ignored
+    given_up: Vec<&'static str>,
+}
+
+impl Supervisor {
+    pub fn new(config: &SupervisorConfig) -> Self {
+        Self {
+            config: config.clone(),
+            restarts: Vec::new(),
+            given_up: Vec::new(),
+        }
+    }
+
+    /// Policy that applies to a component
+    pub fn policy(&self, component: &str) -> RestartPolicy {
+        self.config
+            .components
+            .iter()
+            .find(|(name, _)| name == component)
+            .map_or(self.config.policy, |(_, policy)| *policy)
+    }
+
+    /// Decide how to react to a failure of `component`
+    /// None once the supervisor has degraded or shut down for it.
+    pub fn on_failure(&mut self, component: &'static str) -> Option<SupervisorAction> {
+        if self.given_up.contains(&component) {
+            return None;
+        }
+        let action = match self.policy(component) {
+            RestartPolicy::Restart(max) => {
+                let restarts = match self.restarts.iter_mut().find(|(name, _)| *name == component) {
+                    Some((_, restarts)) => restarts,
+                    None => {
+                        self.restarts.push((component, 0));
+                        &mut self.restarts.last_mut().expect("just pushed").1
+                    }
+                };
+                if *restarts < max {
+                    *restarts += 1;
+                    return Some(SupervisorAction::Restart { attempt: *restarts, max });
+                }
+                match self.config.escalation {
+                    RestartPolicy::Shutdown => SupervisorAction::Shutdown,
+                    _ => SupervisorAction::Degrade,
+                }
+            }
+            RestartPolicy::Degrade => SupervisorAction::Degrade,
+            RestartPolicy::Shutdown => SupervisorAction::Shutdown,
+        };
+        self.given_up.push(component);
+        Some(action)
+    }
+
+    /// Restarts of one component so far
+    pub fn restarts(&self, component: &str) -> u32 {
+        self.restarts
+            .iter()
+            .find(|(name, _)| *name == component)
+            .map_or(0, |(_, restarts)| *restarts)
+    }
+
+    /// Restarts of all components so far
+    pub fn total_restarts(&self) -> u32 {
+        self.restarts.iter().map(|(_, restarts)| restarts).sum()
+    }
+}
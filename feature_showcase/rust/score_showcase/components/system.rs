@@ -0,0 +1,2517 @@
+//! Car system orchestration
+//! This module contains the main CarSystem struct that coordinates all components
+
+use std::any::Any;
+use std::fmt;
+use std::fs;
+#[cfg(feature = "render")]
+use std::io::{self, LineWriter, Write};
+use std::mem;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::components::*;
+use crate::components::json::object;
+use crate::{log_error, log_info, log_warn};
+
+/// Scripted demo driver - speeds up to 130 km/h and back down
+struct DemoDriver {
+    speed: u8,
+    accelerating: bool,
+}
+
+impl DemoDriver {
+    fn new() -> Self {
+        Self {
+            speed: 0,
+            accelerating: true,
+        }
+    }
+
+    /// Target speed for the given tick
+    fn next_speed(&mut self, tick_num: u64) -> u8 {
+        // Simulate speed oscillation
+        if tick_num.is_multiple_of(25) {
+            if self.accelerating {
+                if self.speed >= 130 {
+                    self.accelerating = false;
+                }
+            } else if self.speed == 0 {
+                self.accelerating = true;
+            }
+        }
+
+        if self.accelerating && self.speed < 130 {
+            self.speed += 5;
+        } else if !self.accelerating && self.speed > 0 {
+            self.speed -= 5;
+        }
+        self.speed
+    }
+}
+
+/// Order in which components are processed each tick
+/// Components within a stage don't depend on each other and may run in
+/// parallel; a stage only starts once the previous one has finished. The
+/// dashboard is processed after message routing and isn't listed here.
+pub const PROCESS_STAGES: &[&[ComponentId]] = &[
+    &[ComponentId::ENGINE, ComponentId::BRAKES, ComponentId::STEERING],
+];
+
+/// Bus cycles a request from the car system may wait for its reply
+pub const REQUEST_TIMEOUT_CYCLES: u64 = 3;
+
+/// Components the car cannot run without
+/// Any other component that fails to initialize may stay offline, depending
+/// on the configured `InitPolicy`.
+pub const CRITICAL_COMPONENTS: &[&str] = &["Engine", "Brakes", "Steering"];
+
+/// Car system - orchestrates all components
+/// This demonstrates S-CORE's orchestration pattern
+pub struct CarSystem {
+    pub engine: EngineComponent,
+    pub brakes: BrakesComponent,
+    pub steering: SteeringComponent,
+    pub dashboard: DashboardComponent,
+    /// Traction battery (EV and hybrid powertrains only)
+    pub battery: Option<BatteryComponent>,
+    /// Climate control (only when enabled)
+    pub hvac: Option<HvacComponent>,
+    /// Forward obstacle sensor (automatic emergency braking only)
+    pub obstacle_sensor: Option<ObstacleSensor>,
+    /// Odometry and route tracking (only when enabled)
+    pub location: Option<LocationComponent>,
+    /// Accelerator pedal controller (physics dynamics only)
+    pub throttle: Option<ThrottleComponent>,
+    /// Longitudinal model producing the speed (physics dynamics only)
+    pub dynamics: Option<VehicleDynamics>,
+    pub message_bus: MessageBus,
+    /// IDs of the well-known and runtime-registered components
+    pub registry: ComponentRegistry,
+    pub safety: SafetyMonitor,
+    /// Every safety warning raised so far
+    pub safety_log: SafetyEventLog,
+    /// Liveness supervision of the online components
+    pub watchdog: Watchdog,
+    /// Restart policies for failed components, None when disabled
+    pub supervisor: Option<Supervisor>,
+    /// Votes over redundant sensor channels (see `SensorConfig`)
+    pub voter: Voter,
+    pub config: SystemConfig,
+    pub store: KvStore,
+    #[cfg(feature = "recorder")]
+    pub blackbox: Option<BlackBox>,
+    /// JSON Lines log file sink, registered while the car is initialized
+    log_sink: Option<LogSinkId>,
+    /// Full-screen dashboard, shown while the event loop runs in TUI mode
+    #[cfg(feature = "render")]
+    tui: Option<TuiDashboard>,
+    /// Destination of the JSON dashboard lines, opened at initialization
+    #[cfg(feature = "render")]
+    json_output: Option<Box<dyn Write + Send>>,
+    pub calibration: CalibrationBook,
+    /// Master random generator - components get forked streams
+    pub rng: SimRng,
+    /// Chaos mode (robustness testing), None when disabled
+    pub chaos: Option<ChaosMonkey>,
+    /// Sensor/actuator backend (simulator until `initialize` opens the configured one)
+    pub hardware: Box<dyn HardwareIo>,
+    /// Additional components (see `CarSystemBuilder::with_component`),
+    /// processed after the core components each tick
+    pub extra_components: Vec<Box<dyn CarComponent + Send>>,
+    /// Names of the components whose initialization failure aborts startup
+    critical: Vec<&'static str>,
+    /// Components left offline by a failed initialization, with the reason
+    offline: Vec<(&'static str, String)>,
+    driver: DemoDriver,
+    /// Reused message buffers so steady-state cycles don't allocate
+    outbox: Vec<CarMessage>,
+    inbox: Vec<CarMessage>,
+    requests: Vec<Request>,
+    last_warnings: Vec<SafetyWarning>,
+    /// The emergency workflow ran and the emergency hasn't cleared since
+    emergency_active: bool,
+    /// Automatic emergency braking ran and the collision warning hasn't
+    /// cleared since
+    collision_braking: bool,
+    /// Snapshot of the most recent tick, shared with all readers
+    snapshot: Arc<SystemSnapshot>,
+    config_watcher: Option<ConfigWatcher>,
+    /// Workflows that can be triggered by name (see `trigger_workflow`)
+    pub workflows: WorkflowRegistry,
+    /// Stops the event loop after the current tick (e.g. on Ctrl+C)
+    shutdown: Arc<AtomicBool>,
+    /// Deadline misses counted by the event loop, and how many of them
+    /// were already reported to the safety monitor
+    deadline_misses: Arc<AtomicU64>,
+    deadline_misses_reported: u64,
+    /// Pauses, resumes and steps the event loop
+    loop_control: LoopControl,
+}
+
+impl CarSystem {
+    /// Create a new car system with all components
+    pub fn new() -> Self {
+        Self::with_config(SystemConfig::default())
+    }
+
+    /// Create a new car system using a loaded configuration
+    pub fn with_config(mut config: SystemConfig) -> Self {
+        // Resolve the seed once so it can be printed and reproduced
+        let seed = config.seed.unwrap_or_else(SimRng::seed_from_time);
+        config.seed = Some(seed);
+        let rng = SimRng::new(seed);
+        configure_logging(config.log_level, &config.log);
+        set_display_units(config.units);
+        // JSON on stdout must not be mixed with log lines
+        #[cfg(feature = "render")]
+        if config.render && config.dashboard_mode == DashboardMode::Json && config.dashboard_json_file.is_empty() {
+            pause_console(true);
+        }
+
+        let mut message_bus = MessageBus::new();
+
+        // Register all components with the message bus
+        message_bus.register_component(ComponentId::ENGINE);
+        message_bus.register_component(ComponentId::BRAKES);
+        message_bus.register_component(ComponentId::STEERING);
+        message_bus.register_component(ComponentId::DASHBOARD);
+        message_bus.register_component(ComponentId::CAR_SYSTEM);
+
+        // Dashboard subscribes to all messages
+        message_bus.subscribe_all(ComponentId::DASHBOARD);
+        // CarSystem listens for system commands (e.g. calibration switches)
+        message_bus.subscribe_all(ComponentId::CAR_SYSTEM);
+        // Message tracing is INFO-level output
+        message_bus.set_trace(log_enabled(LogLevel::Info, "MessageBus"));
+
+        let engine = fit_engine(EngineComponent::new(), &config, &rng);
+        let brakes = fit_brakes(BrakesComponent::new(), &config);
+
+        let battery = if config.powertrain.mode.has_battery() {
+            message_bus.register_component(ComponentId::BATTERY);
+            Some(BatteryComponent::new(config.powertrain.battery_kwh, config.powertrain.initial_soc))
+        } else {
+            None
+        };
+        let hvac = if config.hvac.enabled {
+            message_bus.register_component(ComponentId::HVAC);
+            // Reloaded target temperatures reach the HVAC as ConfigChanged
+            message_bus.subscribe(ComponentId::HVAC, "ConfigChanged");
+            // The engine only cares about the load, not every message
+            message_bus.subscribe(ComponentId::ENGINE, "HvacLoad");
+            Some(HvacComponent::new(config.hvac.target_c, config.hvac.ambient_c))
+        } else {
+            None
+        };
+        let obstacle_sensor = if config.adas.enabled {
+            message_bus.register_component(ComponentId::OBSTACLE_SENSOR);
+            let mut sensor =
+                ObstacleSensor::new(config.adas.obstacle_m, config.adas.obstacle_kmh, config.adas.ttc_threshold_s);
+            sensor.set_rng(rng.fork("obstacle"));
+            Some(sensor)
+        } else {
+            None
+        };
+        let location = if config.location.enabled {
+            message_bus.register_component(ComponentId::LOCATION);
+            let mut location = LocationComponent::new();
+            location.set_route_file(&config.location.route);
+            Some(location)
+        } else {
+            None
+        };
+        let (throttle, dynamics) = match config.vehicle.dynamics {
+            DynamicsMode::Physics => (Some(ThrottleComponent::new()), Some(VehicleDynamics::new(config.vehicle.mass_kg))),
+            DynamicsMode::Scripted => (None, None),
+        };
+        let mut critical = CRITICAL_COMPONENTS.to_vec();
+        if throttle.is_some() {
+            critical.push("Throttle");
+        }
+        if battery.is_some() {
+            critical.push("Battery");
+        }
+
+        let chaos = if config.chaos.is_enabled() {
+            Some(ChaosMonkey::new(&config.chaos, rng.fork("chaos")))
+        } else {
+            None
+        };
+
+        let mut system = Self {
+            engine,
+            brakes,
+            steering: SteeringComponent::new(),
+            dashboard: DashboardComponent::new(),
+            battery,
+            hvac,
+            obstacle_sensor,
+            location,
+            throttle,
+            dynamics,
+            message_bus,
+            registry: ComponentRegistry::new(),
+            safety: SafetyMonitor::with_config(&config.safety),
+            safety_log: SafetyEventLog::new(),
+            watchdog: Watchdog::new(config.watchdog.max_missed),
+            supervisor: config.supervisor.enabled.then(|| Supervisor::new(&config.supervisor)),
+            voter: Voter::new(config.sensors.tolerance),
+            store: KvStore::in_memory(),
+            #[cfg(feature = "recorder")]
+            blackbox: None,
+            log_sink: None,
+            #[cfg(feature = "render")]
+            tui: None,
+            #[cfg(feature = "render")]
+            json_output: None,
+            calibration: CalibrationBook::builtin(),
+            rng,
+            chaos,
+            hardware: Box::new(SimulatorIo::new()),
+            extra_components: Vec::new(),
+            critical,
+            offline: Vec::new(),
+            driver: DemoDriver::new(),
+            outbox: Vec::new(),
+            inbox: Vec::new(),
+            requests: Vec::new(),
+            last_warnings: Vec::new(),
+            emergency_active: false,
+            collision_braking: false,
+            snapshot: Arc::default(),
+            config_watcher: config
+                .config_file
+                .as_deref()
+                .map(|path| ConfigWatcher::new(path, config.reload_poll_ticks)),
+            config,
+            workflows: WorkflowRegistry::builtin(),
+            shutdown: Arc::new(AtomicBool::new(false)),
+            deadline_misses: Arc::new(AtomicU64::new(0)),
+            deadline_misses_reported: 0,
+            loop_control: LoopControl::new(),
+        };
+        system.apply_time_step();
+        system.dashboard.set_max_warnings(system.config.dashboard_max_warnings);
+        system.dashboard.set_unit_system(system.config.units);
+        system
+    }
+
+    /// Execute a workflow of `self.workflows` by name
+    /// Workflows registered while it runs are kept.
+    pub fn trigger_workflow(&mut self, name: &str) -> Result<WorkflowReport, String> {
+        let workflows = mem::take(&mut self.workflows);
+        let result = workflows.run(name, self);
+        let registered_meanwhile = mem::replace(&mut self.workflows, workflows);
+        self.workflows.extend(registered_meanwhile);
+        result
+    }
+
+    /// Register a component added at runtime with the registry and the
+    /// message bus; registering a name again returns its existing ID
+    pub fn register_component(&mut self, name: &'static str) -> ComponentId {
+        let known = self.registry.lookup(name).is_some();
+        let id = self.registry.register(name);
+        if !known {
+            self.message_bus.register_component(id);
+        }
+        id
+    }
+
+    /// ID of a registered component
+    pub fn component_id(&self, name: &str) -> Option<ComponentId> {
+        self.registry.lookup(name)
+    }
+
+    /// Push the simulated time per component cycle to all components
+    /// Dynamics integrate against this instead of per-tick constants.
+    pub fn apply_time_step(&mut self) {
+        let every = self.config.event_loop.task_every("components").unwrap_or(1);
+        let dt_s = self.config.event_loop.time_step_s() * every as f32;
+        self.engine.set_time_step(dt_s);
+        self.brakes.set_time_step(dt_s);
+        self.dashboard.set_time_step(dt_s);
+        if let Some(battery) = self.battery.as_mut() {
+            battery.set_time_step(dt_s);
+        }
+        if let Some(hvac) = self.hvac.as_mut() {
+            hvac.set_time_step(dt_s);
+        }
+        if let Some(sensor) = self.obstacle_sensor.as_mut() {
+            sensor.set_time_step(dt_s);
+        }
+        if let Some(location) = self.location.as_mut() {
+            location.set_time_step(dt_s);
+        }
+        // Throttle and dynamics run every tick, not every component cycle
+        let tick_dt_s = self.config.event_loop.time_step_s();
+        if let Some(throttle) = self.throttle.as_mut() {
+            throttle.set_time_step(tick_dt_s);
+        }
+        if let Some(dynamics) = self.dynamics.as_mut() {
+            dynamics.set_time_step(tick_dt_s);
+        }
+    }
+
+    /// Initialize all components
+    pub fn initialize(&mut self) -> Result<(), String> {
+        if !self.config.log.file.is_empty() && self.log_sink.is_none() {
+            let sink = JsonLinesSink::create(
+                &self.config.log.file,
+                self.config.log.file_max_kb * 1024,
+                self.config.log.file_keep,
+            )?;
+            self.log_sink = Some(add_log_sink(Box::new(sink)));
+            // Quiet runs still trace the bus into the file
+            self.message_bus.set_trace(log_enabled(LogLevel::Info, "MessageBus"));
+        }
+        #[cfg(feature = "render")]
+        if self.config.render && self.config.dashboard_mode == DashboardMode::Json && self.json_output.is_none() {
+            self.json_output = Some(if self.config.dashboard_json_file.is_empty() {
+                Box::new(io::stdout())
+            } else {
+                let file = fs::File::create(&self.config.dashboard_json_file)
+                    .map_err(|e| format!("Cannot create {}: {}", self.config.dashboard_json_file, e))?;
+                Box::new(LineWriter::new(file))
+            });
+        }
+
+        log_info!("CarSystem", "\n╔══════════════════════════════════════════════════════════════╗");
+        log_info!("CarSystem", "║          🚗 S-CORE Car System - Phase 7                    ║");
+        log_info!("CarSystem", "║  Multi-Component + Comm + State Machine + Loop + Safety + Workflows ║");
+        log_info!("CarSystem", "╚══════════════════════════════════════════════════════════════╝\n");
+
+        if console_enabled(LogLevel::Info, "CarSystem") {
+            self.config.print_summary();
+        }
+        #[cfg(feature = "render")]
+        if self.json_output.is_some() && !self.config.dashboard_json_file.is_empty() {
+            log_info!("CarSystem", "🧾 Dashboard JSON lines go to {}", self.config.dashboard_json_file);
+        }
+        if self.log_sink.is_some() {
+            log_info!("CarSystem", "📝 Logging to {} (JSON Lines, rotated at {} KB, {} kept)",
+                     self.config.log.file, self.config.log.file_max_kb, self.config.log.file_keep);
+        }
+        log_info!("CarSystem", "🎲 Simulation seed: {} (reproduce with --seed {})\n", self.rng.seed(), self.rng.seed());
+
+        log_info!("CarSystem", "🔧 Initializing message bus...");
+        log_info!("CarSystem", "✅ Message bus ready");
+
+        self.load_safety_limits()?;
+        log_info!("CarSystem", "🔧 Loading safety calibration...");
+        self.load_calibration()?;
+        log_info!("CarSystem", "✅ Calibration sets: {} (active: {})",
+                 self.calibration.names().join(", "),
+                 if self.config.calibration.set.is_empty() { "none" } else { self.calibration.active_name() });
+
+        log_info!("CarSystem", "🔧 Initializing safety monitor...");
+        let units = self.config.units;
+        log_info!("CarSystem", "   Limits: Speed={}{}, Temp={}{}, RPM={}",
+                 units.speed(self.safety.max_speed() as f32).round(), units.speed_unit(),
+                 (units.temperature(self.safety.max_temperature()) * 10.0).round() / 10.0, units.temperature_unit(),
+                 self.safety.max_rpm());
+        log_info!("CarSystem", "✅ Safety monitor ready");
+
+        log_info!("CarSystem", "🔧 Loading persisted data...");
+        self.load_persistent_state()?;
+        log_info!("CarSystem", "✅ Persisted data loaded (odometer: {:.1} {}, engine starts: {})",
+                 units.distance(self.dashboard.get_odometer()), units.distance_unit(), self.engine.get_start_count());
+
+        #[cfg(feature = "recorder")]
+        if !self.config.blackbox.path.is_empty() {
+            log_info!("CarSystem", "🔧 Initializing black box recorder...");
+            let capacity = BlackBox::capacity_for(
+                self.config.blackbox.seconds,
+                self.config.event_loop.base_period_ms(),
+            );
+            let blackbox = BlackBox::create(&self.config.blackbox.path, capacity)?;
+            blackbox.install_panic_hook();
+            log_info!("CarSystem", "✅ Black box recording last {}s ({} records) to {}",
+                     self.config.blackbox.seconds, capacity, blackbox.path());
+            self.blackbox = Some(blackbox);
+        }
+
+        log_info!("CarSystem", "🔧 Connecting hardware I/O...");
+        self.hardware = open_backend(&self.config.hardware)?;
+        log_info!("CarSystem", "✅ Hardware I/O: {}", self.hardware.name());
+
+        log_info!("CarSystem", "🔧 Initializing workflow orchestrator...");
+        log_info!("CarSystem", "✅ Workflow orchestrator ready\n");
+
+        log_info!("CarSystem", "🔧 Initializing all components (policy: {})...\n", self.config.init.policy);
+
+        self.offline.clear();
+        let mut components: Vec<&mut (dyn CarComponent + Send)> = Vec::new();
+        // The traction battery powers everything else, so it comes up first
+        if let Some(battery) = self.battery.as_mut() {
+            components.push(battery);
+        }
+        components.extend([
+            &mut self.engine as &mut (dyn CarComponent + Send),
+            &mut self.brakes,
+            &mut self.steering,
+            &mut self.dashboard,
+        ]);
+        if let Some(hvac) = self.hvac.as_mut() {
+            components.push(hvac);
+        }
+        if let Some(sensor) = self.obstacle_sensor.as_mut() {
+            components.push(sensor);
+        }
+        if let Some(location) = self.location.as_mut() {
+            components.push(location);
+        }
+        if let Some(throttle) = self.throttle.as_mut() {
+            components.push(throttle);
+        }
+        for component in &mut self.extra_components {
+            components.push(component.as_mut());
+        }
+        for (index, component) in components.into_iter().enumerate() {
+            if index > 0 {
+                log_info!("CarSystem", "");
+            }
+            let name = component.name();
+            let Err(e) = initialize_component(component, &self.config.init) else {
+                continue;
+            };
+            let critical = self.critical.contains(&name);
+            if critical || self.config.init.policy == InitPolicy::AbortAll {
+                return Err(format!("{} failed to initialize: {}", name, e));
+            }
+            log_warn!("CarSystem", "⚠️  {} failed to initialize, continuing without it: {}", name, e);
+            self.offline.push((name, e));
+        }
+
+        if self.offline.is_empty() {
+            log_info!("CarSystem", "\n✅ All components initialized successfully!\n");
+        } else {
+            self.print_degraded_report();
+        }
+
+        self.watchdog.clear();
+        let core = [self.engine.name(), self.brakes.name(), self.steering.name(), self.dashboard.name()];
+        let online: Vec<&'static str> = core
+            .into_iter()
+            .chain(self.battery.as_ref().map(|battery| battery.name()))
+            .chain(self.hvac.as_ref().map(|hvac| hvac.name()))
+            .chain(self.obstacle_sensor.as_ref().map(|sensor| sensor.name()))
+            .chain(self.location.as_ref().map(|location| location.name()))
+            .chain(self.throttle.as_ref().map(|throttle| throttle.name()))
+            .chain(self.extra_components.iter().map(|component| component.name()))
+            .filter(|name| self.is_online(name))
+            .collect();
+        for name in online {
+            self.watchdog.supervise(name);
+        }
+        Ok(())
+    }
+
+    /// Components left offline by a failed initialization, with the reason
+    pub fn offline_components(&self) -> &[(&'static str, String)] {
+        &self.offline
+    }
+
+    /// Check whether the system runs without some non-critical components
+    pub fn is_degraded(&self) -> bool {
+        !self.offline.is_empty()
+    }
+
+    /// Check whether a component initialized and is processed each tick
+    pub fn is_online(&self, name: &str) -> bool {
+        !self.offline.iter().any(|(offline, _)| *offline == name)
+    }
+
+    /// Check whether a component's initialization failure aborts startup
+    pub fn is_critical(&self, name: &str) -> bool {
+        self.critical.contains(&name)
+    }
+
+    /// Print which components are offline and why
+    pub fn print_degraded_report(&self) {
+        log_warn!("CarSystem", "\n⚠️  DEGRADED MODE - {} component(s) offline:", self.offline.len());
+        for (name, reason) in &self.offline {
+            log_error!("CarSystem", "   ❌ {}: {}", name, reason);
+        }
+        log_info!("CarSystem", "   Critical components online: {}\n", self.critical.join(", "));
+    }
+
+    /// Start the car
+    pub fn start(&mut self) -> Result<(), String> {
+        log_info!("CarSystem", "🔑 Starting the car...\n");
+        self.engine.start()?;
+        self.dashboard.set_fuel_level(85);
+        log_info!("CarSystem", "\n✅ Car is ready to drive!\n");
+
+        // Demonstrate state machine validation
+        log_info!("CarSystem", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        log_info!("CarSystem", "📚 Phase 4: State Machine Validation Demo");
+        log_info!("CarSystem", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+
+        log_info!("CarSystem", "\n✅ Valid transitions (what just happened):");
+        log_info!("CarSystem", "   OFF → STARTING → RUNNING");
+
+        log_info!("CarSystem", "\n🧪 Testing invalid transition (try to start already-running engine):");
+        match self.engine.start() {
+            Ok(_) => log_error!("CarSystem", "   ❌ Oops - should have failed!"),
+            Err(e) => log_info!("CarSystem", "   ✅ Correctly rejected: {}", e),
+        }
+
+        log_info!("CarSystem", "\n📊 Current engine state: {}\n", self.engine.get_engine_state());
+        log_info!("CarSystem", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
+
+        Ok(())
+    }
+
+    /// Run event loop for continuous processing
+    pub fn run_event_loop(&mut self, num_ticks: u64) -> Result<(), String> {
+        if self.config.event_loop.execution == ExecutionMode::Threaded {
+            return self.run_threaded(num_ticks);
+        }
+        let mut event_loop = EventLoop::new(self.config.event_loop.clone());
+        event_loop.set_shutdown_handle(Arc::clone(&self.shutdown));
+        event_loop.set_deadline_miss_handle(Arc::clone(&self.deadline_misses));
+        event_loop.set_control_handle(self.loop_control.clone());
+        let tick_rate = event_loop.tick_rate_handle();
+        let mut golden = self.prepare_event_loop(num_ticks);
+
+        let mut handlers: TickHandlers<CarSystem> = TickHandlers::new();
+        handlers
+            // Pick up config file edits made while running
+            .on(TickPhase::PreTick, "config", 0, move |car, tick_num| {
+                car.poll_config(tick_num, &tick_rate);
+                Ok(())
+            })
+            .on(TickPhase::Tick, "car", 0, |car, tick_num| car.tick(tick_num).map(drop))
+            .on(TickPhase::PostTick, "golden", 0, |car, _| {
+                golden.record(&car.snapshot);
+                Ok(())
+            });
+        event_loop.run_handlers(num_ticks, &mut handlers, self);
+        drop(handlers);
+        self.finish_event_loop(golden, event_loop.stop_reason())
+    }
+
+    /// Run the event loop on the current Tokio runtime
+    /// Same ticks as `run_event_loop`, but waiting for the next tick yields
+    /// to other tasks instead of blocking the thread.
+    #[cfg(feature = "async_loop")]
+    pub async fn run_event_loop_async(&mut self, num_ticks: u64) -> Result<(), String> {
+        let mut event_loop = AsyncEventLoop::new(self.config.event_loop.clone());
+        event_loop.set_shutdown_handle(Arc::clone(&self.shutdown));
+        event_loop.set_deadline_miss_handle(Arc::clone(&self.deadline_misses));
+        event_loop.set_control_handle(self.loop_control.clone());
+        let tick_rate = event_loop.tick_rate_handle();
+        let mut golden = self.prepare_event_loop(num_ticks);
+
+        event_loop.start();
+        while let Some(tick_num) = event_loop.next_tick(num_ticks).await {
+            self.poll_config(tick_num, &tick_rate);
+            let result = self.tick(tick_num).map(|snapshot| golden.record(&snapshot));
+            event_loop.finish_tick(result);
+        }
+        event_loop.stop();
+        self.finish_event_loop(golden, event_loop.stop_reason())
+    }
+
+    /// Run the event loop with engine, brakes, steering and dashboard as
+    /// actors on their own threads
+    /// The car system becomes their supervisor: it sends the driver's
+    /// commands over the rings, waits for every actor each tick, restarts
+    /// actors whose thread died and reacts to the actors' status like
+    /// `tick` does (safety checks, latched faults, watchdog, emergency and
+    /// degraded-mode workflows). Optional components, hardware I/O,
+    /// rendering and config reloading need the single loop and are skipped.
+    fn run_threaded(&mut self, num_ticks: u64) -> Result<(), String> {
+        let mut event_loop = EventLoop::new(self.config.event_loop.clone());
+        event_loop.set_shutdown_handle(Arc::clone(&self.shutdown));
+        event_loop.set_deadline_miss_handle(Arc::clone(&self.deadline_misses));
+        event_loop.set_control_handle(self.loop_control.clone());
+        let mut golden = self.prepare_event_loop(num_ticks);
+        // Only the actors run, so only they are watched
+        self.watchdog.clear();
+        for name in CORE_COMPONENTS {
+            if self.is_online(name) {
+                self.watchdog.supervise(name);
+            }
+        }
+        let mut runtime = Some(self.start_actors()?);
+
+        let mut handlers: TickHandlers<CarSystem> = TickHandlers::new();
+        handlers
+            .on(TickPhase::Tick, "supervisor", 0, |car, tick_num| car.supervise(tick_num, &mut runtime))
+            .on(TickPhase::PostTick, "golden", 0, |car, _| {
+                golden.record(&car.snapshot);
+                Ok(())
+            });
+        event_loop.run_handlers(num_ticks, &mut handlers, self);
+        drop(handlers);
+        if let Some(runtime) = runtime {
+            self.stop_actors(runtime);
+        }
+        self.finish_event_loop(golden, event_loop.stop_reason())
+    }
+
+    /// Move the core components and the message bus to an actor runtime
+    fn start_actors(&mut self) -> Result<ActorRuntime, String> {
+        // Signals the actors only publish on change start from the current values
+        let snapshot = Arc::make_mut(&mut self.snapshot);
+        snapshot.temperature = self.engine.get_temperature();
+        snapshot.fuel_level = self.dashboard.get_fuel_level();
+        snapshot.odometer = self.dashboard.get_odometer();
+
+        let bus = mem::take(&mut self.message_bus);
+        let mut runtime = ActorRuntime::new(bus, ComponentId::CAR_SYSTEM, &ACTOR_IDS);
+        if let Err(e) = self.spawn_actors(&mut runtime) {
+            self.stop_actors(runtime);
+            return Err(e);
+        }
+        log_info!("CarSystem", "🧵 Threaded execution: {} actors supervised by the car system", runtime.len());
+        Ok(runtime)
+    }
+
+    fn spawn_actors(&mut self, runtime: &mut ActorRuntime) -> Result<(), String> {
+        let engine = mem::take(&mut self.engine);
+        runtime.spawn(ComponentId::ENGINE, engine, EngineComponent::new)?;
+        let brakes = mem::take(&mut self.brakes);
+        runtime.spawn(ComponentId::BRAKES, brakes, BrakesComponent::new)?;
+        let steering = mem::take(&mut self.steering);
+        runtime.spawn(ComponentId::STEERING, steering, SteeringComponent::new)?;
+        let dashboard = mem::take(&mut self.dashboard);
+        runtime.spawn(ComponentId::DASHBOARD, dashboard, DashboardComponent::new)
+    }
+
+    /// Stop the actors and take the components and the message bus back
+    fn stop_actors(&mut self, runtime: ActorRuntime) {
+        let (restarts, missed) = (runtime.restarts(), runtime.missed_deadlines());
+        let dropped = runtime.dropped_messages();
+        let spawned = runtime.len();
+        let (bus, returned) = runtime.stop();
+        let lost = spawned - returned.len();
+        for (id, component) in returned {
+            if id == ComponentId::ENGINE {
+                restore(&mut self.engine, component);
+            } else if id == ComponentId::BRAKES {
+                restore(&mut self.brakes, component);
+            } else if id == ComponentId::STEERING {
+                restore(&mut self.steering, component);
+            } else if id == ComponentId::DASHBOARD {
+                restore(&mut self.dashboard, component);
+            }
+        }
+        self.message_bus = bus;
+        log_info!("CarSystem", "🧵 Actors stopped: {} restart(s), {} missed deadline(s), {} dropped message(s)",
+                  restarts, missed, dropped);
+        if lost > 0 {
+            log_warn!("CarSystem", "⚠️  {} actor(s) died while stopping - replaced by new components", lost);
+        }
+    }
+
+    /// One tick of the threaded execution mode
+    fn supervise(&mut self, tick_num: u64, runtime: &mut Option<ActorRuntime>) -> Result<(), String> {
+        let Some(actors) = runtime.as_mut() else {
+            return Err("The actors are not running".to_string());
+        };
+        let speed = self.driver.next_speed(tick_num);
+        let supervisor = actors.supervisor();
+        supervisor.publish(CarMessage::SpeedUpdate { km_h: speed });
+        supervisor.send_to(ComponentId::BRAKES, CarMessage::SpeedUpdate { km_h: speed })?;
+
+        // The same driver inputs as `tick`, sent as commands
+        if tick_num.is_multiple_of(30) && tick_num > 0 {
+            supervisor.send_to(ComponentId::BRAKES, CarMessage::BrakeApply { pressure: 50 })?;
+        } else if tick_num % 30 == 10 {
+            supervisor.send_to(ComponentId::BRAKES, CarMessage::BrakeRelease)?;
+        }
+        if tick_num % 25 == 15 {
+            supervisor.send_to(ComponentId::STEERING, CarMessage::SteeringTurn { angle: 30 })?;
+        } else if tick_num % 25 == 20 {
+            supervisor.send_to(ComponentId::STEERING, CarMessage::SteeringCenter)?;
+        }
+
+        let outcome = actors.tick(tick_num, self.config.event_loop.wall_period());
+        for (name, reason) in &outcome.restarted {
+            log_warn!("CarSystem", "  🔄 Supervisor: {} died ({}) - restarted", name, reason);
+        }
+        for name in &outcome.missed {
+            log_warn!("CarSystem", "  ⏱️  Supervisor: {} missed tick {}", name, tick_num);
+        }
+        if outcome.dropped > 0 {
+            log_warn!("CarSystem", "  📭 Supervisor: {} message(s) dropped on full rings", outcome.dropped);
+        }
+        actors.bus_mut().advance_cycle();
+
+        // The actors' events go to the black box; their state comes with
+        // the status reports
+        let mut inbox = mem::take(&mut self.inbox);
+        actors.supervisor().receive_all_into(&mut inbox);
+        #[cfg(feature = "recorder")]
+        if let Some(blackbox) = &self.blackbox {
+            blackbox.record_messages(ComponentId::CAR_SYSTEM, &inbox);
+        }
+        inbox.clear();
+        self.inbox = inbox;
+
+        // An actor that answered in time met its deadline
+        let mut reactions = Vec::new();
+        for (name, _) in &outcome.health {
+            self.watchdog.pet(name);
+        }
+        if let Some(name) = self.end_watchdog_cycle() {
+            reactions.push(name);
+        }
+
+        let snapshot = self.supervisor_snapshot(tick_num, speed, &outcome.status);
+        if self.task_due("safety", tick_num, 5) {
+            for (name, health) in &outcome.health {
+                if self.is_online(name) {
+                    self.safety.check_health(name, *health);
+                }
+            }
+            reactions.extend(self.check_safety(tick_num, &snapshot));
+        }
+        self.record_tick(&snapshot);
+
+        let reacted = self.react_with_components(runtime, &reactions);
+        if !outcome.errors.is_empty() {
+            let errors: Vec<String> = outcome.errors.iter().map(|(name, e)| format!("{}: {}", name, e)).collect();
+            return Err(errors.join("; "));
+        }
+        reacted
+    }
+
+    /// Snapshot of a threaded tick, built from the actors' status
+    /// Signals of an actor that didn't report keep their last value.
+    fn supervisor_snapshot(&mut self, tick_num: u64, speed: u8, status: &ActorStatus) -> Arc<SystemSnapshot> {
+        // Redundant sensors are voted like in `tick`
+        let mut temperature = status.temperature;
+        if let Some(vote) = self.voter.vote(&status.temperature_readings) {
+            temperature = Some(vote.value);
+            self.safety.record_vote("temperature", vote);
+        }
+        let mut brake_pressure = status.brake_pressure;
+        if let Some(vote) = self.voter.vote(&status.pressure_readings) {
+            brake_pressure = Some(vote.value.round().clamp(0.0, 100.0) as u8);
+            self.safety.record_vote("brake_pressure", vote);
+        }
+
+        let snapshot = Arc::make_mut(&mut self.snapshot);
+        snapshot.tick = tick_num;
+        snapshot.speed = speed;
+        snapshot.rpm = status.rpm.unwrap_or(snapshot.rpm);
+        snapshot.temperature = temperature.unwrap_or(snapshot.temperature);
+        snapshot.engine_state = status.engine_state.unwrap_or(snapshot.engine_state);
+        snapshot.brake_pressure = brake_pressure.unwrap_or(snapshot.brake_pressure);
+        snapshot.steering_angle = status.steering_angle.unwrap_or(snapshot.steering_angle);
+        snapshot.odometer = status.odometer.unwrap_or(snapshot.odometer);
+        snapshot.fuel_level = status.fuel_level.unwrap_or(snapshot.fuel_level);
+        if let Some(warnings) = &status.warnings {
+            warnings.clone_into(&mut snapshot.warnings);
+        }
+        self.snapshot.clone()
+    }
+
+    /// Run reaction workflows in threaded mode
+    /// Workflows act on the components directly, so the actors hand them
+    /// back while the workflows run and are started again afterwards.
+    fn react_with_components(&mut self, runtime: &mut Option<ActorRuntime>, workflows: &[String]) -> Result<(), String> {
+        if workflows.is_empty() {
+            return Ok(());
+        }
+        if let Some(actors) = runtime.take() {
+            self.stop_actors(actors);
+        }
+        let result = workflows.iter().try_for_each(|name| self.trigger_workflow(name).map(drop));
+        *runtime = Some(self.start_actors()?);
+        result
+    }
+
+    /// Flag that stops the event loop after the current tick
+    /// Hand it to `install_interrupt_handler` for a clean Ctrl+C shutdown.
+    pub fn shutdown_handle(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.shutdown)
+    }
+
+    /// Handle to pause, resume and single-step the event loop from
+    /// another thread
+    pub fn loop_control(&self) -> LoopControl {
+        self.loop_control.clone()
+    }
+
+    /// Check whether the event loop was asked to shut down
+    pub fn shutdown_requested(&self) -> bool {
+        self.shutdown.load(Ordering::SeqCst)
+    }
+
+    /// Safety demo and real-time setup before the event loop starts
+    fn prepare_event_loop(&mut self, num_ticks: u64) -> GoldenRecorder {
+        let golden = GoldenRecorder::new(self.config.golden.mode, &self.config.golden.path);
+        self.deadline_misses_reported = 0;
+        if self.config.event_loop.console {
+            match spawn_console(self.loop_control.clone()) {
+                Ok(()) => log_info!("CarSystem", "⌨️  Loop console: pause | resume | step [N]"),
+                Err(e) => log_warn!("CarSystem", "⚠️  {}", e),
+            }
+        }
+
+        // Phase 6: Show safety demo at start
+        if num_ticks > 10 {
+            log_info!("CarSystem", "\n━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+            log_info!("CarSystem", "📚 Phase 6: Safety Monitor Demo");
+            log_info!("CarSystem", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+
+            log_info!("CarSystem", "\n🧪 Triggering safety warnings for demo...\n");
+
+            let warnings = self.safety.check(130, 85.0, 5000, 50, 0, true);
+            for warning in &warnings {
+                log_info!("CarSystem", "   {}", warning);
+            }
+
+            log_info!("CarSystem", "\n✅ Safety monitor active - will warn during operation\n");
+            log_info!("CarSystem", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
+        }
+
+        // Real-time setup applies to this thread, which runs the loop
+        if self.config.realtime.enabled {
+            setup_realtime_thread(&self.config.realtime).print();
+            log_info!("CarSystem", "");
+        }
+        golden
+    }
+
+    /// Reports and exports after the event loop stopped
+    fn finish_event_loop(&mut self, golden: GoldenRecorder, stop_reason: Option<&str>) -> Result<(), String> {
+        #[cfg(feature = "render")]
+        if let Some(mut tui) = self.tui.take() {
+            tui.leave();
+        }
+        if let Some(reason) = stop_reason {
+            return Err(format!("Event loop stopped: {}", reason));
+        }
+        self.report_dead_letters();
+        if self.config.event_loop.verbose_timing {
+            self.message_bus.print_stats();
+        }
+
+        golden.finish()?;
+        self.export_safety_log()?;
+        self.export_state_history()?;
+
+        if self.chaos.is_some() {
+            self.finish_chaos_run()?;
+        }
+        Ok(())
+    }
+
+    /// Write the safety event log to the configured file, if any
+    /// Done after the event loop and again at shutdown, so a run that
+    /// fails in between still leaves its events behind.
+    pub fn export_safety_log(&self) -> Result<(), String> {
+        let path = &self.config.safety_event_log;
+        if path.is_empty() {
+            return Ok(());
+        }
+        self.safety_log.export(path)?;
+        log_info!("CarSystem", "📝 {} safety event(s) exported to {}", self.safety_log.len(), path);
+        Ok(())
+    }
+
+    /// Transition history of the engine, brakes, steering and (if fitted)
+    /// battery state machines
+    pub fn state_history_json(&self) -> JsonValue {
+        let mut machines = vec![
+            transition_history_json(self.engine.state_machine()),
+            transition_history_json(self.brakes.state_machine()),
+            transition_history_json(self.steering.state_machine()),
+        ];
+        if let Some(battery) = &self.battery {
+            machines.push(transition_history_json(battery.state_machine()));
+        }
+        JsonValue::Array(machines)
+    }
+
+    /// Write the state transition history to the configured file, if any
+    /// Done after the event loop and again at shutdown, like the safety log.
+    pub fn export_state_history(&self) -> Result<(), String> {
+        let path = &self.config.state_history_log;
+        if path.is_empty() {
+            return Ok(());
+        }
+        fs::write(path, self.state_history_json().to_pretty_string() + "\n")
+            .map_err(|e| format!("Cannot write '{}': {}", path, e))?;
+        log_info!("CarSystem", "📝 State transition history exported to {}", path);
+        Ok(())
+    }
+
+    /// Print the messages the bus could not deliver (or that expired) during
+    /// the run
+    pub fn report_dead_letters(&self) {
+        let expired = self.message_bus.expired_count();
+        if expired > 0 {
+            log_info!("CarSystem", "\n⌛ {} message(s) expired before they were received", expired);
+        }
+        let count = self.message_bus.dead_letter_count();
+        if count == 0 {
+            return;
+        }
+        log_info!("CarSystem", "\n📭 {} undeliverable message(s) ({} older ones dropped):",
+                 count, self.message_bus.dropped_dead_letters());
+        for letter in self.message_bus.dead_letters() {
+            log_info!("CarSystem", "   {}", letter);
+        }
+        log_info!("CarSystem", "");
+    }
+
+    /// Run one simulation tick: drive the demo scenario, process all
+    /// components, run safety checks and record the result.
+    /// Returns the shared snapshot of the system after the tick.
+    pub fn tick(&mut self, tick_num: u64) -> Result<Arc<SystemSnapshot>, String> {
+        let speed = self.next_speed(tick_num)?;
+        self.engine.set_tick(tick_num);
+        self.brakes.set_tick(tick_num);
+        self.steering.set_tick(tick_num);
+        if let Some(battery) = self.battery.as_mut() {
+            battery.set_tick(tick_num);
+        }
+
+        // Apply brakes occasionally
+        if tick_num.is_multiple_of(30) && tick_num > 0 {
+            self.brakes.apply(50)?;
+        } else if tick_num % 30 == 10 {
+            self.brakes.release();
+        }
+
+        // Turn occasionally
+        if tick_num % 25 == 15 {
+            self.steering.turn(30)?;
+        } else if tick_num % 25 == 20 {
+            self.steering.center();
+        }
+
+        if let Some(fault) = self.chaos.as_mut().and_then(|chaos| chaos.next_fault(tick_num)) {
+            self.inject_fault(fault)?;
+        }
+
+        if self.task_due("components", tick_num, 1) {
+            self.process_cycle(speed)?;
+            self.store.increment(persistency_keys::OPERATING_TICKS);
+
+            if let Some(name) = self.end_watchdog_cycle() {
+                self.trigger_workflow(&name)?;
+            }
+            self.supervise_components()?;
+        }
+
+        // Actuators and sensors go through the hardware backend
+        self.hardware.update_simulation(&SensorFrame {
+            speed,
+            temperature: self.engine.get_temperature(),
+            rpm: self.engine.get_rpm(),
+            fuel_level: self.dashboard.get_fuel_level(),
+        });
+        self.hardware.set_brake_output(self.brakes.get_pressure())?;
+        self.hardware.set_steering_output(self.steering.get_angle())?;
+        let mut sensors = self.hardware.read_sensors()?;
+
+        // Redundant sensors are voted; the safety monitor sees the voted value
+        if let Some(vote) = self.voter.vote(self.engine.temperature_readings()) {
+            sensors.temperature = vote.value;
+            self.safety.record_vote("temperature", vote);
+        }
+        let brake_pressure = match self.voter.vote(self.brakes.pressure_readings()) {
+            Some(vote) => {
+                self.safety.record_vote("brake_pressure", vote);
+                // Without a trusted pressure the brakes can't run ABS
+                if vote.agrees() {
+                    self.brakes.clear_fault();
+                } else {
+                    self.brakes.report_fault("pressure sensors disagree");
+                }
+                vote.value.round().clamp(0.0, 100.0) as u8
+            }
+            None => self.brakes.get_pressure(),
+        };
+        #[cfg(feature = "fixed_point")]
+        let sensors = sensors.quantized();
+
+        // Everything below reads the same snapshot
+        let snapshot = self.publish_snapshot(tick_num, &sensors, brake_pressure);
+
+        // Display (skipped in headless profiles, optionally only on change)
+        #[cfg(feature = "render")]
+        if self.config.render
+            && self.task_due("dashboard", tick_num, self.config.event_loop.render_every)
+            && self.is_online(self.dashboard.name())
+        {
+            if self.config.dashboard_mode == DashboardMode::Tui {
+                let units = self.config.units;
+                let tui = self.tui.get_or_insert_with(|| {
+                    let mut tui = TuiDashboard::new();
+                    tui.set_unit_system(units);
+                    tui
+                });
+                tui.enter();
+                tui.render(&snapshot, &self.config.safety, self.dashboard.get_faults(), self.message_bus.stats());
+                tui.print_frame();
+            } else if self.config.dashboard_mode == DashboardMode::Json {
+                if let Some(out) = self.json_output.as_mut() {
+                    self.dashboard.render_json(&snapshot);
+                    if let Err(e) = self.dashboard.write_frame(out.as_mut()) {
+                        // The reader is gone (e.g. a closed pipe): stop writing
+                        log_error!("CarSystem", "❌ {} - JSON dashboard output stopped", e);
+                        self.json_output = None;
+                    }
+                }
+            } else {
+                let changed = self.dashboard.render(&snapshot);
+                if changed || !self.config.render_changed_only {
+                    log_info!("CarSystem", "");
+                    self.dashboard.print_frame();
+                }
+            }
+        }
+
+        // Safety checks every 5 ticks unless scheduled otherwise
+        if self.task_due("safety", tick_num, 5) {
+            self.check_component_health();
+            if let Some(battery) = &self.battery {
+                self.safety.check_battery(battery.get_soc(), battery.get_temperature());
+            }
+            if let Some(name) = self.check_safety(tick_num, &snapshot) {
+                self.trigger_workflow(&name)?;
+            }
+        }
+        self.record_tick(&snapshot);
+
+        Ok(snapshot)
+    }
+
+    /// Close the watchdog cycle, logging the components that missed it
+    /// Returns the degraded-mode workflow to run, once per outage, unless
+    /// the supervisor's restart policies take care of it.
+    fn end_watchdog_cycle(&mut self) -> Option<String> {
+        if !self.watchdog.end_cycle() {
+            return None;
+        }
+        for (name, missed) in self.watchdog.expired() {
+            log_warn!("CarSystem", component = name, missed = missed; "  🐕 Watchdog: {} missed {} cycles", name, missed);
+        }
+        if self.config.watchdog.workflow.is_empty() || self.supervisor.is_some() {
+            return None;
+        }
+        let name = self.config.watchdog.workflow.clone();
+        log_warn!("CarSystem", "   🟠 DEGRADED MODE - running workflow '{}'", name);
+        Some(name)
+    }
+
+    /// Safety check of one tick, after the component health and battery
+    /// have been reported: logs the warnings and latches critical faults
+    /// Returns the emergency workflow to run, once until the emergency
+    /// clears.
+    fn check_safety(&mut self, tick_num: u64, snapshot: &SystemSnapshot) -> Option<String> {
+        for warning in self.watchdog.warnings() {
+            self.safety.report(warning);
+        }
+        if let Some(warning) = self.deadline_warning() {
+            self.safety.report(warning);
+        }
+        let warnings = self.safety.check_snapshot(snapshot);
+        self.safety_log.record(tick_num, &warnings);
+
+        if !warnings.is_empty() {
+            log_warn!("CarSystem", "\n⚠️  SAFETY CHECK:");
+            for (goal, violations) in &GoalReport::new(&warnings).groups {
+                log_warn!("CarSystem", "   {}", goal);
+                for warning in violations {
+                    log_warn!("CarSystem", "     {}", warning);
+                }
+            }
+
+            if !self.safety.is_safe(&warnings) {
+                log_warn!("CarSystem", "   🔴 CRITICAL SAFETY ISSUE - Consider stopping!");
+            }
+            log_info!("CarSystem", "");
+
+            // Critical faults are latched in persistent storage
+            for warning in &warnings {
+                if warning.severity() >= SafetySeverity::Critical {
+                    self.store.insert_unique(persistency_keys::LATCHED_FAULTS, warning.type_name());
+                }
+            }
+        }
+
+        let emergency = warnings.iter().any(|w| w.severity() == SafetySeverity::Emergency);
+        let workflow = if emergency && !self.emergency_active && !self.config.emergency_workflow.is_empty() {
+            self.emergency_active = true;
+            let name = self.config.emergency_workflow.clone();
+            log_warn!("CarSystem", "   🚨 EMERGENCY - running workflow '{}'", name);
+            Some(name)
+        } else {
+            None
+        };
+        if !emergency {
+            self.emergency_active = false;
+        }
+        self.last_warnings = warnings;
+        self.dashboard.set_faults(self.safety.active_faults());
+        workflow
+    }
+
+    /// Record the tick in the black box and flush persisted data
+    /// periodically, so a crash loses at most `flush_ticks` of data
+    fn record_tick(&mut self, snapshot: &SystemSnapshot) {
+        #[cfg(feature = "recorder")]
+        if let Some(blackbox) = &self.blackbox {
+            blackbox.record_snapshot(snapshot);
+        }
+
+        let flush_ticks = self.config.persistency.flush_ticks;
+        if flush_ticks > 0 && snapshot.tick % flush_ticks == flush_ticks - 1 {
+            if let Err(e) = self.save_persistent_state() {
+                log_warn!("CarSystem", "   ⚠️  Persistency flush failed: {}", e);
+            }
+        }
+    }
+
+    /// Timing fault for the deadline misses since the last safety check
+    /// (only with `event_loop.deadline_faults`)
+    fn deadline_warning(&mut self) -> Option<SafetyWarning> {
+        let misses = self.deadline_misses.load(Ordering::Relaxed);
+        if !self.config.event_loop.deadline_faults || misses <= self.deadline_misses_reported {
+            return None;
+        }
+        let missed = misses - self.deadline_misses_reported;
+        self.deadline_misses_reported = misses;
+        Some(SafetyWarning::DeadlineMissed { missed, period_ms: self.config.event_loop.base_period_ms() })
+    }
+
+    /// Check whether a task of `SCHEDULED_TASKS` runs in this tick
+    /// Tasks not in `event_loop.schedule` run every `default_every` ticks.
+    fn task_due(&self, task: &str, tick_num: u64, default_every: u64) -> bool {
+        let event_loop = &self.config.event_loop;
+        if event_loop.schedule.period_ms(task).is_none() {
+            return tick_num.is_multiple_of(default_every.max(1));
+        }
+        event_loop
+            .schedule
+            .due(tick_num, event_loop.base_period_ms())
+            .any(|due| due == task)
+    }
+
+    /// Warnings reported by the most recent safety check
+    pub fn last_warnings(&self) -> &[SafetyWarning] {
+        &self.last_warnings
+    }
+
+    /// Shared snapshot of the most recent tick
+    pub fn snapshot(&self) -> Arc<SystemSnapshot> {
+        self.snapshot.clone()
+    }
+
+    /// Capture all observable signals for this tick into the shared snapshot
+    /// Updated in place when no reader holds the previous one, otherwise
+    /// copied first so readers keep an unchanged snapshot.
+    fn publish_snapshot(
+        &mut self,
+        tick: u64,
+        sensors: &SensorFrame,
+        brake_pressure: u8,
+    ) -> Arc<SystemSnapshot> {
+        let snapshot = Arc::make_mut(&mut self.snapshot);
+        snapshot.tick = tick;
+        snapshot.speed = sensors.speed;
+        snapshot.rpm = sensors.rpm;
+        snapshot.temperature = sensors.temperature;
+        snapshot.fuel_level = sensors.fuel_level;
+        snapshot.brake_pressure = brake_pressure;
+        snapshot.steering_angle = self.steering.get_angle();
+        snapshot.odometer = self.dashboard.get_odometer();
+        snapshot.engine_state = self.engine.get_engine_state().as_str();
+        snapshot.battery = self.battery.as_ref().map(|battery| BatteryReading {
+            soc: battery.get_soc(),
+            temperature: battery.get_temperature(),
+            voltage: battery.get_voltage(),
+            charging_state: battery.get_charging_state().as_str(),
+        });
+        self.dashboard.get_warnings().clone_into(&mut snapshot.warnings);
+        self.snapshot.clone()
+    }
+
+    /// Reload the config file if it changed and apply the safe subset
+    fn poll_config(&mut self, tick_num: u64, tick_rate: &AtomicU64) {
+        let changed = match self.config_watcher.as_mut() {
+            Some(watcher) => watcher.poll(tick_num),
+            None => false,
+        };
+        if !changed {
+            return;
+        }
+
+        log_info!("CarSystem", "\n⚙️  Config file changed - reloading...");
+        let reloaded = match self.config.reload() {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                log_error!("CarSystem", "   ❌ Reload failed, keeping current config: {}", e);
+                return;
+            }
+        };
+
+        let moving = self.dashboard.get_speed() > 0;
+        let plan = ReloadPlan::new(&self.config, &reloaded, moving);
+        if plan.is_empty() {
+            log_info!("CarSystem", "   No effective changes");
+            return;
+        }
+
+        for (key, reason) in &plan.rejected {
+            log_warn!("CarSystem", "   🚫 Rejected {}: {}", key, reason);
+        }
+
+        for (key, old, new) in &plan.accepted {
+            // Values were validated by reload(), so set() cannot fail here
+            if let Err(e) = self.config.set(key, new, reloaded.origin(key)) {
+                log_error!("CarSystem", "   ❌ {}: {}", key, e);
+                continue;
+            }
+            log_info!("CarSystem", key = *key, old = old.clone(), new = new.clone();
+                      "   ✅ {}: {} → {}", key, old, new);
+            self.message_bus.publish(
+                ComponentId::CAR_SYSTEM,
+                CarMessage::ConfigChanged {
+                    key: key.to_string(),
+                    value: new.clone(),
+                },
+            );
+        }
+
+        // Push the accepted values to the running subsystems
+        self.safety.reconfigure(&self.config.safety);
+        configure_logging(self.config.log_level, &self.config.log);
+        self.message_bus.set_trace(log_enabled(LogLevel::Info, "MessageBus"));
+        self.apply_time_step();
+        self.dashboard.set_max_warnings(self.config.dashboard_max_warnings);
+        self.dashboard.set_unit_system(self.config.units);
+        set_display_units(self.config.units);
+        #[cfg(feature = "render")]
+        if let Some(tui) = self.tui.as_mut() {
+            tui.set_unit_system(self.config.units);
+        }
+        tick_rate.store(self.config.event_loop.base_period_ms(), Ordering::Relaxed);
+        if let Some(watcher) = self.config_watcher.as_mut() {
+            watcher.set_poll_ticks(self.config.reload_poll_ticks);
+        }
+        log_info!("CarSystem", "");
+    }
+
+    /// Process one cycle
+    pub fn process_cycle(&mut self, speed: u8) -> Result<(), String> {
+        self.brakes.set_vehicle_speed(speed);
+        self.update_powertrain(speed)?;
+        self.update_climate()?;
+        self.update_obstacle_sensor(speed)?;
+        self.update_location(speed)?;
+        self.process_components()?;
+        for component in &mut self.extra_components {
+            if !self.offline.iter().any(|(name, _)| *name == component.name()) {
+                component.process()?;
+                self.watchdog.pet(component.name());
+            }
+        }
+
+        // Collect messages from components and publish them to the bus
+        let mut outbox = mem::take(&mut self.outbox);
+        self.engine.get_messages_into(&mut outbox);
+        self.publish_all(ComponentId::ENGINE, &mut outbox);
+        self.brakes.get_messages_into(&mut outbox);
+        self.publish_all(ComponentId::BRAKES, &mut outbox);
+        self.steering.get_messages_into(&mut outbox);
+        self.publish_all(ComponentId::STEERING, &mut outbox);
+        if let Some(battery) = &self.battery {
+            battery.get_messages_into(&mut outbox);
+            self.publish_all(ComponentId::BATTERY, &mut outbox);
+        }
+        if let Some(hvac) = &self.hvac {
+            hvac.get_messages_into(&mut outbox);
+            self.publish_all(ComponentId::HVAC, &mut outbox);
+        }
+        if let Some(sensor) = &self.obstacle_sensor {
+            sensor.get_messages_into(&mut outbox);
+            self.publish_all(ComponentId::OBSTACLE_SENSOR, &mut outbox);
+        }
+        if let Some(location) = &self.location {
+            location.get_messages_into(&mut outbox);
+            self.publish_all(ComponentId::LOCATION, &mut outbox);
+        }
+        self.outbox = outbox;
+
+        // Handle system commands addressed to the CarSystem
+        let mut inbox = mem::take(&mut self.inbox);
+        self.message_bus.receive_all_into(ComponentId::CAR_SYSTEM, &mut inbox);
+        let mut collision = None;
+        for msg in inbox.drain(..) {
+            match msg {
+                CarMessage::SelectCalibration { name } => {
+                    if let Err(e) = self.select_calibration(&name) {
+                        log_error!("CarSystem", "  ❌ {}", e);
+                    }
+                }
+                CarMessage::CollisionImminent { distance, ttc } => collision = Some((distance, ttc)),
+                _ => {}
+            }
+        }
+
+        self.serve_requests();
+
+        // Dashboard receives all messages
+        self.message_bus.receive_all_into(ComponentId::DASHBOARD, &mut inbox);
+        self.dashboard.process_messages(&inbox);
+        inbox.clear();
+        self.inbox = inbox;
+
+        // Update dashboard
+        self.dashboard.set_speed(speed);
+        self.dashboard.update_odometer(speed as f32 / 10.0);
+        if self.is_online(self.dashboard.name()) {
+            self.dashboard.process()?;
+            self.watchdog.pet(self.dashboard.name());
+        }
+
+        self.message_bus.advance_cycle();
+
+        self.brake_for_collision(collision)
+    }
+
+    /// Automatic emergency braking: run the emergency workflow when the
+    /// obstacle sensor reports an imminent collision, once until the
+    /// warning clears
+    fn brake_for_collision(&mut self, collision: Option<(f32, f32)>) -> Result<(), String> {
+        let Some((distance, ttc)) = collision else {
+            self.collision_braking = false;
+            return Ok(());
+        };
+        if self.collision_braking || self.config.emergency_workflow.is_empty() {
+            return Ok(());
+        }
+        self.collision_braking = true;
+        let name = self.config.emergency_workflow.clone();
+        log_warn!("CarSystem", "   🚨 COLLISION IMMINENT ({:.1} m, {:.1}s) - running workflow '{}'", distance, ttc, name);
+        self.trigger_workflow(&name)?;
+        Ok(())
+    }
+
+    /// Vehicle speed for this tick
+    /// Scripted dynamics use the demo speed profile directly; physics
+    /// dynamics make it the driver's target for the throttle and integrate
+    /// throttle, brakes and resistances into the speed.
+    fn next_speed(&mut self, tick_num: u64) -> Result<u8, String> {
+        let target = self.driver.next_speed(tick_num);
+        let (Some(throttle), Some(dynamics)) = (self.throttle.as_mut(), self.dynamics.as_mut()) else {
+            return Ok(target);
+        };
+        let brake_pressure = self.brakes.get_pressure();
+        throttle.set_target_speed(target);
+        throttle.set_vehicle_speed(dynamics.speed_kmh());
+        throttle.set_braking(brake_pressure > 0);
+        if !self.offline.iter().any(|(name, _)| *name == throttle.name()) {
+            throttle.process()?;
+            self.watchdog.pet(throttle.name());
+        }
+        self.engine.set_throttle(throttle.get_position());
+
+        // Traction comes from the engine or, in EV and hybrid cars, the battery
+        let propulsion = self.engine.is_running()
+            || self.battery.as_ref().is_some_and(|battery| battery.get_soc() > 0.0);
+        dynamics.step(throttle.get_position(), brake_pressure, propulsion);
+        Ok(dynamics.speed_kmh())
+    }
+
+    /// Split this cycle's traction power between engine and battery, and
+    /// process the battery (EV and hybrid powertrains)
+    fn update_powertrain(&mut self, speed: u8) -> Result<(), String> {
+        let Some(battery) = self.battery.as_mut() else {
+            return Ok(());
+        };
+        let split = PowerSplit::decide(
+            self.config.powertrain.mode,
+            speed,
+            self.brakes.get_pressure(),
+            battery.get_soc(),
+            self.engine.is_running(),
+        );
+        self.dashboard.set_combustion_drive(split.combustion);
+        if !self.offline.iter().any(|(name, _)| *name == battery.name()) {
+            battery.apply(&split)?;
+            battery.process()?;
+            self.watchdog.pet(battery.name());
+        }
+        Ok(())
+    }
+
+    /// Run the climate control and hand the engine the messages it
+    /// subscribed to (HVAC enabled only)
+    /// The engine sees the load published in the previous cycle, like any
+    /// bus receiver.
+    fn update_climate(&mut self) -> Result<(), String> {
+        let Some(hvac) = self.hvac.as_mut() else {
+            return Ok(());
+        };
+        let mut inbox = mem::take(&mut self.inbox);
+        self.message_bus.receive_all_into(ComponentId::HVAC, &mut inbox);
+        hvac.process_messages(&inbox);
+        inbox.clear();
+        if !self.offline.iter().any(|(name, _)| *name == hvac.name()) {
+            hvac.process()?;
+            self.watchdog.pet(hvac.name());
+        }
+        self.message_bus.receive_all_into(ComponentId::ENGINE, &mut inbox);
+        self.engine.process_messages(&inbox);
+        inbox.clear();
+        self.inbox = inbox;
+        Ok(())
+    }
+
+    /// Measure the distance to the obstacle ahead (automatic emergency
+    /// braking only)
+    fn update_obstacle_sensor(&mut self, speed: u8) -> Result<(), String> {
+        let Some(sensor) = self.obstacle_sensor.as_mut() else {
+            return Ok(());
+        };
+        sensor.set_vehicle_speed(speed);
+        if !self.offline.iter().any(|(name, _)| *name == sensor.name()) {
+            sensor.process()?;
+            self.watchdog.pet(sensor.name());
+        }
+        Ok(())
+    }
+
+    /// Integrate speed and steering into the car's position (location
+    /// tracking only)
+    fn update_location(&mut self, speed: u8) -> Result<(), String> {
+        let Some(location) = self.location.as_mut() else {
+            return Ok(());
+        };
+        location.set_motion(speed, self.steering.get_angle());
+        if !self.offline.iter().any(|(name, _)| *name == location.name()) {
+            location.process()?;
+            self.watchdog.pet(location.name());
+        }
+        Ok(())
+    }
+
+    /// Send a request from the car system to one component
+    /// Core components answer during the next cycle; poll with `poll_reply`.
+    pub fn request(&mut self, to: ComponentId, message: CarMessage) -> Result<RequestId, String> {
+        self.message_bus.request(ComponentId::CAR_SYSTEM, to, message, REQUEST_TIMEOUT_CYCLES)
+    }
+
+    /// Outcome of a request sent with `request`
+    pub fn poll_reply(&mut self, id: RequestId) -> ReplyStatus {
+        self.message_bus.poll_reply(ComponentId::CAR_SYSTEM, id)
+    }
+
+    /// Ask a component and get the answer right away, without running a
+    /// cycle (e.g. "Engine, report your temperature")
+    pub fn query(&mut self, to: ComponentId, message: CarMessage) -> Result<CarMessage, String> {
+        let id = self.request(to, message)?;
+        self.serve_requests();
+        match self.poll_reply(id) {
+            ReplyStatus::Ready(reply) => Ok(reply),
+            _ => {
+                self.message_bus.cancel(id);
+                Err(format!("{} did not answer request {}", to, id))
+            }
+        }
+    }
+
+    /// Answer the requests addressed to the core components
+    /// Signal queries are answered by the signal's owner; anything else
+    /// gets a `ComponentError` reply.
+    fn serve_requests(&mut self) {
+        let mut requests = mem::take(&mut self.requests);
+        for to in [ComponentId::ENGINE, ComponentId::BRAKES, ComponentId::STEERING, ComponentId::DASHBOARD] {
+            self.message_bus.take_requests_into(to, &mut requests);
+            for request in requests.drain(..) {
+                let error = |error: String| CarMessage::ComponentError { component: to, error };
+                let reply = match &request.message {
+                    CarMessage::QuerySignal { signal } => match SignalId::parse(signal) {
+                        Ok(id) if id.owner() == to => CarMessage::SignalReport {
+                            signal: signal.clone(),
+                            value: self.get_signal(id).as_f64(),
+                        },
+                        Ok(id) => error(format!("{} is owned by {}", id, id.owner())),
+                        Err(e) => error(e),
+                    },
+                    other => error(format!("cannot answer {}", other.type_name())),
+                };
+                if let Err(e) = self.message_bus.reply(request.id, to, reply) {
+                    log_error!("CarSystem", "  ❌ {}", e);
+                }
+            }
+        }
+        self.requests = requests;
+    }
+
+    /// Update all components stage by stage (chaos mode may make one miss
+    /// its slot)
+    fn process_components(&mut self) -> Result<(), String> {
+        let parallel = self.config.event_loop.parallel_components;
+        let chaos = &mut self.chaos;
+        let watchdog = &mut self.watchdog;
+        let mut components: [(ComponentId, &mut (dyn CarComponent + Send), bool); 3] = [
+            (ComponentId::ENGINE, &mut self.engine, false),
+            (ComponentId::BRAKES, &mut self.brakes, false),
+            (ComponentId::STEERING, &mut self.steering, false),
+        ];
+
+        for stage in PROCESS_STAGES {
+            // Chaos decisions are drawn in stage order so runs stay reproducible
+            for id in stage.iter() {
+                let delayed = chaos.as_mut().is_some_and(|chaos| chaos.delay(*id));
+                if let Some(slot) = components.iter_mut().find(|slot| slot.0 == *id) {
+                    slot.2 = !delayed;
+                }
+            }
+
+            if parallel && stage.len() > 1 {
+                thread::scope(|scope| {
+                    let handles: Vec<_> = components
+                        .iter_mut()
+                        .filter(|slot| slot.2)
+                        .map(|slot| {
+                            let component = &mut *slot.1;
+                            scope.spawn(move || component.process())
+                        })
+                        .collect();
+                    handles.into_iter().try_for_each(|handle| {
+                        handle.join().unwrap_or_else(|_| Err("component process() panicked".to_string()))
+                    })
+                })?;
+            } else {
+                for id in stage.iter() {
+                    if let Some(slot) = components.iter_mut().find(|slot| slot.0 == *id && slot.2) {
+                        slot.1.process()?;
+                    }
+                }
+            }
+
+            for slot in components.iter_mut() {
+                if slot.2 {
+                    watchdog.pet(slot.1.name());
+                }
+                slot.2 = false;
+            }
+        }
+        Ok(())
+    }
+
+    /// Record and publish all messages in a buffer as one batch, leaving
+    /// it empty (chaos mode may drop some of them)
+    fn publish_all(&mut self, from: ComponentId, messages: &mut Vec<CarMessage>) {
+        #[cfg(feature = "recorder")]
+        if let Some(blackbox) = &self.blackbox {
+            blackbox.record_messages(from, messages);
+        }
+        if let Some(chaos) = self.chaos.as_mut() {
+            messages.retain(|_| !chaos.drop_message());
+        }
+        self.message_bus.publish_batch(from, messages);
+    }
+
+    /// Apply an injected fault to the running system
+    fn inject_fault(&mut self, fault: ChaosFault) -> Result<(), String> {
+        match fault {
+            ChaosFault::EngineOverheat => self.engine.inject_temperature_spike(25.0),
+            ChaosFault::FuelLeak => {
+                let fuel = self.dashboard.get_fuel_level().saturating_sub(20);
+                self.dashboard.set_fuel_level(fuel);
+            }
+            ChaosFault::BrakeSpike => self.brakes.apply(95)?,
+        }
+        Ok(())
+    }
+
+    /// Check that the system is in a safe state
+    /// Engine settled (not mid-transition), no component in error and
+    /// all sensor values physically plausible.
+    pub fn check_safe_state(&self) -> Result<(), String> {
+        let mut problems = Vec::new();
+
+        match self.engine.get_engine_state() {
+            EngineStateMachine::Off | EngineStateMachine::Running => {}
+            state => problems.push(format!("engine stuck in {}", state)),
+        }
+        if *self.brakes.get_brake_state() == BrakeStateMachine::Fault {
+            problems.push("brakes in FAULT".to_string());
+        }
+        let components: [(&str, ComponentState); 4] = [
+            (self.engine.name(), self.engine.get_state()),
+            (self.brakes.name(), self.brakes.get_state()),
+            (self.steering.name(), self.steering.get_state()),
+            (self.dashboard.name(), self.dashboard.get_state()),
+        ];
+        for (name, state) in components {
+            // Offline components were already reported at startup
+            if !self.is_online(name) {
+                continue;
+            }
+            if let ComponentState::Error(e) = state {
+                problems.push(format!("{} in error: {}", name, e));
+            }
+        }
+        if !self.engine.get_temperature().is_finite() {
+            problems.push("engine temperature is not a number".to_string());
+        }
+        if let Some(battery) = self.battery.as_ref().filter(|battery| self.is_online(battery.name())) {
+            if let ComponentState::Error(e) = battery.get_state() {
+                problems.push(format!("{} in error: {}", battery.name(), e));
+            }
+            if !battery.get_temperature().is_finite() {
+                problems.push("battery temperature is not a number".to_string());
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("unsafe state: {}", problems.join(", ")))
+        }
+    }
+
+    /// Report a chaos run and verify it left the system in a safe state
+    fn finish_chaos_run(&mut self) -> Result<(), String> {
+        log_info!("CarSystem", "");
+        if let Some(chaos) = &self.chaos {
+            chaos.print_report();
+        }
+        self.check_safe_state()?;
+        log_info!("CarSystem", "✅ Chaos run ended in a safe state\n");
+        Ok(())
+    }
+
+    /// Apply the limits of the configured safety limits file
+    /// A calibration set selected afterwards replaces them.
+    pub fn load_safety_limits(&mut self) -> Result<(), String> {
+        if self.config.safety_file.is_empty() {
+            return Ok(());
+        }
+        log_info!("CarSystem", "🔧 Loading safety limits from {}...", self.config.safety_file);
+        self.config.safety = SafetyConfig::load(&self.config.safety_file)?;
+        self.safety.reconfigure(&self.config.safety);
+        Ok(())
+    }
+
+    /// Load calibration sets and apply the configured set
+    pub fn load_calibration(&mut self) -> Result<(), String> {
+        if !self.config.calibration.file.is_empty() {
+            self.calibration = CalibrationBook::load(&self.config.calibration.file)?;
+        }
+        if !self.config.calibration.set.is_empty() {
+            let name = self.config.calibration.set.clone();
+            self.calibration.select(&name)?;
+            self.config.safety = self.calibration.active().clone();
+            self.safety.reconfigure(&self.config.safety);
+        }
+        Ok(())
+    }
+
+    /// Switch the safety monitor to another calibration set at runtime
+    pub fn select_calibration(&mut self, name: &str) -> Result<(), String> {
+        let previous = self.calibration.active_name().to_string();
+        let limits = self.calibration.select(name)?.clone();
+        self.config.calibration.set = name.to_string();
+        self.config.safety = limits;
+        self.safety.reconfigure(&self.config.safety);
+        log_info!("CarSystem", "  🎚️ Calibration switched: {} → {}", previous, name);
+        Ok(())
+    }
+
+    /// Acknowledge a latched fault and update the dashboard
+    pub fn acknowledge_fault(&mut self, fault_id: u32) -> Result<(), String> {
+        self.safety.acknowledge(fault_id)?;
+        self.dashboard.set_faults(self.safety.active_faults());
+        log_info!("CarSystem", "  🔓 Fault #{} acknowledged", fault_id);
+        Ok(())
+    }
+
+    /// Open the configured store and restore persisted values into components
+    pub fn load_persistent_state(&mut self) -> Result<(), String> {
+        let path = &self.config.persistency.path;
+        self.store = if path.is_empty() {
+            KvStore::in_memory()
+        } else {
+            KvStore::open(path)?
+        };
+        self.restore_persistent_state();
+        Ok(())
+    }
+
+    /// Push values from the store into the components
+    pub fn restore_persistent_state(&mut self) {
+        if let Some(km) = self.store.get_f64(persistency_keys::ODOMETER_KM) {
+            self.dashboard.set_odometer(km as f32);
+        }
+        if let Some(count) = self.store.get_u64(persistency_keys::ENGINE_STARTS) {
+            self.engine.set_start_count(count);
+        }
+        if let Some(count) = self.store.get_u64(persistency_keys::BRAKE_APPLICATIONS) {
+            self.brakes.set_application_count(count);
+        }
+    }
+
+    /// Fit a new engine and new brakes, set up like the ones
+    /// `CarSystemBuilder` fits
+    /// Persisted service counters (engine starts, brake applications)
+    /// carry over to the new parts.
+    pub fn replace_engine_and_brakes(&mut self) -> Result<(), String> {
+        self.save_persistent_state()?;
+        self.engine = fit_engine(EngineComponent::new(), &self.config, &self.rng);
+        self.brakes = fit_brakes(BrakesComponent::new(), &self.config);
+        self.apply_time_step();
+        self.restore_persistent_state();
+        Ok(())
+    }
+
+    /// Collect values from the components and flush them to disk
+    pub fn save_persistent_state(&mut self) -> Result<(), String> {
+        self.store.set(persistency_keys::ODOMETER_KM, JsonValue::from(self.dashboard.get_odometer()));
+        self.store.set(persistency_keys::ENGINE_STARTS, JsonValue::from(self.engine.get_start_count()));
+        self.store.set(persistency_keys::BRAKE_APPLICATIONS, JsonValue::from(self.brakes.get_application_count()));
+        self.store.flush()
+    }
+
+    /// Shutdown the car
+    pub fn shutdown(&mut self) -> Result<(), String> {
+        log_info!("CarSystem", "🛑 Shutting down the car...\n");
+        // Reverse initialization order; a failing component doesn't stop
+        // the others (or persistence) from shutting down
+        let mut failed = Vec::new();
+        let mut components: Vec<&mut (dyn CarComponent + Send)> = Vec::new();
+        if let Some(battery) = self.battery.as_mut() {
+            components.push(battery);
+        }
+        components.extend([
+            &mut self.engine as &mut (dyn CarComponent + Send),
+            &mut self.brakes,
+            &mut self.steering,
+            &mut self.dashboard,
+        ]);
+        if let Some(hvac) = self.hvac.as_mut() {
+            components.push(hvac);
+        }
+        if let Some(sensor) = self.obstacle_sensor.as_mut() {
+            components.push(sensor);
+        }
+        if let Some(location) = self.location.as_mut() {
+            components.push(location);
+        }
+        if let Some(throttle) = self.throttle.as_mut() {
+            components.push(throttle);
+        }
+        for component in &mut self.extra_components {
+            components.push(component.as_mut());
+        }
+        for component in components.into_iter().rev() {
+            let name = component.name();
+            if self.offline.iter().any(|(offline, _)| *offline == name) {
+                continue;
+            }
+            if let Err(e) = component.shutdown() {
+                log_warn!("CarSystem", "⚠️  {} failed to shut down: {}", name, e);
+                failed.push(format!("{}: {}", name, e));
+            }
+            log_info!("CarSystem", "");
+        }
+        self.save_persistent_state()?;
+        if let Some(path) = self.store.path() {
+            log_info!("CarSystem", "💾 Persisted data saved to {}", path.display());
+        }
+        #[cfg(feature = "recorder")]
+        if let Some(blackbox) = &self.blackbox {
+            blackbox.sync()?;
+        }
+        self.export_safety_log()?;
+        self.export_state_history()?;
+        if !failed.is_empty() {
+            return Err(format!("Shutdown incomplete: {}", failed.join(", ")));
+        }
+        log_info!("CarSystem", "\n✅ Car shut down complete!");
+        log_info!("CarSystem", "{}", "━".repeat(60));
+        if let Some(sink) = self.log_sink.take() {
+            remove_log_sink(sink)?;
+        }
+        Ok(())
+    }
+
+    /// Report every online component that isn't healthy to the safety
+    /// monitor
+    fn check_component_health(&mut self) {
+        let core: [&dyn CarComponent; 4] = [&self.engine, &self.brakes, &self.steering, &self.dashboard];
+        let battery = self.battery.as_ref().map(|battery| battery as &dyn CarComponent);
+        let hvac = self.hvac.as_ref().map(|hvac| hvac as &dyn CarComponent);
+        let sensor = self.obstacle_sensor.as_ref().map(|sensor| sensor as &dyn CarComponent);
+        let location = self.location.as_ref().map(|location| location as &dyn CarComponent);
+        let throttle = self.throttle.as_ref().map(|throttle| throttle as &dyn CarComponent);
+        let extra = self.extra_components.iter().map(|component| component.as_ref() as &dyn CarComponent);
+        let optional = battery.into_iter().chain(hvac).chain(sensor).chain(location).chain(throttle);
+        for component in core.into_iter().chain(optional).chain(extra) {
+            if self.is_online(component.name()) {
+                self.safety.check_health(component.name(), component.health_check());
+            }
+        }
+    }
+
+    /// Core, optional and additional components
+    fn all_components(&self) -> impl Iterator<Item = &dyn CarComponent> {
+        let core: [&dyn CarComponent; 4] = [&self.engine, &self.brakes, &self.steering, &self.dashboard];
+        let battery = self.battery.as_ref().map(|battery| battery as &dyn CarComponent);
+        let hvac = self.hvac.as_ref().map(|hvac| hvac as &dyn CarComponent);
+        let sensor = self.obstacle_sensor.as_ref().map(|sensor| sensor as &dyn CarComponent);
+        let location = self.location.as_ref().map(|location| location as &dyn CarComponent);
+        let throttle = self.throttle.as_ref().map(|throttle| throttle as &dyn CarComponent);
+        let extra = self.extra_components.iter().map(|component| component.as_ref() as &dyn CarComponent);
+        let optional = battery.into_iter().chain(hvac).chain(sensor).chain(location).chain(throttle);
+        core.into_iter().chain(optional).chain(extra)
+    }
+
+    /// Mutable access to a component by name
+    fn component_mut(&mut self, name: &str) -> Option<&mut (dyn CarComponent + Send)> {
+        let core: [&mut (dyn CarComponent + Send); 4] =
+            [&mut self.engine, &mut self.brakes, &mut self.steering, &mut self.dashboard];
+        let battery = self.battery.as_mut().map(|battery| battery as &mut (dyn CarComponent + Send));
+        let hvac = self.hvac.as_mut().map(|hvac| hvac as &mut (dyn CarComponent + Send));
+        let sensor = self.obstacle_sensor.as_mut().map(|sensor| sensor as &mut (dyn CarComponent + Send));
+        let location = self.location.as_mut().map(|location| location as &mut (dyn CarComponent + Send));
+        let throttle = self.throttle.as_mut().map(|throttle| throttle as &mut (dyn CarComponent + Send));
+        let extra = self
+            .extra_components
+            .iter_mut()
+            .map(|component| component.as_mut() as &mut (dyn CarComponent + Send));
+        let optional = battery.into_iter().chain(hvac).chain(sensor).chain(location).chain(throttle);
+        core.into_iter().chain(optional).chain(extra).find(|component| component.name() == name)
+    }
+
+    /// Online components that failed, with the reason: error state,
+    /// unhealthy health check or missed heartbeats
+    fn failed_components(&self) -> Vec<(&'static str, String)> {
+        self.all_components()
+            .filter(|component| self.is_online(component.name()))
+            .filter_map(|component| {
+                let reason = match (component.get_state(), component.health_check()) {
+                    (ComponentState::Error(e), _) => e,
+                    (_, HealthStatus::Unhealthy(reason)) => reason.to_string(),
+                    _ => {
+                        let (_, missed) = self.watchdog.expired().find(|(name, _)| *name == component.name())?;
+                        format!("missed {} cycles", missed)
+                    }
+                };
+                Some((component.name(), reason))
+            })
+            .collect()
+    }
+
+    /// Apply the restart policies to failed components (supervisor enabled only)
+    fn supervise_components(&mut self) -> Result<(), String> {
+        let Some(mut supervisor) = self.supervisor.take() else {
+            return Ok(());
+        };
+        let result = self
+            .failed_components()
+            .into_iter()
+            .try_for_each(|(name, reason)| self.recover(&mut supervisor, name, &reason));
+        self.supervisor = Some(supervisor);
+        result
+    }
+
+    /// React to one failed component as its policy says
+    fn recover(&mut self, supervisor: &mut Supervisor, name: &'static str, reason: &str) -> Result<(), String> {
+        log_warn!("CarSystem", "  🩺 Supervisor: {} failed ({})", name, reason);
+        while let Some(action) = supervisor.on_failure(name) {
+            match action {
+                SupervisorAction::Restart { attempt, max } => {
+                    log_info!("CarSystem", component = name, attempt = attempt, max = max;
+                              "  🔄 Supervisor: Restarting {} (attempt {}/{})", name, attempt, max);
+                    if let Err(e) = self.restart_component(name) {
+                        log_error!("CarSystem", "  ❌ Supervisor: {} failed to restart: {}", name, e);
+                        continue;
+                    }
+                    self.watchdog.supervise(name);
+                    if let Some(id) = self.component_id(name) {
+                        self.message_bus.publish(ComponentId::CAR_SYSTEM, CarMessage::ComponentRestarted {
+                            component: id,
+                            attempt,
+                        });
+                    }
+                }
+                // The car can't run without a critical component
+                SupervisorAction::Degrade if !self.is_critical(name) => {
+                    log_warn!("CarSystem", "   🟠 DEGRADED MODE - {} taken offline", name);
+                    self.offline.push((name, reason.to_string()));
+                    self.watchdog.release(name);
+                    if !self.config.watchdog.workflow.is_empty() {
+                        let workflow = self.config.watchdog.workflow.clone();
+                        self.trigger_workflow(&workflow)?;
+                    }
+                }
+                SupervisorAction::Degrade | SupervisorAction::Shutdown => {
+                    log_warn!("CarSystem", "   🔴 Supervisor: {} can't recover - shutting down", name);
+                    self.shutdown.store(true, Ordering::SeqCst);
+                }
+            }
+            break;
+        }
+        Ok(())
+    }
+
+    /// Shut a component down and initialize it again
+    /// A restarted engine is started again if it was running.
+    fn restart_component(&mut self, name: &str) -> Result<(), String> {
+        let engine_running = self.engine.is_running();
+        let component = self.component_mut(name).ok_or_else(|| format!("Unknown component: {}", name))?;
+        if let Err(e) = component.shutdown() {
+            log_warn!("CarSystem", "  ⚠️  {} failed to shut down: {}", name, e);
+        }
+        component.initialize()?;
+        if name == self.engine.name() && engine_running {
+            self.engine.start()?;
+        }
+        Ok(())
+    }
+
+    /// Create a "Start Car" workflow
+    pub fn create_start_workflow() -> super::Workflow {
+        let mut builder = crate::components::WorkflowBuilder::new(
+            "Start Car",
+            "Sequence to start the car and prepare for driving"
+        );
+        builder.step(
+            "Start Engine",
+            "Initialize the engine",
+            Box::new(|system| {
+                log_info!("CarSystem", "🔑 Turning key to start engine...");
+                system.engine.start()?;
+                Ok(())
+            }),
+        );
+        builder.requirement("REQ-WF-001");
+        builder.retry(3, Duration::from_millis(100));
+        builder.precondition(|system| {
+            if system.engine.is_running() {
+                Err("engine is already running".to_string())
+            } else {
+                Ok(())
+            }
+        });
+        builder.step(
+            "Initialize Dashboard",
+            "Set initial dashboard values",
+            Box::new(|system| {
+                log_info!("CarSystem", "📊 Setting up dashboard...");
+                system.dashboard.set_fuel_level(85);
+                Ok(())
+            }),
+        );
+        builder.step(
+            "Ready Announcement",
+            "Announce car is ready",
+            Box::new(|_system| {
+                log_info!("CarSystem", "\n✅ Car is ready to drive!\n");
+                Ok(())
+            }),
+        );
+        builder.build()
+    }
+
+    /// Create a "Shutdown Car" workflow
+    pub fn create_shutdown_workflow() -> super::Workflow {
+        let mut builder = crate::components::WorkflowBuilder::new(
+            "Shutdown Car",
+            "Sequence to safely shutdown the car"
+        );
+        builder
+            .parallel(
+                "Release Controls",
+                "Release brakes and center steering",
+                vec![
+                    ParallelTask::brakes("Release Brakes", |brakes| {
+                        log_info!("CarSystem", "🛞 Releasing brakes...");
+                        brakes.release();
+                        Ok(())
+                    }),
+                    ParallelTask::steering("Center Steering", |steering| {
+                        log_info!("CarSystem", "🔄 Centering steering...");
+                        steering.center();
+                        Ok(())
+                    }),
+                ],
+            )
+            .expect("shutdown tasks use distinct components");
+        builder.requirement("REQ-WF-002");
+        builder.step(
+            "Stop Engine",
+            "Turn off the engine",
+            Box::new(|system| {
+                log_info!("CarSystem", "🔑 Turning off engine...");
+                system.engine.stop()?;
+                Ok(())
+            }),
+        );
+        builder.requirement("REQ-WF-002");
+        builder.precondition(|system| {
+            if system.engine.is_running() {
+                Ok(())
+            } else {
+                Err("engine is not running".to_string())
+            }
+        });
+        builder.build()
+    }
+
+    /// Create an "Emergency Stop" workflow
+    pub fn create_emergency_stop_workflow() -> super::Workflow {
+        let mut builder = crate::components::WorkflowBuilder::new(
+            "Emergency Stop",
+            "Immediate emergency stop sequence"
+        );
+        builder.step(
+            "Max Brakes",
+            "Apply maximum brake pressure",
+            Box::new(|system| {
+                log_warn!("CarSystem", "🚨 APPLYING MAXIMUM BRAKES!");
+                system.brakes.apply(100)?;
+                Ok(())
+            }),
+        );
+        builder.requirement("REQ-WF-003");
+        builder.step(
+            "Stop Engine",
+            "Immediately stop engine",
+            Box::new(|system| {
+                log_warn!("CarSystem", "🚨 STOPPING ENGINE!");
+                system.engine.stop()?;
+                Ok(())
+            }),
+        );
+        builder.requirement("REQ-WF-003");
+        builder.step(
+            "Hazard Warning",
+            "Display emergency status",
+            Box::new(|_system| {
+                log_warn!("CarSystem", "\n🚨 EMERGENCY STOP COMPLETE! 🚨");
+                log_info!("CarSystem", "   Vehicle safely stopped\n");
+                Ok(())
+            }),
+        );
+        builder.build()
+    }
+
+    /// Create a "Degraded Mode" workflow (run when a component stops responding)
+    pub fn create_degraded_workflow() -> super::Workflow {
+        let mut builder = crate::components::WorkflowBuilder::new(
+            "Degraded Mode",
+            "Slow the car down and tell the driver to stop safely"
+        );
+        builder.step(
+            "Reduce Speed",
+            "Apply moderate brake pressure",
+            Box::new(|system| {
+                log_info!("CarSystem", "🟠 Reducing speed...");
+                system.brakes.apply(30)?;
+                Ok(())
+            }),
+        );
+        builder.requirement("REQ-WF-004");
+        builder.step(
+            "Driver Notice",
+            "Ask the driver to stop",
+            Box::new(|system| {
+                system.dashboard.add_warning(WarningSeverity::Critical, "DEGRADED MODE - stop when safe".to_string());
+                log_info!("CarSystem", "🟠 Degraded mode active - stop when safe");
+                Ok(())
+            }),
+        );
+        builder.requirement("REQ-WF-004");
+        builder.build()
+    }
+}
+
+impl Default for CarSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parts of a tick that can run at their own period (`event_loop.schedule`)
+/// - `components`: processing of engine, brakes, steering and dashboard
+/// - `safety`: safety check (default every 5 ticks)
+/// - `dashboard`: rendering (default every `event_loop.render_every` ticks)
+pub const SCHEDULED_TASKS: &[&str] = &["components", "safety", "dashboard"];
+
+/// Creates a fresh instance of a built-in workflow
+pub type WorkflowFactory = fn() -> Workflow;
+
+/// Built-in workflows by name: (name, factory)
+pub const WORKFLOWS: &[(&str, WorkflowFactory)] = &[
+    ("start", CarSystem::create_start_workflow),
+    ("shutdown", CarSystem::create_shutdown_workflow),
+    ("emergency-stop", CarSystem::create_emergency_stop_workflow),
+    ("degraded", CarSystem::create_degraded_workflow),
+];
+
+impl WorkflowRegistry {
+    /// A registry with the built-in workflows under their `WORKFLOWS` names
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        for (name, create) in WORKFLOWS {
+            registry.register(name, create());
+        }
+        registry
+    }
+}
+
+/// Create a built-in workflow by name, load a workflow file (`.json`), or
+/// a scripted one from a `.rhai` file (needs the `scripting` feature)
+pub fn find_workflow(name: &str) -> Result<Workflow, String> {
+    if name.ends_with(".json") {
+        return Workflow::load(name, &ActionRegistry::new());
+    }
+    if name.ends_with(".rhai") {
+        #[cfg(feature = "scripting")]
+        return script_workflow(name);
+        #[cfg(not(feature = "scripting"))]
+        return Err(format!("{} is a script, but the binary was built without the scripting feature", name));
+    }
+    WORKFLOWS
+        .iter()
+        .find(|(workflow, _)| *workflow == name)
+        .map(|(_, create)| create())
+        .ok_or_else(|| {
+            let names: Vec<&str> = WORKFLOWS.iter().map(|(name, _)| *name).collect();
+            format!("Unknown workflow '{}' (available: {})", name, names.join(", "))
+        })
+}
+
+/// Print the built-in workflows with their steps
+pub fn list_workflows() -> Result<(), String> {
+    println!("📋 Workflows:");
+    for (name, create) in WORKFLOWS {
+        let workflow = create();
+        println!("  {:<16} {} ({} steps)", name, workflow.name(), workflow.step_count());
+        for step in workflow.steps() {
+            let mut line = format!("     - {}", step.name());
+            if let Some(requirement) = step.requirement() {
+                line.push_str(&format!(" [{}]", requirement));
+            }
+            if step.retry().max_attempts > 1 {
+                line.push_str(&format!(" (up to {} attempts)", step.retry().max_attempts));
+            }
+            if let Some(timeout) = step.timeout() {
+                line.push_str(&format!(" (timeout {} ms)", timeout.as_millis()));
+            }
+            if let Some(after) = step.after() {
+                line.push_str(&format!(" (after: {})", after.join(", ")));
+            }
+            if step.has_precondition() {
+                line.push_str(" (guarded)");
+            }
+            println!("{}", line);
+            for task in step.parallel_tasks() {
+                println!("         ∥ {} ({})", task.name(), task.component());
+            }
+        }
+    }
+    println!("\n📋 Actions for workflow files:");
+    for (name, args, _) in BUILTIN_ACTIONS {
+        if args.is_empty() {
+            println!("  {}", name);
+        } else {
+            println!("  {:<20} args: {}", name, args);
+        }
+    }
+    Ok(())
+}
+
+/// Initialize a system and run one workflow on it
+/// Workflows other than "Start Car" expect a running car, so the start
+/// workflow runs quietly first.
+pub fn run_workflow(config: &SystemConfig, name: &str) -> Result<(), String> {
+    let mut car = prepare_workflow(config, name)?;
+    let report = car.trigger_workflow(name)?;
+    report.print_summary();
+    report.result()
+}
+
+/// Dry-run one workflow on a freshly started car: report which steps
+/// would run and which preconditions fail, without running any step
+pub fn check_workflow(config: &SystemConfig, name: &str) -> Result<(), String> {
+    let car = prepare_workflow(config, name)?;
+    let workflow = car.workflows.get(name).ok_or_else(|| format!("Unknown workflow '{}'", name))?;
+    let report = workflow.dry_run(&car);
+    report.print();
+    match report.failing_step() {
+        None => Ok(()),
+        Some((step, error)) => Err(format!("Workflow '{}' would fail at step '{}': {}", report.workflow, step, error)),
+    }
+}
+
+/// Initialized system with the workflow registered under `name`
+fn prepare_workflow(config: &SystemConfig, name: &str) -> Result<CarSystem, String> {
+    let workflow = find_workflow(name)?;
+    let starts_car = workflow.name() == CarSystem::create_start_workflow().name();
+    let mut car = CarSystemBuilder::new()
+        .with_config(config.clone())
+        .with_workflow(name, workflow)
+        .build()?;
+    car.initialize()?;
+    if !starts_car {
+        log_info!("CarSystem", "🔑 Starting the car first (quiet)...\n");
+        CarSystem::create_start_workflow().execute_quiet(&mut car)?;
+    }
+    Ok(car)
+}
+
+/// Names of the components every car system has
+/// Initialize one component, retrying with exponential backoff when the
+/// policy allows it
+fn initialize_component(component: &mut (dyn CarComponent + Send), init: &InitConfig) -> Result<(), String> {
+    let mut attempt = 0;
+    loop {
+        match component.initialize() {
+            Ok(()) => return Ok(()),
+            Err(e) if init.policy == InitPolicy::RetryWithBackoff && attempt < init.retries => {
+                let delay_ms = init.backoff_ms.saturating_mul(1 << attempt);
+                log_info!("CarSystem", "   🔁 {} failed to initialize ({}), retry {}/{} in {} ms",
+                         component.name(), e, attempt + 1, init.retries, delay_ms);
+                thread::sleep(Duration::from_millis(delay_ms));
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Set an engine up for a system: its own random stream and the
+/// configured sensors
+fn fit_engine(mut engine: EngineComponent, config: &SystemConfig, rng: &SimRng) -> EngineComponent {
+    engine.set_rng(rng.fork("engine"));
+    engine.set_sensor_noise(config.sensor_noise);
+    engine.set_temperature_channels(config.sensors.channels);
+    engine
+}
+
+/// Set brakes up for a system: the configured pressure sensors
+fn fit_brakes(mut brakes: BrakesComponent, config: &SystemConfig) -> BrakesComponent {
+    brakes.set_pressure_channels(config.sensors.channels);
+    brakes
+}
+
+/// Put a component handed back by its actor into its slot
+fn restore<T: 'static>(slot: &mut T, component: Box<dyn Any + Send>) {
+    if let Ok(component) = component.downcast::<T>() {
+        *slot = *component;
+    }
+}
+
+const CORE_COMPONENTS: [&str; 4] = ["Engine", "Brakes", "Steering", "Dashboard"];
+
+/// Components the threaded execution mode runs as actors
+const ACTOR_IDS: [ComponentId; 4] =
+    [ComponentId::ENGINE, ComponentId::BRAKES, ComponentId::STEERING, ComponentId::DASHBOARD];
+
+/// Car system builder - assembles a system from custom or additional
+/// components without editing `CarSystem::with_config`
+///
+/// Core components not provided are created with their defaults. `build()`
+/// validates the assembly: additional components need unique names that
+/// don't shadow a core component, nothing may be initialized yet (the
+/// system owns the lifecycle), and the configuration, including the safety
+/// limits, must pass `SystemConfig::validate`.
+pub struct CarSystemBuilder {
+    config: SystemConfig,
+    engine: Option<EngineComponent>,
+    brakes: Option<BrakesComponent>,
+    steering: Option<SteeringComponent>,
+    dashboard: Option<DashboardComponent>,
+    components: Vec<Box<dyn CarComponent + Send>>,
+    /// Additional components whose initialization failure aborts startup
+    critical: Vec<&'static str>,
+    workflows: Vec<(String, Workflow)>,
+}
+
+impl CarSystemBuilder {
+    /// Create a builder with the default configuration
+    pub fn new() -> Self {
+        Self {
+            config: SystemConfig::default(),
+            engine: None,
+            brakes: None,
+            steering: None,
+            dashboard: None,
+            components: Vec::new(),
+            critical: Vec::new(),
+            workflows: Vec::new(),
+        }
+    }
+
+    /// Use a loaded configuration (safety limits set later still apply)
+    pub fn with_config(mut self, config: SystemConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Use a custom engine (gets the system's random stream and sensors)
+    pub fn with_engine(mut self, engine: EngineComponent) -> Self {
+        self.engine = Some(engine);
+        self
+    }
+
+    /// Use custom brakes (get the configured pressure sensors)
+    pub fn with_brakes(mut self, brakes: BrakesComponent) -> Self {
+        self.brakes = Some(brakes);
+        self
+    }
+
+    pub fn with_steering(mut self, steering: SteeringComponent) -> Self {
+        self.steering = Some(steering);
+        self
+    }
+
+    pub fn with_dashboard(mut self, dashboard: DashboardComponent) -> Self {
+        self.dashboard = Some(dashboard);
+        self
+    }
+
+    /// Add a component processed after the core components
+    pub fn with_component<C: CarComponent + Send + 'static>(mut self, component: C) -> Self {
+        self.components.push(Box::new(component));
+        self
+    }
+
+    /// Add a component processed after the core components that the car
+    /// cannot run without (see `InitPolicy`)
+    pub fn with_critical_component<C: CarComponent + Send + 'static>(mut self, component: C) -> Self {
+        self.critical.push(component.name());
+        self.with_component(component)
+    }
+
+    /// Register a workflow that can be triggered by name, replacing a
+    /// built-in one with the same name
+    pub fn with_workflow(mut self, name: &str, workflow: Workflow) -> Self {
+        self.workflows.push((name.to_string(), workflow));
+        self
+    }
+
+    /// Override the safety limits of the configuration
+    pub fn with_safety_limits(mut self, limits: SafetyConfig) -> Self {
+        self.config.safety = limits;
+        self
+    }
+
+    /// Validate the assembly and create the system
+    pub fn build(self) -> Result<CarSystem, String> {
+        self.validate()?;
+
+        let mut system = CarSystem::with_config(self.config);
+        if let Some(engine) = self.engine {
+            system.engine = fit_engine(engine, &system.config, &system.rng);
+        }
+        if let Some(brakes) = self.brakes {
+            system.brakes = fit_brakes(brakes, &system.config);
+        }
+        if let Some(steering) = self.steering {
+            system.steering = steering;
+        }
+        if let Some(dashboard) = self.dashboard {
+            system.dashboard = dashboard;
+        }
+        for component in &self.components {
+            system.register_component(component.name());
+        }
+        system.extra_components = self.components;
+        system.critical.extend(self.critical);
+        for (name, workflow) in self.workflows {
+            system.workflows.register(&name, workflow);
+        }
+        let emergency = &system.config.emergency_workflow;
+        if !emergency.is_empty() && !system.workflows.contains(emergency) {
+            return Err(format!("safety.emergency_workflow: unknown workflow '{}'", emergency));
+        }
+        let degraded = &system.config.watchdog.workflow;
+        if !degraded.is_empty() && !system.workflows.contains(degraded) {
+            return Err(format!("watchdog.workflow: unknown workflow '{}'", degraded));
+        }
+        system.apply_time_step();
+        Ok(system)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+
+        let core = [
+            self.engine.as_ref().map(|c| c as &dyn CarComponent),
+            self.brakes.as_ref().map(|c| c as &dyn CarComponent),
+            self.steering.as_ref().map(|c| c as &dyn CarComponent),
+            self.dashboard.as_ref().map(|c| c as &dyn CarComponent),
+        ];
+        let extra = self.components.iter().map(|c| c.as_ref() as &dyn CarComponent);
+        for component in core.into_iter().flatten().chain(extra) {
+            if component.get_state() != ComponentState::Offline {
+                errors.push(format!("{} must not be initialized before build()", component.name()));
+            }
+        }
+
+        let mut names: Vec<&str> = Vec::new();
+        for component in &self.components {
+            let name = component.name();
+            if name.is_empty() {
+                errors.push("Additional components need a name".to_string());
+            } else if CORE_COMPONENTS.contains(&name) {
+                errors.push(format!("Component '{}' shadows a core component (use with_{})",
+                                    name, name.to_lowercase()));
+            } else if names.contains(&name) {
+                errors.push(format!("Component '{}' added twice", name));
+            }
+            names.push(name);
+        }
+
+        if let Err(e) = self.config.validate() {
+            errors.push(e);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Invalid car system: {}", errors.join("; ")))
+        }
+    }
+}
+
+impl Default for CarSystemBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Audit trail of one state machine as JSON
+fn transition_history_json<S>(machine: &TransitionMachine<S>) -> JsonValue
+where
+    S: Clone + PartialEq + fmt::Debug + fmt::Display,
+{
+    let transitions = machine
+        .history()
+        .map(|record| {
+            object([
+                ("tick", JsonValue::from(record.tick)),
+                ("from", JsonValue::from(record.from.to_string())),
+                ("to", JsonValue::from(record.to.to_string())),
+                ("reason", JsonValue::from(record.reason)),
+            ])
+        })
+        .collect();
+    object([
+        ("machine", JsonValue::from(machine.name())),
+        ("state", JsonValue::from(machine.current_state().to_string())),
+        ("transitions", JsonValue::Array(transitions)),
+    ])
+}
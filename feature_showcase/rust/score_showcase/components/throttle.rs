@@ -0,0 +1,138 @@
+//! Throttle component - accelerator pedal driven by a speed controller
+//! Demonstrates S-CORE patterns:
+//! - Closed-loop control: a PI controller sets the pedal position from the
+//!   driver's target speed and the measured vehicle speed
+//! - Anti-windup: the integral stops growing while the pedal is saturated
+//! - Actuator limits: the pedal closes while the brakes are applied
+//!
+//! Only used with physics dynamics (see `VehicleDynamics`).
+
+use crate::components::{CarComponent, ComponentState, HealthStatus};
+use crate::components::event_loop::DEFAULT_TIME_STEP_S;
+use crate::log_info;
+
+/// Pedal % per km/h of speed error
+const PROPORTIONAL_GAIN: f32 = 8.0;
+
+/// Pedal % per km/h of speed error and second
+const INTEGRAL_GAIN: f32 = 2.0;
+
+/// Throttle component - the driver's right foot
+pub struct ThrottleComponent {
+    state: ComponentState,
+    /// Pedal position, 0-100%
+    position: u8,
+    target_speed: u8,
+    vehicle_speed: u8,
+    /// Brakes applied: the pedal is released
+    braking: bool,
+    /// Integral part of the controller output, in %
+    integral: f32,
+    /// Simulated seconds per process() call
+    dt_s: f32,
+}
+
+impl ThrottleComponent {
+    pub fn new() -> Self {
+        Self {
+            state: ComponentState::Offline,
+            position: 0,
+            target_speed: 0,
+            vehicle_speed: 0,
+            braking: false,
+            integral: 0.0,
+            dt_s: DEFAULT_TIME_STEP_S,
+        }
+    }
+
+    /// Set the simulated time covered by each process() call
+    pub fn set_time_step(&mut self, dt_s: f32) {
+        self.dt_s = dt_s;
+    }
+
+    /// Set the speed the driver wants, in km/h
+    pub fn set_target_speed(&mut self, km_h: u8) {
+        self.target_speed = km_h;
+    }
+
+    /// Set the measured vehicle speed, in km/h
+    pub fn set_vehicle_speed(&mut self, km_h: u8) {
+        self.vehicle_speed = km_h;
+    }
+
+    /// Tell the controller whether the brakes are applied
+    pub fn set_braking(&mut self, braking: bool) {
+        self.braking = braking;
+    }
+
+    /// Pedal position in %
+    pub fn get_position(&self) -> u8 {
+        self.position
+    }
+
+    pub fn get_target_speed(&self) -> u8 {
+        self.target_speed
+    }
+}
+
+impl Default for ThrottleComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CarComponent for ThrottleComponent {
+    fn name(&self) -> &'static str {
+        "Throttle"
+    }
+
+    fn initialize(&mut self) -> Result<(), String> {
+        log_info!("Throttle", "🔧 Throttle: Initializing component...");
+        self.state = ComponentState::Initializing;
+
+        log_info!("Throttle", "  🔍 Throttle: Checking pedal position sensors... OK");
+        self.position = 0;
+        self.integral = 0.0;
+
+        self.state = ComponentState::Online;
+        log_info!("Throttle", "✅ Throttle: Initialized (state: {})", self.state);
+        Ok(())
+    }
+
+    fn process(&mut self) -> Result<(), String> {
+        if self.braking {
+            self.position = 0;
+            self.integral = 0.0;
+            return Ok(());
+        }
+        let error = self.target_speed as f32 - self.vehicle_speed as f32;
+        let output = PROPORTIONAL_GAIN * error + self.integral;
+        // Anti-windup: only integrate while the output isn't saturated
+        // in the direction of the error
+        if (output < 100.0 || error < 0.0) && (output > 0.0 || error > 0.0) {
+            self.integral = (self.integral + INTEGRAL_GAIN * error * self.dt_s).clamp(0.0, 100.0);
+        }
+        self.position = output.clamp(0.0, 100.0).round() as u8;
+        Ok(())
+    }
+
+    fn get_state(&self) -> ComponentState {
+        self.state.clone()
+    }
+
+    fn shutdown(&mut self) -> Result<(), String> {
+        log_info!("Throttle", "🔧 Throttle: Shutting down...");
+        self.position = 0;
+        self.integral = 0.0;
+        self.state = ComponentState::Offline;
+        log_info!("Throttle", "✅ Throttle: Pedal released (state: {})", self.state);
+        Ok(())
+    }
+
+    fn health_check(&self) -> HealthStatus {
+        if !self.integral.is_finite() {
+            return HealthStatus::Unhealthy("controller state is not a number");
+        }
+        HealthStatus::from_state(&self.state)
+    }
+}
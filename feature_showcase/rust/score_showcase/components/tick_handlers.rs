@@ -0,0 +1,161 @@
+//! Tick handler registration
+//! Demonstrates S-CORE event loop composition patterns:
+//! - Independent handlers (config reload, physics, safety, display)
+//!   register separately instead of sharing one monolithic closure
+//! - Each tick runs three phases in order: pre-tick, tick, post-tick;
+//!   inside a phase handlers run by priority (lower first), then in
+//!   registration order
+//! - The first failing handler ends the tick; error handlers then see the
+//!   failure before the loop's error policy applies
+//!
+//! Handlers share a context (`C`) instead of capturing it, so several of
+//! them can work on the same state:
+//!
+//! ```ignore
+//! let mut handlers = TickHandlers::new();
+//! handlers.on(TickPhase::Tick, "physics", 0, |car: &mut Car, _| car.step());
+//! handlers.on(TickPhase::PostTick, "safety", 10, |car: &mut Car, _| car.check());
+//! handlers.on_error("log", 0, |_, tick, e| eprintln!("tick {}: {}", tick, e));
+//! event_loop.run_handlers(100, &mut handlers, &mut car);
+//! ```
+
+use std::fmt;
+
+/// Part of a tick a handler runs in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TickPhase {
+    PreTick,
+    Tick,
+    PostTick,
+}
+
+impl TickPhase {
+    pub const ALL: [TickPhase; 3] = [TickPhase::PreTick, TickPhase::Tick, TickPhase::PostTick];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TickPhase::PreTick => "pre-tick",
+            TickPhase::Tick => "tick",
+            TickPhase::PostTick => "post-tick",
+        }
+    }
+}
+
+impl fmt::Display for TickPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+type TickFn<'a, C> = Box<dyn FnMut(&mut C, u64) -> Result<(), String> + 'a>;
+type ErrorFn<'a, C> = Box<dyn FnMut(&mut C, u64, &str) + 'a>;
+
+struct Registered<F> {
+    name: String,
+    priority: i32,
+    handler: F,
+}
+
+/// Handlers of every tick phase plus the error handlers
+pub struct TickHandlers<'a, C> {
+    /// One list per `TickPhase`, kept sorted by priority
+    phases: [Vec<Registered<TickFn<'a, C>>>; 3],
+    on_error: Vec<Registered<ErrorFn<'a, C>>>,
+}
+
+impl<'a, C> Default for TickHandlers<'a, C> {
+    fn default() -> Self {
+        Self {
+            phases: [Vec::new(), Vec::new(), Vec::new()],
+            on_error: Vec::new(),
+        }
+    }
+}
+
+impl<'a, C> TickHandlers<'a, C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a handler for a phase
+    /// Its errors are reported as "<name>: <error>".
+    pub fn on<F>(&mut self, phase: TickPhase, name: &str, priority: i32, handler: F) -> &mut Self
+    where
+        F: FnMut(&mut C, u64) -> Result<(), String> + 'a,
+    {
+        insert_sorted(&mut self.phases[phase as usize], Registered {
+            name: name.to_string(),
+            priority,
+            handler: Box::new(handler),
+        });
+        self
+    }
+
+    /// Register a handler called with the tick number and error of every
+    /// failed tick
+    pub fn on_error<F>(&mut self, name: &str, priority: i32, handler: F) -> &mut Self
+    where
+        F: FnMut(&mut C, u64, &str) + 'a,
+    {
+        insert_sorted(&mut self.on_error, Registered {
+            name: name.to_string(),
+            priority,
+            handler: Box::new(handler),
+        });
+        self
+    }
+
+    /// Remove every handler with this name; returns whether one was found
+    pub fn remove(&mut self, name: &str) -> bool {
+        let before = self.len();
+        for handlers in &mut self.phases {
+            handlers.retain(|registered| registered.name != name);
+        }
+        self.on_error.retain(|registered| registered.name != name);
+        self.len() < before
+    }
+
+    /// Names of a phase's handlers in the order they run
+    pub fn names(&self, phase: TickPhase) -> Vec<&str> {
+        self.phases[phase as usize].iter().map(|registered| registered.name.as_str()).collect()
+    }
+
+    /// Names of the error handlers in the order they run
+    pub fn error_handler_names(&self) -> Vec<&str> {
+        self.on_error.iter().map(|registered| registered.name.as_str()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.phases.iter().map(Vec::len).sum::<usize>() + self.on_error.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Run all phases of one tick, then the error handlers if it failed
+    pub fn run_tick(&mut self, context: &mut C, tick: u64) -> Result<(), String> {
+        let result = self.run_phases(context, tick);
+        if let Err(e) = &result {
+            for registered in &mut self.on_error {
+                (registered.handler)(context, tick, e);
+            }
+        }
+        result
+    }
+
+    fn run_phases(&mut self, context: &mut C, tick: u64) -> Result<(), String> {
+        for handlers in &mut self.phases {
+            for registered in handlers.iter_mut() {
+                (registered.handler)(context, tick).map_err(|e| format!("{}: {}", registered.name, e))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Insert after every handler of the same or a lower priority
+fn insert_sorted<F>(handlers: &mut Vec<Registered<F>>, registered: Registered<F>) {
+    let index = handlers.partition_point(|other| other.priority <= registered.priority);
+    handlers.insert(index, registered);
+}
@@ -0,0 +1,219 @@
+//! Requirement traceability
+//! Demonstrates S-CORE traceability practices (ISO 26262 style):
+//! - Requirements have stable IDs (e.g. `REQ-SAFE-004`)
+//! - Safety rules, workflow steps and verification activities are tagged
+//! - A generated matrix links requirement → code items → covering tests
+//!
+//! Safety rules are tagged through `SafetyWarning::requirement()` (and
+//! listed with their safety goal, see `SafetyWarning::goal()`), workflow
+//! steps through `WorkflowBuilder::requirement()`. Verification activities
+//! are listed in `VERIFICATIONS`; projects reusing the components can add
+//! their own tests with `TraceMatrix::add_test`.
+
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::components::json::{object, JsonValue};
+use crate::components::safety::SafetyWarning;
+use crate::components::system::CarSystem;
+
+/// All requirements of the car system (ID, statement)
+pub const REQUIREMENTS: &[(&str, &str)] = &[
+    ("REQ-SAFE-001", "Vehicle speed shall be monitored against the configured limit"),
+    ("REQ-SAFE-002", "Engine temperature shall be monitored against the configured limit"),
+    ("REQ-SAFE-003", "Engine RPM shall be monitored against the configured limit"),
+    ("REQ-SAFE-004", "A low fuel level shall be reported"),
+    ("REQ-SAFE-005", "Excessive brake pressure shall be reported"),
+    ("REQ-SAFE-006", "A moving vehicle with the engine off shall be detected"),
+    ("REQ-SAFE-007", "Implausible jumps of the speed and temperature signals shall be detected"),
+    ("REQ-SAFE-008", "A component that stops being processed shall be detected"),
+    ("REQ-SAFE-009", "Redundant sensor channels that diverge shall be detected"),
+    ("REQ-SAFE-010", "Event loop ticks that miss their deadline shall be reported"),
+    ("REQ-SAFE-011", "Components diagnosed as degraded or unhealthy shall be reported"),
+    ("REQ-SAFE-012", "A traction battery above its temperature limit shall be reported"),
+    ("REQ-SAFE-013", "A traction battery discharged below its minimum charge shall be reported"),
+    ("REQ-ENG-001", "The engine shall only perform valid state transitions"),
+    ("REQ-WF-001", "The engine shall be started before the car is ready to drive"),
+    ("REQ-WF-002", "Shutdown shall release brakes, center steering and stop the engine"),
+    ("REQ-WF-003", "Emergency stop shall apply full brakes before stopping the engine"),
+    ("REQ-WF-004", "A component missing its watchdog deadline shall put the car into degraded mode"),
+    ("REQ-SYS-001", "The system shall end in a safe state despite injected faults"),
+];
+
+/// Code items tagged outside safety rules and workflows (item, requirement)
+const CODE_ITEMS: &[(&str, &str)] = &[
+    ("EngineStateMachine::can_transition_to", "REQ-ENG-001"),
+    ("CarSystem::check_safe_state", "REQ-SYS-001"),
+];
+
+/// Built-in verification activities (name, covered requirements)
+pub const VERIFICATIONS: &[(&str, &[&str])] = &[
+    ("--transition-coverage", &["REQ-ENG-001"]),
+    ("--chaos", &["REQ-SYS-001", "REQ-SAFE-002", "REQ-SAFE-004", "REQ-SAFE-005", "REQ-SAFE-007"]),
+    ("safety monitor demo (run_event_loop)", &["REQ-SAFE-001", "REQ-SAFE-003"]),
+    ("demo workflows (main)", &["REQ-WF-001", "REQ-WF-002", "REQ-WF-003"]),
+];
+
+/// One row of the matrix
+#[derive(Debug, Clone, Default)]
+pub struct TraceEntry {
+    pub statement: String,
+    pub code: Vec<String>,
+    pub tests: Vec<String>,
+}
+
+/// Requirement → code items → covering tests
+pub struct TraceMatrix {
+    pub entries: BTreeMap<String, TraceEntry>,
+    /// Tags that reference an unknown requirement ID
+    pub unknown: Vec<String>,
+}
+
+impl TraceMatrix {
+    /// Collect all tags from the car system
+    pub fn collect() -> Self {
+        let mut matrix = Self {
+            entries: REQUIREMENTS
+                .iter()
+                .map(|(id, statement)| {
+                    let entry = TraceEntry {
+                        statement: statement.to_string(),
+                        ..Default::default()
+                    };
+                    (id.to_string(), entry)
+                })
+                .collect(),
+            unknown: Vec::new(),
+        };
+
+        for warning in SafetyWarning::all_types() {
+            let goal = warning.goal();
+            let item = format!("SafetyWarning::{} ({}, {})", warning.type_name(), goal.id, goal.asil);
+            matrix.add_code(warning.requirement(), &item);
+        }
+
+        let workflows = [
+            CarSystem::create_start_workflow(),
+            CarSystem::create_shutdown_workflow(),
+            CarSystem::create_emergency_stop_workflow(),
+            CarSystem::create_degraded_workflow(),
+        ];
+        for workflow in &workflows {
+            for step in workflow.steps() {
+                if let Some(requirement) = step.requirement() {
+                    let item = format!("Workflow '{}' / {}", workflow.name(), step.name());
+                    matrix.add_code(requirement, &item);
+                }
+            }
+        }
+
+        for (item, requirement) in CODE_ITEMS {
+            matrix.add_code(requirement, item);
+        }
+        for (test, requirements) in VERIFICATIONS {
+            matrix.add_test(test, requirements);
+        }
+        matrix
+    }
+
+    fn entry(&mut self, requirement: &str, tag: &str) -> Option<&mut TraceEntry> {
+        if !self.entries.contains_key(requirement) {
+            self.unknown.push(format!("{} → {}", tag, requirement));
+        }
+        self.entries.get_mut(requirement)
+    }
+
+    /// Record a code item implementing a requirement
+    pub fn add_code(&mut self, requirement: &str, item: &str) {
+        if let Some(entry) = self.entry(requirement, item) {
+            entry.code.push(item.to_string());
+        }
+    }
+
+    /// Record a test covering requirements
+    pub fn add_test(&mut self, test: &str, requirements: &[&str]) {
+        for requirement in requirements {
+            if let Some(entry) = self.entry(requirement, test) {
+                entry.tests.push(test.to_string());
+            }
+        }
+    }
+
+    /// Requirements without implementing code or without covering tests
+    pub fn gaps(&self) -> Vec<String> {
+        let mut gaps = Vec::new();
+        for (id, entry) in &self.entries {
+            if entry.code.is_empty() {
+                gaps.push(format!("{}: no implementing code", id));
+            }
+            if entry.tests.is_empty() {
+                gaps.push(format!("{}: no covering test", id));
+            }
+        }
+        gaps
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        let strings = |items: &[String]| {
+            JsonValue::Array(items.iter().map(|s| JsonValue::from(s.as_str())).collect())
+        };
+        let requirements = self
+            .entries
+            .iter()
+            .map(|(id, entry)| {
+                object([
+                    ("id", JsonValue::from(id.as_str())),
+                    ("statement", JsonValue::from(entry.statement.as_str())),
+                    ("code", strings(&entry.code)),
+                    ("tests", strings(&entry.tests)),
+                ])
+            })
+            .collect();
+        object([
+            ("requirements", JsonValue::Array(requirements)),
+            ("unknown_tags", strings(&self.unknown)),
+        ])
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Traceability Matrix\n\n");
+        out.push_str("| Requirement | Statement | Code | Tests |\n");
+        out.push_str("|---|---|---|---|\n");
+        for (id, entry) in &self.entries {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                id,
+                entry.statement,
+                entry.code.join("<br>"),
+                entry.tests.join("<br>")
+            ));
+        }
+        out
+    }
+
+    /// Write as Markdown (`.md`) or JSON (anything else)
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let text = if path.ends_with(".md") {
+            self.to_markdown()
+        } else {
+            self.to_json().to_pretty_string() + "\n"
+        };
+        fs::write(path, text).map_err(|e| format!("Cannot write '{}': {}", path, e))
+    }
+}
+
+/// Generate the traceability matrix and report gaps
+pub fn run_trace_matrix(path: &str) -> Result<(), String> {
+    let matrix = TraceMatrix::collect();
+    matrix.save(path)?;
+    println!("🔗 Traceability matrix: {} requirements → {}", matrix.entries.len(), path);
+
+    for gap in matrix.gaps() {
+        println!("   ⚠️  {}", gap);
+    }
+    if matrix.unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("tags reference unknown requirements: {}", matrix.unknown.join(", ")))
+    }
+}
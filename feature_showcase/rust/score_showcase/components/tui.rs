@@ -0,0 +1,296 @@
+//! Terminal UI dashboard
+//! Demonstrates S-CORE HMI patterns:
+//! - Full-screen view redrawn in place on the terminal's alternate screen,
+//!   with a single write per frame
+//! - Gauges for speed, RPM, temperature, fuel and brake pressure, scaled
+//!   to and colored by the safety limits
+//! - A scrolling warning panel: warnings are listed when they are raised,
+//!   newest at the bottom
+//! - A message-bus activity view: deliveries per message type since the
+//!   previous frame and in total
+//!
+//! Columns are padded by display width (wide symbols count twice), so
+//! long values can't push the frame borders out of line. Console logging
+//! is paused while the TUI is shown; errors still get through.
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::io::{self, Write as _};
+
+use crate::components::pause_console;
+use crate::components::message_bus::LatencyStats;
+use crate::components::units::UnitSystem;
+use crate::components::{Fault, SafetyConfig, SystemSnapshot};
+
+/// Inner width of the frame, in terminal columns
+const WIDTH: usize = 62;
+
+/// Width of a gauge bar, in terminal columns
+const GAUGE_WIDTH: usize = 30;
+
+/// Warning lines shown in the warning panel
+const WARNING_LINES: usize = 6;
+
+/// Message types shown in the bus activity view
+const ACTIVITY_LINES: usize = 8;
+
+/// Deliveries per frame shown as a full activity bar
+const ACTIVITY_FULL_SCALE: u64 = 20;
+
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const RED: &str = "\x1b[31m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Full-screen dashboard for interactive terminals
+pub struct TuiDashboard {
+    /// Raised warnings as "t<tick> <warning>", newest last
+    warning_log: VecDeque<String>,
+    /// Warnings shown in the previous frame (to detect new ones)
+    active_warnings: Vec<String>,
+    /// Deliveries per message type up to the previous frame
+    delivered: Vec<(&'static str, u64)>,
+    /// Activity of the current frame: (message type, new deliveries, total)
+    activity: Vec<(&'static str, u64, u64)>,
+    frame: String,
+    active: bool,
+    /// Units values are shown in (gauges scale in internal units)
+    units: UnitSystem,
+}
+
+impl TuiDashboard {
+    pub fn new() -> Self {
+        Self {
+            warning_log: VecDeque::with_capacity(WARNING_LINES),
+            active_warnings: Vec::new(),
+            delivered: Vec::new(),
+            activity: Vec::new(),
+            frame: String::new(),
+            active: false,
+            units: UnitSystem::Metric,
+        }
+    }
+
+    /// Set the units speed, temperature and distance are shown in
+    pub fn set_unit_system(&mut self, units: UnitSystem) {
+        self.units = units;
+    }
+
+    /// Switch to the alternate screen and pause console logging
+    pub fn enter(&mut self) {
+        if self.active {
+            return;
+        }
+        self.active = true;
+        pause_console(true);
+        let _ = io::stdout().lock().write_all(b"\x1b[?1049h\x1b[?25l\x1b[2J");
+    }
+
+    /// Restore the terminal and console logging
+    pub fn leave(&mut self) {
+        if !self.active {
+            return;
+        }
+        self.active = false;
+        let mut stdout = io::stdout().lock();
+        let _ = stdout.write_all(b"\x1b[?25h\x1b[?1049l");
+        let _ = stdout.flush();
+        pause_console(false);
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Render a frame for a snapshot and the bus statistics
+    pub fn render(&mut self, snapshot: &SystemSnapshot, limits: &SafetyConfig, faults: &[Fault], bus: &[LatencyStats]) {
+        self.track_warnings(snapshot);
+        self.track_activity(bus);
+
+        let units = self.units;
+        let out = &mut self.frame;
+        out.clear();
+        out.push_str("\x1b[H");
+
+        let title = format!(" S-CORE Car System  tick {}  engine {} ", snapshot.tick, snapshot.engine_state);
+        line(out, &format!("┌{:─<1$}┐", fit(&title, WIDTH), WIDTH));
+
+        section(out, "Gauges");
+        gauge(out, "Speed", snapshot.speed as f32, limits.max_speed as f32 * 1.25, limits.max_speed as f32,
+              &format!("{:.0} {}", units.speed(snapshot.speed as f32), units.speed_unit()), false);
+        gauge(out, "RPM", snapshot.rpm as f32, limits.max_rpm as f32 * 1.25, limits.max_rpm as f32,
+              &snapshot.rpm.to_string(), false);
+        gauge(out, "Temp", snapshot.temperature, limits.max_temperature * 1.25, limits.max_temperature,
+              &format!("{:.1} {}", units.temperature(snapshot.temperature), units.temperature_unit()), false);
+        gauge(out, "Fuel", snapshot.fuel_level as f32, 100.0, limits.min_fuel as f32,
+              &format!("{} %", snapshot.fuel_level), true);
+        gauge(out, "Brake", snapshot.brake_pressure as f32, 100.0, limits.max_brake_pressure as f32,
+              &format!("{} %", snapshot.brake_pressure), false);
+        if let Some(battery) = &snapshot.battery {
+            gauge(out, "Battery", battery.soc, 100.0, limits.min_battery_soc as f32,
+                  &format!("{:.1} % {}", battery.soc, battery.charging_state), true);
+        }
+        row(out, &format!(" Steering {:>4}°   Odometer {:.1} {}", snapshot.steering_angle,
+                          units.distance(snapshot.odometer), units.distance_unit()));
+
+        section(out, "Warnings");
+        let mut lines = 0;
+        for fault in faults {
+            row(out, &format!(" {}■ {}{}", RED, fit(&fault.to_string(), WIDTH - 3), RESET));
+            lines += 1;
+        }
+        if faults.is_empty() && self.warning_log.is_empty() {
+            row(out, &format!(" {}All systems OK{}", GREEN, RESET));
+            lines += 1;
+        }
+        let skipped = self.warning_log.len().saturating_sub(WARNING_LINES.saturating_sub(lines));
+        for warning in self.warning_log.iter().skip(skipped) {
+            let color = if self.active_warnings.iter().any(|active| warning.ends_with(active.as_str())) {
+                YELLOW
+            } else {
+                DIM
+            };
+            row(out, &format!(" {}{}{}", color, fit(warning, WIDTH - 1), RESET));
+            lines += 1;
+        }
+        for _ in lines..WARNING_LINES {
+            row(out, "");
+        }
+
+        section(out, "Bus activity (this frame / total)");
+        self.activity.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)));
+        for &(message_type, recent, total) in self.activity.iter().take(ACTIVITY_LINES) {
+            let filled = (recent.min(ACTIVITY_FULL_SCALE) as usize * 12).div_ceil(ACTIVITY_FULL_SCALE as usize);
+            row(out, &format!(" {:<22} {}{:<12}{} {:>4} / {:<8}", message_type, GREEN, "█".repeat(filled), RESET,
+                              recent, total));
+        }
+        for _ in self.activity.len().min(ACTIVITY_LINES)..ACTIVITY_LINES {
+            row(out, "");
+        }
+        line(out, &format!("└{:─<1$}┘", "", WIDTH));
+        out.push_str("\x1b[J");
+    }
+
+    /// Draw the most recently rendered frame with a single write
+    pub fn print_frame(&self) {
+        let mut stdout = io::stdout().lock();
+        let _ = stdout.write_all(self.frame.as_bytes());
+        let _ = stdout.flush();
+    }
+
+    /// Log newly raised warnings
+    fn track_warnings(&mut self, snapshot: &SystemSnapshot) {
+        for warning in &snapshot.warnings {
+            if !self.active_warnings.contains(warning) {
+                if self.warning_log.len() == WARNING_LINES {
+                    self.warning_log.pop_front();
+                }
+                self.warning_log.push_back(format!("t{:<4} {}", snapshot.tick, warning));
+            }
+        }
+        self.active_warnings.clone_from(&snapshot.warnings);
+    }
+
+    /// Deliveries per message type since the previous frame
+    fn track_activity(&mut self, bus: &[LatencyStats]) {
+        self.activity.clear();
+        for stats in bus {
+            let previous = match self.delivered.iter_mut().find(|(name, _)| *name == stats.message_type) {
+                Some((_, count)) => std::mem::replace(count, stats.count),
+                None => {
+                    self.delivered.push((stats.message_type, stats.count));
+                    0
+                }
+            };
+            self.activity.push((stats.message_type, stats.count.saturating_sub(previous), stats.count));
+        }
+    }
+}
+
+impl Default for TuiDashboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for TuiDashboard {
+    /// Never leave the terminal on the alternate screen, also on errors
+    fn drop(&mut self) {
+        self.leave();
+    }
+}
+
+/// Append a line, clearing the rest of the terminal row
+fn line(out: &mut String, text: &str) {
+    out.push_str(text);
+    out.push_str("\x1b[K\n");
+}
+
+/// Append a framed row, padded to the frame width
+fn row(out: &mut String, text: &str) {
+    let padding = WIDTH.saturating_sub(display_width(text));
+    let _ = writeln!(out, "│{}{:2$}│\x1b[K", text, "", padding);
+}
+
+/// Append a section separator with a title
+fn section(out: &mut String, title: &str) {
+    let title = format!(" {} ", title);
+    line(out, &format!("├{:─<1$}┤", title, WIDTH));
+}
+
+/// Append a gauge row; `limit` switches the color to red (above it, or
+/// below it for `low_is_bad` gauges) and the last 10% before it to yellow
+fn gauge(out: &mut String, label: &str, value: f32, full_scale: f32, limit: f32, text: &str, low_is_bad: bool) {
+    let fraction = if full_scale > 0.0 { (value / full_scale).clamp(0.0, 1.0) } else { 0.0 };
+    let filled = (fraction * GAUGE_WIDTH as f32).round() as usize;
+    let color = if low_is_bad {
+        if value <= limit { RED } else if value <= limit * 2.0 { YELLOW } else { GREEN }
+    } else if value > limit {
+        RED
+    } else if value > limit * 0.9 {
+        YELLOW
+    } else {
+        GREEN
+    };
+    row(out, &format!(" {:<8} {}{}{}{}{} {}", label, color, "█".repeat(filled), DIM,
+                      "░".repeat(GAUGE_WIDTH - filled), RESET, text));
+}
+
+/// Longest prefix of `text` that fits into `width` columns
+fn fit(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (index, c) in text.char_indices() {
+        used += display_width(c.encode_utf8(&mut [0; 4]));
+        if used > width {
+            return &text[..index];
+        }
+    }
+    text
+}
+
+/// Terminal columns a string takes up
+/// ANSI escape sequences take none, combining marks and variation
+/// selectors none, wide symbols (emoji, CJK) two.
+pub fn display_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip a CSI sequence up to its final byte
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        width += match c as u32 {
+            0x200B..=0x200F | 0x0300..=0x036F | 0xFE00..=0xFE0F => 0,
+            0x1100..=0x115F | 0x2E80..=0xA4CF | 0xAC00..=0xD7A3 | 0xF900..=0xFAFF | 0xFF00..=0xFF60
+            | 0x1F300..=0x1FAFF => 2,
+            _ => 1,
+        };
+    }
+    width
+}
@@ -0,0 +1,142 @@
+//! Display units
+//! Demonstrates S-CORE HMI patterns:
+//! - Signals stay in SI-style units internally (km/h, °C, km, m); units
+//!   are converted only where values are shown to people
+//! - One unit system for the dashboard, the terminal UI and log messages
+//! - Conversion helpers shared by every component that prints values
+//!
+//! Machine-readable output (snapshots, JSON dashboard lines, log fields)
+//! keeps the internal units, named in its keys (e.g. `speed_kmh`).
+
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Kilometers per statute mile
+pub const KM_PER_MILE: f32 = 1.609_344;
+
+/// Meters per foot
+pub const M_PER_FOOT: f32 = 0.3048;
+
+pub fn kmh_to_mph(km_h: f32) -> f32 {
+    km_h / KM_PER_MILE
+}
+
+pub fn celsius_to_fahrenheit(celsius: f32) -> f32 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+pub fn km_to_miles(km: f32) -> f32 {
+    km / KM_PER_MILE
+}
+
+pub fn meters_to_feet(m: f32) -> f32 {
+    m / M_PER_FOOT
+}
+
+/// Units values are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitSystem {
+    /// km/h, °C, km and m
+    Metric,
+    /// mph, °F, miles and feet
+    Imperial,
+}
+
+impl UnitSystem {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnitSystem::Metric => "metric",
+            UnitSystem::Imperial => "imperial",
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<UnitSystem, String> {
+        match s.trim().to_lowercase().as_str() {
+            "metric" => Ok(UnitSystem::Metric),
+            "imperial" => Ok(UnitSystem::Imperial),
+            _ => Err(format!("Invalid unit system: {} (metric|imperial)", s)),
+        }
+    }
+
+    /// Speed given in km/h
+    pub fn speed(&self, km_h: f32) -> f32 {
+        match self {
+            UnitSystem::Metric => km_h,
+            UnitSystem::Imperial => kmh_to_mph(km_h),
+        }
+    }
+
+    pub fn speed_unit(&self) -> &'static str {
+        match self {
+            UnitSystem::Metric => "km/h",
+            UnitSystem::Imperial => "mph",
+        }
+    }
+
+    /// Temperature given in °C
+    pub fn temperature(&self, celsius: f32) -> f32 {
+        match self {
+            UnitSystem::Metric => celsius,
+            UnitSystem::Imperial => celsius_to_fahrenheit(celsius),
+        }
+    }
+
+    pub fn temperature_unit(&self) -> &'static str {
+        match self {
+            UnitSystem::Metric => "°C",
+            UnitSystem::Imperial => "°F",
+        }
+    }
+
+    /// Distance given in km
+    pub fn distance(&self, km: f32) -> f32 {
+        match self {
+            UnitSystem::Metric => km,
+            UnitSystem::Imperial => km_to_miles(km),
+        }
+    }
+
+    pub fn distance_unit(&self) -> &'static str {
+        match self {
+            UnitSystem::Metric => "km",
+            UnitSystem::Imperial => "mi",
+        }
+    }
+
+    /// Short distance given in m
+    pub fn length(&self, m: f32) -> f32 {
+        match self {
+            UnitSystem::Metric => m,
+            UnitSystem::Imperial => meters_to_feet(m),
+        }
+    }
+
+    pub fn length_unit(&self) -> &'static str {
+        match self {
+            UnitSystem::Metric => "m",
+            UnitSystem::Imperial => "ft",
+        }
+    }
+}
+
+impl fmt::Display for UnitSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Units of the log messages of all components (0 = metric, 1 = imperial)
+static DISPLAY_UNITS: AtomicU8 = AtomicU8::new(0);
+
+/// Set the units all components log values in
+pub fn set_display_units(units: UnitSystem) {
+    DISPLAY_UNITS.store(units as u8, Ordering::Relaxed);
+}
+
+/// Units components log values in
+pub fn display_units() -> UnitSystem {
+    match DISPLAY_UNITS.load(Ordering::Relaxed) {
+        0 => UnitSystem::Metric,
+        _ => UnitSystem::Imperial,
+    }
+}
@@ -0,0 +1,125 @@
+//! Longitudinal vehicle dynamics
+//! Demonstrates S-CORE plant model patterns:
+//! - Speed emerges from forces instead of being scripted: drive force
+//!   from the throttle (engine torque through the driveline, capped by
+//!   engine power), brake force from the brake pressure, aerodynamic
+//!   drag and rolling resistance
+//! - The model is owned by the car system, not by a component: components
+//!   only set the inputs (throttle, brakes) and read the resulting speed
+//!
+//! With `DynamicsMode::Scripted` (the default) the demo speed profile is
+//! used directly and this model isn't created.
+
+use std::fmt;
+
+use crate::components::event_loop::DEFAULT_TIME_STEP_S;
+
+/// Wheel force at full throttle, in N
+const MAX_DRIVE_FORCE_N: f32 = 6000.0;
+
+/// Drive power limit, in W (caps the drive force at speed)
+const MAX_DRIVE_POWER_W: f32 = 110_000.0;
+
+/// Wheel force at full brake pressure, in N
+const MAX_BRAKE_FORCE_N: f32 = 15_000.0;
+
+/// Air density times drag coefficient times frontal area, halved (F = k·v²)
+const DRAG_N_PER_M2_S2: f32 = 0.42;
+
+/// Rolling resistance coefficient
+const ROLLING_RESISTANCE: f32 = 0.012;
+
+const GRAVITY_M_S2: f32 = 9.81;
+
+/// Where the vehicle speed comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynamicsMode {
+    /// The demo speed profile is the speed
+    Scripted,
+    /// The demo speed profile is the driver's target; throttle, brakes and
+    /// the longitudinal model produce the speed
+    Physics,
+}
+
+impl DynamicsMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DynamicsMode::Scripted => "scripted",
+            DynamicsMode::Physics => "physics",
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<DynamicsMode, String> {
+        match s.trim().to_lowercase().as_str() {
+            "scripted" => Ok(DynamicsMode::Scripted),
+            "physics" => Ok(DynamicsMode::Physics),
+            _ => Err(format!("Invalid dynamics mode: {} (scripted|physics)", s)),
+        }
+    }
+}
+
+impl fmt::Display for DynamicsMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Longitudinal motion of the car
+pub struct VehicleDynamics {
+    mass_kg: f32,
+    speed_m_s: f32,
+    /// Net acceleration of the last step, in m/s²
+    acceleration: f32,
+    /// Simulated seconds per step
+    dt_s: f32,
+}
+
+impl VehicleDynamics {
+    /// A car of the given mass, standing still
+    pub fn new(mass_kg: f32) -> Self {
+        Self {
+            mass_kg,
+            speed_m_s: 0.0,
+            acceleration: 0.0,
+            dt_s: DEFAULT_TIME_STEP_S,
+        }
+    }
+
+    /// Set the simulated time covered by each step
+    pub fn set_time_step(&mut self, dt_s: f32) {
+        self.dt_s = dt_s;
+    }
+
+    /// Current speed in km/h, saturated to the `u8` the components use
+    pub fn speed_kmh(&self) -> u8 {
+        (self.speed_m_s * 3.6).round().clamp(0.0, u8::MAX as f32) as u8
+    }
+
+    /// Net acceleration of the last step, in m/s²
+    pub fn acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    /// Advance one step
+    /// `throttle` and `brake_pressure` are in %; without propulsion (engine
+    /// off, battery empty) the throttle has no effect.
+    pub fn step(&mut self, throttle: u8, brake_pressure: u8, propulsion: bool) {
+        let v = self.speed_m_s;
+        let drive = if propulsion {
+            let force = throttle.min(100) as f32 / 100.0 * MAX_DRIVE_FORCE_N;
+            force.min(MAX_DRIVE_POWER_W / v.max(1.0))
+        } else {
+            0.0
+        };
+        // Resistances only act against motion; they can't push the car backwards
+        let resistance = if v > 0.0 {
+            brake_pressure.min(100) as f32 / 100.0 * MAX_BRAKE_FORCE_N
+                + DRAG_N_PER_M2_S2 * v * v
+                + ROLLING_RESISTANCE * self.mass_kg * GRAVITY_M_S2
+        } else {
+            0.0
+        };
+        self.acceleration = (drive - resistance) / self.mass_kg;
+        self.speed_m_s = (v + self.acceleration * self.dt_s).max(0.0);
+    }
+}
@@ -0,0 +1,211 @@
+//! Dashboard warnings with a lifecycle
+//! Demonstrates S-CORE HMI patterns:
+//! - Warnings as objects: severity, source component, raised and cleared
+//!   timestamps (simulated seconds since start)
+//! - One warning per condition: raising it again refreshes it and updates
+//!   its text (e.g. the fuel level) instead of adding another line
+//! - Auto-clear: a warning goes away when its condition resolves, or when
+//!   it hasn't been raised again for a while (events without an "all
+//!   clear" counterpart)
+//! - Active warnings ordered most severe first, so a capped display shows
+//!   the ones that matter; cleared ones are kept in a short history
+//!
+//! Steady state (no warnings raised or cleared) doesn't allocate.
+
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+
+use crate::log_debug;
+
+/// Seconds a `ClearPolicy::Timeout` warning stays without being raised again
+pub const WARNING_HOLD_S: f32 = 2.0;
+
+/// Cleared warnings kept in the history
+const CLEARED_HISTORY: usize = 16;
+
+/// How urgent a warning is, least urgent first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WarningSeverity {
+    Info,
+    Caution,
+    Critical,
+}
+
+impl WarningSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WarningSeverity::Info => "info",
+            WarningSeverity::Caution => "caution",
+            WarningSeverity::Critical => "critical",
+        }
+    }
+}
+
+impl fmt::Display for WarningSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// When a warning goes away by itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClearPolicy {
+    /// Cleared by whoever raised it once the condition resolved (or by hand)
+    Resolved,
+    /// Cleared after `WARNING_HOLD_S` without being raised again
+    Timeout,
+}
+
+/// One warning shown on the dashboard
+#[derive(Debug, Clone, PartialEq)]
+pub struct DashboardWarning {
+    /// Stable identifier of the condition, e.g. "low_fuel"
+    pub code: &'static str,
+    /// Component the condition was reported for
+    pub source: &'static str,
+    pub severity: WarningSeverity,
+    pub message: String,
+    pub policy: ClearPolicy,
+    pub raised_at_s: f32,
+    /// Last time the warning was raised (again)
+    pub seen_at_s: f32,
+    pub cleared_at_s: Option<f32>,
+}
+
+/// Active warnings plus a short history of cleared ones
+#[derive(Debug, Clone, Default)]
+pub struct WarningSet {
+    /// Most severe first, then oldest first
+    active: Vec<DashboardWarning>,
+    /// Newest last
+    cleared: VecDeque<DashboardWarning>,
+    /// Messages of the active warnings, in the same order
+    messages: Vec<String>,
+    /// Reused buffer for formatting messages
+    buffer: String,
+}
+
+impl WarningSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Raise a warning, or refresh it if it is already active
+    /// Returns whether it was newly raised.
+    pub fn raise(
+        &mut self,
+        now_s: f32,
+        source: &'static str,
+        code: &'static str,
+        severity: WarningSeverity,
+        policy: ClearPolicy,
+        message: fmt::Arguments,
+    ) -> bool {
+        self.buffer.clear();
+        // Writing to a String cannot fail
+        let _ = self.buffer.write_fmt(message);
+        if let Some(warning) = self.active.iter_mut().find(|w| w.code == code && w.source == source) {
+            warning.seen_at_s = now_s;
+            if warning.message != self.buffer {
+                warning.message.clone_from(&self.buffer);
+                self.update_messages();
+            }
+            return false;
+        }
+        log_debug!("Dashboard", source = source, severity = severity.as_str();
+                   "  ⚠️  Dashboard: Warning raised - {}", self.buffer);
+        self.active.push(DashboardWarning {
+            code,
+            source,
+            severity,
+            message: self.buffer.clone(),
+            policy,
+            raised_at_s: now_s,
+            seen_at_s: now_s,
+            cleared_at_s: None,
+        });
+        // Stable sort keeps equally severe warnings oldest first
+        self.active.sort_by_key(|w| std::cmp::Reverse(w.severity));
+        self.update_messages();
+        true
+    }
+
+    /// Clear an active warning; returns whether it was active
+    pub fn clear(&mut self, now_s: f32, source: &'static str, code: &'static str) -> bool {
+        match self.active.iter().position(|w| w.code == code && w.source == source) {
+            Some(index) => {
+                self.retire(now_s, index);
+                self.update_messages();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Clear every active warning
+    pub fn clear_all(&mut self, now_s: f32) {
+        while !self.active.is_empty() {
+            self.retire(now_s, 0);
+        }
+        self.update_messages();
+    }
+
+    /// Clear the `Timeout` warnings not raised again within `WARNING_HOLD_S`
+    pub fn expire(&mut self, now_s: f32) {
+        let mut changed = false;
+        let mut index = 0;
+        while index < self.active.len() {
+            let warning = &self.active[index];
+            if warning.policy == ClearPolicy::Timeout && now_s - warning.seen_at_s > WARNING_HOLD_S {
+                self.retire(now_s, index);
+                changed = true;
+            } else {
+                index += 1;
+            }
+        }
+        if changed {
+            self.update_messages();
+        }
+    }
+
+    /// Active warnings, most severe first
+    pub fn active(&self) -> &[DashboardWarning] {
+        &self.active
+    }
+
+    /// Messages of the active warnings, most severe first
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+
+    /// Recently cleared warnings, oldest first
+    pub fn cleared(&self) -> impl Iterator<Item = &DashboardWarning> {
+        self.cleared.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    /// Move an active warning to the history
+    fn retire(&mut self, now_s: f32, index: usize) {
+        let mut warning = self.active.remove(index);
+        log_debug!("Dashboard", source = warning.source, active_s = now_s - warning.raised_at_s;
+                   "  ✅ Dashboard: Warning cleared - {}", warning.message);
+        warning.cleared_at_s = Some(now_s);
+        if self.cleared.len() == CLEARED_HISTORY {
+            self.cleared.pop_front();
+        }
+        self.cleared.push_back(warning);
+    }
+
+    fn update_messages(&mut self) {
+        self.messages.truncate(self.active.len());
+        for (index, warning) in self.active.iter().enumerate() {
+            match self.messages.get_mut(index) {
+                Some(message) => message.clone_from(&warning.message),
+                None => self.messages.push(warning.message.clone()),
+            }
+        }
+    }
+}
@@ -0,0 +1,1399 @@
+//! Workflow orchestration
+//! This demonstrates S-CORE's Orchestrator pattern - sequences of actions
+//!
+//! Step closures are boxed one by one, or - for workflows with many steps,
+//! e.g. generated from configuration - stored together in a bump arena
+//! owned by the workflow (`WorkflowBuilder::with_arena`).
+//!
+//! A step can be retried (`RetryPolicy`) so transient failures like an
+//! engine that isn't ready yet don't abort the whole workflow, and can
+//! have a timeout. Actions run on the caller's thread and can't be
+//! interrupted, so an attempt that overruns its timeout is checked when it
+//! returns: the step then fails as timed out, whatever the attempt's own
+//! result. It is not retried, since the late attempt's side effects (e.g.
+//! a started engine) have already happened; only attempts that fail in
+//! time are retried.
+//!
+//! A parallel group (`WorkflowBuilder::parallel`) is one step made of
+//! independent tasks, each with exclusive access to one core component.
+//! The tasks run on their own threads and the group joins them before the
+//! workflow continues; every failed task is reported.
+//!
+//! `Workflow::execute` returns a `WorkflowReport` (status, attempts,
+//! duration and error of every step). `execute_with` additionally calls an
+//! observer with `WorkflowEvent`s as the workflow progresses, so a
+//! dashboard or logger can show progress; `execute` uses the console
+//! observer `print_workflow_event`.
+//!
+//! A step can have a precondition on the car's state, checked before its
+//! first attempt. `Workflow::dry_run` evaluates the preconditions without
+//! running anything, e.g. to validate an emergency procedure up front.
+//!
+//! A `WorkflowRegistry` holds workflows under names, so messages, CLI
+//! commands or safety events can trigger them by name.
+//!
+//! By default a step runs after the one before it. A step can instead
+//! declare its prerequisites (`WorkflowBuilder::after`); the workflow then
+//! is a dependency graph, executed wave by wave (`Workflow::schedule`).
+//! The steps of a wave don't depend on each other, so they all start
+//! together and each runs to completion, even if another one fails; the
+//! next wave waits for all of them. Parallel groups on distinct components
+//! run on their own threads at the same time. Other steps need the whole
+//! car, which can't leave the caller's thread, so they take turns attempt
+//! by attempt: while one waits to retry, the attempts of the others run.
+
+use std::fmt;
+use std::ptr::NonNull;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::components::arena::BumpArena;
+use crate::components::{BrakesComponent, DashboardComponent, EngineComponent, SteeringComponent};
+use crate::{log_error, log_info, log_warn};
+
+/// Action run by a workflow step
+pub type StepAction = dyn Fn(&mut crate::components::system::CarSystem) -> Result<(), String>;
+
+/// Check of the car's state that must pass before a step runs
+pub type Precondition = dyn Fn(&crate::components::system::CarSystem) -> Result<(), String>;
+
+/// Action of a parallel task on one component of type `C`
+pub type TaskFn<C> = dyn Fn(&mut C) -> Result<(), String> + Send + Sync;
+
+/// A failed attempt of a parallel step: attempt, error and retry delay
+type FailedAttempt = (u32, String, Duration);
+
+/// Where a step's closure is stored
+enum ActionStorage {
+    Boxed(Box<StepAction>),
+    /// In the arena of the owning workflow, which outlives its steps
+    Arena(NonNull<StepAction>),
+    /// Tasks of a parallel group, on distinct components
+    Parallel(Vec<ParallelTask>),
+}
+
+/// Action of a parallel task on the one component it works on
+pub enum TaskAction {
+    Engine(Box<TaskFn<EngineComponent>>),
+    Brakes(Box<TaskFn<BrakesComponent>>),
+    Steering(Box<TaskFn<SteeringComponent>>),
+    Dashboard(Box<TaskFn<DashboardComponent>>),
+}
+
+impl TaskAction {
+    fn component(&self) -> &'static str {
+        match self {
+            TaskAction::Engine(_) => "Engine",
+            TaskAction::Brakes(_) => "Brakes",
+            TaskAction::Steering(_) => "Steering",
+            TaskAction::Dashboard(_) => "Dashboard",
+        }
+    }
+}
+
+/// One task of a parallel group
+pub struct ParallelTask {
+    name: String,
+    action: TaskAction,
+}
+
+impl ParallelTask {
+    pub fn engine<F>(name: &str, action: F) -> Self
+    where
+        F: Fn(&mut EngineComponent) -> Result<(), String> + Send + Sync + 'static,
+    {
+        Self::new(name, TaskAction::Engine(Box::new(action)))
+    }
+
+    pub fn brakes<F>(name: &str, action: F) -> Self
+    where
+        F: Fn(&mut BrakesComponent) -> Result<(), String> + Send + Sync + 'static,
+    {
+        Self::new(name, TaskAction::Brakes(Box::new(action)))
+    }
+
+    pub fn steering<F>(name: &str, action: F) -> Self
+    where
+        F: Fn(&mut SteeringComponent) -> Result<(), String> + Send + Sync + 'static,
+    {
+        Self::new(name, TaskAction::Steering(Box::new(action)))
+    }
+
+    pub fn dashboard<F>(name: &str, action: F) -> Self
+    where
+        F: Fn(&mut DashboardComponent) -> Result<(), String> + Send + Sync + 'static,
+    {
+        Self::new(name, TaskAction::Dashboard(Box::new(action)))
+    }
+
+    pub fn new(name: &str, action: TaskAction) -> Self {
+        Self {
+            name: name.to_string(),
+            action,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Component this task has exclusive access to
+    pub fn component(&self) -> &'static str {
+        self.action.component()
+    }
+}
+
+/// Core components a parallel group may use; `None` if another step holds it
+#[derive(Default)]
+struct Parts<'a> {
+    engine: Option<&'a mut EngineComponent>,
+    brakes: Option<&'a mut BrakesComponent>,
+    steering: Option<&'a mut SteeringComponent>,
+    dashboard: Option<&'a mut DashboardComponent>,
+}
+
+impl<'a> Parts<'a> {
+    fn of(system: &'a mut crate::components::system::CarSystem) -> Self {
+        let crate::components::system::CarSystem { engine, brakes, steering, dashboard, .. } = system;
+        Self {
+            engine: Some(engine),
+            brakes: Some(brakes),
+            steering: Some(steering),
+            dashboard: Some(dashboard),
+        }
+    }
+
+    /// Move out the components the tasks work on
+    fn take(&mut self, tasks: &[ParallelTask]) -> Parts<'a> {
+        let mut taken = Parts::default();
+        for task in tasks {
+            match task.action {
+                TaskAction::Engine(_) => taken.engine = self.engine.take(),
+                TaskAction::Brakes(_) => taken.brakes = self.brakes.take(),
+                TaskAction::Steering(_) => taken.steering = self.steering.take(),
+                TaskAction::Dashboard(_) => taken.dashboard = self.dashboard.take(),
+            }
+        }
+        taken
+    }
+}
+
+/// Run the tasks of a parallel group on scoped threads and join them
+/// Errors of all failed tasks are combined into one.
+fn run_parallel(tasks: &[ParallelTask], parts: &mut Parts) -> Result<(), String> {
+    let (mut engine, mut brakes, mut steering, mut dashboard) = (
+        parts.engine.as_deref_mut(),
+        parts.brakes.as_deref_mut(),
+        parts.steering.as_deref_mut(),
+        parts.dashboard.as_deref_mut(),
+    );
+
+    let results: Vec<(&str, Result<(), String>)> = thread::scope(|scope| {
+        let mut handles = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            let taken = format!("{} is used by another task of the group", task.component());
+            let handle = match &task.action {
+                TaskAction::Engine(action) => engine.take().map(|c| scope.spawn(move || action(c))),
+                TaskAction::Brakes(action) => brakes.take().map(|c| scope.spawn(move || action(c))),
+                TaskAction::Steering(action) => steering.take().map(|c| scope.spawn(move || action(c))),
+                TaskAction::Dashboard(action) => dashboard.take().map(|c| scope.spawn(move || action(c))),
+            };
+            handles.push((task.name.as_str(), handle.ok_or(taken)));
+        }
+        handles
+            .into_iter()
+            .map(|(name, handle)| {
+                let result = handle.and_then(|handle| {
+                    handle.join().unwrap_or_else(|_| Err("task panicked".to_string()))
+                });
+                (name, result)
+            })
+            .collect()
+    });
+
+    let errors: Vec<String> = results
+        .into_iter()
+        .filter_map(|(name, result)| result.err().map(|e| format!("{}: {}", name, e)))
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} of {} parallel tasks failed: {}", errors.len(), tasks.len(), errors.join("; ")))
+    }
+}
+
+/// How often a failing step is attempted
+/// The delay before retry n is `backoff * 2^(n-1)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts in total, including the first one (at least 1)
+    pub max_attempts: u32,
+    pub backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// A single attempt
+    pub const NONE: RetryPolicy = RetryPolicy {
+        max_attempts: 1,
+        backoff: Duration::ZERO,
+    };
+
+    pub fn new(max_attempts: u32, backoff: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff,
+        }
+    }
+
+    /// Delay after failed attempt `attempt` (1-based; 0 is treated as 1)
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.backoff.saturating_mul(1 << attempt.saturating_sub(1).min(16))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// Workflow step - a single action in a workflow
+pub struct WorkflowStep {
+    name: String,
+    description: String,
+    /// Requirement this step implements (e.g. "REQ-WF-001")
+    requirement: Option<String>,
+    retry: RetryPolicy,
+    /// Longest an attempt may take before the step fails as timed out
+    timeout: Option<Duration>,
+    /// Steps this one runs after; `None` means the previous step
+    after: Option<Vec<String>>,
+    precondition: Option<Box<Precondition>>,
+    action: ActionStorage,
+}
+
+impl WorkflowStep {
+    /// Create a new workflow step
+    pub fn new(
+        name: &str,
+        description: &str,
+        action: Box<StepAction>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            requirement: None,
+            retry: RetryPolicy::NONE,
+            timeout: None,
+            after: None,
+            precondition: None,
+            action: ActionStorage::Boxed(action),
+        }
+    }
+
+    fn invoke(&self, system: &mut crate::components::system::CarSystem) -> Result<(), String> {
+        match &self.action {
+            ActionStorage::Boxed(action) => action(system),
+            // Safety: arena steps only exist inside the workflow owning the
+            // arena, and the arena is dropped after the steps
+            ActionStorage::Arena(action) => (unsafe { action.as_ref() })(system),
+            ActionStorage::Parallel(tasks) => run_parallel(tasks, &mut Parts::of(system)),
+        }
+    }
+
+    /// Tasks of a parallel group (empty for a plain step)
+    pub fn parallel_tasks(&self) -> &[ParallelTask] {
+        match &self.action {
+            ActionStorage::Parallel(tasks) => tasks,
+            _ => &[],
+        }
+    }
+
+    /// Tag this step with the requirement it implements
+    pub fn with_requirement(mut self, requirement: &str) -> Self {
+        self.requirement = Some(requirement.to_string());
+        self
+    }
+
+    /// Retry this step when it fails
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Fail this step (without retrying) if an attempt takes longer than
+    /// `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Only run this step if `check` passes
+    pub fn with_precondition<F>(mut self, check: F) -> Self
+    where
+        F: Fn(&crate::components::system::CarSystem) -> Result<(), String> + 'static,
+    {
+        self.precondition = Some(Box::new(check));
+        self
+    }
+
+    pub fn has_precondition(&self) -> bool {
+        self.precondition.is_some()
+    }
+
+    /// Evaluate the precondition (Ok if the step has none)
+    pub fn check_precondition(&self, system: &crate::components::system::CarSystem) -> Result<(), String> {
+        match &self.precondition {
+            Some(check) => check(system),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the step name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Requirement this step implements, if tagged
+    pub fn requirement(&self) -> Option<&str> {
+        self.requirement.as_deref()
+    }
+
+    pub fn retry(&self) -> RetryPolicy {
+        self.retry
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Execute this step, reporting every failed attempt
+    pub fn execute(&self, system: &mut crate::components::system::CarSystem) -> Result<(), String> {
+        log_info!("Workflow", "  ▶ Step: {}", self.name);
+        let (attempts, result) = self.run(system, |attempt, error, retry_in| {
+            print_attempt_failed(&self.name, attempt, self.retry.max_attempts, error, retry_in)
+        });
+        result?;
+        print_step_complete(&self.name, attempts, self.retry.max_attempts);
+        Ok(())
+    }
+
+    /// Execute this step without progress output
+    pub fn execute_quiet(&self, system: &mut crate::components::system::CarSystem) -> Result<(), String> {
+        self.run(system, |_, _, _| {}).1
+    }
+
+    /// Steps this one runs after, if declared (else the previous step)
+    pub fn after(&self) -> Option<&[String]> {
+        self.after.as_deref()
+    }
+
+    /// Run attempts until one succeeds or the retry policy is exhausted,
+    /// calling `on_failed_attempt(attempt, error, retry_in)` before retries
+    /// Returns the number of attempts made with the outcome; none are made
+    /// if the precondition fails.
+    fn run<F>(&self, system: &mut crate::components::system::CarSystem, on_failed_attempt: F) -> (u32, Result<(), String>)
+    where
+        F: FnMut(u32, &str, Duration),
+    {
+        if let Err(e) = self.check_precondition(system) {
+            return (0, Err(format!("precondition failed: {}", e)));
+        }
+        run_attempts(&self.name, self.retry, self.timeout, || self.invoke(system), on_failed_attempt)
+    }
+}
+
+/// Attempt `invoke` as `retry` allows; see `WorkflowStep::run`
+fn run_attempts<I, F>(
+    name: &str,
+    retry: RetryPolicy,
+    timeout: Option<Duration>,
+    mut invoke: I,
+    mut on_failed_attempt: F,
+) -> (u32, Result<(), String>)
+where
+    I: FnMut() -> Result<(), String>,
+    F: FnMut(u32, &str, Duration),
+{
+    let mut attempts = Attempts::new(name, retry, timeout);
+    loop {
+        let start = Instant::now();
+        let result = invoke();
+        match attempts.record(result, start.elapsed()) {
+            Next::Done(result) => return (attempts.made, result),
+            Next::Retry { error, delay } => {
+                on_failed_attempt(attempts.made, &error, delay);
+                thread::sleep(delay);
+            }
+        }
+    }
+}
+
+/// Attempts of one step under its retry policy and timeout
+/// An attempt past the timeout fails the step, even if it succeeded, and
+/// is not retried: its side effects (e.g. a started engine) have happened.
+struct Attempts<'a> {
+    name: &'a str,
+    retry: RetryPolicy,
+    timeout: Option<Duration>,
+    /// Attempts made so far
+    made: u32,
+}
+
+/// How a step goes on after an attempt
+enum Next {
+    /// The step is finished
+    Done(Result<(), String>),
+    /// The attempt failed with `error`; try again after `delay`
+    Retry { error: String, delay: Duration },
+}
+
+impl<'a> Attempts<'a> {
+    fn new(name: &'a str, retry: RetryPolicy, timeout: Option<Duration>) -> Self {
+        Self { name, retry, timeout, made: 0 }
+    }
+
+    /// Record the result of an attempt that took `elapsed`
+    fn record(&mut self, result: Result<(), String>, elapsed: Duration) -> Next {
+        self.made += 1;
+        if let Some(limit) = self.timeout.filter(|limit| elapsed > *limit) {
+            log_warn!("Workflow", "  ⏱️  {}: attempt {} took {} ms (timeout {} ms)", self.name, self.made,
+                      elapsed.as_millis(), limit.as_millis());
+            let error = format!("timed out after {} ms (limit {} ms)", elapsed.as_millis(), limit.as_millis());
+            return Next::Done(Err(error));
+        }
+        let error = match result {
+            Ok(()) => return Next::Done(Ok(())),
+            Err(e) => e,
+        };
+        let max_attempts = self.retry.max_attempts.max(1);
+        if self.made >= max_attempts {
+            let error = if max_attempts > 1 {
+                format!("{} (after {} attempts)", error, self.made)
+            } else {
+                error
+            };
+            return Next::Done(Err(error));
+        }
+        Next::Retry { error, delay: self.retry.delay(self.made) }
+    }
+}
+
+/// Workflow - a sequence of steps to execute
+/// This is like S-CORE's Orchestrator - manages complex procedures
+pub struct Workflow {
+    name: String,
+    description: String,
+    steps: Vec<WorkflowStep>,
+    /// Storage of step closures (declared after `steps` so it's dropped last)
+    arena: Option<BumpArena>,
+}
+
+impl Workflow {
+    /// Create a new workflow
+    pub fn new(name: &str, description: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            steps: Vec::new(),
+            arena: None,
+        }
+    }
+
+    /// Create a workflow that stores step closures in an arena
+    pub fn with_arena(name: &str, description: &str) -> Self {
+        Self {
+            arena: Some(BumpArena::new()),
+            ..Self::new(name, description)
+        }
+    }
+
+    /// Add a step to the workflow
+    pub fn add_step(&mut self, step: WorkflowStep) {
+        self.steps.push(step);
+    }
+
+    /// Add a step from a closure, stored in the arena if the workflow has one
+    pub fn add_step_fn<F>(&mut self, name: &str, description: &str, action: F)
+    where
+        F: Fn(&mut crate::components::system::CarSystem) -> Result<(), String> + 'static,
+    {
+        let action = match &mut self.arena {
+            Some(arena) => ActionStorage::Arena(arena.alloc(action) as NonNull<StepAction>),
+            None => ActionStorage::Boxed(Box::new(action)),
+        };
+        self.steps.push(WorkflowStep {
+            name: name.to_string(),
+            description: description.to_string(),
+            requirement: None,
+            retry: RetryPolicy::NONE,
+            timeout: None,
+            after: None,
+            precondition: None,
+            action,
+        });
+    }
+
+    /// Execute all steps, printing progress to the console
+    /// The steps after a failed one are skipped; `WorkflowReport::result`
+    /// turns the report into the usual `Result`.
+    pub fn execute(&self, system: &mut crate::components::system::CarSystem) -> WorkflowReport {
+        self.execute_with(system, print_workflow_event)
+    }
+
+    /// Execute all steps wave by wave, calling `observer` with progress events
+    pub fn execute_with<F>(&self, system: &mut crate::components::system::CarSystem, mut observer: F) -> WorkflowReport
+    where
+        F: FnMut(&WorkflowEvent),
+    {
+        let start = Instant::now();
+        observer(&WorkflowEvent::Started {
+            workflow: &self.name,
+            description: &self.description,
+            steps: self.steps.len(),
+        });
+
+        let mut steps = Vec::with_capacity(self.steps.len());
+        let mut failed = false;
+        for wave in self.schedule() {
+            if failed {
+                steps.extend(wave.iter().map(|&index| StepReport::skipped(&self.steps[index].name)));
+                continue;
+            }
+            let reports = self.run_wave(&wave, system, &mut observer);
+            failed = reports.iter().any(|report| report.status == StepStatus::Failed);
+            steps.extend(reports);
+        }
+
+        let report = WorkflowReport {
+            workflow: self.name.clone(),
+            steps,
+            duration: start.elapsed(),
+        };
+        observer(&WorkflowEvent::Finished { report: &report });
+        report
+    }
+
+    /// Run all steps of a wave, reported in wave order
+    fn run_wave<F>(&self, wave: &[usize], system: &mut crate::components::system::CarSystem,
+                   observer: &mut F) -> Vec<StepReport>
+    where
+        F: FnMut(&WorkflowEvent),
+    {
+        let groups = self.concurrent_steps(wave);
+        let others: Vec<usize> = wave.iter().copied().filter(|index| !groups.contains(index)).collect();
+        let mut reports: Vec<(usize, StepReport)> = Vec::with_capacity(wave.len());
+        if !groups.is_empty() {
+            reports.extend(groups.iter().copied().zip(self.run_concurrently(&groups, system, observer)));
+        }
+        reports.extend(others.iter().copied().zip(self.take_turns(&others, system, observer)));
+        reports.sort_by_key(|(index, _)| *index);
+        reports.into_iter().map(|(_, report)| report).collect()
+    }
+
+    /// Run whole-car steps on the caller's thread, one attempt at a time:
+    /// the next attempt is always that of the step due first, so steps
+    /// waiting to retry don't hold up the others
+    fn take_turns<F>(&self, batch: &[usize], system: &mut crate::components::system::CarSystem,
+                     observer: &mut F) -> Vec<StepReport>
+    where
+        F: FnMut(&WorkflowEvent),
+    {
+        for &index in batch {
+            let step = &self.steps[index];
+            observer(&WorkflowEvent::StepStarted {
+                index,
+                total: self.steps.len(),
+                step: &step.name,
+                description: &step.description,
+            });
+        }
+
+        let start = Instant::now();
+        let mut reports: Vec<Option<StepReport>> = vec![None; batch.len()];
+        // Position in `batch`, attempts so far and when the next one is due
+        let mut pending: Vec<(usize, Attempts, Instant)> = batch
+            .iter()
+            .enumerate()
+            .map(|(position, &index)| {
+                let step = &self.steps[index];
+                (position, Attempts::new(&step.name, step.retry, step.timeout), start)
+            })
+            .collect();
+        while let Some(next) = (0..pending.len()).min_by_key(|&i| (pending[i].2, pending[i].0)) {
+            let (position, attempts, due) = &mut pending[next];
+            let index = batch[*position];
+            let step = &self.steps[index];
+            if let Some(wait) = due.checked_duration_since(Instant::now()) {
+                thread::sleep(wait);
+            }
+            let precondition = if attempts.made == 0 { step.check_precondition(system) } else { Ok(()) };
+            let outcome = match precondition {
+                Err(e) => Next::Done(Err(format!("precondition failed: {}", e))),
+                Ok(()) => {
+                    let attempt_start = Instant::now();
+                    let result = step.invoke(system);
+                    attempts.record(result, attempt_start.elapsed())
+                }
+            };
+            match outcome {
+                Next::Retry { error, delay } => {
+                    observer(&WorkflowEvent::AttemptFailed {
+                        index,
+                        step: &step.name,
+                        attempt: attempts.made,
+                        max_attempts: step.retry.max_attempts,
+                        error: &error,
+                        retry_in: delay,
+                    });
+                    *due = Instant::now() + delay;
+                }
+                Next::Done(result) => {
+                    let report = StepReport::finished(&step.name, attempts.made, start.elapsed(), result);
+                    observer(&WorkflowEvent::StepFinished {
+                        index,
+                        report: &report,
+                        max_attempts: step.retry.max_attempts,
+                    });
+                    reports[*position] = Some(report);
+                    pending.swap_remove(next);
+                }
+            }
+        }
+        reports.into_iter().flatten().collect()
+    }
+
+    /// Parallel groups of a wave that can run at the same time (none if
+    /// fewer than two); a group on a component already taken, or with a
+    /// precondition on the whole car, runs later
+    fn concurrent_steps(&self, wave: &[usize]) -> Vec<usize> {
+        let mut used: Vec<&str> = Vec::new();
+        let mut concurrent = Vec::new();
+        for &index in wave {
+            let tasks = self.steps[index].parallel_tasks();
+            if !tasks.is_empty()
+                && !self.steps[index].has_precondition()
+                && tasks.iter().all(|task| !used.contains(&task.component()))
+            {
+                used.extend(tasks.iter().map(ParallelTask::component));
+                concurrent.push(index);
+            }
+        }
+        if concurrent.len() > 1 {
+            concurrent
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Run parallel groups on their own threads, each with its components
+    /// Failed attempts are reported once all groups have finished.
+    fn run_concurrently<F>(&self, batch: &[usize], system: &mut crate::components::system::CarSystem,
+                           observer: &mut F) -> Vec<StepReport>
+    where
+        F: FnMut(&WorkflowEvent),
+    {
+        for &index in batch {
+            observer(&WorkflowEvent::StepStarted {
+                index,
+                total: self.steps.len(),
+                step: &self.steps[index].name,
+                description: &self.steps[index].description,
+            });
+        }
+
+        let mut parts = Parts::of(system);
+        let mut claimed: Vec<Parts> = batch
+            .iter()
+            .map(|&index| parts.take(self.steps[index].parallel_tasks()))
+            .collect();
+        let outcomes: Vec<(StepReport, Vec<FailedAttempt>)> = thread::scope(|scope| {
+            let handles: Vec<_> = batch
+                .iter()
+                .zip(claimed.iter_mut())
+                .map(|(&index, parts)| {
+                    let step = &self.steps[index];
+                    let (name, tasks, retry, timeout) = (step.name.as_str(), step.parallel_tasks(), step.retry, step.timeout);
+                    let handle = scope.spawn(move || {
+                        let start = Instant::now();
+                        let mut failed_attempts = Vec::new();
+                        let (attempts, result) = run_attempts(name, retry, timeout, || run_parallel(tasks, parts),
+                            |attempt, error, retry_in| failed_attempts.push((attempt, error.to_string(), retry_in)));
+                        (StepReport::finished(name, attempts, start.elapsed(), result), failed_attempts)
+                    });
+                    (name, handle)
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|(name, handle)| {
+                    handle.join().unwrap_or_else(|_| {
+                        (StepReport::finished(name, 1, Duration::ZERO, Err("step panicked".to_string())), Vec::new())
+                    })
+                })
+                .collect()
+        });
+
+        batch
+            .iter()
+            .zip(outcomes)
+            .map(|(&index, (report, failed_attempts))| {
+                let step = &self.steps[index];
+                for (attempt, error, retry_in) in &failed_attempts {
+                    observer(&WorkflowEvent::AttemptFailed {
+                        index,
+                        step: &step.name,
+                        attempt: *attempt,
+                        max_attempts: step.retry.max_attempts,
+                        error,
+                        retry_in: *retry_in,
+                    });
+                }
+                observer(&WorkflowEvent::StepFinished {
+                    index,
+                    report: &report,
+                    max_attempts: step.retry.max_attempts,
+                });
+                report
+            })
+            .collect()
+    }
+
+    /// Prerequisites of step `index`, as step indices
+    pub fn prerequisites(&self, index: usize) -> Vec<usize> {
+        match &self.steps[index].after {
+            None => index.checked_sub(1).into_iter().collect(),
+            Some(names) => names
+                .iter()
+                .filter_map(|name| self.steps[..index].iter().rposition(|step| &step.name == name))
+                .collect(),
+        }
+    }
+
+    /// Step indices in execution waves: a step runs in the wave after the
+    /// last of its prerequisites, so the steps of a wave are independent
+    /// A workflow without declared prerequisites has one step per wave.
+    pub fn schedule(&self) -> Vec<Vec<usize>> {
+        let mut waves: Vec<Vec<usize>> = Vec::new();
+        let mut wave_of = Vec::with_capacity(self.steps.len());
+        for index in 0..self.steps.len() {
+            let wave = self
+                .prerequisites(index)
+                .into_iter()
+                .map(|prerequisite| wave_of[prerequisite] + 1)
+                .max()
+                .unwrap_or(0);
+            if wave == waves.len() {
+                waves.push(Vec::new());
+            }
+            waves[wave].push(index);
+            wave_of.push(wave);
+        }
+        waves
+    }
+
+    /// Evaluate every precondition against the current state, without
+    /// running any step
+    /// Preconditions see the state before the workflow, not the effects of
+    /// the steps before them; steps without one would run unchecked.
+    pub fn dry_run(&self, system: &crate::components::system::CarSystem) -> DryRunReport {
+        let mut steps = Vec::with_capacity(self.steps.len());
+        let mut failed = false;
+        for index in self.schedule().into_iter().flatten() {
+            let step = &self.steps[index];
+            let verdict = if failed {
+                DryRunVerdict::WouldSkip
+            } else if let Err(e) = step.check_precondition(system) {
+                failed = true;
+                DryRunVerdict::WouldFail(e)
+            } else {
+                DryRunVerdict::WouldRun { checked: step.has_precondition() }
+            };
+            steps.push((step.name.clone(), verdict));
+        }
+        DryRunReport {
+            workflow: self.name.clone(),
+            steps,
+        }
+    }
+
+    /// Execute all steps like `execute`, without progress output
+    /// (benchmarks, headless runs)
+    pub fn execute_quiet(&self, system: &mut crate::components::system::CarSystem) -> Result<(), String> {
+        self.execute_with(system, |_| {}).result()
+    }
+
+    /// Get the number of steps
+    pub fn step_count(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Get the workflow name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// All steps in execution order
+    pub fn steps(&self) -> &[WorkflowStep] {
+        &self.steps
+    }
+}
+
+/// Outcome of one step of a workflow run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepStatus {
+    Completed,
+    Failed,
+    /// Not run because an earlier step failed
+    Skipped,
+}
+
+impl StepStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StepStatus::Completed => "completed",
+            StepStatus::Failed => "failed",
+            StepStatus::Skipped => "skipped",
+        }
+    }
+}
+
+/// Result of one step of a workflow run
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepReport {
+    pub name: String,
+    pub status: StepStatus,
+    /// Attempts made (0 if skipped or the precondition failed)
+    pub attempts: u32,
+    pub duration: Duration,
+    pub error: Option<String>,
+}
+
+impl StepReport {
+    fn finished(name: &str, attempts: u32, duration: Duration, result: Result<(), String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: if result.is_ok() { StepStatus::Completed } else { StepStatus::Failed },
+            attempts,
+            duration,
+            error: result.err(),
+        }
+    }
+
+    fn skipped(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            status: StepStatus::Skipped,
+            attempts: 0,
+            duration: Duration::ZERO,
+            error: None,
+        }
+    }
+}
+
+/// Result of a workflow run, one entry per step in execution order
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkflowReport {
+    pub workflow: String,
+    pub steps: Vec<StepReport>,
+    pub duration: Duration,
+}
+
+impl WorkflowReport {
+    /// Check whether every step completed
+    pub fn is_success(&self) -> bool {
+        self.steps.iter().all(|step| step.status == StepStatus::Completed)
+    }
+
+    /// The step that failed, if any
+    pub fn failed_step(&self) -> Option<&StepReport> {
+        self.steps.iter().find(|step| step.status == StepStatus::Failed)
+    }
+
+    /// Ok if every step completed, else the error of the failed step
+    pub fn result(&self) -> Result<(), String> {
+        match self.failed_step() {
+            Some(step) => Err(step.error.clone().unwrap_or_default()),
+            None => Ok(()),
+        }
+    }
+
+    /// Print one line per step with status, attempts and duration
+    pub fn print_summary(&self) {
+        log_info!("Workflow", "📋 Workflow '{}' report ({:.1} ms):", self.workflow, self.duration.as_secs_f64() * 1e3);
+        for step in &self.steps {
+            let icon = match step.status {
+                StepStatus::Completed => "✅",
+                StepStatus::Failed => "❌",
+                StepStatus::Skipped => "⏭️ ",
+            };
+            let error = step.error.as_deref().map_or(String::new(), |error| format!("  {}", error));
+            log_info!("Workflow", "   {} {:<24} {:<9} {:>2} attempt(s) {:>8.1} ms{}",
+                      icon, step.name, step.status.as_str(), step.attempts, step.duration.as_secs_f64() * 1e3, error);
+        }
+    }
+}
+
+/// What a step would do if the workflow ran now
+#[derive(Debug, Clone, PartialEq)]
+pub enum DryRunVerdict {
+    /// `checked`: the step has a precondition and it holds
+    WouldRun { checked: bool },
+    /// The precondition fails with this error
+    WouldFail(String),
+    /// An earlier step would fail
+    WouldSkip,
+}
+
+/// Result of `Workflow::dry_run`, one entry per step in execution order
+#[derive(Debug, Clone, PartialEq)]
+pub struct DryRunReport {
+    pub workflow: String,
+    pub steps: Vec<(String, DryRunVerdict)>,
+}
+
+impl DryRunReport {
+    /// Check whether no precondition fails
+    pub fn would_succeed(&self) -> bool {
+        self.steps
+            .iter()
+            .all(|(_, verdict)| matches!(verdict, DryRunVerdict::WouldRun { .. }))
+    }
+
+    /// The first step whose precondition fails, with the error
+    pub fn failing_step(&self) -> Option<(&str, &str)> {
+        self.steps.iter().find_map(|(name, verdict)| match verdict {
+            DryRunVerdict::WouldFail(error) => Some((name.as_str(), error.as_str())),
+            _ => None,
+        })
+    }
+
+    /// Print one line per step with its verdict
+    pub fn print(&self) {
+        log_info!("Workflow", "🔍 Dry run of workflow '{}':", self.workflow);
+        for (name, verdict) in &self.steps {
+            match verdict {
+                DryRunVerdict::WouldRun { checked: true } => {
+                    log_info!("Workflow", "   ✅ {:<24} would run (precondition holds)", name)
+                }
+                DryRunVerdict::WouldRun { checked: false } => {
+                    log_info!("Workflow", "   ✅ {:<24} would run (no precondition)", name)
+                }
+                DryRunVerdict::WouldFail(error) => log_info!("Workflow", "   ❌ {:<24} would fail: {}", name, error),
+                DryRunVerdict::WouldSkip => log_info!("Workflow", "   ⏭️  {:<24} would be skipped", name),
+            }
+        }
+    }
+}
+
+/// Progress of a workflow run, passed to the observer of `execute_with`
+/// `index` is the 0-based step index.
+#[derive(Debug)]
+pub enum WorkflowEvent<'a> {
+    Started {
+        workflow: &'a str,
+        description: &'a str,
+        steps: usize,
+    },
+    StepStarted {
+        index: usize,
+        total: usize,
+        step: &'a str,
+        description: &'a str,
+    },
+    /// An attempt failed and the step will be retried after `retry_in`
+    AttemptFailed {
+        index: usize,
+        step: &'a str,
+        attempt: u32,
+        max_attempts: u32,
+        error: &'a str,
+        retry_in: Duration,
+    },
+    StepFinished {
+        index: usize,
+        report: &'a StepReport,
+        max_attempts: u32,
+    },
+    Finished {
+        report: &'a WorkflowReport,
+    },
+}
+
+/// Console observer: the progress output of `Workflow::execute`
+pub fn print_workflow_event(event: &WorkflowEvent) {
+    match event {
+        WorkflowEvent::Started { workflow, description, steps } => {
+            log_info!("Workflow", "\n╔══════════════════════════════════════════════════════════════╗");
+            log_info!("Workflow", "║           📋 Workflow: {:<40} ║", &workflow[..workflow.len().min(40)]);
+            log_info!("Workflow", "║           {:<52}║", description);
+            log_info!("Workflow", "╚══════════════════════════════════════════════════════════════╝\n");
+            log_info!("Workflow", "📝 Total steps: {}\n", steps);
+        }
+        WorkflowEvent::StepStarted { index, total, step, description } => {
+            log_info!("Workflow", "─ Step {}/{} ─────────────────────────────────────────────────", index + 1, total);
+            log_info!("Workflow", "  ▶ Step: {} - {}", step, description);
+        }
+        WorkflowEvent::AttemptFailed { step, attempt, max_attempts, error, retry_in, .. } => {
+            print_attempt_failed(step, *attempt, *max_attempts, error, *retry_in)
+        }
+        WorkflowEvent::StepFinished { report, max_attempts, .. } => {
+            match &report.error {
+                None => print_step_complete(&report.name, report.attempts, *max_attempts),
+                Some(error) => log_error!("Workflow", "  ❌ {}: {}", report.name, error),
+            }
+            log_info!("Workflow", "");
+        }
+        WorkflowEvent::Finished { report } => match report.failed_step() {
+            None => log_info!("Workflow", "✅ Workflow '{}' completed successfully!\n", report.workflow),
+            Some(step) => log_error!("Workflow", "❌ Workflow '{}' failed at step '{}'\n", report.workflow, step.name),
+        },
+    }
+}
+
+fn print_attempt_failed(step: &str, attempt: u32, max_attempts: u32, error: &str, retry_in: Duration) {
+    log_info!("Workflow", "  🔁 {}: attempt {}/{} failed ({}), retrying in {} ms",
+             step, attempt, max_attempts, error, retry_in.as_millis());
+}
+
+fn print_step_complete(name: &str, attempts: u32, max_attempts: u32) {
+    if attempts > 1 {
+        log_info!("Workflow", "  ✅ {}: Complete (attempt {}/{})", name, attempts, max_attempts);
+    } else {
+        log_info!("Workflow", "  ✅ {}: Complete", name);
+    }
+}
+
+/// Workflows registered under names
+#[derive(Default)]
+pub struct WorkflowRegistry {
+    workflows: Vec<(String, Workflow)>,
+}
+
+impl WorkflowRegistry {
+    /// An empty registry (`builtin()` has the car's standard workflows)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a workflow, returning the one it replaces
+    pub fn register(&mut self, name: &str, workflow: Workflow) -> Option<Workflow> {
+        let previous = self.remove(name);
+        self.workflows.push((name.to_string(), workflow));
+        previous
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Workflow> {
+        let index = self.workflows.iter().position(|(known, _)| known == name)?;
+        Some(self.workflows.remove(index).1)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Workflow> {
+        self.workflows
+            .iter()
+            .find(|(known, _)| known == name)
+            .map(|(_, workflow)| workflow)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Registered names, in registration order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.workflows.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Execute a registered workflow
+    /// Err only if no workflow has that name; a failed run is in the report.
+    pub fn run(&self, name: &str, system: &mut crate::components::system::CarSystem) -> Result<WorkflowReport, String> {
+        let workflow = self.get(name).ok_or_else(|| {
+            let names: Vec<&str> = self.names().collect();
+            format!("Unknown workflow '{}' (available: {})", name, names.join(", "))
+        })?;
+        Ok(workflow.execute(system))
+    }
+
+    /// Register all workflows of another registry, replacing same names
+    pub fn extend(&mut self, other: WorkflowRegistry) {
+        for (name, workflow) in other.workflows {
+            self.register(&name, workflow);
+        }
+    }
+}
+
+/// Workflow builder - helps construct workflows easily
+pub struct WorkflowBuilder {
+    workflow: Workflow,
+}
+
+impl WorkflowBuilder {
+    /// Create a new workflow builder
+    pub fn new(name: &str, description: &str) -> Self {
+        Self {
+            workflow: Workflow::new(name, description),
+        }
+    }
+
+    /// Create a builder whose workflow stores closures in an arena
+    pub fn with_arena(name: &str, description: &str) -> Self {
+        Self {
+            workflow: Workflow::with_arena(name, description),
+        }
+    }
+
+    /// Add a step to the workflow
+    pub fn step(&mut self, name: &str, description: &str,
+               action: Box<StepAction>) -> &mut Self {
+        self.workflow.add_step(WorkflowStep::new(name, description, action));
+        self
+    }
+
+    /// Add a step from an unboxed closure (arena-stored if enabled)
+    pub fn step_fn<F>(&mut self, name: &str, description: &str, action: F) -> &mut Self
+    where
+        F: Fn(&mut crate::components::system::CarSystem) -> Result<(), String> + 'static,
+    {
+        self.workflow.add_step_fn(name, description, action);
+        self
+    }
+
+    /// Tag the most recently added step with a requirement ID
+    pub fn requirement(&mut self, requirement: &str) -> &mut Self {
+        if let Some(step) = self.workflow.steps.last_mut() {
+            step.requirement = Some(requirement.to_string());
+        }
+        self
+    }
+
+    /// Add a parallel group: the tasks run concurrently, each on its own
+    /// component, and the group completes when all of them have
+    /// Fails if two tasks work on the same component.
+    pub fn parallel(&mut self, name: &str, description: &str, tasks: Vec<ParallelTask>) -> Result<&mut Self, String> {
+        if tasks.is_empty() {
+            return Err(format!("Parallel group '{}' has no tasks", name));
+        }
+        for (index, task) in tasks.iter().enumerate() {
+            if tasks[..index].iter().any(|other| other.component() == task.component()) {
+                return Err(format!("Parallel group '{}': {} is used by more than one task", name, task.component()));
+            }
+        }
+        self.workflow.steps.push(WorkflowStep {
+            name: name.to_string(),
+            description: description.to_string(),
+            requirement: None,
+            retry: RetryPolicy::NONE,
+            timeout: None,
+            after: None,
+            precondition: None,
+            action: ActionStorage::Parallel(tasks),
+        });
+        Ok(self)
+    }
+
+    /// Run the most recently added step after `steps` instead of after
+    /// the step before it (`&[]`: no prerequisites)
+    /// Prerequisites must be added before the step, so there are no cycles.
+    pub fn after(&mut self, steps: &[&str]) -> Result<&mut Self, String> {
+        let Some((step, earlier)) = self.workflow.steps.split_last_mut() else {
+            return Err("No step to add prerequisites to".to_string());
+        };
+        for name in steps {
+            if !earlier.iter().any(|other| other.name == *name) {
+                return Err(format!("Step '{}' can't run after '{}': no such earlier step", step.name, name));
+            }
+        }
+        step.after = Some(steps.iter().map(|name| name.to_string()).collect());
+        Ok(self)
+    }
+
+    /// Only run the most recently added step if `check` passes
+    pub fn precondition<F>(&mut self, check: F) -> &mut Self
+    where
+        F: Fn(&crate::components::system::CarSystem) -> Result<(), String> + 'static,
+    {
+        if let Some(step) = self.workflow.steps.last_mut() {
+            step.precondition = Some(Box::new(check));
+        }
+        self
+    }
+
+    /// Retry the most recently added step: `max_attempts` in total, with
+    /// a doubling delay starting at `backoff`
+    pub fn retry(&mut self, max_attempts: u32, backoff: Duration) -> &mut Self {
+        if let Some(step) = self.workflow.steps.last_mut() {
+            step.retry = RetryPolicy::new(max_attempts, backoff);
+        }
+        self
+    }
+
+    /// Fail the most recently added step (without retrying) if an attempt
+    /// takes longer than `timeout`
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        if let Some(step) = self.workflow.steps.last_mut() {
+            step.timeout = Some(timeout);
+        }
+        self
+    }
+
+    /// Build the workflow
+    pub fn build(self) -> Workflow {
+        self.workflow
+    }
+}
+
+impl fmt::Display for Workflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Workflow[{}] ({} steps)", self.name, self.steps.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::system::CarSystem;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn retry_delay_doubles_and_tolerates_attempt_zero() {
+        let retry = RetryPolicy::new(5, Duration::from_millis(10));
+        assert_eq!(retry.delay(0), Duration::from_millis(10));
+        assert_eq!(retry.delay(1), Duration::from_millis(10));
+        assert_eq!(retry.delay(3), Duration::from_millis(40));
+        assert_eq!(retry.delay(u32::MAX), Duration::from_millis(10 << 16));
+    }
+
+    #[test]
+    fn late_success_times_out_without_retry() {
+        let mut calls = 0;
+        let (attempts, result) = run_attempts(
+            "Start Engine",
+            RetryPolicy::new(3, Duration::ZERO),
+            Some(Duration::ZERO),
+            || {
+                calls += 1;
+                thread::sleep(Duration::from_millis(2));
+                Ok(())
+            },
+            |_, _, _| panic!("a timed out attempt must not be retried"),
+        );
+        assert_eq!((attempts, calls), (1, 1));
+        assert!(result.unwrap_err().starts_with("timed out after"));
+    }
+
+    #[test]
+    fn attempts_within_the_timeout_succeed() {
+        let (attempts, result) = run_attempts(
+            "Check",
+            RetryPolicy::NONE,
+            Some(Duration::from_secs(10)),
+            || Ok(()),
+            |_, _, _| {},
+        );
+        assert_eq!((attempts, result), (1, Ok(())));
+    }
+
+    #[test]
+    fn failures_are_retried_until_success() {
+        let mut calls = 0;
+        let mut failed = Vec::new();
+        let (attempts, result) = run_attempts(
+            "Check",
+            RetryPolicy::new(3, Duration::ZERO),
+            None,
+            || {
+                calls += 1;
+                if calls < 3 { Err("not ready".to_string()) } else { Ok(()) }
+            },
+            |attempt, error, _| failed.push((attempt, error.to_string())),
+        );
+        assert_eq!((attempts, result), (3, Ok(())));
+        assert_eq!(failed, [(1, "not ready".to_string()), (2, "not ready".to_string())]);
+    }
+
+    #[test]
+    fn exhausted_retries_report_the_attempts() {
+        let (attempts, result) = run_attempts(
+            "Check",
+            RetryPolicy::new(2, Duration::ZERO),
+            None,
+            || Err("broken".to_string()),
+            |_, _, _| {},
+        );
+        assert_eq!((attempts, result), (2, Err("broken (after 2 attempts)".to_string())));
+    }
+
+    /// Attempts of the steps in order: step name and attempt start
+    type AttemptLog = Rc<RefCell<Vec<(&'static str, Instant)>>>;
+
+    /// Step action that logs its attempts and fails the first `failures`
+    fn logged(log: &AttemptLog, name: &'static str, failures: usize)
+        -> impl Fn(&mut CarSystem) -> Result<(), String> + 'static
+    {
+        let log = Rc::clone(log);
+        move |_| {
+            let mut log = log.borrow_mut();
+            log.push((name, Instant::now()));
+            if log.iter().filter(|(step, _)| *step == name).count() <= failures {
+                Err("not ready".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// Brakes and Steering checks that each fail once, then Ready after both
+    fn startup_checks(log: &AttemptLog, backoff: Duration) -> Workflow {
+        let mut builder = WorkflowBuilder::new("Startup", "Independent checks");
+        builder.step_fn("Brakes", "Check brakes", logged(log, "Brakes", 1)).retry(2, backoff);
+        builder.step_fn("Steering", "Check steering", logged(log, "Steering", 1)).retry(2, backoff);
+        builder.after(&[]).unwrap();
+        builder.step_fn("Ready", "Announce", logged(log, "Ready", 0));
+        builder.after(&["Brakes", "Steering"]).unwrap();
+        builder.build()
+    }
+
+    #[test]
+    fn independent_steps_overlap_and_dependents_wait() {
+        let log = AttemptLog::default();
+        let backoff = Duration::from_millis(60);
+        let workflow = startup_checks(&log, backoff);
+        assert_eq!(workflow.schedule(), vec![vec![0, 1], vec![2]]);
+
+        let report = workflow.execute_with(&mut CarSystem::new(), |_| {});
+        assert!(report.is_success(), "{:?}", report);
+        let order: Vec<&str> = log.borrow().iter().map(|(step, _)| *step).collect();
+        // Steering is attempted while Brakes waits to retry, and Ready
+        // only runs once both have succeeded
+        assert_eq!(order, ["Brakes", "Steering", "Brakes", "Steering", "Ready"]);
+        assert!(report.duration < backoff * 2, "took {:?}, the retries didn't overlap", report.duration);
+        let attempts: Vec<u32> = report.steps.iter().map(|step| step.attempts).collect();
+        assert_eq!(attempts, [2, 2, 1]);
+    }
+
+    #[test]
+    fn quiet_execution_runs_the_same_waves() {
+        let log = AttemptLog::default();
+        let workflow = startup_checks(&log, Duration::from_millis(5));
+        workflow.execute_quiet(&mut CarSystem::new()).unwrap();
+        let order: Vec<&str> = log.borrow().iter().map(|(step, _)| *step).collect();
+        assert_eq!(order, ["Brakes", "Steering", "Brakes", "Steering", "Ready"]);
+    }
+
+    #[test]
+    fn failed_step_finishes_its_wave_and_skips_the_next() {
+        let log = AttemptLog::default();
+        let mut builder = WorkflowBuilder::new("Startup", "Independent checks");
+        builder.step_fn("Brakes", "Check brakes", logged(&log, "Brakes", 1));
+        builder.step_fn("Steering", "Check steering", logged(&log, "Steering", 0));
+        builder.after(&[]).unwrap();
+        builder.step_fn("Ready", "Announce", logged(&log, "Ready", 0));
+        builder.after(&["Brakes", "Steering"]).unwrap();
+
+        let report = builder.build().execute_with(&mut CarSystem::new(), |_| {});
+        let statuses: Vec<StepStatus> = report.steps.iter().map(|step| step.status).collect();
+        assert_eq!(statuses, [StepStatus::Failed, StepStatus::Completed, StepStatus::Skipped]);
+        assert_eq!(report.result(), Err("not ready".to_string()));
+    }
+}
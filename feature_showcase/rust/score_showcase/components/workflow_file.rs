@@ -0,0 +1,265 @@
+//! Workflow files
+//! Demonstrates S-CORE declarative orchestration patterns:
+//! - Procedures described as data and loaded at runtime, no recompiling
+//! - Steps name an action from a registry instead of carrying code
+//! - Actions take their parameters from the step's `args`
+//!
+//! ```json
+//! {
+//!   "name": "Start Car",
+//!   "description": "Sequence to start the car and prepare for driving",
+//!   "steps": [
+//!     { "name": "Start Engine", "action": "engine.start",
+//!       "requirement": "REQ-WF-001", "retry": { "attempts": 3, "backoff_ms": 100 } },
+//!     { "name": "Initialize Dashboard", "action": "dashboard.set_fuel", "args": { "level": 85 } },
+//!     { "name": "Ready Announcement", "action": "announce", "args": { "message": "Car is ready to drive!" } }
+//!   ]
+//! }
+//! ```
+//!
+//! `description`, `args`, `requirement`, `retry` and `timeout_ms` are
+//! optional. `"after": ["Start Engine"]` makes a step wait for the named
+//! earlier steps instead of the one before it (`[]`: for none). The format is JSON because that's what the crate can parse
+//! without dependencies.
+
+use std::fs;
+use std::time::Duration;
+
+use crate::components::json::JsonValue;
+use crate::components::signal::{SignalId, SignalValue};
+use crate::components::workflow::{StepAction, Workflow, WorkflowBuilder};
+
+/// Creates a step action from the step's `args`
+pub type ActionFactory = fn(&JsonValue) -> Result<Box<StepAction>, String>;
+
+/// Actions every registry starts with: (name, args, factory)
+pub const BUILTIN_ACTIONS: &[(&str, &str, ActionFactory)] = &[
+    ("engine.start", "", engine_start),
+    ("engine.stop", "", engine_stop),
+    ("brakes.apply", "pressure", brakes_apply),
+    ("brakes.release", "", brakes_release),
+    ("steering.turn", "angle", steering_turn),
+    ("steering.center", "", steering_center),
+    ("dashboard.set_fuel", "level", dashboard_set_fuel),
+    ("signal.set", "signal, value", signal_set),
+    ("announce", "message", announce),
+];
+
+fn engine_start(_args: &JsonValue) -> Result<Box<StepAction>, String> {
+    Ok(Box::new(|system| {
+        println!("🔑 Turning key to start engine...");
+        system.engine.start()
+    }))
+}
+
+fn engine_stop(_args: &JsonValue) -> Result<Box<StepAction>, String> {
+    Ok(Box::new(|system| {
+        println!("🔑 Turning off engine...");
+        system.engine.stop()
+    }))
+}
+
+fn brakes_apply(args: &JsonValue) -> Result<Box<StepAction>, String> {
+    let pressure = integer_arg(args, "pressure", 0.0, 100.0)? as u8;
+    Ok(Box::new(move |system| {
+        println!("🛞 Applying brakes ({}%)...", pressure);
+        system.brakes.apply(pressure)
+    }))
+}
+
+fn brakes_release(_args: &JsonValue) -> Result<Box<StepAction>, String> {
+    Ok(Box::new(|system| {
+        println!("🛞 Releasing brakes...");
+        system.brakes.release();
+        Ok(())
+    }))
+}
+
+fn steering_turn(args: &JsonValue) -> Result<Box<StepAction>, String> {
+    let angle = integer_arg(args, "angle", i16::MIN as f64, i16::MAX as f64)? as i16;
+    Ok(Box::new(move |system| {
+        println!("🔄 Turning steering to {}°...", angle);
+        system.steering.turn(angle)
+    }))
+}
+
+fn steering_center(_args: &JsonValue) -> Result<Box<StepAction>, String> {
+    Ok(Box::new(|system| {
+        println!("🔄 Centering steering...");
+        system.steering.center();
+        Ok(())
+    }))
+}
+
+fn dashboard_set_fuel(args: &JsonValue) -> Result<Box<StepAction>, String> {
+    let level = integer_arg(args, "level", 0.0, 100.0)? as u8;
+    Ok(Box::new(move |system| {
+        println!("📊 Setting up dashboard...");
+        system.dashboard.set_fuel_level(level);
+        Ok(())
+    }))
+}
+
+fn signal_set(args: &JsonValue) -> Result<Box<StepAction>, String> {
+    let signal = SignalId::parse(&string_arg(args, "signal")?)?;
+    let value = args
+        .get("value")
+        .and_then(JsonValue::as_f64)
+        .ok_or("argument 'value' missing or not a number")?;
+    Ok(Box::new(move |system| system.set_signal(signal, SignalValue::F32(value as f32))))
+}
+
+fn announce(args: &JsonValue) -> Result<Box<StepAction>, String> {
+    let message = string_arg(args, "message")?;
+    Ok(Box::new(move |_system| {
+        println!("\n✅ {}\n", message);
+        Ok(())
+    }))
+}
+
+fn integer_arg(args: &JsonValue, key: &str, min: f64, max: f64) -> Result<f64, String> {
+    let value = args
+        .get(key)
+        .and_then(JsonValue::as_f64)
+        .ok_or_else(|| format!("argument '{}' missing or not a number", key))?;
+    if value.fract() == 0.0 && value >= min && value <= max {
+        Ok(value)
+    } else {
+        Err(format!("argument '{}' must be an integer in {}..={}", key, min, max))
+    }
+}
+
+fn string_arg(args: &JsonValue, key: &str) -> Result<String, String> {
+    args.get(key)
+        .and_then(JsonValue::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| format!("argument '{}' missing or not a string", key))
+}
+
+/// Named actions that workflow files can refer to
+#[derive(Clone)]
+pub struct ActionRegistry {
+    actions: Vec<(String, ActionFactory)>,
+}
+
+impl ActionRegistry {
+    /// A registry without any actions
+    pub fn empty() -> Self {
+        Self { actions: Vec::new() }
+    }
+
+    /// A registry with the built-in actions
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        for (name, _, factory) in BUILTIN_ACTIONS {
+            registry.register(name, *factory);
+        }
+        registry
+    }
+
+    /// Add an action, replacing one with the same name
+    pub fn register(&mut self, name: &str, factory: ActionFactory) -> &mut Self {
+        self.actions.retain(|(known, _)| known != name);
+        self.actions.push((name.to_string(), factory));
+        self
+    }
+
+    /// Create an action from its name and arguments
+    pub fn create(&self, name: &str, args: &JsonValue) -> Result<Box<StepAction>, String> {
+        let (_, factory) = self
+            .actions
+            .iter()
+            .find(|(known, _)| known == name)
+            .ok_or_else(|| format!("unknown action '{}'", name))?;
+        factory(args)
+    }
+
+    /// Registered action names
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.actions.iter().map(|(name, _)| name.as_str())
+    }
+}
+
+impl Default for ActionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Workflow {
+    /// Build a workflow from its JSON description
+    pub fn from_json(json: &JsonValue, actions: &ActionRegistry) -> Result<Workflow, String> {
+        let name = json
+            .get("name")
+            .and_then(JsonValue::as_str)
+            .ok_or("'name' missing or not a string")?;
+        let description = json.get("description").and_then(JsonValue::as_str).unwrap_or("");
+        let steps = json
+            .get("steps")
+            .and_then(JsonValue::as_array)
+            .ok_or("'steps' missing or not an array")?;
+
+        let mut builder = WorkflowBuilder::new(name, description);
+        for (index, step) in steps.iter().enumerate() {
+            add_step(&mut builder, step, actions).map_err(|e| format!("steps[{}]: {}", index, e))?;
+        }
+        Ok(builder.build())
+    }
+
+    /// Load a workflow file
+    pub fn load(path: &str, actions: &ActionRegistry) -> Result<Workflow, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read workflow '{}': {}", path, e))?;
+        JsonValue::parse(&text)
+            .and_then(|json| Self::from_json(&json, actions))
+            .map_err(|e| format!("{}: {}", path, e))
+    }
+}
+
+fn add_step(builder: &mut WorkflowBuilder, step: &JsonValue, actions: &ActionRegistry) -> Result<(), String> {
+    let name = step
+        .get("name")
+        .and_then(JsonValue::as_str)
+        .ok_or("'name' missing or not a string")?;
+    let description = step.get("description").and_then(JsonValue::as_str).unwrap_or("");
+    let action = step
+        .get("action")
+        .and_then(JsonValue::as_str)
+        .ok_or("'action' missing or not a string")?;
+    let no_args = JsonValue::Object(Default::default());
+    let args = step.get("args").unwrap_or(&no_args);
+    if args.as_object().is_none() {
+        return Err("'args' must be an object".to_string());
+    }
+
+    builder.step(name, description, actions.create(action, args)?);
+    if let Some(requirement) = step.get("requirement") {
+        builder.requirement(requirement.as_str().ok_or("'requirement' must be a string")?);
+    }
+    if let Some(after) = step.get("after") {
+        let names = after
+            .as_array()
+            .and_then(|items| items.iter().map(JsonValue::as_str).collect::<Option<Vec<&str>>>())
+            .ok_or("'after' must be an array of step names")?;
+        builder.after(&names)?;
+    }
+    if let Some(retry) = step.get("retry") {
+        let attempts = retry
+            .get("attempts")
+            .and_then(JsonValue::as_u64)
+            .filter(|attempts| (1..=10).contains(attempts))
+            .ok_or("'retry.attempts' must be an integer in 1..=10")?;
+        let backoff_ms = match retry.get("backoff_ms") {
+            None => 0,
+            Some(value) => value.as_u64().ok_or("'retry.backoff_ms' must be a non-negative integer")?,
+        };
+        builder.retry(attempts as u32, Duration::from_millis(backoff_ms));
+    }
+    if let Some(timeout) = step.get("timeout_ms") {
+        let timeout = timeout
+            .as_u64()
+            .ok_or("'timeout_ms' must be a non-negative integer")?;
+        builder.timeout(Duration::from_millis(timeout));
+    }
+    Ok(())
+}
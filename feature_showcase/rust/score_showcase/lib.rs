@@ -0,0 +1,26 @@
+//
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! S-CORE Showcase library
+//!
+//! The patterns of the car system example as a reusable crate:
+//! - `components`: car components, system assembly and tooling
+//! - `message_bus`: publish/subscribe communication
+//! - `event_loop`: tick-based processing
+//! - `safety`: safety monitoring and rules
+//! - `workflow`: workflow orchestration
+//! - `prelude`: the commonly used types and the stable API surface
+//!
+//! The `car_system_example` and `hello_score_example` binaries are thin
+//! front ends over this crate; downstream projects depend on it the same way
+//! (`//feature_showcase/rust:score_showcase`).
+
+extern crate alloc;
+
+pub mod components;
+pub mod prelude;
+
+pub use components::{event_loop, message_bus, safety, workflow};
@@ -0,0 +1,39 @@
+//! Commonly used types in one import
+//!
+//! ```ignore
+//! use score_showcase::prelude::*;
+//! ```
+//!
+//! Stability: everything re-exported here is the stable API surface of the
+//! crate. Removing or renaming an item, or changing a trait's required
+//! methods, is a breaking change and needs a major version bump. New items
+//! may be added in minor versions - a glob import can then shadow, or be
+//! shadowed by, a local name of the same name.
+//!
+//! Items reached only through `score_showcase::components` (tool modes,
+//! benchmarks, soak and fuzz runners, config internals) are not covered and
+//! may change in any release.
+
+pub use crate::components::{
+    // Component model
+    CarComponent, ComponentState, HealthStatus,
+    // Communication
+    BusHandle, CarMessage, ComponentId, ComponentRegistry, MessageBus, MessagePriority, ReplyStatus,
+    RequestId, SharedMessageBus,
+    // State management
+    EngineStateMachine, EventDriven, StateMachine, StateMachineBuilder,
+    // Signals
+    SignalId, SignalValue,
+    // Safety
+    Fault, SafetyConfig, SafetyMonitor, SafetySeverity, SafetyWarning,
+    // Orchestration
+    DryRunReport, ParallelTask, RetryPolicy, Workflow, WorkflowBuilder, WorkflowEvent, WorkflowRegistry,
+    WorkflowReport, WorkflowStep,
+    // System assembly
+    CarSystem, CarSystemBuilder, SystemConfig,
+    // Logging
+    LogConfig, LogLevel, ScoreLogger,
+};
+
+// Logging macros
+pub use crate::{log_debug, log_error, log_info, log_trace, log_warn};
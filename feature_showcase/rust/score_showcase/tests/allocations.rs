@@ -0,0 +1,44 @@
+//
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Steady-state allocation checks
+//! The message path and the dashboard frame must not allocate once the
+//! system has warmed up. Runs as its own test binary so it can install
+//! `CountingAllocator` as the global allocator.
+
+use std::sync::Mutex;
+
+use score_showcase::components::{message_path_allocations, ClockSource, CountingAllocator};
+use score_showcase::prelude::*;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// The allocation counter is global, so measurements run one at a time
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Configuration the soak test measures with
+fn soak_config() -> SystemConfig {
+    let mut config = SystemConfig::default();
+    config.event_loop.clock = ClockSource::Simulated;
+    config.render = false;
+    config
+}
+
+#[test]
+fn message_path_does_not_allocate_per_tick() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let allocations = message_path_allocations(&soak_config()).expect("message path run failed");
+    assert_eq!(allocations, 0, "steady-state message path allocated {} times", allocations);
+}
+
+#[cfg(feature = "render")]
+#[test]
+fn dashboard_render_does_not_allocate_per_frame() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let allocations = score_showcase::components::render_allocations(&soak_config()).expect("render run failed");
+    assert_eq!(allocations, 0, "steady-state dashboard render allocated {} times", allocations);
+}
@@ -0,0 +1,111 @@
+//
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Full-system scenarios driven through `TestHarness`
+//! Every run uses the simulated clock, so nothing sleeps and results don't
+//! depend on the machine the tests run on.
+
+use score_showcase::components::{Profile, TestHarness};
+use score_showcase::prelude::*;
+use score_showcase::{expect, expect_no_warning, expect_warning};
+
+#[test]
+fn start_car_gets_the_engine_running() {
+    let mut harness = TestHarness::new_system_with_sim_clock().unwrap();
+    harness.start_car().unwrap();
+    harness.tick_n(40).unwrap();
+
+    assert_eq!(harness.current_tick(), 40);
+    assert_eq!(harness.snapshots().len(), 40);
+    let snapshot = harness.last_snapshot().unwrap();
+    assert!(snapshot.engine_running(), "engine state {}", snapshot.engine_state);
+    assert!(snapshot.rpm > 0);
+}
+
+#[test]
+fn same_seed_gives_the_same_run() {
+    let run = |seed| {
+        let mut harness = TestHarness::with_seed(seed).unwrap();
+        harness.start_car().unwrap();
+        harness.tick_n(100).unwrap();
+        harness.snapshots().iter().map(|s| (**s).clone()).collect::<Vec<_>>()
+    };
+    assert_eq!(run(7), run(7));
+}
+
+#[test]
+fn injected_overheating_reaches_the_dashboard() {
+    let mut harness = TestHarness::new_system_with_sim_clock().unwrap();
+    harness.start_car().unwrap();
+    harness.tick_n(10).unwrap();
+    assert!(harness.last_snapshot().unwrap().warnings.is_empty());
+
+    harness.inject(CarMessage::EngineOverheating { temperature: 130.0 });
+    harness.tick().unwrap();
+
+    let warnings = &harness.last_snapshot().unwrap().warnings;
+    assert!(warnings.iter().any(|w| w.contains("overheating")), "warnings {:?}", warnings);
+}
+
+#[test]
+fn speeding_is_reported_by_the_safety_check() {
+    let mut harness = TestHarness::new_system_with_sim_clock().unwrap();
+    harness.start_car().unwrap();
+    let limit = harness.system.config.safety.max_speed;
+
+    let mut reported = 0;
+    for _ in 0..200 {
+        harness.tick().unwrap();
+        for warning in harness.warnings() {
+            if let SafetyWarning::SpeedExceeded { current, max } = *warning {
+                assert_eq!(max, limit);
+                assert!(current > limit, "{} km/h reported as speeding", current);
+                reported += 1;
+            }
+        }
+    }
+    assert!(reported > 0, "the test drive never exceeded {} km/h", limit);
+}
+
+#[test]
+fn requirements_hold_on_a_test_drive() -> Result<(), String> {
+    let mut harness = TestHarness::new_system_with_sim_clock()?;
+    harness.start_car()?;
+
+    expect!(harness, at_tick 40, rpm > 0)?;
+    expect_no_warning!(harness, EngineStateInvalid within 50 ticks)?;
+    expect!(harness, always, rpm < 8000)?;
+    expect!(harness, always, brake_pressure <= 100)?;
+    expect_warning!(harness, SpeedExceeded within 200 ticks)
+}
+
+#[test]
+fn lowered_temperature_limit_raises_overheating() -> Result<(), String> {
+    let mut config = SystemConfig::default();
+    config.apply_profile(Profile::Test)?;
+    config.safety.max_temperature = 21.5;
+    let mut harness = TestHarness::with_config(config)?;
+    expect_no_warning!(harness, Overheating within 5 ticks)?;
+
+    harness.start_car()?;
+    expect_warning!(harness, Overheating within 100 ticks)
+}
+
+#[test]
+fn failed_expectations_name_the_requirement() {
+    let mut harness = TestHarness::new_system_with_sim_clock().unwrap();
+    harness.start_car().unwrap();
+
+    let err = expect!(harness, at_tick 5, rpm > 100_000).unwrap_err();
+    assert!(err.starts_with("expectation failed at tick 5: rpm > 100_000"), "{}", err);
+    assert!(err.contains("snapshot: "), "{}", err);
+
+    let err = expect!(harness, always, speed > 200).unwrap_err();
+    assert!(err.starts_with("expectation failed at tick 0: always speed > 200"), "{}", err);
+
+    let err = expect_warning!(harness, BatteryDeepDischarge within 3 ticks).unwrap_err();
+    assert_eq!(err, "expectation failed: BatteryDeepDischarge warning within 3 ticks (ticks 6..9)");
+}